@@ -7,6 +7,7 @@ use crate::*;
 pub mod conformance {}
 
 #[doc = include_str!("_docs/formal_intro.md")]
+#[allow(clippy::doc_overindented_list_items)]
 pub mod formal_intro {}
 
 #[cfg_attr(feature = "gmp", doc = include_str!("_docs/intro.md"))]
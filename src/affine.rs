@@ -0,0 +1,682 @@
+use crate::{forward_ref::*, interval::*, vector::point};
+use alloc::{vec, vec::Vec};
+use core::{
+    ops::{Add, Div, Mul, Neg, Sub},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NEXT_SYMBOL: AtomicUsize = AtomicUsize::new(0);
+
+fn new_symbol() -> usize {
+    NEXT_SYMBOL.fetch_add(1, Ordering::Relaxed)
+}
+
+/// An affine form $x_0 + \sum_i x_i ε_i + e ε$, where $ε_i, ε ∈ \[-1, 1\]$ are unknowns.
+///
+/// The unknowns $ε_i$ are called noise symbols, which are shared between affine forms to keep
+/// track of their correlation. The noise symbol $ε$ accounts for the rounding errors
+/// accumulated during the computation, and is not shared with other affine forms.
+///
+/// Every affine form created from an [`Interval`] introduces a new noise symbol, which
+/// represents the unknown position of a number in the interval. The arithmetic operations and
+/// the elementary functions return affine forms that enclose the results for every choice of
+/// the noise symbols. The approximation errors of nonlinear operations are represented by
+/// new noise symbols, and the rounding errors are added to $e$.
+///
+/// An affine form can also represent the empty set or the entire real line, which are created
+/// from [`Interval::EMPTY`] and any unbounded interval, respectively. The result of an
+/// operation that overflows is the entire real line.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, AffineForm as A, Interval};
+/// let x = A::from(c!(0.0, 1.0));
+/// assert_eq!(Interval::from(&x - &x), c!(0.0, 0.0));
+/// // x (1 - x)
+/// let y = &x * &(c!(1.0, 1.0) - &x);
+/// assert_eq!(Interval::from(y), c!(0.0, 0.5));
+/// // Compare with the interval evaluation.
+/// let x = c!(0.0, 1.0);
+/// assert_eq!(x * (c!(1.0, 1.0) - x), c!(0.0, 1.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AffineForm {
+    // NaN if the affine form is empty.
+    x0: f64,
+    // The coefficients sorted by the indices of the noise symbols.
+    xs: Vec<(usize, f64)>,
+    // +∞ if the affine form is entire.
+    err: f64,
+}
+
+impl AffineForm {
+    const EMPTY: Self = Self {
+        x0: f64::NAN,
+        xs: vec![],
+        err: 0.0,
+    };
+
+    const ENTIRE: Self = Self {
+        x0: 0.0,
+        xs: vec![],
+        err: f64::INFINITY,
+    };
+
+    /// Returns the central value $x_0$.
+    ///
+    /// NaN is returned if `self` is empty.
+    pub fn center(&self) -> f64 {
+        self.x0
+    }
+
+    /// Returns an iterator over the indices $i$ of the noise symbols and the coefficients $x_i$.
+    pub fn coefficients(&self) -> impl Iterator<Item = (usize, f64)> + '_ {
+        self.xs.iter().copied()
+    }
+
+    /// Returns the accumulated rounding error $e$.
+    pub fn error(&self) -> f64 {
+        self.err
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.x0.is_nan()
+    }
+
+    /// Returns an upper bound of $\sum_i |x_i| + e$, i.e., the maximum deviation from the central
+    /// value.
+    ///
+    /// NaN is returned if `self` is empty.
+    pub fn rad(&self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+        if self.is_entire() {
+            return f64::INFINITY;
+        }
+        self.xs
+            .iter()
+            .fold(point(self.err), |s, &(_, x)| s + point(x.abs()))
+            .sup()
+    }
+
+    /// Returns the tightest interval that contains the range of `self`.
+    pub fn to_interval(&self) -> Interval {
+        if self.is_empty() {
+            return Interval::EMPTY;
+        }
+        let r = self.rad();
+        point(self.x0) + Interval::with_infsup_raw(-r, r)
+    }
+
+    /// Returns the reciprocal of `self`.
+    ///
+    /// The Chebyshev approximation is used if the range of `self` does not contain zero.
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        let x = self.to_interval();
+        if x.contains(0.0) {
+            return Self::from(x.recip());
+        }
+        let one = point(1.0);
+        self.chebyshev(
+            |x| one / x,
+            |a| {
+                let u = (-one / a).sqrt();
+                if x.inf() > 0.0 {
+                    u
+                } else {
+                    -u
+                }
+            },
+        )
+    }
+
+    /// Returns the principal square root of `self`.
+    ///
+    /// The Chebyshev approximation is used if the range of `self` is nonnegative.
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        if self.to_interval().inf() < 0.0 {
+            return Self::from(self.to_interval().sqrt());
+        }
+        // The derivative is 1 / (2 √x).
+        let four = point(4.0);
+        self.chebyshev(|x| x.sqrt(), |a| (four * a.sqr()).recip())
+    }
+
+    // Returns the affine form α x + ζ + δ ε', where `g` = [ζ - δ, ζ + δ] is an enclosure of the
+    // range of f(x) - α x, and ε' is a new noise symbol. If the arguments are not suitable,
+    // returns the affine form of `fallback`.
+    fn affine_approx(&self, alpha: f64, g: Interval, fallback: Interval) -> Self {
+        if !(alpha.is_finite() && g.is_common_interval()) {
+            return Self::from(fallback);
+        }
+        &self.scale(alpha) + &Self::from(g)
+    }
+
+    // Returns the Chebyshev approximation of f(x), where f is either convex or concave over the
+    // range [a, b] of `self`. `df_inv(α)` must return an enclosure of the points in [a, b] where
+    // the derivative of f is α.
+    fn chebyshev(
+        &self,
+        f: impl Fn(Interval) -> Interval,
+        df_inv: impl Fn(Interval) -> Interval,
+    ) -> Self {
+        let x = self.to_interval();
+        if !x.is_common_interval() {
+            return Self::from(f(x));
+        }
+
+        // Since f(x) - α x is also convex or concave, its range is the convex hull of the values
+        // at the endpoints and at the point where its derivative is zero.
+        let a = point(x.inf());
+        let b = point(x.sup());
+        let alpha = ((f(b) - f(a)) / (b - a)).mid();
+        if !alpha.is_finite() {
+            return Self::from(f(x));
+        }
+        let g = |x| f(x) - point(alpha) * x;
+        let u = df_inv(point(alpha)).intersection(x);
+        let range = g(a).convex_hull(g(b)).convex_hull(g(u));
+        self.affine_approx(alpha, range, f(x))
+    }
+
+    fn is_entire(&self) -> bool {
+        self.err == f64::INFINITY
+    }
+
+    // Returns a x + b y with the central value replaced by `c`.
+    fn lin_comb(a: f64, x: &Self, b: f64, y: &Self, c: Interval) -> Self {
+        let mut err = point(a.abs()).mul_add(point(x.err), point(b.abs()) * point(y.err));
+        let x0 = round(c, &mut err);
+        let mut xs = Vec::with_capacity(x.xs.len() + y.xs.len());
+        let (mut i, mut j) = (0, 0);
+        while i < x.xs.len() || j < y.xs.len() {
+            let (k, c) = match (x.xs.get(i), y.xs.get(j)) {
+                (Some(&(k, u)), Some(&(l, v))) if k == l => {
+                    i += 1;
+                    j += 1;
+                    (k, point(a).mul_add(point(u), point(b) * point(v)))
+                }
+                (Some(&(k, u)), Some(&(l, _))) if k < l => {
+                    i += 1;
+                    (k, point(a) * point(u))
+                }
+                (Some(&(k, u)), None) => {
+                    i += 1;
+                    (k, point(a) * point(u))
+                }
+                (_, Some(&(l, v))) => {
+                    j += 1;
+                    (l, point(b) * point(v))
+                }
+                (None, None) => unreachable!(),
+            };
+            let c = round(c, &mut err);
+            if c != 0.0 {
+                xs.push((k, c));
+            }
+        }
+        Self::normalize(x0, xs, err)
+    }
+
+    fn scale(&self, a: f64) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+        if self.is_entire() {
+            return Self::ENTIRE;
+        }
+        let zero = Self::from(Interval::zero());
+        Self::lin_comb(a, self, 0.0, &zero, point(a) * point(self.x0))
+    }
+
+    // Returns the affine form with the given parameters, or the entire real line if any of them
+    // are not finite.
+    fn normalize(x0: f64, xs: Vec<(usize, f64)>, err: Interval) -> Self {
+        let err = err.sup();
+        if x0.is_finite() && err.is_finite() && xs.iter().all(|&(_, x)| x.is_finite()) {
+            Self { x0, xs, err }
+        } else {
+            Self::ENTIRE
+        }
+    }
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+impl AffineForm {
+    /// Returns the cosine of `self`.
+    ///
+    /// The Chebyshev approximation is used if the range of `self` is contained in an interval
+    /// $\[(k - 1/2)π, (k + 1/2)π\]$ for some integer $k$, where the cosine is either convex or
+    /// concave. Otherwise, the min-range approximation is used.
+    #[must_use]
+    pub fn cos(&self) -> Self {
+        let x = self.to_interval();
+        match convexity_cell(x / Interval::PI + point(0.5)) {
+            // cos(x) = sin(-α) has the solution kπ - (-1)^k asin(α) in the k-th cell.
+            Some(k) => self.chebyshev(
+                |x| x.cos(),
+                |a| {
+                    let u = a.asin();
+                    let u = if k % 2.0 == 0.0 { -u } else { u };
+                    point(k) * Interval::PI + u
+                },
+            ),
+            None => self.min_range(|x| x.cos(), |x| -x.sin()),
+        }
+    }
+
+    /// Returns `self` raised to the power of $\e$.
+    ///
+    /// The Chebyshev approximation is used.
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        self.chebyshev(|x| x.exp(), |a| a.ln())
+    }
+
+    /// Returns the natural logarithm of `self`.
+    ///
+    /// The Chebyshev approximation is used if the range of `self` is positive.
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        if self.to_interval().inf() <= 0.0 {
+            return Self::from(self.to_interval().ln());
+        }
+        self.chebyshev(|x| x.ln(), |a| a.recip())
+    }
+
+    /// Returns the sine of `self`.
+    ///
+    /// The Chebyshev approximation is used if the range of `self` is contained in an interval
+    /// $\[kπ, (k + 1)π\]$ for some integer $k$, where the sine is either convex or concave.
+    /// Otherwise, the min-range approximation is used.
+    #[must_use]
+    pub fn sin(&self) -> Self {
+        let x = self.to_interval();
+        match convexity_cell(x / Interval::PI) {
+            // cos(x) = α has the solution kπ + acos(α) in the k-th cell if k is even,
+            // and (k + 1)π - acos(α) otherwise.
+            Some(k) => self.chebyshev(
+                |x| x.sin(),
+                |a| {
+                    let u = a.acos();
+                    if k % 2.0 == 0.0 {
+                        point(k) * Interval::PI + u
+                    } else {
+                        point(k + 1.0) * Interval::PI - u
+                    }
+                },
+            ),
+            None => self.min_range(|x| x.sin(), |x| x.cos()),
+        }
+    }
+
+    // Returns the min-range approximation of f(x), where `df` returns an enclosure of
+    // the range of the derivative of f. If f' has a constant sign over the range [a, b] of
+    // `self`, α is chosen as the bound of f' closest to zero, so that f(x) - α x is monotonic
+    // and its range is spanned by the values at a and b. Otherwise, the range of f is returned.
+    fn min_range(
+        &self,
+        f: impl Fn(Interval) -> Interval,
+        df: impl Fn(Interval) -> Interval,
+    ) -> Self {
+        let x = self.to_interval();
+        if !x.is_common_interval() {
+            return Self::from(f(x));
+        }
+
+        let d = df(x);
+        let alpha = if d.inf() > 0.0 {
+            d.inf()
+        } else if d.sup() < 0.0 {
+            d.sup()
+        } else {
+            return Self::from(f(x));
+        };
+        let g = |x| f(x) - point(alpha) * x;
+        let range = g(point(x.inf())).convex_hull(g(point(x.sup())));
+        self.affine_approx(alpha, range, f(x))
+    }
+}
+
+// Returns the integer k such that `q` ⊆ [k, k + 1], if any.
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn convexity_cell(q: Interval) -> Option<f64> {
+    if !q.is_common_interval() {
+        return None;
+    }
+    let k = q.floor();
+    (k.inf() == k.sup()).then(|| k.inf())
+}
+
+// Returns the midpoint of `x` and adds the error to `err`.
+fn round(x: Interval, err: &mut Interval) -> f64 {
+    let m = x.mid();
+    let r = x.rad();
+    *err = if r.is_finite() {
+        *err + point(r)
+    } else {
+        Interval::ENTIRE
+    };
+    m
+}
+
+impl PartialEq for AffineForm {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.is_empty() && rhs.is_empty()
+            || self.x0 == rhs.x0 && self.xs == rhs.xs && self.err == rhs.err
+    }
+}
+
+impl Eq for AffineForm {}
+
+impl From<Interval> for AffineForm {
+    /// Creates an affine form $\mid(𝚡) + \rad(𝚡) ε_i$ with a new noise symbol $ε_i$.
+    ///
+    /// No noise symbol is introduced if `x` is a singleton.
+    fn from(x: Interval) -> Self {
+        if x.is_empty() {
+            Self::EMPTY
+        } else if !x.is_common_interval() {
+            Self::ENTIRE
+        } else if x.is_singleton() {
+            Self {
+                x0: x.inf(),
+                xs: vec![],
+                err: 0.0,
+            }
+        } else {
+            Self {
+                x0: x.mid(),
+                xs: vec![(new_symbol(), x.rad())],
+                err: 0.0,
+            }
+        }
+    }
+}
+
+impl From<&AffineForm> for Interval {
+    fn from(x: &AffineForm) -> Self {
+        x.to_interval()
+    }
+}
+
+impl From<AffineForm> for Interval {
+    fn from(x: AffineForm) -> Self {
+        x.to_interval()
+    }
+}
+
+impl Neg for &AffineForm {
+    type Output = AffineForm;
+
+    fn neg(self) -> AffineForm {
+        AffineForm {
+            x0: -self.x0,
+            xs: self.xs.iter().map(|&(i, x)| (i, -x)).collect(),
+            err: self.err,
+        }
+    }
+}
+
+impl Neg for AffineForm {
+    type Output = AffineForm;
+
+    fn neg(self) -> AffineForm {
+        -&self
+    }
+}
+
+impl Add for &AffineForm {
+    type Output = AffineForm;
+
+    fn add(self, rhs: Self) -> AffineForm {
+        if self.is_empty() || rhs.is_empty() {
+            return AffineForm::EMPTY;
+        }
+        if self.is_entire() || rhs.is_entire() {
+            return AffineForm::ENTIRE;
+        }
+        let c = point(self.x0) + point(rhs.x0);
+        AffineForm::lin_comb(1.0, self, 1.0, rhs, c)
+    }
+}
+
+forward_owned_binop!(impl Add, add for AffineForm, AffineForm, AffineForm);
+
+impl Sub for &AffineForm {
+    type Output = AffineForm;
+
+    fn sub(self, rhs: Self) -> AffineForm {
+        self + &-rhs
+    }
+}
+
+forward_owned_binop!(impl Sub, sub for AffineForm, AffineForm, AffineForm);
+
+impl Mul for &AffineForm {
+    type Output = AffineForm;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> AffineForm {
+        if self.is_empty() || rhs.is_empty() {
+            return AffineForm::EMPTY;
+        }
+        if self.is_entire() || rhs.is_entire() {
+            return AffineForm::ENTIRE;
+        }
+
+        // (x₀ + Σ xᵢ εᵢ + e ε)(y₀ + Σ yᵢ εᵢ + f ε')
+        //   = x₀ y₀ + Σ (y₀ xᵢ + x₀ yᵢ) εᵢ + |y₀| e ε + |x₀| f ε'
+        //     + (Σ xᵢ εᵢ + e ε)(Σ yᵢ εᵢ + f ε'),
+        // where the last term is bounded by the product of the radii.
+        let c = point(self.x0) * point(rhs.x0);
+        let z = AffineForm::lin_comb(rhs.x0, self, self.x0, rhs, c);
+        let delta = (point(self.rad()) * point(rhs.rad())).sup();
+        &z + &AffineForm::from(Interval::with_infsup_raw(-delta, delta))
+    }
+}
+
+forward_owned_binop!(impl Mul, mul for AffineForm, AffineForm, AffineForm);
+
+impl Div for &AffineForm {
+    type Output = AffineForm;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> AffineForm {
+        self * &rhs.recip()
+    }
+}
+
+forward_owned_binop!(impl Div, div for AffineForm, AffineForm, AffineForm);
+
+macro_rules! impl_interval_op {
+    ($imp:ident, $method:ident) => {
+        impl $imp<Interval> for &AffineForm {
+            type Output = AffineForm;
+
+            fn $method(self, rhs: Interval) -> AffineForm {
+                $imp::$method(self, &AffineForm::from(rhs))
+            }
+        }
+
+        impl $imp<Interval> for AffineForm {
+            type Output = AffineForm;
+
+            fn $method(self, rhs: Interval) -> AffineForm {
+                $imp::$method(&self, &AffineForm::from(rhs))
+            }
+        }
+
+        impl $imp<&AffineForm> for Interval {
+            type Output = AffineForm;
+
+            fn $method(self, rhs: &AffineForm) -> AffineForm {
+                $imp::$method(&AffineForm::from(self), rhs)
+            }
+        }
+
+        impl $imp<AffineForm> for Interval {
+            type Output = AffineForm;
+
+            fn $method(self, rhs: AffineForm) -> AffineForm {
+                $imp::$method(&AffineForm::from(self), &rhs)
+            }
+        }
+    };
+}
+
+impl_interval_op!(Add, add);
+impl_interval_op!(Sub, sub);
+impl_interval_op!(Mul, mul);
+impl_interval_op!(Div, div);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use AffineForm as A;
+    use Interval as I;
+
+    #[test]
+    fn conversion() {
+        let x = A::from(const_interval!(1.0, 3.0));
+        assert_eq!(x.center(), 2.0);
+        assert_eq!(x.coefficients().count(), 1);
+        assert_eq!(I::from(&x), const_interval!(1.0, 3.0));
+
+        let x = A::from(const_interval!(0.1, 0.1));
+        assert_eq!(x.coefficients().count(), 0);
+        assert_eq!(I::from(x), const_interval!(0.1, 0.1));
+
+        assert!(A::from(I::EMPTY).is_empty());
+        assert_eq!(I::from(A::from(I::EMPTY)), I::EMPTY);
+        assert_eq!(
+            I::from(A::from(const_interval!(0.0, f64::INFINITY))),
+            I::ENTIRE
+        );
+        assert_eq!(I::from(A::from(I::ENTIRE)), I::ENTIRE);
+    }
+
+    #[test]
+    fn arith() {
+        let x = A::from(const_interval!(1.0, 2.0));
+        let y = A::from(const_interval!(-1.0, 1.0));
+        assert_eq!(I::from(&x - &x), const_interval!(0.0, 0.0));
+        assert_eq!(I::from(&x + &y), const_interval!(0.0, 3.0));
+        assert_eq!(I::from(&(&x + &y) - &y), const_interval!(1.0, 2.0));
+        assert_eq!(I::from(&x * &y), const_interval!(-2.0, 2.0));
+        assert_eq!(I::from(-&x), const_interval!(-2.0, -1.0));
+
+        // The rounding errors are accumulated.
+        let z = A::from(const_interval!(0.1, 0.1)) + const_interval!(0.2, 0.2);
+        assert!(z.error() > 0.0);
+        assert!(I::from(&z).contains(0.3));
+        assert!(I::from(&z).contains(0.30000000000000004));
+
+        // (x + y)² - x² - 2xy - y² = 0
+        let s = &x + &y;
+        let z =
+            &(&(&(&s * &s) - &(&x * &x)) - &(const_interval!(2.0, 2.0) * &(&x * &y))) - &(&y * &y);
+        assert!(I::from(z).contains(0.0));
+
+        let z = &x / &x;
+        assert!(I::from(&z).contains(1.0));
+        assert!(I::from(&z).subset(const_interval!(0.8, 1.3)));
+
+        assert!((&x + &A::from(I::EMPTY)).is_empty());
+        assert_eq!(A::from(I::EMPTY), A::from(I::EMPTY));
+        assert_eq!(x, x.clone());
+        assert_ne!(x, A::from(I::EMPTY));
+        assert!((&x * &A::from(I::EMPTY)).is_empty());
+        assert_eq!(I::from(&x * &A::from(I::ENTIRE)), I::ENTIRE);
+        assert_eq!(I::from(&x / &y), I::ENTIRE);
+
+        let z = A::from(const_interval!(1e300, 1e300)) * const_interval!(1e300, 1e300);
+        assert_eq!(I::from(z), I::ENTIRE);
+    }
+
+    #[test]
+    fn recip_sqrt() {
+        let x = A::from(const_interval!(1.0, 2.0));
+        let y = x.recip();
+        assert!(const_interval!(0.5, 1.0).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.6);
+        assert!(I::from(&(&x * &y)).subset(const_interval!(0.8, 1.3)));
+
+        let y = (-&x).recip();
+        assert!(const_interval!(-1.0, -0.5).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.6);
+
+        let y = x.sqrt();
+        assert!(const_interval!(1.0, std::f64::consts::SQRT_2).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.44);
+        assert!(I::from(&(&(&y * &y) - &x)).subset(const_interval!(-0.1, 0.1)));
+
+        assert_eq!(
+            I::from(A::from(const_interval!(-1.0, 4.0)).sqrt()),
+            const_interval!(0.0, 2.0)
+        );
+        assert_eq!(
+            I::from(A::from(const_interval!(4.0, 4.0)).sqrt()),
+            const_interval!(2.0, 2.0)
+        );
+        assert_eq!(
+            I::from(A::from(const_interval!(-1.0, 1.0)).recip()),
+            I::ENTIRE
+        );
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn elementary() {
+        let x = A::from(const_interval!(0.0, 1.0));
+        let y = x.exp();
+        assert!(const_interval!(1.0, std::f64::consts::E).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 1.94);
+        assert!(I::from(&(&y * &(-&x).exp())).subset(const_interval!(0.6, 1.7)));
+
+        let y = (&x + const_interval!(1.0, 1.0)).ln();
+        assert!(const_interval!(0.0, std::f64::consts::LN_2).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.76);
+        assert!(I::from(&(&y - &x)).subset(const_interval!(-0.4, 0.1)));
+
+        let y = &x.sin();
+        assert!(const_interval!(0.0, 0.8414709848078965).subset(I::from(y)));
+        let z = &x.cos();
+        assert!(const_interval!(0.5403023058681398, 1.0).subset(I::from(z)));
+        assert!(I::from(&(&(y * y) + &(z * z))).subset(const_interval!(0.0, 2.0)));
+
+        // The Chebyshev approximation.
+        let x = A::from(const_interval!(0.5, 1.5));
+        let y = x.sin();
+        assert!(const_interval!(0.479425538604203, 0.9974949866040546).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.63);
+        assert!(I::from(&(&y - &x)).wid() < 0.59);
+        let y = x.cos();
+        assert!(const_interval!(0.0707372016677029, 0.8775825618903728).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 0.88);
+        assert!(I::from(&(&y + &x)).wid() < 0.27);
+
+        // The min-range approximation, where the derivative does not change its sign.
+        let x = A::from(const_interval!(-1.0, 1.0));
+        let y = x.sin();
+        assert!(const_interval!(-0.8414709848078965, 0.8414709848078966).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 1.69);
+        let y = (&x + const_interval!(1.5, 1.5)).cos();
+        assert!(const_interval!(-0.5984721441039565, 0.8775825618903728).subset(I::from(&y)));
+        assert!(I::from(&y).wid() < 1.68);
+
+        let x = A::from(const_interval!(-10.0, 10.0));
+        assert!(I::from(x.sin()).subset(const_interval!(-1.0, 1.0)));
+        assert!(I::from(x.cos()).subset(const_interval!(-1.0, 1.0)));
+        assert_eq!(I::from(A::from(const_interval!(-1.0, 1.0)).ln()), I::ENTIRE);
+        assert_eq!(
+            I::from(A::from(const_interval!(1000.0, 1000.0)).exp()),
+            I::ENTIRE
+        );
+    }
+}
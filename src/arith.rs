@@ -1,6 +1,5 @@
-use crate::{classify::*, interval::*, simd::*};
-use forward_ref::*;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::{classify::*, forward_ref::*, interval::*, simd::*};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 impl Neg for Interval {
     type Output = Self;
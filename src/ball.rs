@@ -0,0 +1,488 @@
+use crate::{
+    forward_ref::*,
+    interval::*,
+    simd::{add_ru, constant, extract0, max, mul_add_ru, splat, swap},
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An interval in the midpoint-radius form $\[m - r, m + r\]$, where $m$ and $r$
+/// are [`f64`] numbers.
+///
+/// The midpoint is computed in the default rounding mode, and the radius is inflated
+/// by a bound on the rounding error of the midpoint, so that every operation returns a ball
+/// that encloses the exact result. This makes the arithmetic cheaper than that of [`Interval`]
+/// in the sums and products of many terms, at the cost of an overestimation of at most
+/// a factor of 1.5 in the radius of a product.
+///
+/// A ball with an infinite radius represents $\R$. Only the addition, subtraction and
+/// multiplication are evaluated in the midpoint-radius form; the other functions convert
+/// the ball to an [`Interval`], evaluate the function, and convert the result back.
+///
+/// The conversion from [`Interval`] uses [`Interval::mid`] and [`Interval::rad`], and the one
+/// to [`Interval`] rounds the bounds outward. Both of them are enclosures.
+///
+/// # Examples
+///
+/// ```
+/// use inari::*;
+/// let x = Ball::from(const_interval!(1.0, 2.0));
+/// assert_eq!((x.mid(), x.rad()), (1.5, 0.5));
+/// let y = x * x - x;
+/// assert_eq!((y.mid(), y.rad()), (0.75, 2.25));
+/// assert_eq!(Interval::from(y), const_interval!(-1.5, 3.0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Ball {
+    mid: f64,
+    // NaN if the ball is empty.
+    rad: f64,
+}
+
+impl Ball {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = Self {
+        mid: 0.0,
+        rad: f64::NAN,
+    };
+
+    /// $\[-∞, +∞\]$.
+    pub const ENTIRE: Self = Self {
+        mid: 0.0,
+        rad: f64::INFINITY,
+    };
+
+    /// Returns the midpoint of `self` if it is nonempty; otherwise, a NaN.
+    pub fn mid(self) -> f64 {
+        if self.is_empty() {
+            return f64::NAN;
+        }
+
+        self.mid
+    }
+
+    /// Returns the radius of `self` if it is nonempty; otherwise, a NaN.
+    pub fn rad(self) -> f64 {
+        self.rad
+    }
+
+    /// Returns `true` if `rhs` is a member of `self`: $\rhs ∈ \self$.
+    pub fn contains(self, rhs: f64) -> bool {
+        Interval::from(self).contains(rhs)
+    }
+
+    /// Returns `true` if `self` is $\[-∞, +∞\]$.
+    pub fn is_entire(self) -> bool {
+        self.rad == f64::INFINITY
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(self) -> bool {
+        self.rad.is_nan()
+    }
+
+    /// Returns `true` if `self` is a subset of `rhs`: $\self ⊆ \rhs$.
+    pub fn subset(self, rhs: Self) -> bool {
+        Interval::from(self).subset(Interval::from(rhs))
+    }
+
+    /// Returns the square of `self`.
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        if let Some(y) = special_case(self, self) {
+            return y;
+        }
+
+        // (m + e)² = m² + 2 m e + e², where |e| ≤ r.
+        let (m, r) = (self.mid, self.rad);
+        let m2 = m * m;
+        let r2 = mul_add1_ru(2.0 * m.abs(), r, mul_add1_ru(r, r, mul_err(m, m, m2)));
+        Self::new_checked(m2, r2)
+    }
+
+    // Returns the ball with midpoint `mid` and radius `rad`, or $\R$ if either of them is not
+    // finite.
+    fn new_checked(mid: f64, rad: f64) -> Self {
+        if mid.is_finite() && rad.is_finite() {
+            Self { mid, rad }
+        } else {
+            Self::ENTIRE
+        }
+    }
+}
+
+macro_rules! impl_via_interval {
+    ($(#[$meta:meta])* $f:ident) => {
+        $(#[$meta])*
+        #[must_use]
+        pub fn $f(self) -> Self {
+            Self::from(Interval::from(self).$f())
+        }
+    };
+}
+
+impl Ball {
+    impl_via_interval!(
+        /// Returns the absolute value of `self`.
+        abs
+    );
+    impl_via_interval!(
+        /// Returns the reciprocal of `self`, in the same manner as [`Interval::recip`].
+        recip
+    );
+    impl_via_interval!(
+        /// Returns the principal square root of `self`, in the same manner as [`Interval::sqrt`].
+        sqrt
+    );
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+impl Ball {
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::acos`].
+        acos
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::acosh`].
+        acosh
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::asin`].
+        asin
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::asinh`].
+        asinh
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::atan`].
+        atan
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::atanh`].
+        atanh
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::cos`].
+        cos
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::cosh`].
+        cosh
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::exp`].
+        exp
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::ln`].
+        ln
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::sin`].
+        sin
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::sinh`].
+        sinh
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::tan`].
+        tan
+    );
+    impl_via_interval!(
+        /// The [`Ball`] version of [`Interval::tanh`].
+        tanh
+    );
+
+    /// The [`Ball`] version of [`Interval::powi`].
+    #[must_use]
+    pub fn powi(self, rhs: i32) -> Self {
+        Self::from(Interval::from(self).powi(rhs))
+    }
+}
+
+// Returns the result of a binary operation if either operand is empty or unbounded.
+fn special_case(x: Ball, y: Ball) -> Option<Ball> {
+    if x.is_empty() || y.is_empty() {
+        Some(Ball::EMPTY)
+    } else if x.is_entire() || y.is_entire() {
+        Some(Ball::ENTIRE)
+    } else {
+        None
+    }
+}
+
+fn point(x: f64) -> Interval {
+    Interval::with_infsup_raw(x, x)
+}
+
+fn add1_ru(x: f64, y: f64) -> f64 {
+    extract0(add_ru(splat(x), splat(y)))
+}
+
+fn mul_add1_ru(x: f64, y: f64, z: f64) -> f64 {
+    extract0(mul_add_ru(splat(x), splat(y), splat(z)))
+}
+
+// Returns |x + y - s|, where `s` is x + y rounded to nearest (Knuth's TwoSum).
+// The result is exact unless `s` overflows.
+fn add_err(x: f64, y: f64, s: f64) -> f64 {
+    let y2 = s - x;
+    let x2 = s - y2;
+    ((x - x2) + (y - y2)).abs()
+}
+
+// Returns an upper bound of |x y - p|, where `p` is x y rounded to nearest.
+// The bound is exact unless the error underflows.
+fn mul_err(x: f64, y: f64, p: f64) -> f64 {
+    // [x y - p; p - x y] rounded upward.
+    let e = mul_add_ru(constant(x, -x), splat(y), constant(-p, p));
+    extract0(max(e, swap(e)))
+}
+
+impl PartialEq for Ball {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.is_empty() && rhs.is_empty() || self.mid == rhs.mid && self.rad == rhs.rad
+    }
+}
+
+impl Eq for Ball {}
+
+impl TryFrom<(f64, f64)> for Ball {
+    type Error = IntervalError;
+
+    /// Creates a ball $\[m - r, m + r\]$ from `(m, r)`.
+    ///
+    /// [`Ball::ENTIRE`] is returned if $r = +∞$. An error is returned if $m$ is not finite
+    /// or if $r$ is negative or NaN.
+    fn try_from((m, r): (f64, f64)) -> Result<Self> {
+        if m.is_finite() && r >= 0.0 {
+            if r == f64::INFINITY {
+                Ok(Self::ENTIRE)
+            } else {
+                Ok(Self { mid: m, rad: r })
+            }
+        } else {
+            Err(Self::Error {
+                kind: IntervalErrorKind::UndefinedOperation,
+            })
+        }
+    }
+}
+
+impl From<Interval> for Ball {
+    fn from(x: Interval) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        // `x` ⊆ [m - r, m + r] by the definition of `Interval::rad`.
+        let r = x.rad();
+        if r == f64::INFINITY {
+            Self::ENTIRE
+        } else {
+            Self {
+                mid: x.mid(),
+                rad: r,
+            }
+        }
+    }
+}
+
+impl From<Ball> for Interval {
+    fn from(x: Ball) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        point(x.mid) + Self::with_infsup_raw(-x.rad, x.rad)
+    }
+}
+
+impl Neg for Ball {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            mid: -self.mid,
+            rad: self.rad,
+        }
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for Ball);
+
+impl Add for Ball {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if let Some(z) = special_case(self, rhs) {
+            return z;
+        }
+
+        let m = self.mid + rhs.mid;
+        let r = add1_ru(add1_ru(self.rad, rhs.rad), add_err(self.mid, rhs.mid, m));
+        Self::new_checked(m, r)
+    }
+}
+
+forward_ref_binop!(impl Add, add for Ball, Ball);
+
+impl Sub for Ball {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for Ball, Ball);
+
+impl Mul for Ball {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if let Some(z) = special_case(self, rhs) {
+            return z;
+        }
+
+        // (m + e)(m' + e') = m m' + m e' + m' e + e e', where |e| ≤ r and |e'| ≤ r'.
+        let (m, m2) = (self.mid, rhs.mid);
+        let (r, r2) = (self.rad, rhs.rad);
+        let p = m * m2;
+        let e = mul_err(m, m2, p);
+        let r = mul_add1_ru(m.abs(), r2, mul_add1_ru(m2.abs(), r, mul_add1_ru(r, r2, e)));
+        Self::new_checked(p, r)
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Ball, Ball);
+
+impl Div for Ball {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::from(Interval::from(self) / Interval::from(rhs))
+    }
+}
+
+forward_ref_binop!(impl Div, div for Ball, Ball);
+
+macro_rules! impl_op_assign {
+    ($OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl $OpAssign for Ball {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        forward_ref_op_assign!(impl $OpAssign, $op_assign for Ball, Ball);
+    };
+}
+
+impl_op_assign!(AddAssign, add_assign, add);
+impl_op_assign!(SubAssign, sub_assign, sub);
+impl_op_assign!(MulAssign, mul_assign, mul);
+impl_op_assign!(DivAssign, div_assign, div);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use const_interval as c;
+    type B = Ball;
+    type I = Interval;
+
+    #[test]
+    fn conversion() {
+        assert_eq!(B::from(c!(-2.0, 3.0)), B::try_from((0.5, 2.5)).unwrap());
+        assert_eq!(I::from(B::try_from((0.5, 2.5)).unwrap()), c!(-2.0, 3.0));
+        assert_eq!(B::from(I::EMPTY), B::EMPTY);
+        assert_eq!(I::from(B::EMPTY), I::EMPTY);
+        assert_eq!(B::from(I::ENTIRE), B::ENTIRE);
+        assert_eq!(B::from(c!(1.0, f64::INFINITY)), B::ENTIRE);
+        assert_eq!(I::from(B::ENTIRE), I::ENTIRE);
+        assert!(B::try_from((f64::INFINITY, 1.0)).is_err());
+        assert!(B::try_from((0.0, -1.0)).is_err());
+        assert!(B::try_from((0.0, f64::NAN)).is_err());
+        assert_eq!(B::try_from((1.0, f64::INFINITY)).unwrap(), B::ENTIRE);
+        assert!(B::EMPTY.mid().is_nan());
+
+        // The midpoint is inexact.
+        let x = c!(1.0, 1.0 + f64::EPSILON);
+        let b = B::from(x);
+        assert!(x.subset(I::from(b)));
+        assert!(b.contains(1.0) && b.contains(1.0 + f64::EPSILON));
+
+        let x = c!(-f64::MAX, f64::MAX);
+        assert!(x.subset(I::from(B::from(x))));
+        let x = c!(0.1, 0.7);
+        assert!(x.subset(I::from(B::from(x))));
+        assert!(B::from(x).subset(B::from(c!(0.0, 1.0))));
+    }
+
+    #[test]
+    fn arith() {
+        let x = B::try_from((1.0, 0.5)).unwrap();
+        let y = B::try_from((-2.0, 0.25)).unwrap();
+        assert_eq!(x + y, B::try_from((-1.0, 0.75)).unwrap());
+        assert_eq!(x - y, B::try_from((3.0, 0.75)).unwrap());
+        assert_eq!(-x, B::try_from((-1.0, 0.5)).unwrap());
+        // |1| × 0.25 + |-2| × 0.5 + 0.5 × 0.25 = 1.375
+        assert_eq!(x * y, B::try_from((-2.0, 1.375)).unwrap());
+        assert_eq!(x.sqr(), B::try_from((1.0, 1.25)).unwrap());
+        assert!(I::from(x / y).subset(c!(-0.86, -0.22)));
+
+        // The rounding error of the midpoint is added to the radius.
+        let a = B::try_from((0.1, 0.0)).unwrap();
+        let b = B::try_from((0.2, 0.0)).unwrap();
+        let s = a + b;
+        assert!(s.rad() > 0.0);
+        assert!((c!(0.1, 0.1) + c!(0.2, 0.2)).subset(I::from(s)));
+        let p = a * b;
+        assert!(p.rad() > 0.0);
+        assert!((c!(0.1, 0.1) * c!(0.2, 0.2)).subset(I::from(p)));
+        // The rounding error is bounded even if it underflows.
+        let t = B::try_from((1e-200, 0.0)).unwrap();
+        assert!((t * t).rad() > 0.0 && t.sqr().rad() > 0.0);
+        assert!((c!(1e-200, 1e-200) * c!(1e-200, 1e-200)).subset(I::from(t * t)));
+
+        assert!((B::EMPTY + x).is_empty());
+        assert!((x * B::EMPTY).is_empty());
+        assert_eq!(B::ENTIRE * x, B::ENTIRE);
+        let max = B::try_from((f64::MAX, 0.0)).unwrap();
+        assert_eq!(max * B::try_from((2.0, 0.0)).unwrap(), B::ENTIRE);
+        assert!((x / B::from(c!(0.0, 0.0))).is_empty());
+
+        let mut z = x;
+        z += y;
+        z -= y;
+        z *= y;
+        z /= y;
+        assert!(x.subset(z));
+    }
+
+    #[test]
+    fn elementary() {
+        let x = B::try_from((0.0, 4.0)).unwrap();
+        assert_eq!(x.abs(), B::try_from((2.0, 2.0)).unwrap());
+        assert_eq!(x.sqrt(), B::try_from((1.0, 1.0)).unwrap());
+        assert_eq!(
+            B::try_from((1.5, 0.5)).unwrap().recip(),
+            B::from(c!(0.5, 1.0))
+        );
+        assert_eq!(x.recip(), B::ENTIRE);
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn elementary_gmp() {
+        let x = B::try_from((1.0, 0.5)).unwrap();
+        assert!(I::from(x.exp()).subset(c!(1.64, 4.49)));
+        assert!(x.exp().contains(1f64.exp()));
+        assert!(x.ln().contains(0.0));
+        assert!(x.sin().contains(1f64.sin()));
+        assert!(x.cos().contains(0.5f64.cos()));
+        assert!(x.powi(-2).contains(1.0));
+        assert!(x.acos().contains(0.0));
+    }
+}
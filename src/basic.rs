@@ -1,5 +1,5 @@
 use crate::{classify::*, interval::*, simd::*};
-use std::{cmp::Ordering, unreachable};
+use core::{cmp::Ordering, unreachable};
 
 // NOTE: `neg`, `add`, `sub`, `mul` and `div` are implemented in arith.rs
 
@@ -1,5 +1,5 @@
 use crate::{interval::Interval, simd::*};
-use std::mem::transmute;
+use core::mem::transmute;
 
 impl Interval {
     pub(crate) fn classify(self) -> IntervalClass {
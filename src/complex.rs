@@ -0,0 +1,663 @@
+use crate::{const_interval, forward_ref::*, interval::*};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A rectangular enclosure of a set of complex numbers, represented by
+/// the intervals of the real and imaginary parts.
+///
+/// The multiplication and the reciprocal return the tightest rectangles that enclose
+/// the exact results, up to the rounding of the bounds. The division $z / w$ is computed
+/// as $z × (1 / w)$.
+///
+/// If either part is empty, the value is the empty set, and both parts are empty.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, ComplexInterval as C};
+/// let z = C::new(c!(1.0, 2.0), c!(1.0, 1.0));
+/// let w = C::new(c!(1.0, 1.0), c!(2.0, 2.0));
+/// assert_eq!(z * w, C::new(c!(-1.0, 0.0), c!(3.0, 5.0)));
+/// let w = C::new(c!(0.0, 0.0), c!(1.0, 1.0));
+/// assert_eq!(w.recip(), C::new(c!(0.0, 0.0), c!(-1.0, -1.0)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ComplexInterval {
+    re: Interval,
+    im: Interval,
+}
+
+impl ComplexInterval {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = Self {
+        re: Interval::EMPTY,
+        im: Interval::EMPTY,
+    };
+
+    /// $\C$, the entire complex plane.
+    pub const ENTIRE: Self = Self {
+        re: Interval::ENTIRE,
+        im: Interval::ENTIRE,
+    };
+
+    /// Creates a rectangle from the intervals of the real and imaginary parts.
+    ///
+    /// [`ComplexInterval::EMPTY`] is returned if either of them is empty.
+    pub fn new(re: Interval, im: Interval) -> Self {
+        if re.is_empty() || im.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self { re, im }
+    }
+
+    /// Returns the interval of the real part of `self`.
+    pub fn re(self) -> Interval {
+        self.re
+    }
+
+    /// Returns the interval of the imaginary part of `self`.
+    pub fn im(self) -> Interval {
+        self.im
+    }
+
+    /// Returns `true` if `self` contains the complex number $\re + i \im$.
+    pub fn contains(self, re: f64, im: f64) -> bool {
+        self.re.contains(re) && self.im.contains(im)
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(self) -> bool {
+        self.re.is_empty()
+    }
+
+    /// Returns `true` if `self` is a subset of `rhs`.
+    pub fn subset(self, rhs: Self) -> bool {
+        self.re.subset(rhs.re) && self.im.subset(rhs.im)
+    }
+
+    /// Returns the complex conjugate of `self`.
+    #[must_use]
+    pub fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Returns the integer power of `self`, computed by repeated squaring.
+    ///
+    /// $z^0 = 1$ for any nonempty $z$, including $z = 0$.
+    #[must_use]
+    pub fn powi(self, rhs: i32) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let mut n = rhs.unsigned_abs();
+        let mut x = self;
+        let mut y = Self::from(const_interval!(1.0, 1.0));
+        while n > 0 {
+            if n & 1 == 1 {
+                y *= x;
+            }
+            n >>= 1;
+            if n > 0 {
+                x = x.sqr();
+            }
+        }
+
+        if rhs < 0 {
+            y.recip()
+        } else {
+            y
+        }
+    }
+
+    /// Returns the reciprocal of `self`.
+    ///
+    /// If `self` contains zero, [`ComplexInterval::ENTIRE`] is returned,
+    /// unless `self` is $\set 0$, in which case [`ComplexInterval::EMPTY`] is returned.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let (c, d) = (self.re, self.im);
+        if c.contains(0.0) && d.contains(0.0) {
+            return if c.is_singleton() && d.is_singleton() {
+                Self::EMPTY
+            } else {
+                Self::ENTIRE
+            };
+        }
+
+        // 1 / (c + i d) = c / (c² + d²) - i d / (c² + d²)
+        Self {
+            re: recip_re(c, d),
+            im: -recip_re(d, c),
+        }
+    }
+
+    /// Returns the square of `self`.
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        // (a + i b)² = a² - b² + 2 i a b
+        Self {
+            re: self.re.sqr() - self.im.sqr(),
+            im: const_interval!(2.0, 2.0) * (self.re * self.im),
+        }
+    }
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+impl ComplexInterval {
+    /// Returns the range of the absolute value $|z|$ over $z ∈ \self$.
+    pub fn abs(self) -> Interval {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the range of the principal argument $\operatorname{Arg} z ∈ (-π, π\]$
+    /// over $z ∈ \self ∖ \set 0$.
+    pub fn arg(self) -> Interval {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns the exponential of `self`.
+    #[must_use]
+    pub fn exp(self) -> Self {
+        // e^(a + i b) = e^a cos b + i e^a sin b
+        let r = self.re.exp();
+        Self::new(r * self.im.cos(), r * self.im.sin())
+    }
+
+    /// Returns the principal branch of the natural logarithm of `self`.
+    #[must_use]
+    pub fn ln(self) -> Self {
+        // ln z = ln |z| + i Arg z
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    /// Returns the principal square root of `self`.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        // √z = √|z| e^(i Arg z / 2)
+        let r = self.abs().sqrt();
+        let t = self.arg() / const_interval!(2.0, 2.0);
+        Self::new(r * t.cos(), r * t.sin())
+    }
+}
+
+// Returns an enclosure of the range of x / (x² + y²) over the rectangle c × d,
+// which must not contain the origin. Since the function is harmonic and vanishes at infinity,
+// its extrema are attained on the edges of the rectangle: at the corners, at the points
+// where an edge crosses the real axis, or at the points (±|y|, y) on the horizontal edges.
+fn recip_re(c: Interval, d: Interval) -> Interval {
+    let f = |x: f64, y: f64| {
+        if x.is_infinite() || y.is_infinite() {
+            return const_interval!(0.0, 0.0);
+        }
+        let x = Interval::with_infsup_raw(x, x);
+        let y = Interval::with_infsup_raw(y, y);
+        x / (x.sqr() + y.sqr())
+    };
+
+    let (c0, c1, d0, d1) = (c.inf(), c.sup(), d.inf(), d.sup());
+    let mut r = f(c0, d0).convex_hull(f(c0, d1));
+    r = r.convex_hull(f(c1, d0)).convex_hull(f(c1, d1));
+    if d.contains(0.0) {
+        r = r.convex_hull(f(c0, 0.0)).convex_hull(f(c1, 0.0));
+    }
+    for y in [d0, d1] {
+        for x in [y.abs(), -y.abs()] {
+            if c.contains(x) {
+                r = r.convex_hull(f(x, y));
+            }
+        }
+    }
+    r
+}
+
+impl From<Interval> for ComplexInterval {
+    fn from(x: Interval) -> Self {
+        Self::new(x, const_interval!(0.0, 0.0))
+    }
+}
+
+impl Neg for ComplexInterval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for ComplexInterval);
+
+impl Add for ComplexInterval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+forward_ref_binop!(impl Add, add for ComplexInterval, ComplexInterval);
+
+impl Sub for ComplexInterval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for ComplexInterval, ComplexInterval);
+
+impl Mul for ComplexInterval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // (a + i b)(c + i d) = ac - bd + i (ad + bc)
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        Self::new(a.mul_add(c, -(b * d)), a.mul_add(d, b * c))
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for ComplexInterval, ComplexInterval);
+
+impl Div for ComplexInterval {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.recip()
+    }
+}
+
+forward_ref_binop!(impl Div, div for ComplexInterval, ComplexInterval);
+
+/// A closed disk in the complex plane, represented by its center and radius.
+///
+/// The center is a pair of [`f64`] numbers, and the radius is rounded upward in all operations,
+/// so that every operation returns a disk that encloses the exact result.
+/// A disk with an infinite radius represents the entire complex plane.
+///
+/// The conversion from a [`ComplexInterval`] returns a disk that circumscribes the rectangle,
+/// and the conversion in the opposite direction returns the bounding rectangle of the disk.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, ComplexBall as B, ComplexInterval as C};
+/// let b = B::try_from(((1.0, 0.0), 0.5)).unwrap();
+/// assert_eq!(C::from(b), C::new(c!(0.5, 1.5), c!(-0.5, 0.5)));
+/// let z = b * b;
+/// assert!(z.contains(0.25, 0.0) && z.contains(2.25, 0.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexBall {
+    re: f64,
+    im: f64,
+    // NaN if the disk is empty.
+    rad: f64,
+}
+
+impl ComplexBall {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = Self {
+        re: 0.0,
+        im: 0.0,
+        rad: f64::NAN,
+    };
+
+    /// $\C$, the entire complex plane.
+    pub const ENTIRE: Self = Self {
+        re: 0.0,
+        im: 0.0,
+        rad: f64::INFINITY,
+    };
+
+    /// Returns the center of `self` as a pair of the real and imaginary parts
+    /// if `self` is nonempty; otherwise, a pair of NaNs.
+    pub fn mid(self) -> (f64, f64) {
+        if self.is_empty() {
+            return (f64::NAN, f64::NAN);
+        }
+
+        (self.re, self.im)
+    }
+
+    /// Returns the radius of `self` if it is nonempty; otherwise, a NaN.
+    pub fn rad(self) -> f64 {
+        self.rad
+    }
+
+    /// Returns `true` if `self` contains the complex number $\re + i \im$.
+    ///
+    /// The distance from the center is bounded from above, so `false` can be returned
+    /// for a point that lies within rounding error of the boundary.
+    pub fn contains(self, re: f64, im: f64) -> bool {
+        if self.rad == f64::INFINITY {
+            return re.is_finite() && im.is_finite();
+        }
+
+        let dx = point(re) - point(self.re);
+        let dy = point(im) - point(self.im);
+        re.is_finite() && im.is_finite() && (dx.sqr() + dy.sqr()).sqrt().sup() <= self.rad
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(self) -> bool {
+        self.rad.is_nan()
+    }
+
+    // Returns a disk that encloses the disks of radius `rad` centered at
+    // the points in the rectangle `re` × `im`.
+    fn enclose(re: Interval, im: Interval, rad: Interval) -> Self {
+        if re.is_empty() || im.is_empty() || rad.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let m_re = re.mid();
+        let m_im = im.mid();
+        let e_re = point((re - point(m_re)).mag());
+        let e_im = point((im - point(m_im)).mag());
+        let r = (rad + (e_re.sqr() + e_im.sqr()).sqrt()).sup();
+        if r == f64::INFINITY {
+            Self::ENTIRE
+        } else {
+            Self {
+                re: m_re,
+                im: m_im,
+                rad: r,
+            }
+        }
+    }
+
+    // The absolute value of the center.
+    fn abs_mid(self) -> Interval {
+        (point(self.re).sqr() + point(self.im).sqr()).sqrt()
+    }
+}
+
+// Returns the result of a binary operation if either operand is empty or unbounded.
+fn special_case(x: ComplexBall, y: ComplexBall) -> Option<ComplexBall> {
+    if x.is_empty() || y.is_empty() {
+        Some(ComplexBall::EMPTY)
+    } else if x.rad == f64::INFINITY || y.rad == f64::INFINITY {
+        Some(ComplexBall::ENTIRE)
+    } else {
+        None
+    }
+}
+
+fn point(x: f64) -> Interval {
+    Interval::with_infsup_raw(x, x)
+}
+
+impl TryFrom<((f64, f64), f64)> for ComplexBall {
+    type Error = IntervalError;
+
+    /// Creates a disk from `((re, im), rad)`, where $\re + i \im$ is the center and
+    /// `rad` is the radius.
+    ///
+    /// An error is returned if the center is not finite or if `rad` is negative or NaN.
+    fn try_from(((re, im), rad): ((f64, f64), f64)) -> Result<Self> {
+        if re.is_finite() && im.is_finite() && rad >= 0.0 {
+            Ok(Self { re, im, rad })
+        } else {
+            Err(Self::Error {
+                kind: IntervalErrorKind::UndefinedOperation,
+            })
+        }
+    }
+}
+
+impl From<ComplexInterval> for ComplexBall {
+    fn from(x: ComplexInterval) -> Self {
+        Self::enclose(x.re, x.im, const_interval!(0.0, 0.0))
+    }
+}
+
+impl From<ComplexBall> for ComplexInterval {
+    fn from(x: ComplexBall) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let r = Interval::with_infsup_raw(-x.rad, x.rad);
+        Self::new(point(x.re) + r, point(x.im) + r)
+    }
+}
+
+impl Neg for ComplexBall {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+            rad: self.rad,
+        }
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for ComplexBall);
+
+impl Add for ComplexBall {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if let Some(z) = special_case(self, rhs) {
+            return z;
+        }
+
+        Self::enclose(
+            point(self.re) + point(rhs.re),
+            point(self.im) + point(rhs.im),
+            point(self.rad) + point(rhs.rad),
+        )
+    }
+}
+
+forward_ref_binop!(impl Add, add for ComplexBall, ComplexBall);
+
+impl Sub for ComplexBall {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for ComplexBall, ComplexBall);
+
+impl Mul for ComplexBall {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if let Some(z) = special_case(self, rhs) {
+            return z;
+        }
+
+        // (m + e)(m' + e') = m m' + m e' + m' e + e e', where |e| ≤ r and |e'| ≤ r'.
+        let (a, b, c, d) = (point(self.re), point(self.im), point(rhs.re), point(rhs.im));
+        let r = point(self.rad);
+        let r2 = point(rhs.rad);
+        Self::enclose(
+            a.mul_add(c, -(b * d)),
+            a.mul_add(d, b * c),
+            self.abs_mid().mul_add(r2, rhs.abs_mid().mul_add(r, r * r2)),
+        )
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for ComplexBall, ComplexBall);
+
+macro_rules! impl_op_assign {
+    ($T:ty, $OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl $OpAssign for $T {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        forward_ref_op_assign!(impl $OpAssign, $op_assign for $T, $T);
+    };
+}
+
+impl_op_assign!(ComplexInterval, AddAssign, add_assign, add);
+impl_op_assign!(ComplexInterval, SubAssign, sub_assign, sub);
+impl_op_assign!(ComplexInterval, MulAssign, mul_assign, mul);
+impl_op_assign!(ComplexInterval, DivAssign, div_assign, div);
+impl_op_assign!(ComplexBall, AddAssign, add_assign, add);
+impl_op_assign!(ComplexBall, SubAssign, sub_assign, sub);
+impl_op_assign!(ComplexBall, MulAssign, mul_assign, mul);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use const_interval as c;
+    type B = ComplexBall;
+    type C = ComplexInterval;
+
+    #[test]
+    fn arith() {
+        let z = C::new(c!(1.0, 2.0), c!(3.0, 4.0));
+        let w = C::new(c!(-1.0, 1.0), c!(1.0, 1.0));
+        assert_eq!(z + w, C::new(c!(0.0, 3.0), c!(4.0, 5.0)));
+        assert_eq!(z - w, C::new(c!(0.0, 3.0), c!(2.0, 3.0)));
+        // (a + 3..4 i)(c + i) = (ac - 3..4) + (a + 3..4 c) i
+        assert_eq!(z * w, C::new(c!(-6.0, -1.0), c!(-3.0, 6.0)));
+        assert_eq!(-z, C::new(c!(-2.0, -1.0), c!(-4.0, -3.0)));
+        assert_eq!(z.conj(), C::new(c!(1.0, 2.0), c!(-4.0, -3.0)));
+        assert_eq!(z.sqr(), z * z);
+        assert_eq!(z.powi(3), z * z * z);
+        assert_eq!(z.powi(0), C::from(c!(1.0, 1.0)));
+        assert!((C::EMPTY + z).is_empty());
+        assert!(C::new(Interval::EMPTY, c!(1.0, 1.0)).is_empty());
+
+        let mut u = z;
+        u += w;
+        u -= w;
+        assert!(z.subset(u));
+        u *= C::from(c!(2.0, 2.0));
+        u /= C::from(c!(2.0, 2.0));
+        assert!(z.subset(u));
+    }
+
+    #[test]
+    fn recip() {
+        // 1 / (1 + i) = (1 - i) / 2
+        let z = C::new(c!(1.0, 1.0), c!(1.0, 1.0));
+        assert_eq!(z.recip(), C::new(c!(0.5, 0.5), c!(-0.5, -0.5)));
+        assert_eq!(z.powi(-2), C::new(c!(0.0, 0.0), c!(-0.5, -0.5)));
+
+        // The extrema of Re(1 / w) = c / (c² + d²) for c = 1 and |d| ≤ 1 are
+        // 1 at d = 0 and 1/2 at d = ±1; those of -Im(1 / w) = d / (c² + d²) are ±1/2.
+        let w = C::new(c!(1.0, 1.0), c!(-1.0, 1.0));
+        assert_eq!(w.recip(), C::new(c!(0.5, 1.0), c!(-0.5, 0.5)));
+        // For c ∈ [0, 2] and d = 1, Re(1 / w) attains 1/2 at c = 1.
+        let w = C::new(c!(0.0, 2.0), c!(1.0, 1.0));
+        assert_eq!(w.recip().re(), c!(0.0, 0.5));
+
+        let w = C::new(c!(1.0, f64::INFINITY), c!(0.0, 0.0));
+        assert_eq!(w.recip(), C::new(c!(0.0, 1.0), c!(0.0, 0.0)));
+        assert_eq!(C::new(c!(-1.0, 1.0), c!(0.0, 0.0)).recip(), C::ENTIRE);
+        assert!(C::from(c!(0.0, 0.0)).recip().is_empty());
+        assert_eq!(
+            C::from(c!(0.0, 0.0)) / C::new(c!(-1.0, 1.0), c!(0.0, 0.0)),
+            C::from(c!(0.0, 0.0))
+        );
+
+        // Sample points of the enclosure of 1 / w.
+        let w = C::new(c!(-3.0, 2.0), c!(0.5, 4.0));
+        let r = w.recip();
+        assert!(r.re().is_common_interval() && r.im().is_common_interval());
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let x = -3.0 + 5.0 * i as f64 / 20.0;
+                let y = 0.5 + 3.5 * j as f64 / 20.0;
+                let n = x * x + y * y;
+                assert!(r.re().inf() <= x / n + 1e-15 && x / n - 1e-15 <= r.re().sup());
+                assert!(r.im().inf() <= -y / n + 1e-15 && -y / n - 1e-15 <= r.im().sup());
+            }
+        }
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn elementary() {
+        let z = C::new(c!(3.0, 3.0), c!(4.0, 4.0));
+        assert!(z.abs().contains(5.0));
+        assert!(z.arg().subset(c!(0.927, 0.928)));
+
+        let e = C::new(c!(0.0, 0.0), Interval::PI).exp();
+        assert!(e.contains(-1.0, 0.0));
+        assert!(e.re().subset(c!(-1.0000001, -0.9999999)));
+
+        let l = C::from(c!(-1.0, -1.0)).ln();
+        assert_eq!(l.re(), c!(0.0, 0.0));
+        assert_eq!(l.im(), Interval::PI);
+        assert!(C::from(c!(0.0, 0.0)).ln().is_empty());
+
+        let s = C::from(c!(-4.0, -4.0)).sqrt();
+        assert!(s.contains(0.0, 2.0));
+        assert!(s.im().subset(c!(1.9999999, 2.0000001)));
+        let s = z.sqrt();
+        assert!(s.contains(2.0, 1.0));
+        assert!(s.sqr().contains(3.0, 4.0));
+    }
+
+    #[test]
+    fn ball() {
+        let b = B::try_from(((1.0, 2.0), 0.5)).unwrap();
+        assert_eq!(b.mid(), (1.0, 2.0));
+        assert_eq!(b.rad(), 0.5);
+        assert!(b.contains(1.5, 2.0));
+        assert!(!b.contains(1.5, 2.5));
+        assert!(B::try_from(((1.0, f64::NAN), 0.5)).is_err());
+        assert!(B::try_from(((1.0, 2.0), -0.5)).is_err());
+        assert!(B::EMPTY.is_empty());
+        assert!(C::from(B::EMPTY).is_empty());
+        assert!(B::from(C::EMPTY).is_empty());
+        assert_eq!(C::from(B::ENTIRE), C::ENTIRE);
+        assert_eq!(B::from(C::ENTIRE), B::ENTIRE);
+
+        let z = C::new(c!(0.0, 2.0), c!(-1.0, 1.0));
+        let b = B::from(z);
+        assert_eq!(b.mid(), (1.0, 0.0));
+        assert!(b.rad() >= 2f64.sqrt());
+        assert!(z.subset(C::from(b)));
+        for (x, y) in [(0.0, -1.0), (2.0, 1.0), (0.0, 1.0), (2.0, -1.0)] {
+            assert!(b.contains(x, y) || b.rad() > 2f64.sqrt());
+        }
+
+        let u = B::try_from(((1.0, 1.0), 0.25)).unwrap();
+        let v = B::try_from(((2.0, -1.0), 0.5)).unwrap();
+        let s = u + v;
+        assert_eq!(s.mid(), (3.0, 0.0));
+        assert_eq!(s.rad(), 0.75);
+        let s = u - v;
+        assert_eq!(s.mid(), (-1.0, 2.0));
+        assert_eq!(s.rad(), 0.75);
+        // (1 + i)(2 - i) = 3 + i
+        let p = u * v;
+        assert_eq!(p.mid(), (3.0, 1.0));
+        assert!(p.rad() >= 2f64.sqrt() * 0.5 + 5f64.sqrt() * 0.25 + 0.125);
+        assert!(p.rad() <= 1.4);
+        let mut q = u;
+        q *= v;
+        q += v;
+        q -= v;
+        assert!(p.rad() <= q.rad());
+        assert!((B::EMPTY * u).is_empty());
+        assert_eq!(B::ENTIRE + u, B::ENTIRE);
+        assert_eq!(B::ENTIRE * u, B::ENTIRE);
+        assert!(B::ENTIRE.contains(1e300, -1e300));
+    }
+}
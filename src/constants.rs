@@ -1,7 +1,7 @@
 #![allow(clippy::approx_constant)]
 
 use crate::{const_interval, interval::*};
-use std::mem::transmute;
+use core::mem::transmute;
 
 impl Interval {
     /// $∅$, the empty set.
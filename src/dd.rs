@@ -0,0 +1,639 @@
+use crate::{classify::*, forward_ref::*, interval::*, simd::*};
+use core::{
+    fmt,
+    mem::transmute,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// An interval with double-double bounds.
+///
+/// Each bound is an unevaluated sum $h + l$ of two [`f64`] numbers with $|l| ≤ \ulp(h) / 2$,
+/// which gives about 106 bits of precision. The bounds are rounded outward in all operations.
+/// Unlike `MpInterval`, the type does not depend on the `gmp` feature, and its arithmetic is
+/// implemented with error-free transformations in hardware floating-point. Near the limits of
+/// the range of [`f64`], where the transformations underflow or overflow, the results can be
+/// as wide as those of [`Interval`].
+///
+/// The conversion from [`Interval`] is exact, and the one to [`Interval`] rounds the bounds
+/// outward.
+///
+/// # Examples
+///
+/// ```
+/// use inari::*;
+/// let big = DdInterval::from(const_interval!(1e16, 1e16));
+/// let one = DdInterval::from(const_interval!(1.0, 1.0));
+/// assert_eq!(Interval::from((big + one) - big), const_interval!(1.0, 1.0));
+/// ```
+///
+/// The [`Debug`][std::fmt::Debug] output looks like `DdInterval((a, a'), (b, b'))`,
+/// where $a + a'$ and $b + b'$ are the bounds of the interval.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DdInterval {
+    // The leading and trailing parts of the bounds, respectively, each stored
+    // in the neginf-sup-nan form in the same manner as `Interval`.
+    // For an empty interval, `hi` is [NaN; NaN] and `lo` is unspecified.
+    rep: DdX2,
+}
+
+unsafe impl Send for DdInterval {}
+unsafe impl Sync for DdInterval {}
+impl Unpin for DdInterval {}
+
+impl fmt::Debug for DdInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b] = extract(self.rep.hi);
+        let [a2, b2] = extract(self.rep.lo);
+        f.debug_tuple("DdInterval")
+            .field(&(-a, -a2))
+            .field(&(b, b2))
+            .finish()
+    }
+}
+
+// A pair of double-double numbers [x0 + x0'; x1 + x1'] stored as
+// `hi` = [x0; x1] and `lo` = [x0'; x1'].
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct DdX2 {
+    hi: F64X2,
+    lo: F64X2,
+}
+
+// `two_sum(x, y) = (s, e)`, where `s` = x + y rounded to nearest and `e` = x + y - s exactly,
+// unless `s` overflows.
+fn two_sum(x: F64X2, y: F64X2) -> (F64X2, F64X2) {
+    let s = add_rn(x, y);
+    let y2 = sub_rn(s, x);
+    let x2 = sub_rn(s, y2);
+    let e = add_rn(sub_rn(x, x2), sub_rn(y, y2));
+    (s, e)
+}
+
+impl DdX2 {
+    fn splat(x: f64) -> Self {
+        Self {
+            hi: splat(x),
+            lo: splat(0.0),
+        }
+    }
+
+    // Returns [s0 + t0; s1 + t1] normalized, where `s` and `t` are upper bounds computed
+    // by the caller. Lanes in which the normalization overflows or fails due to infinite
+    // operands are recomputed from `fallback`, an upper bound of the exact result in `f64`.
+    fn normalize(s: F64X2, t: F64X2, fallback: F64X2) -> Self {
+        let (hi, lo) = two_sum(s, t);
+        if all(lt(abs(hi), splat(f64::INFINITY))) {
+            return Self { hi, lo };
+        }
+
+        let [h0, h1] = extract(hi);
+        let [l0, l1] = extract(lo);
+        let [f0, f1] = extract(fallback);
+        let fix = |h: f64, l: f64, f: f64| {
+            if h.is_finite() {
+                (h, l)
+            } else if h == f64::INFINITY {
+                (h, 0.0)
+            } else if h == f64::NEG_INFINITY {
+                // `s + t` < -f64::MAX holds.
+                (f64::MIN, 0.0)
+            } else {
+                (f, 0.0)
+            }
+        };
+        let (h0, l0) = fix(h0, l0, f0);
+        let (h1, l1) = fix(h1, l1, f1);
+        Self {
+            hi: constant(h0, h1),
+            lo: constant(l0, l1),
+        }
+    }
+
+    // Each of the following arithmetic operations returns upper bounds of the exact results.
+
+    fn add_ru(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let lo = add_ru(self.lo, rhs.lo);
+        let t = add_ru(lo, e);
+        Self::normalize(s, t, add_ru(add_ru(self.hi, rhs.hi), lo))
+    }
+
+    fn mul_ru(self, rhs: Self) -> Self {
+        let p = mul_ru(self.hi, rhs.hi);
+        // The rounding error of `p`, which is exact unless it underflows.
+        let e = mul_add_ru(self.hi, rhs.hi, neg(p));
+        let t = add_ru(
+            add_ru(mul_ru(self.hi, rhs.lo), mul_ru(self.lo, rhs.hi)),
+            add_ru(mul_ru(self.lo, rhs.lo), e),
+        );
+        Self::normalize(p, t, p)
+    }
+
+    fn div_ru(self, rhs: Self) -> Self {
+        // Flip the signs so that the divisor is positive: x / y = (-x) / (-y).
+        let [y0, y1] = extract(rhs.hi);
+        let sign = constant(y0.signum(), y1.signum());
+        let x = self.mul_f64(sign);
+        let y = rhs.mul_f64(sign);
+
+        // x / y = q + r / y, where r = x - q y.
+        let q = div_ru(x.hi, y.hi);
+        let r = mul_add_ru(neg(q), y.hi, x.hi); // x.hi - q y.hi
+        let r = add_ru(add_ru(r, x.lo), mul_ru(neg(q), y.lo));
+        // r / y ≤ r / y.hi + |r / y.hi| 2^-52, since |y.lo| ≤ 2^-53 y.hi.
+        let c = div_ru(r, y.hi);
+        let c = add_ru(c, mul_ru(abs(c), splat(f64::EPSILON / 2.0)));
+        Self::normalize(q, c, q)
+    }
+
+    fn mul_f64(self, rhs: F64X2) -> Self {
+        // Only used for multiplication by ±1, which is exact.
+        Self {
+            hi: mul_ru(self.hi, rhs),
+            lo: mul_ru(self.lo, rhs),
+        }
+    }
+
+    // The square roots of nonnegative numbers.
+    fn sqrt_ru(self) -> Self {
+        let [x0, x1] = extract(self.hi);
+        let s = constant(sqrt1_ru(x0), sqrt1_ru(x1));
+        // r = x - s^2
+        let p = mul_ru(s, s);
+        let e = mul_add_ru(neg(s), s, p); // p - s^2
+        let r = add_ru(add_ru(self.hi, neg(p)), add_ru(self.lo, e));
+        // Since the square root is concave, √x ≤ s + r / 2s.
+        let c = div_ru(r, add_rn(s, s));
+        Self::normalize(s, c, s)
+    }
+
+    // The lane-wise maximum of upper bounds.
+    fn max(self, rhs: Self) -> Self {
+        let [x0, x1] = extract(self.hi);
+        let [x0_lo, x1_lo] = extract(self.lo);
+        let [y0, y1] = extract(rhs.hi);
+        let [y0_lo, y1_lo] = extract(rhs.lo);
+        let max = |x: f64, x_lo: f64, y: f64, y_lo: f64| {
+            if x > y || x == y && x_lo > y_lo {
+                (x, x_lo)
+            } else {
+                (y, y_lo)
+            }
+        };
+        let (h0, l0) = max(x0, x0_lo, y0, y0_lo);
+        let (h1, l1) = max(x1, x1_lo, y1, y1_lo);
+        Self {
+            hi: constant(h0, h1),
+            lo: constant(l0, l1),
+        }
+    }
+
+    fn neg0(self) -> Self {
+        Self {
+            hi: neg0(self.hi),
+            lo: neg0(self.lo),
+        }
+    }
+
+    fn shuffle02(self, rhs: Self) -> Self {
+        Self {
+            hi: shuffle02(self.hi, rhs.hi),
+            lo: shuffle02(self.lo, rhs.lo),
+        }
+    }
+
+    fn shuffle03(self, rhs: Self) -> Self {
+        Self {
+            hi: shuffle03(self.hi, rhs.hi),
+            lo: shuffle03(self.lo, rhs.lo),
+        }
+    }
+
+    fn shuffle13(self, rhs: Self) -> Self {
+        Self {
+            hi: shuffle13(self.hi, rhs.hi),
+            lo: shuffle13(self.lo, rhs.lo),
+        }
+    }
+
+    fn swap(self) -> Self {
+        Self {
+            hi: swap(self.hi),
+            lo: swap(self.lo),
+        }
+    }
+}
+
+impl DdInterval {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = unsafe { transmute([f64::NAN, f64::NAN, 0.0, 0.0]) };
+
+    /// $\[-∞, +∞\]$.
+    pub const ENTIRE: Self = unsafe { transmute([f64::INFINITY, f64::INFINITY, 0.0, 0.0]) };
+
+    /// Returns the lower bound of `self` as a pair `(h, l)` that represents $h + l$.
+    ///
+    /// `(+∞, 0.0)` is returned if `self` is empty.
+    pub fn inf(self) -> (f64, f64) {
+        if self.is_empty() {
+            return (f64::INFINITY, 0.0);
+        }
+
+        (-extract0(self.rep.hi), -extract0(self.rep.lo))
+    }
+
+    /// Returns the upper bound of `self` as a pair `(h, l)` that represents $h + l$.
+    ///
+    /// `(-∞, 0.0)` is returned if `self` is empty.
+    pub fn sup(self) -> (f64, f64) {
+        if self.is_empty() {
+            return (f64::NEG_INFINITY, 0.0);
+        }
+
+        (extract1(self.rep.hi), extract1(self.rep.lo))
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(self) -> bool {
+        extract0(self.rep.hi).is_nan()
+    }
+
+    /// Returns `true` if $\self = \[-∞, +∞\]$.
+    pub fn is_entire(self) -> bool {
+        all(eq(self.rep.hi, splat(f64::INFINITY)))
+    }
+
+    /// Returns `true` if `self` is a subset of `rhs`.
+    pub fn subset(self, rhs: Self) -> bool {
+        self.is_empty() | {
+            // all([-a; b] .≤ [-c; d])
+            let [x0, x1] = extract(self.rep.hi);
+            let [x0_lo, x1_lo] = extract(self.rep.lo);
+            let [y0, y1] = extract(rhs.rep.hi);
+            let [y0_lo, y1_lo] = extract(rhs.rep.lo);
+            let le = |x: f64, x_lo: f64, y: f64, y_lo: f64| x < y || x == y && x_lo <= y_lo;
+            le(x0, x0_lo, y0, y0_lo) && le(x1, x1_lo, y1, y1_lo)
+        }
+    }
+
+    /// The double-double version of [`Interval::sqr`].
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        use IntervalClass::*;
+        match self.classify() {
+            E => Self::EMPTY,
+            Z => Self::zero(),
+            M => {
+                let x = self.rep; // [-a; b]
+                let r = x.mul_ru(x); // [a^2; b^2]
+                let r = r.max(r.swap()); // [max(a^2, b^2); _]
+                Self {
+                    rep: DdX2::splat(0.0).shuffle02(r),
+                }
+            }
+            N0 | N1 => {
+                let x = self.rep.swap(); // [b; -a]
+                let y = x.neg0(); // [-b; -a]
+                Self { rep: x.mul_ru(y) }
+            }
+            P0 | P1 => {
+                let x = self.rep; // [-a; b]
+                let y = x.neg0(); // [a; b]
+                Self { rep: x.mul_ru(y) }
+            }
+        }
+    }
+
+    /// The double-double version of [`Interval::sqrt`].
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let x = self.rep; // [-a; b]
+        let a = -extract0(x.hi);
+        let b = extract1(x.hi);
+
+        if b < 0.0 {
+            Self::EMPTY
+        } else if a <= 0.0 {
+            let u = x.shuffle13(x).sqrt_ru(); // [√b; √b]
+            Self {
+                rep: DdX2::splat(0.0).shuffle03(u),
+            }
+        } else {
+            // -√a = -a / √a ≤ -a / u0, where u0 ≥ √a.
+            let u = x.neg0().sqrt_ru(); // [u0; u1] = [√a; √b]
+            let l = x.div_ru(u); // [-a / u0; _]
+            Self {
+                rep: l.shuffle03(u),
+            }
+        }
+    }
+
+    fn zero() -> Self {
+        Self {
+            rep: DdX2::splat(0.0),
+        }
+    }
+
+    fn classify(self) -> IntervalClass {
+        // Since |l| ≤ ulp(h) / 2, the sign of h + l is the same as that of h.
+        Interval { rep: self.rep.hi }.classify()
+    }
+
+    fn classify2(self, rhs: Self) -> IntervalClass2 {
+        Interval { rep: self.rep.hi }.classify2(Interval { rep: rhs.rep.hi })
+    }
+}
+
+impl PartialEq for DdInterval {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.is_empty() & rhs.is_empty()
+            | all(eq(self.rep.hi, rhs.rep.hi)) & all(eq(self.rep.lo, rhs.rep.lo))
+    }
+}
+
+impl Eq for DdInterval {}
+
+impl TryFrom<((f64, f64), (f64, f64))> for DdInterval {
+    type Error = IntervalError;
+
+    /// Creates an interval $\[a + a', b + b'\]$ from `((a, a'), (b, b'))`.
+    ///
+    /// The bounds are normalized exactly unless they overflow, in which case
+    /// they are rounded outward. An error is returned if any of the numbers is NaN,
+    /// if $a'$ or $b'$ is infinite or nonzero while $a$ or $b$ is infinite, respectively,
+    /// or if $a + a' ≤ b + b' ∧ a < +∞ ∧ b > -∞$ does not hold.
+    fn try_from(((a, a2), (b, b2)): ((f64, f64), (f64, f64))) -> Result<Self> {
+        let valid = |h: f64, l: f64| l.is_finite() && (h.is_finite() || l == 0.0);
+        if valid(a, a2) && valid(b, b2) && a != f64::INFINITY && b != f64::NEG_INFINITY {
+            let hi = constant(-a, b);
+            let lo = constant(-a2, b2);
+            let x = DdX2::normalize(hi, lo, add_ru(hi, lo));
+            let [h0, h1] = extract(x.hi);
+            let [l0, l1] = extract(x.lo);
+            // a + a' ≤ b + b'
+            if -h0 < h1 || -h0 == h1 && -l0 <= l1 {
+                return Ok(Self { rep: x });
+            }
+        }
+
+        Err(Self::Error {
+            kind: IntervalErrorKind::UndefinedOperation,
+        })
+    }
+}
+
+impl From<Interval> for DdInterval {
+    fn from(x: Interval) -> Self {
+        Self {
+            rep: DdX2 {
+                hi: x.rep,
+                lo: splat(0.0),
+            },
+        }
+    }
+}
+
+impl From<DdInterval> for Interval {
+    fn from(x: DdInterval) -> Self {
+        // [-a; b] ↦ [-a rounded up; b rounded up]
+        Self {
+            rep: add_ru(x.rep.hi, x.rep.lo),
+        }
+    }
+}
+
+impl Neg for DdInterval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            rep: self.rep.swap(),
+        }
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for DdInterval);
+
+impl Add for DdInterval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self {
+            rep: self.rep.add_ru(rhs.rep),
+        }
+    }
+}
+
+forward_ref_binop!(impl Add, add for DdInterval, DdInterval);
+
+impl Sub for DdInterval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for DdInterval, DdInterval);
+
+impl Mul for DdInterval {
+    type Output = Self;
+
+    // See `Mul for Interval` for the derivation of each case.
+    fn mul(self, rhs: Self) -> Self {
+        use IntervalClass2::*;
+        let x = self.rep;
+        let y = rhs.rep;
+        let rep = match self.classify2(rhs) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | P0_E | P1_E | Z_E => {
+                return Self::EMPTY
+            }
+            M_Z | N0_Z | N1_Z | P0_Z | P1_Z | Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 | Z_Z => {
+                return Self::zero()
+            }
+            M_M => {
+                let xy = x.shuffle02(x).mul_ru(y.swap()); // [-a; -a] .* [d; -c]
+                let zw = x.shuffle13(x).mul_ru(y); // [b; b] .* [-c; d]
+                xy.max(zw)
+            }
+            M_N0 | M_N1 => x.swap().mul_ru(y.shuffle02(y)), // [b; -a] .* [-c; -c]
+            M_P0 | M_P1 => x.mul_ru(y.shuffle13(y)),        // [-a; b] .* [d; d]
+            N0_M | N1_M => x.shuffle02(x).mul_ru(y.swap()), // [-a; -a] .* [d; -c]
+            N0_N0 | N0_N1 | N1_N0 | N1_N1 => x.swap().neg0().mul_ru(y.swap()), // [-b; -a] .* [d; -c]
+            N0_P0 | N0_P1 | N1_P0 | N1_P1 => x.mul_ru(y.neg0().swap()),        // [-a; b] .* [d; c]
+            P0_M | P1_M => x.shuffle13(x).mul_ru(y),                           // [b; b] .* [-c; d]
+            P0_N0 | P0_N1 | P1_N0 | P1_N1 => x.neg0().swap().mul_ru(y),        // [b; a] .* [-c; d]
+            P0_P0 | P0_P1 | P1_P0 | P1_P1 => x.mul_ru(y.neg0()),               // [-a; b] .* [c; d]
+        };
+        Self { rep }
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for DdInterval, DdInterval);
+
+impl Div for DdInterval {
+    type Output = Self;
+
+    // See `Div for Interval` and the division functions in basic.rs
+    // for the derivation of each case.
+    fn div(self, rhs: Self) -> Self {
+        use IntervalClass2::*;
+        let x = self.rep;
+        let y = rhs.rep;
+        let inf = DdX2::splat(f64::INFINITY);
+        let rep = match self.classify2(rhs) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | M_Z | N0_E | N0_Z | N1_E | N1_Z
+            | P0_E | P0_Z | P1_E | P1_Z | Z_E | Z_Z => return Self::EMPTY,
+            M_M | M_N0 | M_P0 | N0_M | N1_M | P0_M | P1_M => return Self::ENTIRE,
+            Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 => return Self::zero(),
+            M_N1 => {
+                // [b; -a] ./ [-d; -d]
+                let y = y.swap().neg0();
+                x.swap().div_ru(y.shuffle02(y))
+            }
+            M_P1 => {
+                // [-a; b] ./ [c; c]
+                let y = y.neg0();
+                x.div_ru(y.shuffle02(y))
+            }
+            N0_N0 | N1_N0 => x.swap().div_ru(y).shuffle02(inf), // [b/-c; +∞]
+            N0_N1 | N1_N1 => x.neg0().swap().div_ru(y),         // [b; a] ./ [-c; d]
+            N0_P0 | N1_P0 => inf.shuffle03(x.div_ru(y)),        // [+∞; b/d]
+            N0_P1 | N1_P1 => x.div_ru(y.neg0()),                // [-a; b] ./ [c; d]
+            P0_N0 | P1_N0 => inf.shuffle02(x.div_ru(y)),        // [+∞; -a/-c]
+            P0_N1 | P1_N1 => x.swap().neg0().div_ru(y.swap()),  // [-b; -a] ./ [d; -c]
+            P0_P0 | P1_P0 => x.div_ru(y.swap()).shuffle02(inf), // [-a/d; +∞]
+            P0_P1 | P1_P1 => x.div_ru(y.neg0().swap()),         // [-a; b] ./ [d; c]
+        };
+        Self { rep }
+    }
+}
+
+forward_ref_binop!(impl Div, div for DdInterval, DdInterval);
+
+macro_rules! impl_op_assign {
+    ($OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl $OpAssign for DdInterval {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        forward_ref_op_assign!(impl $OpAssign, $op_assign for DdInterval, DdInterval);
+    };
+}
+
+impl_op_assign!(AddAssign, add_assign, add);
+impl_op_assign!(SubAssign, sub_assign, sub);
+impl_op_assign!(MulAssign, mul_assign, mul);
+impl_op_assign!(DivAssign, div_assign, div);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    type I = Interval;
+    type D = DdInterval;
+
+    fn d(a: f64, b: f64) -> D {
+        D::from(I::try_from((a, b)).unwrap())
+    }
+
+    #[test]
+    fn conversion() {
+        assert!(D::from(I::EMPTY).is_empty());
+        assert!(D::from(I::ENTIRE).is_entire());
+        assert!(I::from(D::EMPTY).is_empty());
+        assert!(I::from(D::ENTIRE).is_entire());
+        assert_eq!(I::from(D::from(I::PI)), I::PI);
+
+        let x = D::try_from(((1.0, -1e-20), (1.0, 1e-20))).unwrap();
+        assert_eq!(x.inf(), (1.0, -1e-20));
+        assert_eq!(x.sup(), (1.0, 1e-20));
+        assert_eq!(
+            I::from(x),
+            const_interval!(0.9999999999999999, 1.0000000000000002)
+        );
+        assert!(D::try_from(((1e-20, 1.0), (0.5, 0.5))).is_err());
+        let x = D::try_from(((1e-20, 1.0), (0.5, 0.75))).unwrap();
+        assert_eq!(x.inf(), (1.0, 1e-20));
+        assert_eq!(x.sup(), (1.25, 0.0));
+        assert!(D::try_from(((1.0, 1e-20), (1.0, 0.0))).is_err());
+        assert!(D::try_from(((f64::NEG_INFINITY, 1.0), (1.0, 0.0))).is_err());
+        assert!(D::try_from(((1.0, f64::NAN), (1.0, 0.0))).is_err());
+        assert!(D::try_from(((f64::INFINITY, 0.0), (f64::INFINITY, 0.0))).is_err());
+        assert!(D::try_from(((1.0, 0.0), (f64::NEG_INFINITY, 0.0))).is_err());
+    }
+
+    #[test]
+    fn arith() {
+        let one = d(1.0, 1.0);
+        let three = d(3.0, 3.0);
+        let x = one / three;
+        assert!(x.subset(D::from(const_interval!(0.333, 0.334))));
+        let y = x * three;
+        assert!(d(1.0, 1.0).subset(y));
+        let (a, a2) = y.inf();
+        let (b, b2) = y.sup();
+        assert!((b - a) + (b2 - a2) < 1e-30);
+
+        let big = d(1e16, 1e16);
+        assert_eq!((big + one) - big, one);
+        assert_eq!(-(big - (big + one)), one);
+        let mut z = big;
+        z += one;
+        z -= big;
+        z *= three;
+        z /= three;
+        assert_eq!(z, one);
+
+        let m = d(-2.0, 3.0);
+        let n = d(-3.0, -2.0);
+        let p = d(2.0, 4.0);
+        assert_eq!(m * m, d(-6.0, 9.0));
+        assert_eq!(m * n, d(-9.0, 6.0));
+        assert_eq!(n * p, d(-12.0, -4.0));
+        assert_eq!(n * n, d(4.0, 9.0));
+        assert_eq!(m / p, d(-1.0, 1.5));
+        assert!((m / m).is_entire());
+        assert!((p / d(0.0, 0.0)).is_empty());
+        assert_eq!(p / d(0.0, 2.0), d(1.0, f64::INFINITY));
+        assert_eq!(p / d(-2.0, 0.0), d(f64::NEG_INFINITY, -1.0));
+        assert_eq!(p / d(1.0, f64::INFINITY), d(0.0, 4.0));
+        assert!((D::EMPTY + p).is_empty());
+        assert!((p * D::EMPTY).is_empty());
+        assert_eq!(m.sqr(), d(0.0, 9.0));
+        assert_eq!(n.sqr(), d(4.0, 9.0));
+
+        let x = d(f64::MAX, f64::MAX);
+        assert_eq!(x + x, d(f64::MAX, f64::INFINITY));
+        assert_eq!(-x - x, d(f64::NEG_INFINITY, f64::MIN));
+        assert_eq!(x * x, d(f64::MAX, f64::INFINITY));
+    }
+
+    #[test]
+    fn sqrt() {
+        let x = d(2.0, 2.0).sqrt();
+        assert!(I::from(x).subset(I::SQRT_2));
+        let y = x.sqr();
+        assert!(d(2.0, 2.0).subset(y));
+        let (a, a2) = y.inf();
+        let (b, b2) = y.sup();
+        assert!((b - a) + (b2 - a2) < 1e-30);
+
+        assert_eq!(d(4.0, 9.0).sqrt(), d(2.0, 3.0));
+        assert_eq!(d(-4.0, 9.0).sqrt(), d(0.0, 3.0));
+        assert_eq!(d(0.0, f64::INFINITY).sqrt(), d(0.0, f64::INFINITY));
+        assert!(d(-4.0, -1.0).sqrt().is_empty());
+        assert!(D::EMPTY.sqrt().is_empty());
+    }
+}
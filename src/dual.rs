@@ -0,0 +1,648 @@
+use crate::interval::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An interval paired with enclosures of the partial derivatives with respect to `N` variables,
+/// which implements forward-mode automatic differentiation.
+///
+/// Each operation returns an enclosure of the range of the function over the input intervals,
+/// along with enclosures of the ranges of its partial derivatives. At points where the function
+/// is not differentiable, the enclosures of the derivatives contain the one-sided derivatives,
+/// or are the entire set of real numbers if they cannot be bounded.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, DualInterval};
+/// // f(x) = x² + 3x
+/// let [x] = DualInterval::variables([c!(1.0, 2.0)]);
+/// let y = x.sqr() + c!(3.0, 3.0) * x;
+/// assert_eq!(y.value(), c!(4.0, 10.0));
+/// assert_eq!(y.derivative(), c!(5.0, 7.0));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DualInterval<const N: usize = 1> {
+    x: Interval,
+    dx: [Interval; N],
+}
+
+/// The decorated version of [`DualInterval`].
+///
+/// Both the value and the partial derivatives are decorated intervals. Thus, for example,
+/// a decoration [`Decoration::Com`] or [`Decoration::Dac`] of every partial derivative
+/// indicates that the derivatives are defined and continuous on the input intervals.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_dec_interval as c, DecDualInterval, Decoration};
+/// let [x] = DecDualInterval::variables([c!(0.0, 1.0)]);
+/// let y = x.sqrt();
+/// assert_eq!(y.value().decoration(), Decoration::Com);
+/// assert_eq!(y.derivative().decoration(), Decoration::Trv);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecDualInterval<const N: usize = 1> {
+    x: DecInterval,
+    dx: [DecInterval; N],
+}
+
+// Operations on the scalar types that are not provided by them in a uniform way.
+trait Scalar: Copy {
+    fn point(x: f64) -> Self;
+
+    // The enclosure of the derivative at points where the function is not differentiable.
+    fn undefined() -> Self;
+}
+
+impl Scalar for Interval {
+    fn point(x: f64) -> Self {
+        Self::with_infsup_raw(x, x)
+    }
+
+    fn undefined() -> Self {
+        Self::ENTIRE
+    }
+}
+
+impl Scalar for DecInterval {
+    fn point(x: f64) -> Self {
+        Self::new(Interval::with_infsup_raw(x, x))
+    }
+
+    fn undefined() -> Self {
+        Self::set_dec(Interval::ENTIRE, Decoration::Trv)
+    }
+}
+
+macro_rules! impl_dual {
+    ($Dual:ident, $T:ty) => {
+        impl<const N: usize> $Dual<N> {
+            /// Creates a value from the given interval and the partial derivatives.
+            pub fn new(x: $T, dx: [$T; N]) -> Self {
+                Self { x, dx }
+            }
+
+            /// Creates a constant, i.e., a value whose partial derivatives are all zero.
+            pub fn constant(x: $T) -> Self {
+                Self {
+                    x,
+                    dx: [<$T as Scalar>::point(0.0); N],
+                }
+            }
+
+            /// Creates the independent variables $x_0, …, x_{N-1}$ that range over
+            /// the given intervals.
+            pub fn variables(xs: [$T; N]) -> [Self; N] {
+                let mut i = 0;
+                xs.map(|x| {
+                    let mut v = Self::constant(x);
+                    v.dx[i] = <$T as Scalar>::point(1.0);
+                    i += 1;
+                    v
+                })
+            }
+
+            /// Returns the enclosure of the range of the function.
+            pub fn value(self) -> $T {
+                self.x
+            }
+
+            /// Returns the enclosures of the ranges of the partial derivatives.
+            pub fn grad(self) -> [$T; N] {
+                self.dx
+            }
+
+            /// Returns the reciprocal of `self`.
+            #[must_use]
+            pub fn recip(self) -> Self {
+                let y = self.x.recip();
+                self.chain(y, -y.sqr())
+            }
+
+            /// Returns the square of `self`.
+            #[must_use]
+            pub fn sqr(self) -> Self {
+                self.chain(self.x.sqr(), <$T as Scalar>::point(2.0) * self.x)
+            }
+
+            /// Returns the principal square root of `self`.
+            #[must_use]
+            pub fn sqrt(self) -> Self {
+                let y = self.x.sqrt();
+                self.chain(y, (<$T as Scalar>::point(2.0) * y).recip())
+            }
+
+            // Returns `f(self)`, where `y` is the enclosure of the range of `f`
+            // and `fp` is the enclosure of the range of the derivative of `f`.
+            fn chain(self, y: $T, fp: $T) -> Self {
+                let fp = if fp.is_empty() && !y.is_empty() {
+                    <$T as Scalar>::undefined()
+                } else {
+                    fp
+                };
+                Self {
+                    x: y,
+                    dx: self.dx.map(|d| d * fp),
+                }
+            }
+
+            // Returns `f(self, rhs)`, where `y` is the enclosure of the range of `f`
+            // and `fx` and `fy` are the enclosures of the ranges of the partial derivatives
+            // with respect to the first and the second argument, respectively.
+            #[cfg(any(feature = "gmp", feature = "pure-rust"))]
+            fn chain2(self, rhs: Self, y: $T, fx: $T, fy: $T) -> Self {
+                let sanitize = |fp: $T| {
+                    if fp.is_empty() && !y.is_empty() {
+                        <$T as Scalar>::undefined()
+                    } else {
+                        fp
+                    }
+                };
+                let (fx, fy) = (sanitize(fx), sanitize(fy));
+                let mut dx = self.dx;
+                for (d, e) in dx.iter_mut().zip(rhs.dx) {
+                    *d = d.mul_add(fx, e * fy);
+                }
+                Self { x: y, dx }
+            }
+        }
+
+        impl $Dual<1> {
+            /// Returns the enclosure of the range of the derivative.
+            pub fn derivative(self) -> $T {
+                self.dx[0]
+            }
+        }
+
+        #[cfg(any(feature = "gmp", feature = "pure-rust"))]
+        impl<const N: usize> $Dual<N> {
+            /// Returns the inverse cosine of `self`.
+            #[must_use]
+            pub fn acos(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = -((one - self.x) * (one + self.x)).sqrt().recip();
+                self.chain(self.x.acos(), fp)
+            }
+
+            /// Returns the inverse hyperbolic cosine of `self`.
+            #[must_use]
+            pub fn acosh(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = ((self.x - one) * (self.x + one)).sqrt().recip();
+                self.chain(self.x.acosh(), fp)
+            }
+
+            /// Returns the inverse sine of `self`.
+            #[must_use]
+            pub fn asin(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = ((one - self.x) * (one + self.x)).sqrt().recip();
+                self.chain(self.x.asin(), fp)
+            }
+
+            /// Returns the inverse hyperbolic sine of `self`.
+            #[must_use]
+            pub fn asinh(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = (self.x.sqr() + one).sqrt().recip();
+                self.chain(self.x.asinh(), fp)
+            }
+
+            /// Returns the inverse tangent of `self`.
+            #[must_use]
+            pub fn atan(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = (self.x.sqr() + one).recip();
+                self.chain(self.x.atan(), fp)
+            }
+
+            /// Returns the angle of the point $(\rhs, \self)$ measured counterclockwise from
+            /// the positive $x$-axis in the Euclidean plane.
+            ///
+            /// The function is discontinuous on the negative $x$-axis, where the partial
+            /// derivatives are only one-sided.
+            #[must_use]
+            pub fn atan2(self, rhs: Self) -> Self {
+                let (y, x) = (self.x, rhs.x);
+                let r2 = x.sqr() + y.sqr();
+                self.chain2(rhs, y.atan2(x), x / r2, -y / r2)
+            }
+
+            /// Returns the inverse hyperbolic tangent of `self`.
+            #[must_use]
+            pub fn atanh(self) -> Self {
+                let one = <$T as Scalar>::point(1.0);
+                let fp = ((one - self.x) * (one + self.x)).recip();
+                self.chain(self.x.atanh(), fp)
+            }
+
+            /// Returns the cosine of `self`.
+            #[must_use]
+            pub fn cos(self) -> Self {
+                self.chain(self.x.cos(), -self.x.sin())
+            }
+
+            /// Returns the hyperbolic cosine of `self`.
+            #[must_use]
+            pub fn cosh(self) -> Self {
+                self.chain(self.x.cosh(), self.x.sinh())
+            }
+
+            /// Returns `self` raised to the power of $\e$.
+            #[must_use]
+            pub fn exp(self) -> Self {
+                let y = self.x.exp();
+                self.chain(y, y)
+            }
+
+            /// Returns `self` raised to the power of 10.
+            #[must_use]
+            pub fn exp10(self) -> Self {
+                let y = self.x.exp10();
+                self.chain(y, y * <$T>::LN_10)
+            }
+
+            /// Returns `self` raised to the power of 2.
+            #[must_use]
+            pub fn exp2(self) -> Self {
+                let y = self.x.exp2();
+                self.chain(y, y * <$T>::LN_2)
+            }
+
+            /// Returns the natural logarithm of `self`.
+            #[must_use]
+            pub fn ln(self) -> Self {
+                self.chain(self.x.ln(), self.x.recip())
+            }
+
+            /// Returns the base-10 logarithm of `self`.
+            #[must_use]
+            pub fn log10(self) -> Self {
+                self.chain(self.x.log10(), (self.x * <$T>::LN_10).recip())
+            }
+
+            /// Returns the base-2 logarithm of `self`.
+            #[must_use]
+            pub fn log2(self) -> Self {
+                self.chain(self.x.log2(), (self.x * <$T>::LN_2).recip())
+            }
+
+            /// Returns `self` raised to the power of `rhs`.
+            ///
+            /// See [`Interval::pow`] for the domain of the function.
+            #[must_use]
+            pub fn pow(self, rhs: Self) -> Self {
+                let (x, y) = (self.x, rhs.x);
+                let z = x.pow(y);
+                let one = <$T as Scalar>::point(1.0);
+                self.chain2(rhs, z, y * x.pow(y - one), z * x.ln())
+            }
+
+            /// Returns `self` raised to the power of `rhs`.
+            #[must_use]
+            pub fn powi(self, rhs: i32) -> Self {
+                let n = <$T as Scalar>::point(rhs as f64);
+                let fp = match rhs.checked_sub(1) {
+                    Some(m) => n * self.x.powi(m),
+                    _ => n * self.x.powi(rhs) / self.x,
+                };
+                self.chain(self.x.powi(rhs), fp)
+            }
+
+            /// Returns the sine of `self`.
+            #[must_use]
+            pub fn sin(self) -> Self {
+                self.chain(self.x.sin(), self.x.cos())
+            }
+
+            /// Returns the hyperbolic sine of `self`.
+            #[must_use]
+            pub fn sinh(self) -> Self {
+                self.chain(self.x.sinh(), self.x.cosh())
+            }
+
+            /// Returns the tangent of `self`.
+            #[must_use]
+            pub fn tan(self) -> Self {
+                let y = self.x.tan();
+                let one = <$T as Scalar>::point(1.0);
+                self.chain(y, y.sqr() + one)
+            }
+
+            /// Returns the hyperbolic tangent of `self`.
+            #[must_use]
+            pub fn tanh(self) -> Self {
+                self.chain(self.x.tanh(), self.x.cosh().sqr().recip())
+            }
+        }
+
+        impl<const N: usize> From<$T> for $Dual<N> {
+            fn from(x: $T) -> Self {
+                Self::constant(x)
+            }
+        }
+
+        impl<const N: usize> Neg for $Dual<N> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    x: -self.x,
+                    dx: self.dx.map(|d| -d),
+                }
+            }
+        }
+
+        impl<const N: usize> Add for $Dual<N> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                let mut dx = self.dx;
+                for (d, e) in dx.iter_mut().zip(rhs.dx) {
+                    *d += e;
+                }
+                Self {
+                    x: self.x + rhs.x,
+                    dx,
+                }
+            }
+        }
+
+        impl<const N: usize> Sub for $Dual<N> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                let mut dx = self.dx;
+                for (d, e) in dx.iter_mut().zip(rhs.dx) {
+                    *d -= e;
+                }
+                Self {
+                    x: self.x - rhs.x,
+                    dx,
+                }
+            }
+        }
+
+        impl<const N: usize> Mul for $Dual<N> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                let mut dx = self.dx;
+                for (d, e) in dx.iter_mut().zip(rhs.dx) {
+                    *d = d.mul_add(rhs.x, self.x * e);
+                }
+                Self {
+                    x: self.x * rhs.x,
+                    dx,
+                }
+            }
+        }
+
+        impl<const N: usize> Div for $Dual<N> {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                // (u / v)' = (u' - (u / v) v') / v
+                let q = self.x / rhs.x;
+                let mut dx = self.dx;
+                for (d, e) in dx.iter_mut().zip(rhs.dx) {
+                    *d = (-q).mul_add(e, *d) / rhs.x;
+                }
+                Self { x: q, dx }
+            }
+        }
+
+        impl<const N: usize> Add<$T> for $Dual<N> {
+            type Output = Self;
+
+            fn add(self, rhs: $T) -> Self {
+                Self {
+                    x: self.x + rhs,
+                    dx: self.dx,
+                }
+            }
+        }
+
+        impl<const N: usize> Add<$Dual<N>> for $T {
+            type Output = $Dual<N>;
+
+            fn add(self, rhs: $Dual<N>) -> $Dual<N> {
+                rhs + self
+            }
+        }
+
+        impl<const N: usize> Sub<$T> for $Dual<N> {
+            type Output = Self;
+
+            fn sub(self, rhs: $T) -> Self {
+                Self {
+                    x: self.x - rhs,
+                    dx: self.dx,
+                }
+            }
+        }
+
+        impl<const N: usize> Sub<$Dual<N>> for $T {
+            type Output = $Dual<N>;
+
+            fn sub(self, rhs: $Dual<N>) -> $Dual<N> {
+                -rhs + self
+            }
+        }
+
+        impl<const N: usize> Mul<$T> for $Dual<N> {
+            type Output = Self;
+
+            fn mul(self, rhs: $T) -> Self {
+                Self {
+                    x: self.x * rhs,
+                    dx: self.dx.map(|d| d * rhs),
+                }
+            }
+        }
+
+        impl<const N: usize> Mul<$Dual<N>> for $T {
+            type Output = $Dual<N>;
+
+            fn mul(self, rhs: $Dual<N>) -> $Dual<N> {
+                rhs * self
+            }
+        }
+
+        impl<const N: usize> Div<$T> for $Dual<N> {
+            type Output = Self;
+
+            fn div(self, rhs: $T) -> Self {
+                Self {
+                    x: self.x / rhs,
+                    dx: self.dx.map(|d| d / rhs),
+                }
+            }
+        }
+
+        impl<const N: usize> Div<$Dual<N>> for $T {
+            type Output = $Dual<N>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: $Dual<N>) -> $Dual<N> {
+                // (c / v)' = -(c / v) v' / v
+                let q = self / rhs.x;
+                $Dual {
+                    x: q,
+                    dx: rhs.dx.map(|d| -q * d / rhs.x),
+                }
+            }
+        }
+
+        impl_dual_op_assign!($Dual, $T, AddAssign, add_assign, add);
+        impl_dual_op_assign!($Dual, $T, SubAssign, sub_assign, sub);
+        impl_dual_op_assign!($Dual, $T, MulAssign, mul_assign, mul);
+        impl_dual_op_assign!($Dual, $T, DivAssign, div_assign, div);
+    };
+}
+
+macro_rules! impl_dual_op_assign {
+    ($Dual:ident, $T:ty, $OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl<const N: usize> $OpAssign for $Dual<N> {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        impl<const N: usize> $OpAssign<$T> for $Dual<N> {
+            fn $op_assign(&mut self, rhs: $T) {
+                *self = self.$op(rhs);
+            }
+        }
+    };
+}
+
+impl_dual!(DualInterval, Interval);
+impl_dual!(DecDualInterval, DecInterval);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use DecDualInterval as DD;
+    use DualInterval as D;
+    use Interval as I;
+
+    #[test]
+    fn arith() {
+        let [x, y] = D::variables([const_interval!(1.0, 2.0), const_interval!(3.0, 4.0)]);
+        let z = x * y - x / y + const_interval!(1.0, 1.0);
+        assert!(z.value().subset(const_interval!(3.0, 9.0)));
+        // ∂z/∂x = y - 1/y, ∂z/∂y = x + x/y²
+        let [dx, dy] = z.grad();
+        assert!(const_interval!(2.7, 3.75).subset(dx));
+        assert!(const_interval!(1.0625, 2.2).subset(dy));
+        assert!(dx.wid() < 1.5 && dy.wid() < 1.5);
+
+        let c = D::<2>::constant(const_interval!(2.0, 2.0));
+        assert_eq!(c.grad(), [const_interval!(0.0, 0.0); 2]);
+        assert_eq!((x + c).grad(), x.grad());
+        assert_eq!((c - x).grad(), (-x).grad());
+
+        let mut w = x;
+        w *= x;
+        w += const_interval!(1.0, 1.0);
+        w -= y;
+        w /= const_interval!(2.0, 2.0);
+        assert_eq!(
+            w.grad(),
+            [const_interval!(1.0, 2.0), const_interval!(-0.5, -0.5)]
+        );
+
+        let [x] = D::variables([const_interval!(1.0, 2.0)]);
+        let r = const_interval!(1.0, 1.0) / x;
+        assert_eq!(r.value(), const_interval!(0.5, 1.0));
+        assert_eq!(r.derivative(), const_interval!(-1.0, -0.25));
+        assert_eq!(x.recip().derivative(), const_interval!(-1.0, -0.25));
+        assert_eq!(x.sqr().derivative(), const_interval!(2.0, 4.0));
+    }
+
+    #[test]
+    fn domain_edges() {
+        let [x] = D::variables([const_interval!(0.0, 4.0)]);
+        let y = x.sqrt();
+        assert_eq!(y.value(), const_interval!(0.0, 2.0));
+        assert_eq!(y.derivative(), const_interval!(0.25, f64::INFINITY));
+
+        let [x] = D::variables([const_interval!(0.0, 0.0)]);
+        let y = x.sqrt();
+        assert_eq!(y.value(), const_interval!(0.0, 0.0));
+        assert_eq!(y.derivative(), I::ENTIRE);
+
+        let [x] = D::variables([I::EMPTY]);
+        assert!(x.sqrt().value().is_empty());
+        assert!(x.sqrt().derivative().is_empty());
+
+        let [x] = DD::variables([const_dec_interval!(0.0, 0.0)]);
+        let y = x.sqrt();
+        assert_eq!(y.derivative().decoration(), Decoration::Trv);
+        let [x] = DD::variables([const_dec_interval!(1.0, 4.0)]);
+        let y = x.sqrt();
+        assert_eq!(y.value().decoration(), Decoration::Com);
+        assert_eq!(y.derivative().decoration(), Decoration::Com);
+        assert_eq!(y.derivative(), const_dec_interval!(0.25, 0.5));
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn elementary() {
+        // Checks the derivative enclosures against central differences at the midpoints.
+        fn check(f: fn(D) -> D, g: fn(f64) -> f64, x: I) {
+            let [v] = D::variables([x]);
+            let d = f(v).derivative();
+            let m = x.mid();
+            let h = 1e-6;
+            let fd = (g(m + h) - g(m - h)) / (2.0 * h);
+            assert!(d.inf() - 1e-6 <= fd && fd <= d.sup() + 1e-6);
+            let [p] = D::variables([interval!(m, m).unwrap()]);
+            assert!((f(p).derivative().mid() - fd).abs() < 1e-6);
+        }
+
+        let x = const_interval!(0.25, 0.5);
+        check(D::acos, f64::acos, x);
+        check(D::asin, f64::asin, x);
+        check(D::asinh, f64::asinh, x);
+        check(D::atan, f64::atan, x);
+        check(D::atanh, f64::atanh, x);
+        check(D::cos, f64::cos, x);
+        check(D::cosh, f64::cosh, x);
+        check(D::exp, f64::exp, x);
+        check(D::exp10, |x| 10f64.powf(x), x);
+        check(D::exp2, f64::exp2, x);
+        check(D::ln, f64::ln, x);
+        check(D::log10, f64::log10, x);
+        check(D::log2, f64::log2, x);
+        check(D::sin, f64::sin, x);
+        check(D::sinh, f64::sinh, x);
+        check(D::tan, f64::tan, x);
+        check(D::tanh, f64::tanh, x);
+        check(|x| x.powi(3), |x| x.powi(3), x);
+        check(|x| x.powi(-2), |x| x.powi(-2), x);
+        check(D::acosh, f64::acosh, const_interval!(1.5, 2.0));
+
+        let [x, y] = D::variables([const_interval!(1.0, 2.0), const_interval!(0.5, 1.0)]);
+        let z = x.pow(y);
+        assert_eq!(z.value(), const_interval!(1.0, 2.0));
+        let [dx, dy] = z.grad();
+        assert!(dx.contains(0.5) && dx.contains(1.0));
+        assert!(dy.contains(0.0) && dy.contains(2.0 * 2f64.ln()));
+
+        let z = y.atan2(x);
+        let [dx, dy] = z.grad();
+        // ∂/∂y = x / (x² + y²), ∂/∂x = -y / (x² + y²) at (x, y) = (1, 1)
+        assert!(dy.contains(0.5) && dx.contains(-0.5));
+
+        let [y, x] = D::variables([const_interval!(0.0, 0.0), const_interval!(0.0, 0.0)]);
+        assert_eq!(y.atan2(x).grad(), [I::EMPTY; 2]);
+        let [x] = D::variables([const_interval!(1.0, 1.0)]);
+        assert_eq!(x.acos().derivative(), I::ENTIRE);
+        assert_eq!(
+            x.powi(i32::MIN).derivative(),
+            const_interval!(-2147483648.0, -2147483648.0)
+        );
+    }
+}
@@ -1,103 +1,53 @@
-use crate::{classify::*, const_interval, interval::*};
-use gmp_mpfr_sys::mpfr;
-use rug::Float;
-
-macro_rules! mpfr_fn {
-    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
-        fn $f_rd(x: f64) -> f64 {
-            mpfr_fn!($mpfr_f(x, RNDD))
-        }
-
-        fn $f_ru(x: f64) -> f64 {
-            mpfr_fn!($mpfr_f(x, RNDU))
-        }
-    };
-
-    ($mpfr_f:ident($x:ident, $rnd:ident)) => {{
-        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
-        let rnd = mpfr::rnd_t::$rnd;
-        unsafe {
-            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), rnd);
-            mpfr::get_d(x.as_raw(), rnd)
-        }
-    }};
-}
-
-macro_rules! mpfr_fn2 {
-    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
-        fn $f_rd(x: f64, y: f64) -> f64 {
-            mpfr_fn2!($mpfr_f(x, y, RNDD))
-        }
-
-        fn $f_ru(x: f64, y: f64) -> f64 {
-            mpfr_fn2!($mpfr_f(x, y, RNDU))
-        }
-    };
-
-    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
-        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
-        let y = Float::with_val(f64::MANTISSA_DIGITS, $y);
-        let rnd = mpfr::rnd_t::$rnd;
-        unsafe {
-            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), y.as_raw(), rnd);
-            mpfr::get_d(x.as_raw(), rnd)
-        }
-    }};
+use crate::{
+    classify::*,
+    const_interval,
+    interval::*,
+    simd::{extract0, floor, splat},
+};
+
+// The point functions rounded downward and upward, `f_rd` and `f_ru`, are provided by MPFR
+// or by a pure-Rust implementation if the feature `pure-rust` is enabled.
+cfg_if::cfg_if! {
+    if #[cfg(feature = "pure-rust")] {
+        // Used as the reference in the tests of `pure`.
+        #[cfg(feature = "gmp")]
+        #[allow(dead_code)]
+        mod mpfr;
+        mod pure;
+        pub(crate) use pure::*;
+    } else {
+        mod mpfr;
+        pub(crate) use mpfr::*;
+    }
 }
 
-macro_rules! mpfr_fn_si {
-    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
-        fn $f_rd(x: f64, y: i32) -> f64 {
-            mpfr_fn_si!($mpfr_f(x, y, RNDD))
-        }
-
-        fn $f_ru(x: f64, y: i32) -> f64 {
-            mpfr_fn_si!($mpfr_f(x, y, RNDU))
-        }
-    };
+// Every number whose magnitude is at least this bound is an even integer.
+const MAX_EXACT: f64 = 9007199254740992.0; // 2^53
 
-    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
-        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
-        let rnd = mpfr::rnd_t::$rnd;
-        unsafe {
-            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), $y.into(), rnd);
-            mpfr::get_d(x.as_raw(), rnd)
-        }
-    }};
+// The scalar version of `simd::floor`, which is available without `std`.
+fn floor1(x: f64) -> f64 {
+    extract0(floor(splat(x)))
 }
 
-mpfr_fn!(acos, acos_rd, acos_ru);
-mpfr_fn!(acosh, acosh_rd, acosh_ru);
-mpfr_fn!(asin, asin_rd, asin_ru);
-mpfr_fn!(asinh, asinh_rd, asinh_ru);
-mpfr_fn!(atan, atan_rd, atan_ru);
-mpfr_fn2!(atan2, atan2_rd, atan2_ru);
-mpfr_fn!(atanh, atanh_rd, atanh_ru);
-mpfr_fn!(cos, cos_rd, cos_ru);
-mpfr_fn!(cosh, cosh_rd, cosh_ru);
-mpfr_fn!(exp, exp_rd, exp_ru);
-mpfr_fn!(exp10, exp10_rd, exp10_ru);
-mpfr_fn!(exp2, exp2_rd, exp2_ru);
-mpfr_fn!(log, ln_rd, ln_ru);
-mpfr_fn!(log10, log10_rd, log10_ru);
-mpfr_fn!(log2, log2_rd, log2_ru);
-mpfr_fn2!(pow, pow_rd, pow_ru);
-mpfr_fn_si!(pow_si, pown_rd, pown_ru);
-mpfr_fn!(sin, sin_rd, sin_ru);
-mpfr_fn!(sinh, sinh_rd, sinh_ru);
-mpfr_fn!(tan, tan_rd, tan_ru);
-mpfr_fn!(tanh, tanh_rd, tanh_ru);
-
 fn rem_euclid_2(x: f64) -> f64 {
-    if 2.0 * (x / 2.0).floor() == x {
+    if 2.0 * floor1(x / 2.0) == x {
         0.0
     } else {
         1.0
     }
 }
 
+fn rem_euclid_4(x: f64) -> f64 {
+    x - 4.0 * floor1(x / 4.0)
+}
+
 macro_rules! impl_log {
     ($(#[$meta:meta])* $f:ident, $f_impl:ident, $f_rd:ident, $f_ru:ident) => {
+        impl_log!($(#[$meta])* $f, $f_impl, $f_rd, $f_ru, 0.0);
+    };
+
+    // The domain of the point function is `($dom_inf, ∞)`.
+    ($(#[$meta:meta])* $f:ident, $f_impl:ident, $f_rd:ident, $f_ru:ident, $dom_inf:expr) => {
         $(#[$meta])*
         #[must_use]
         pub fn $f(self) -> Self {
@@ -107,12 +57,12 @@ macro_rules! impl_log {
         #[allow(clippy::many_single_char_names)]
         fn $f_impl(self) -> (Self, Decoration) {
             // See the comment in atanh_impl.
-            const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+            const DOM: Interval = const_interval!($dom_inf, f64::INFINITY);
             let x = self.intersection(DOM);
 
             let a = x.inf_raw();
             let b = x.sup_raw();
-            if x.is_empty() || b <= 0.0 {
+            if x.is_empty() || b <= $dom_inf {
                 return (Self::EMPTY, Decoration::Trv);
             }
 
@@ -171,6 +121,35 @@ impl Interval {
         (y, d)
     }
 
+    /// Returns the inverse cosine of `self`, divided by π.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain      | Range      |
+    /// | ----------- | ---------- |
+    /// | $\[-1, 1\]$ | $\[0, 1\]$ |
+    #[must_use]
+    pub fn acos_pi(self) -> Self {
+        self.acos_pi_impl().0
+    }
+
+    fn acos_pi_impl(self) -> (Self, Decoration) {
+        const DOM: Interval = const_interval!(-1.0, 1.0);
+        let x = self.intersection(DOM);
+
+        if x.is_empty() {
+            return (x, Decoration::Trv);
+        }
+
+        let y = Self::with_infsup_raw(acos_pi_rd(x.sup_raw()), acos_pi_ru(x.inf_raw()));
+        let d = if self.subset(DOM) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
     /// Returns the inverse hyperbolic cosine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -200,6 +179,135 @@ impl Interval {
         (y, d)
     }
 
+    /// Returns the inverse cotangent of `self`.
+    ///
+    /// The point function is defined as $x ↦ π/2 - \operatorname{atan}(x)$, which is continuous
+    /// at $x = 0$.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range    |
+    /// | ------ | -------- |
+    /// | $\R$   | $(0, π)$ |
+    #[must_use]
+    pub fn acot(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        // acot(x) = atan2(1, x), which is monotonically decreasing.
+        Self::with_infsup_raw(atan2_rd(1.0, self.sup_raw()), atan2_ru(1.0, self.inf_raw()))
+    }
+
+    /// Returns the inverse hyperbolic cotangent of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain           | Range         |
+    /// | ---------------- | ------------- |
+    /// | $\R ∖ \[-1, 1\]$ | $\R ∖ \set 0$ |
+    #[must_use]
+    pub fn acoth(self) -> Self {
+        self.acoth_impl().0
+    }
+
+    fn acoth_impl(self) -> (Self, Decoration) {
+        // As in atanh_impl, we include ±1 in the domain since acoth_rd/ru return ±∞ for them.
+        const DOM_NEG: Interval = const_interval!(f64::NEG_INFINITY, -1.0);
+        const DOM_POS: Interval = const_interval!(1.0, f64::INFINITY);
+        let neg = self.intersection(DOM_NEG);
+        let pos = self.intersection(DOM_POS);
+        let has_neg = !neg.is_empty() && neg.inf_raw() < -1.0;
+        let has_pos = !pos.is_empty() && pos.sup_raw() > 1.0;
+
+        // The point function is decreasing on both branches,
+        // and is negative on the left branch and positive on the right one.
+        let y = match (has_neg, has_pos) {
+            (false, false) => return (Self::EMPTY, Decoration::Trv),
+            (true, false) => {
+                Self::with_infsup_raw(acoth_rd(neg.sup_raw()), acoth_ru(neg.inf_raw()))
+            }
+            (false, true) => {
+                Self::with_infsup_raw(acoth_rd(pos.sup_raw()), acoth_ru(pos.inf_raw()))
+            }
+            (true, true) => Self::ENTIRE,
+        };
+        let d = if self.interior(DOM_NEG) || self.interior(DOM_POS) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
+    /// Returns the inverse cosecant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain         | Range                    |
+    /// | -------------- | ------------------------ |
+    /// | $\R ∖ (-1, 1)$ | $\[-π/2, π/2\] ∖ \set 0$ |
+    #[must_use]
+    pub fn acsc(self) -> Self {
+        self.acsc_impl().0
+    }
+
+    fn acsc_impl(self) -> (Self, Decoration) {
+        const DOM_NEG: Interval = const_interval!(f64::NEG_INFINITY, -1.0);
+        const DOM_POS: Interval = const_interval!(1.0, f64::INFINITY);
+        let neg = self.intersection(DOM_NEG);
+        let pos = self.intersection(DOM_POS);
+
+        // The point function is decreasing on both branches,
+        // and is negative on the left branch and positive on the right one.
+        let y = match (neg.is_empty(), pos.is_empty()) {
+            (true, true) => return (Self::EMPTY, Decoration::Trv),
+            (false, true) => Self::with_infsup_raw(acsc_rd(neg.sup_raw()), acsc_ru(neg.inf_raw())),
+            (true, false) => Self::with_infsup_raw(acsc_rd(pos.sup_raw()), acsc_ru(pos.inf_raw())),
+            (false, false) => Self::with_infsup_raw(acsc_rd(neg.sup_raw()), acsc_ru(pos.inf_raw())),
+        };
+        let d = if self.subset(DOM_NEG) || self.subset(DOM_POS) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
+    /// Returns the inverse secant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain         | Range                  |
+    /// | -------------- | ---------------------- |
+    /// | $\R ∖ (-1, 1)$ | $\[0, π\] ∖ \set{π/2}$ |
+    #[must_use]
+    pub fn asec(self) -> Self {
+        self.asec_impl().0
+    }
+
+    fn asec_impl(self) -> (Self, Decoration) {
+        const DOM_NEG: Interval = const_interval!(f64::NEG_INFINITY, -1.0);
+        const DOM_POS: Interval = const_interval!(1.0, f64::INFINITY);
+        let neg = self.intersection(DOM_NEG);
+        let pos = self.intersection(DOM_POS);
+
+        // The point function is increasing on both branches,
+        // and takes larger values on the left branch than on the right one.
+        let y = match (neg.is_empty(), pos.is_empty()) {
+            (true, true) => return (Self::EMPTY, Decoration::Trv),
+            (false, true) => Self::with_infsup_raw(asec_rd(neg.inf_raw()), asec_ru(neg.sup_raw())),
+            (true, false) => Self::with_infsup_raw(asec_rd(pos.inf_raw()), asec_ru(pos.sup_raw())),
+            (false, false) => Self::with_infsup_raw(asec_rd(pos.inf_raw()), asec_ru(neg.sup_raw())),
+        };
+        let d = if self.subset(DOM_NEG) || self.subset(DOM_POS) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
     /// Returns the inverse sine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -229,6 +337,35 @@ impl Interval {
         (y, d)
     }
 
+    /// Returns the inverse sine of `self`, divided by π.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain      | Range           |
+    /// | ----------- | --------------- |
+    /// | $\[-1, 1\]$ | $\[-1/2, 1/2\]$ |
+    #[must_use]
+    pub fn asin_pi(self) -> Self {
+        self.asin_pi_impl().0
+    }
+
+    fn asin_pi_impl(self) -> (Self, Decoration) {
+        const DOM: Interval = const_interval!(-1.0, 1.0);
+        let x = self.intersection(DOM);
+
+        if x.is_empty() {
+            return (x, Decoration::Trv);
+        }
+
+        let y = Self::with_infsup_raw(asin_pi_rd(x.inf_raw()), asin_pi_ru(x.sup_raw()));
+        let d = if self.subset(DOM) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
     impl_mono_inc!(
         /// Returns the inverse hyperbolic sine of `self`.
         ///
@@ -267,8 +404,44 @@ impl Interval {
         self.atan2_impl(rhs).0
     }
 
-    #[allow(clippy::many_single_char_names)]
     fn atan2_impl(self, rhs: Self) -> (Self, Decoration) {
+        self.atan2_impl_with(rhs, atan2_rd, atan2_ru, Self::PI, Self::FRAC_PI_2)
+    }
+
+    /// Returns the angle of the point $(\rhs, \self)$ measured counterclockwise from the positive
+    /// $x$-axis in the Euclidean plane, divided by π.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                | Range     |
+    /// | --------------------- | --------- |
+    /// | $\R^2 ∖ \set{(0, 0)}$ | $(-1, 1\]$ |
+    #[must_use]
+    pub fn atan2_pi(self, rhs: Self) -> Self {
+        self.atan2_pi_impl(rhs).0
+    }
+
+    fn atan2_pi_impl(self, rhs: Self) -> (Self, Decoration) {
+        self.atan2_impl_with(
+            rhs,
+            atan2_pi_rd,
+            atan2_pi_ru,
+            const_interval!(1.0, 1.0),
+            const_interval!(0.5, 0.5),
+        )
+    }
+
+    // `pi` and `frac_pi_2` must be the enclosures of the values of the point function
+    // at (-1, 0) and (0, 1), respectively.
+    #[allow(clippy::many_single_char_names)]
+    fn atan2_impl_with(
+        self,
+        rhs: Self,
+        f_rd: fn(f64, f64) -> f64,
+        f_ru: fn(f64, f64) -> f64,
+        pi: Self,
+        frac_pi_2: Self,
+    ) -> (Self, Decoration) {
         let (x, y) = (rhs, self);
         let a = x.inf_raw();
         let b = x.sup_raw();
@@ -280,55 +453,52 @@ impl Interval {
             E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | P0_E | P1_E | Z_E
             | Z_Z => (Self::EMPTY, Decoration::Trv),
             M_M | M_N0 | N0_M | N0_N0 => (
-                Self::with_infsup_raw(-Self::PI.sup_raw(), Self::PI.sup_raw()),
+                Self::with_infsup_raw(-pi.sup_raw(), pi.sup_raw()),
                 Decoration::Trv,
             ),
 
             // First quadrant
             P0_P0 => (
-                Self::with_infsup_raw(0.0, Self::FRAC_PI_2.sup_raw()),
+                Self::with_infsup_raw(0.0, frac_pi_2.sup_raw()),
                 Decoration::Trv,
             ),
             P0_P1 | P1_P0 | P1_P1 | P1_Z | Z_P1 => (
-                Self::with_infsup_raw(atan2_rd(c, b), atan2_ru(d, a)),
+                Self::with_infsup_raw(f_rd(c, b), f_ru(d, a)),
                 Decoration::Com,
             ),
 
             // First & second quadrant
-            M_P0 | M_Z => (
-                Self::with_infsup_raw(0.0, Self::PI.sup_raw()),
-                Decoration::Trv,
-            ),
+            M_P0 | M_Z => (Self::with_infsup_raw(0.0, pi.sup_raw()), Decoration::Trv),
             M_P1 => (
-                Self::with_infsup_raw(atan2_rd(c, b), atan2_ru(c, a)),
+                Self::with_infsup_raw(f_rd(c, b), f_ru(c, a)),
                 Decoration::Com,
             ),
 
             // Second quadrant
             N0_P0 => (
-                Self::with_infsup_raw(Self::FRAC_PI_2.inf_raw(), Self::PI.sup_raw()),
+                Self::with_infsup_raw(frac_pi_2.inf_raw(), pi.sup_raw()),
                 Decoration::Trv,
             ),
             N0_P1 | N1_P1 => (
-                Self::with_infsup_raw(atan2_rd(d, b), atan2_ru(c, a)),
+                Self::with_infsup_raw(f_rd(d, b), f_ru(c, a)),
                 Decoration::Com,
             ),
             N1_P0 => (
-                Self::with_infsup_raw(atan2_rd(d, b), Self::PI.sup_raw()),
+                Self::with_infsup_raw(f_rd(d, b), pi.sup_raw()),
                 Decoration::Dac,
             ),
 
             // Second & third quadrant
             //N0_M => See above.
             N1_M | N1_N0 => (
-                Self::with_infsup_raw(-Self::PI.sup_raw(), Self::PI.sup_raw()),
+                Self::with_infsup_raw(-pi.sup_raw(), pi.sup_raw()),
                 Decoration::Def,
             ),
 
             // Third quadrant
             //N0_N0 => See above.
             N0_N1 | N1_N1 => (
-                Self::with_infsup_raw(atan2_rd(d, a), atan2_ru(c, b)),
+                Self::with_infsup_raw(f_rd(d, a), f_ru(c, b)),
                 Decoration::Com,
             ),
             //N1_N0 => See above.
@@ -336,48 +506,61 @@ impl Interval {
             // Third & fourth quadrant
             //M_N0 => See above.
             M_N1 => (
-                Self::with_infsup_raw(atan2_rd(d, a), atan2_ru(d, b)),
+                Self::with_infsup_raw(f_rd(d, a), f_ru(d, b)),
                 Decoration::Com,
             ),
 
             // Fourth quadrant
             P0_N0 => (
-                Self::with_infsup_raw(-Self::FRAC_PI_2.sup_raw(), 0.0),
+                Self::with_infsup_raw(-frac_pi_2.sup_raw(), 0.0),
                 Decoration::Trv,
             ),
             P0_N1 | P1_N0 | P1_N1 | Z_N1 => (
-                Self::with_infsup_raw(atan2_rd(c, a), atan2_ru(d, b)),
+                Self::with_infsup_raw(f_rd(c, a), f_ru(d, b)),
                 Decoration::Com,
             ),
 
             // Fourth & first quadrant
             P0_M | Z_M => (
-                Self::with_infsup_raw(-Self::FRAC_PI_2.sup_raw(), Self::FRAC_PI_2.sup_raw()),
+                Self::with_infsup_raw(-frac_pi_2.sup_raw(), frac_pi_2.sup_raw()),
                 Decoration::Trv,
             ),
             P1_M => (
-                Self::with_infsup_raw(atan2_rd(c, a), atan2_ru(d, a)),
+                Self::with_infsup_raw(f_rd(c, a), f_ru(d, a)),
                 Decoration::Com,
             ),
 
             // X axis
             //M_Z => See above.
-            N0_Z => (Self::PI, Decoration::Trv),
+            N0_Z => (pi, Decoration::Trv),
             // The next case cannot be merged with N1_P0 unless we replace -0.0 with +0.0
-            // since IEEE 754/MPFR's atan2 returns ±π for y = ±0.0, x < 0.0, while we want only +π.
-            N1_Z => (Self::PI, Decoration::Dac),
+            // since IEEE 754/MPFR's atan2 (atan2Pi) returns ±π (±1) for y = ±0.0, x < 0.0, while we want only +π.
+            N1_Z => (pi, Decoration::Dac),
             P0_Z => (Self::zero(), Decoration::Trv),
             //P1_Z => See above.
 
             // Y axis
             //Z_M => See above.
-            Z_N0 => (-Self::FRAC_PI_2, Decoration::Trv),
+            Z_N0 => (-frac_pi_2, Decoration::Trv),
             //Z_N1 => See above.
-            Z_P0 => (Self::FRAC_PI_2, Decoration::Trv),
+            Z_P0 => (frac_pi_2, Decoration::Trv),
             //Z_P1 => See above.
         }
     }
 
+    impl_mono_inc!(
+        /// Returns the inverse tangent of `self`, divided by π.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range         |
+        /// | ------ | ------------- |
+        /// | $\R$   | $(-1/2, 1/2)$ |
+        atan_pi,
+        atan_pi_rd,
+        atan_pi_ru
+    );
+
     /// Returns the inverse hyperbolic tangent of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -413,6 +596,56 @@ impl Interval {
         (y, d)
     }
 
+    /// Returns `1 + self` raised to the power of `rhs`.
+    ///
+    /// The point functions are indexed by $n$. For $n ≥ 0$, the point function is defined as
+    /// $x ↦ (1 + x)^n$, where $0^0 = 1$.
+    ///
+    /// The domains and the ranges of the point functions are:
+    ///
+    /// |         | Domain     | Range     |
+    /// | ------- | ---------- | --------- |
+    /// | $n > 0$ | $\[-1, ∞)$ | $\[0, ∞)$ |
+    /// | $n = 0$ | $\[-1, ∞)$ | $\set 1$  |
+    /// | $n < 0$ | $(-1, ∞)$  | $(0, ∞)$  |
+    #[must_use]
+    pub fn compound(self, rhs: i32) -> Self {
+        self.compound_impl(rhs).0
+    }
+
+    fn compound_impl(self, rhs: i32) -> (Self, Decoration) {
+        const DOM: Interval = const_interval!(-1.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        let a = x.inf_raw();
+        let b = x.sup_raw();
+        if x.is_empty() || rhs < 0 && b <= -1.0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        if rhs < 0 {
+            let d = if self.interior(DOM) {
+                Decoration::Com
+            } else {
+                Decoration::Trv
+            };
+            (
+                Self::with_infsup_raw(compound_rd(b, rhs), compound_ru(a, rhs)),
+                d,
+            )
+        } else {
+            let d = if self.subset(DOM) {
+                Decoration::Com
+            } else {
+                Decoration::Trv
+            };
+            (
+                Self::with_infsup_raw(compound_rd(a, rhs), compound_ru(b, rhs)),
+                d,
+            )
+        }
+    }
+
     /// Returns the cosine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -462,6 +695,52 @@ impl Interval {
         }
     }
 
+    /// Returns the cosine of π times `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range       |
+    /// | ------ | ----------- |
+    /// | $\R$   | $\[-1, 1\]$ |
+    #[must_use]
+    pub fn cos_pi(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b && a.abs() >= MAX_EXACT {
+            // cos(πa) = 1 since a is an even integer.
+            return const_interval!(1.0, 1.0);
+        }
+        // Unlike in `cos`, the half-periods can be computed exactly.
+        let qa = floor1(a);
+        let qb = floor1(b);
+        let n = qb - qa;
+        let q = rem_euclid_2(qa);
+
+        if n == 0.0 {
+            if q == 0.0 {
+                // monotonically decreasing
+                Self::with_infsup_raw(cos_pi_rd(b), cos_pi_ru(a))
+            } else {
+                // monotonically increasing
+                Self::with_infsup_raw(cos_pi_rd(a), cos_pi_ru(b))
+            }
+        } else if n <= 1.0 {
+            if q == 0.0 {
+                // decreasing, then increasing
+                Self::with_infsup_raw(-1.0, cos_pi_ru(a).max(cos_pi_ru(b)))
+            } else {
+                // increasing, then decreasing
+                Self::with_infsup_raw(cos_pi_rd(a).min(cos_pi_rd(b)), 1.0)
+            }
+        } else {
+            const_interval!(-1.0, 1.0)
+        }
+    }
+
     /// Returns the hyperbolic cosine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -486,45 +765,225 @@ impl Interval {
         }
     }
 
-    impl_mono_inc!(
-        /// Returns `self` raised to the power of $\e$.
-        ///
-        /// The domain and the range of the point function are:
-        ///
-        /// | Domain | Range    |
-        /// | ------ | -------- |
-        /// | $\R$   | $(0, ∞)$ |
-        exp,
-        exp_rd,
-        exp_ru
-    );
-    impl_mono_inc!(
-        /// Returns `self` raised to the power of 10.
-        ///
-        /// The domain and the range of the point function are:
-        ///
-        /// | Domain | Range    |
-        /// | ------ | -------- |
-        /// | $\R$   | $(0, ∞)$ |
-        exp10,
-        exp10_rd,
-        exp10_ru
-    );
-    impl_mono_inc!(
-        /// Returns `self` raised to the power of 2.
-        ///
-        /// The domain and the range of the point function are:
-        ///
-        /// | Domain | Range    |
-        /// | ------ | -------- |
-        /// | $\R$   | $(0, ∞)$ |
-        exp2,
-        exp2_rd,
-        exp2_ru
-    );
+    /// Returns the cotangent of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range |
+    /// | ------------------------- | ----- |
+    /// | $\R ∖ \set{n π ∣ n ∈ \Z}$ | $\R$  |
+    #[must_use]
+    pub fn cot(self) -> Self {
+        self.cot_impl().0
+    }
 
-    impl_log!(
-        /// Returns the natural logarithm of `self`.
+    fn cot_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (self, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b {
+            // For strict test cases on huge values.
+            return if a == 0.0 {
+                (Self::EMPTY, Decoration::Trv)
+            } else {
+                (Self::with_infsup_raw(cot_rd(a), cot_ru(a)), Decoration::Com)
+            };
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_2(qa);
+        let (a, b, d) = Self::pole_at_zero_bounds(a, b);
+
+        if q == 0.0 && n < 2.0 || q == 1.0 && n < 1.0 {
+            // monotonically decreasing
+            // In case of overflow, the decoration must be corrected by the caller.
+            (Self::with_infsup_raw(cot_rd(b), cot_ru(a)), d)
+        } else {
+            (Self::ENTIRE, Decoration::Trv)
+        }
+    }
+
+    /// Returns the hyperbolic cotangent of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain        | Range            |
+    /// | ------------- | ---------------- |
+    /// | $\R ∖ \set 0$ | $\R ∖ \[-1, 1\]$ |
+    #[must_use]
+    pub fn coth(self) -> Self {
+        self.coth_impl().0
+    }
+
+    fn coth_impl(self) -> (Self, Decoration) {
+        self.pole_at_zero_impl_with(coth_rd, coth_ru)
+    }
+
+    /// Returns the cosecant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range                 |
+    /// | ------------------------- | --------------------- |
+    /// | $\R ∖ \set{n π ∣ n ∈ \Z}$ | $(-∞, -1\] ∪ \[1, ∞)$ |
+    #[must_use]
+    pub fn csc(self) -> Self {
+        self.csc_impl().0
+    }
+
+    fn csc_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (self, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b {
+            // For strict test cases on huge values.
+            return if a == 0.0 {
+                (Self::EMPTY, Decoration::Trv)
+            } else {
+                (Self::with_infsup_raw(csc_rd(a), csc_ru(a)), Decoration::Com)
+            };
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(qa);
+        let (a, b, d) = Self::pole_at_zero_bounds(a, b);
+
+        if (q == 1.0 || q == 2.0) && n < 1.0 {
+            // monotonically increasing
+            (Self::with_infsup_raw(csc_rd(a), csc_ru(b)), d)
+        } else if (q == 0.0 || q == 3.0) && n < 1.0 {
+            // monotonically decreasing
+            (Self::with_infsup_raw(csc_rd(b), csc_ru(a)), d)
+        } else if q == 0.0 && n < 2.0 {
+            // decreasing, then increasing
+            (Self::with_infsup_raw(1.0, csc_ru(a).max(csc_ru(b))), d)
+        } else if q == 2.0 && n < 2.0 {
+            // increasing, then decreasing
+            (Self::with_infsup_raw(csc_rd(a).min(csc_rd(b)), -1.0), d)
+        } else {
+            (Self::ENTIRE, Decoration::Trv)
+        }
+    }
+
+    /// Returns the hyperbolic cosecant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain        | Range         |
+    /// | ------------- | ------------- |
+    /// | $\R ∖ \set 0$ | $\R ∖ \set 0$ |
+    #[must_use]
+    pub fn csch(self) -> Self {
+        self.csch_impl().0
+    }
+
+    fn csch_impl(self) -> (Self, Decoration) {
+        self.pole_at_zero_impl_with(csch_rd, csch_ru)
+    }
+
+    impl_mono_inc!(
+        /// Returns `self` raised to the power of $\e$.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range    |
+        /// | ------ | -------- |
+        /// | $\R$   | $(0, ∞)$ |
+        exp,
+        exp_rd,
+        exp_ru
+    );
+    impl_mono_inc!(
+        /// Returns `self` raised to the power of 10.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range    |
+        /// | ------ | -------- |
+        /// | $\R$   | $(0, ∞)$ |
+        exp10,
+        exp10_rd,
+        exp10_ru
+    );
+    impl_mono_inc!(
+        /// Returns `self` raised to the power of 2.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range    |
+        /// | ------ | -------- |
+        /// | $\R$   | $(0, ∞)$ |
+        exp2,
+        exp2_rd,
+        exp2_ru
+    );
+
+    impl_mono_inc!(
+        /// Returns `self` raised to the power of 10, minus 1.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range     |
+        /// | ------ | --------- |
+        /// | $\R$   | $(-1, ∞)$ |
+        exp10m1,
+        exp10m1_rd,
+        exp10m1_ru
+    );
+    impl_mono_inc!(
+        /// Returns `self` raised to the power of 2, minus 1.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range     |
+        /// | ------ | --------- |
+        /// | $\R$   | $(-1, ∞)$ |
+        exp2m1,
+        exp2m1_rd,
+        exp2m1_ru
+    );
+    impl_mono_inc!(
+        /// Returns $\e$ raised to the power of `self`, minus 1.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain | Range     |
+        /// | ------ | --------- |
+        /// | $\R$   | $(-1, ∞)$ |
+        expm1,
+        expm1_rd,
+        expm1_ru
+    );
+
+    /// Returns $\sqrt{\self^2 + \rhs^2}$.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R^2$ | $\[0, ∞)$ |
+    #[must_use]
+    pub fn hypot(self, rhs: Self) -> Self {
+        if self.either_empty(rhs) {
+            return Self::EMPTY;
+        }
+
+        let x = self.abs();
+        let y = rhs.abs();
+        Self::with_infsup_raw(
+            hypot_rd(x.inf_raw(), y.inf_raw()),
+            hypot_ru(x.sup_raw(), y.sup_raw()),
+        )
+    }
+
+    impl_log!(
+        /// Returns the natural logarithm of `self`.
         ///
         /// The domain and the range of the point function are:
         ///
@@ -563,6 +1022,49 @@ impl Interval {
         log2_ru
     );
 
+    impl_log!(
+        /// Returns the base-10 logarithm of `1 + self`.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain    | Range |
+        /// | --------- | ----- |
+        /// | $(-1, ∞)$ | $\R$  |
+        log10p1,
+        log10p1_impl,
+        log10p1_rd,
+        log10p1_ru,
+        -1.0
+    );
+    impl_log!(
+        /// Returns the base-2 logarithm of `1 + self`.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain    | Range |
+        /// | --------- | ----- |
+        /// | $(-1, ∞)$ | $\R$  |
+        log2p1,
+        log2p1_impl,
+        log2p1_rd,
+        log2p1_ru,
+        -1.0
+    );
+    impl_log!(
+        /// Returns the natural logarithm of `1 + self`.
+        ///
+        /// The domain and the range of the point function are:
+        ///
+        /// | Domain    | Range |
+        /// | --------- | ----- |
+        /// | $(-1, ∞)$ | $\R$  |
+        logp1,
+        logp1_impl,
+        logp1_rd,
+        logp1_ru,
+        -1.0
+    );
+
     /// Returns `self` raised to the power of `rhs`.
     ///
     /// The point function is defined as follows:
@@ -730,6 +1232,194 @@ impl Interval {
         }
     }
 
+    /// Returns the `rhs`-th root of `self`.
+    ///
+    /// The point functions are indexed by $n$, and are defined as the real $n$-th root
+    /// $x ↦ x^{1/n}$ for $n ≠ 0$, which is negative for negative $x$ and odd $n$.
+    /// For $n = 0$, the point function is nowhere defined.
+    ///
+    /// The domains and the ranges of the point functions are:
+    ///
+    /// |                | Domain        | Range         |
+    /// | -------------- | ------------- | ------------- |
+    /// | $n > 0$, odd   | $\R$          | $\R$          |
+    /// | $n > 0$, even  | $\[0, ∞)$     | $\[0, ∞)$     |
+    /// | $n = 0$        | $∅$           | $∅$           |
+    /// | $n < 0$, odd   | $\R ∖ \set 0$ | $\R ∖ \set 0$ |
+    /// | $n < 0$, even  | $(0, ∞)$      | $(0, ∞)$      |
+    #[must_use]
+    pub fn rootn(self, rhs: i32) -> Self {
+        self.rootn_impl(rhs).0
+    }
+
+    fn rootn_impl(self, rhs: i32) -> (Self, Decoration) {
+        if self.is_empty() || rhs == 0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        if rhs % 2 == 0 {
+            const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+            let x = self.intersection(DOM);
+
+            let a = x.inf_raw();
+            let b = x.sup_raw();
+            if x.is_empty() || rhs < 0 && b <= 0.0 {
+                return (Self::EMPTY, Decoration::Trv);
+            }
+
+            if rhs < 0 {
+                let d = if self.interior(DOM) {
+                    Decoration::Com
+                } else {
+                    Decoration::Trv
+                };
+                (Self::with_infsup_raw(rootn_rd(b, rhs), rootn_ru(a, rhs)), d)
+            } else {
+                let d = if self.subset(DOM) {
+                    Decoration::Com
+                } else {
+                    Decoration::Trv
+                };
+                (Self::with_infsup_raw(rootn_rd(a, rhs), rootn_ru(b, rhs)), d)
+            }
+        } else {
+            let mut a = self.inf_raw();
+            let mut b = self.sup_raw();
+
+            if rhs < 0 {
+                let d = if a <= 0.0 && b >= 0.0 {
+                    Decoration::Trv
+                } else {
+                    Decoration::Com
+                };
+
+                if a == 0.0 && b == 0.0 {
+                    (Self::EMPTY, d)
+                } else if a < 0.0 && b > 0.0 {
+                    (Self::ENTIRE, d)
+                } else {
+                    if a == 0.0 {
+                        a = 0.0; // [0, b]
+                    }
+                    if b == 0.0 {
+                        b = -0.0; // [a, 0]
+                    }
+                    (Self::with_infsup_raw(rootn_rd(b, rhs), rootn_ru(a, rhs)), d)
+                }
+            } else {
+                (
+                    Self::with_infsup_raw(rootn_rd(a, rhs), rootn_ru(b, rhs)),
+                    Decoration::Com,
+                )
+            }
+        }
+    }
+
+    /// Returns the reciprocal of the square root of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain   | Range    |
+    /// | -------- | -------- |
+    /// | $(0, ∞)$ | $(0, ∞)$ |
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        self.rsqrt_impl().0
+    }
+
+    fn rsqrt_impl(self) -> (Self, Decoration) {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        let a = x.inf_raw();
+        let b = x.sup_raw();
+        if x.is_empty() || b <= 0.0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let y = Self::with_infsup_raw(rsqrt_rd(b), rsqrt_ru(a));
+        let d = if self.interior(DOM) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
+    /// Returns the secant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                            | Range                 |
+    /// | --------------------------------- | --------------------- |
+    /// | $\R ∖ \set{(n + 1/2) π ∣ n ∈ \Z}$ | $(-∞, -1\] ∪ \[1, ∞)$ |
+    #[must_use]
+    pub fn sec(self) -> Self {
+        self.sec_impl().0
+    }
+
+    fn sec_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (self, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b {
+            // For strict test cases on huge values.
+            return (Self::with_infsup_raw(sec_rd(a), sec_ru(a)), Decoration::Com);
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(qa);
+
+        if (q == 0.0 || q == 1.0) && n < 1.0 {
+            // monotonically increasing
+            (Self::with_infsup_raw(sec_rd(a), sec_ru(b)), Decoration::Com)
+        } else if (q == 2.0 || q == 3.0) && n < 1.0 {
+            // monotonically decreasing
+            (Self::with_infsup_raw(sec_rd(b), sec_ru(a)), Decoration::Com)
+        } else if q == 1.0 && n < 2.0 {
+            // increasing, then decreasing
+            (
+                Self::with_infsup_raw(sec_rd(a).min(sec_rd(b)), -1.0),
+                Decoration::Com,
+            )
+        } else if q == 3.0 && n < 2.0 {
+            // decreasing, then increasing
+            (
+                Self::with_infsup_raw(1.0, sec_ru(a).max(sec_ru(b))),
+                Decoration::Com,
+            )
+        } else {
+            (Self::ENTIRE, Decoration::Trv)
+        }
+    }
+
+    /// Returns the hyperbolic secant of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $(0, 1\]$ |
+    #[must_use]
+    pub fn sech(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if b < 0.0 {
+            Self::with_infsup_raw(sech_rd(a), sech_ru(b))
+        } else if a > 0.0 {
+            Self::with_infsup_raw(sech_rd(b), sech_ru(a))
+        } else {
+            Self::with_infsup_raw(sech_rd((-a).max(b)), 1.0)
+        }
+    }
+
     /// Returns the sine of `self`.
     ///
     /// The domain and the range of the point function are:
@@ -749,7 +1439,7 @@ impl Interval {
         let qa = q_nowrap.inf_raw();
         let qb = q_nowrap.sup_raw();
         let n = if a == b { 0.0 } else { qb - qa };
-        let q = qa.rem_euclid(4.0);
+        let q = rem_euclid_4(qa);
 
         if q == 0.0 && n < 1.0 || q == 3.0 && n < 2.0 {
             // monotonically increasing
@@ -768,6 +1458,48 @@ impl Interval {
         }
     }
 
+    /// Returns the sine of π times `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range       |
+    /// | ------ | ----------- |
+    /// | $\R$   | $\[-1, 1\]$ |
+    #[must_use]
+    pub fn sin_pi(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b && a.abs() >= MAX_EXACT {
+            // sin(πa) = 0 since a is an even integer. Also, 2a can overflow.
+            return Self::zero();
+        }
+        // Unlike in `sin`, the quadrants can be computed exactly.
+        let qa = floor1(2.0 * a);
+        let qb = floor1(2.0 * b);
+        let n = qb - qa;
+        let q = rem_euclid_4(qa);
+
+        if q == 0.0 && n < 1.0 || q == 3.0 && n < 2.0 {
+            // monotonically increasing
+            Self::with_infsup_raw(sin_pi_rd(a), sin_pi_ru(b))
+        } else if q == 1.0 && n < 2.0 || q == 2.0 && n < 1.0 {
+            // monotonically decreasing
+            Self::with_infsup_raw(sin_pi_rd(b), sin_pi_ru(a))
+        } else if q == 0.0 && n < 3.0 || q == 3.0 && n < 4.0 {
+            // increasing, then decreasing
+            Self::with_infsup_raw(sin_pi_rd(a).min(sin_pi_rd(b)), 1.0)
+        } else if q == 1.0 && n < 4.0 || q == 2.0 && n < 3.0 {
+            // decreasing, then increasing
+            Self::with_infsup_raw(-1.0, sin_pi_ru(a).max(sin_pi_ru(b)))
+        } else {
+            const_interval!(-1.0, 1.0)
+        }
+    }
+
     impl_mono_inc!(
         /// Returns the hyperbolic sine of `self`.
         ///
@@ -816,6 +1548,71 @@ impl Interval {
         }
     }
 
+    /// Returns the tangent of π times `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                        | Range |
+    /// | ----------------------------- | ----- |
+    /// | $\R ∖ \set{n + 1/2 ∣ n ∈ \Z}$ | $\R$  |
+    #[must_use]
+    pub fn tan_pi(self) -> Self {
+        self.tan_pi_impl().0
+    }
+
+    fn tan_pi_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (self, Decoration::Trv);
+        }
+
+        // Returns `true` if x/2 is a pole of the point function, i.e., x is an odd integer.
+        let is_pole = |x: f64| x == floor1(x) && rem_euclid_2(x) == 1.0;
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        let a2 = 2.0 * a;
+        let b2 = 2.0 * b;
+        if a == b {
+            // For strict test cases on huge values.
+            return if is_pole(a2) {
+                (Self::EMPTY, Decoration::Trv)
+            } else {
+                (
+                    Self::with_infsup_raw(tan_pi_rd(a), tan_pi_ru(b)),
+                    Decoration::Com,
+                )
+            };
+        }
+        if a2.abs().max(b2.abs()) >= MAX_EXACT {
+            // There is a pole between a and b.
+            return (Self::ENTIRE, Decoration::Trv);
+        }
+
+        // The least odd integer greater than 2a, which is computed exactly.
+        let k = floor1(a2) + 1.0;
+        let k = if rem_euclid_2(k) == 0.0 { k + 1.0 } else { k };
+        if k < b2 {
+            return (Self::ENTIRE, Decoration::Trv);
+        }
+
+        match (is_pole(a2), is_pole(b2)) {
+            // In case of overflow, the decoration must be corrected by the caller.
+            (false, false) => (
+                Self::with_infsup_raw(tan_pi_rd(a), tan_pi_ru(b)),
+                Decoration::Com,
+            ),
+            (true, false) => (
+                Self::with_infsup_raw(f64::NEG_INFINITY, tan_pi_ru(b)),
+                Decoration::Trv,
+            ),
+            (false, true) => (
+                Self::with_infsup_raw(tan_pi_rd(a), f64::INFINITY),
+                Decoration::Trv,
+            ),
+            (true, true) => (Self::ENTIRE, Decoration::Trv),
+        }
+    }
+
     impl_mono_inc!(
         /// Returns the hyperbolic tangent of `self`.
         ///
@@ -828,6 +1625,56 @@ impl Interval {
         tanh_rd,
         tanh_ru
     );
+
+    // Returns `qa`, the index of the quadrant of width π/2 that contains the lower bound of `self`,
+    // and `n`, the number of the boundaries of quadrants between the bounds of `self`.
+    // `qa` can be underestimated and `n` can be overestimated. `self` must be nonempty.
+    fn quadrant_span(self) -> (f64, f64) {
+        let b = self.sup_raw();
+        let q_nowrap = (self / Self::FRAC_PI_2).floor();
+        let qa = q_nowrap.inf_raw();
+        let qb = q_nowrap.sup_raw();
+        // If the condition holds, b lies in the quadrant qb - 1 (or is zero).
+        let cont =
+            qb != f64::INFINITY && b <= (Self::with_infsup_raw(qb, qb) * Self::FRAC_PI_2).inf_raw();
+        let n = if cont { qb - qa - 1.0 } else { qb - qa };
+        (qa, n)
+    }
+
+    // Replaces the bounds with the signed zeros for evaluating a point function
+    // that has a pole at 0, and returns the decoration of the result.
+    // MPFR returns ±∞ at ±0, which are the limits of such a function (e.g., cot)
+    // from the right and the left, respectively.
+    fn pole_at_zero_bounds(a: f64, b: f64) -> (f64, f64, Decoration) {
+        if a == 0.0 || b == 0.0 {
+            let a = if a == 0.0 { 0.0 } else { a };
+            let b = if b == 0.0 { -0.0 } else { b };
+            (a, b, Decoration::Trv)
+        } else {
+            (a, b, Decoration::Com)
+        }
+    }
+
+    // Evaluates a point function that has a pole at 0 and is decreasing on both (-∞, 0)
+    // and (0, ∞), such as coth and csch.
+    fn pole_at_zero_impl_with(
+        self,
+        f_rd: fn(f64) -> f64,
+        f_ru: fn(f64) -> f64,
+    ) -> (Self, Decoration) {
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if self.is_empty() || a == 0.0 && b == 0.0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+        if a < 0.0 && b > 0.0 {
+            return (Self::ENTIRE, Decoration::Trv);
+        }
+
+        let (a, b, d) = Self::pole_at_zero_bounds(a, b);
+        // In case of overflow, the decoration must be corrected by the caller.
+        (Self::with_infsup_raw(f_rd(b), f_ru(a)), d)
+    }
 }
 
 macro_rules! impl_dec {
@@ -854,6 +1701,16 @@ macro_rules! impl_dec {
 }
 
 macro_rules! impl_dec2 {
+    ($f:ident) => {
+        #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
+        ///
+        /// A NaI is returned if `self` or `rhs` is NaI.
+        #[must_use]
+        pub fn $f(self, rhs: Self) -> Self {
+            Self::set_dec(self.x.$f(rhs.x), self.d.min(rhs.d))
+        }
+    };
+
     ($f:ident, $f_impl:ident) => {
         #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
         ///
@@ -868,20 +1725,50 @@ macro_rules! impl_dec2 {
 
 impl DecInterval {
     impl_dec!(acos, acos_impl);
+    impl_dec!(acos_pi, acos_pi_impl);
     impl_dec!(acosh, acosh_impl);
+    impl_dec!(acot);
+    impl_dec!(acoth, acoth_impl);
+    impl_dec!(acsc, acsc_impl);
+    impl_dec!(asec, asec_impl);
     impl_dec!(asin, asin_impl);
+    impl_dec!(asin_pi, asin_pi_impl);
     impl_dec!(asinh);
     impl_dec!(atan);
     impl_dec2!(atan2, atan2_impl);
+    impl_dec2!(atan2_pi, atan2_pi_impl);
+    impl_dec!(atan_pi);
     impl_dec!(atanh, atanh_impl);
+
+    /// The decorated version of [`Interval::compound`].
+    ///
+    /// A NaI is returned if `self` is NaI.
+    #[must_use]
+    pub fn compound(self, rhs: i32) -> Self {
+        let (y, d) = self.x.compound_impl(rhs);
+        Self::set_dec(y, self.d.min(d))
+    }
+
     impl_dec!(cos);
+    impl_dec!(cos_pi);
     impl_dec!(cosh);
+    impl_dec!(cot, cot_impl);
+    impl_dec!(coth, coth_impl);
+    impl_dec!(csc, csc_impl);
+    impl_dec!(csch, csch_impl);
     impl_dec!(exp);
     impl_dec!(exp10);
+    impl_dec!(exp10m1);
     impl_dec!(exp2);
+    impl_dec!(exp2m1);
+    impl_dec!(expm1);
+    impl_dec2!(hypot);
     impl_dec!(ln, ln_impl);
     impl_dec!(log10, log10_impl);
+    impl_dec!(log10p1, log10p1_impl);
     impl_dec!(log2, log2_impl);
+    impl_dec!(log2p1, log2p1_impl);
+    impl_dec!(logp1, logp1_impl);
     impl_dec2!(pow, pow_impl);
 
     /// The decorated version of [`Interval::powi`].
@@ -893,9 +1780,23 @@ impl DecInterval {
         Self::set_dec(y, self.d.min(d))
     }
 
+    /// The decorated version of [`Interval::rootn`].
+    ///
+    /// A NaI is returned if `self` is NaI.
+    #[must_use]
+    pub fn rootn(self, rhs: i32) -> Self {
+        let (y, d) = self.x.rootn_impl(rhs);
+        Self::set_dec(y, self.d.min(d))
+    }
+
+    impl_dec!(rsqrt, rsqrt_impl);
+    impl_dec!(sec, sec_impl);
+    impl_dec!(sech);
     impl_dec!(sin);
+    impl_dec!(sin_pi);
     impl_dec!(sinh);
     impl_dec!(tan, tan_impl);
+    impl_dec!(tan_pi, tan_pi_impl);
     impl_dec!(tanh);
 }
 
@@ -908,30 +1809,359 @@ mod tests {
     #[test]
     fn nai() {
         assert!(DI::NAI.acos().is_nai());
+        assert!(DI::NAI.acos_pi().is_nai());
         assert!(DI::NAI.acosh().is_nai());
+        assert!(DI::NAI.acot().is_nai());
+        assert!(DI::NAI.acoth().is_nai());
+        assert!(DI::NAI.acsc().is_nai());
+        assert!(DI::NAI.asec().is_nai());
         assert!(DI::NAI.asin().is_nai());
+        assert!(DI::NAI.asin_pi().is_nai());
         assert!(DI::NAI.asinh().is_nai());
         assert!(DI::NAI.atan().is_nai());
         assert!(DI::NAI.atan2(DI::EMPTY).is_nai());
         assert!(DI::EMPTY.atan2(DI::NAI).is_nai());
+        assert!(DI::NAI.atan2_pi(DI::EMPTY).is_nai());
+        assert!(DI::EMPTY.atan2_pi(DI::NAI).is_nai());
+        assert!(DI::NAI.atan_pi().is_nai());
         assert!(DI::NAI.atanh().is_nai());
+        assert!(DI::NAI.compound(1).is_nai());
         assert!(DI::NAI.cos().is_nai());
+        assert!(DI::NAI.cos_pi().is_nai());
         assert!(DI::NAI.cosh().is_nai());
+        assert!(DI::NAI.cot().is_nai());
+        assert!(DI::NAI.coth().is_nai());
+        assert!(DI::NAI.csc().is_nai());
+        assert!(DI::NAI.csch().is_nai());
         assert!(DI::NAI.exp().is_nai());
         assert!(DI::NAI.exp10().is_nai());
+        assert!(DI::NAI.exp10m1().is_nai());
         assert!(DI::NAI.exp2().is_nai());
+        assert!(DI::NAI.exp2m1().is_nai());
+        assert!(DI::NAI.expm1().is_nai());
+        assert!(DI::NAI.hypot(DI::EMPTY).is_nai());
+        assert!(DI::EMPTY.hypot(DI::NAI).is_nai());
         assert!(DI::NAI.ln().is_nai());
         assert!(DI::NAI.log10().is_nai());
+        assert!(DI::NAI.log10p1().is_nai());
         assert!(DI::NAI.log2().is_nai());
+        assert!(DI::NAI.log2p1().is_nai());
+        assert!(DI::NAI.logp1().is_nai());
         assert!(DI::NAI.pow(DI::EMPTY).is_nai());
         assert!(DI::EMPTY.pow(DI::NAI).is_nai());
         assert!(DI::NAI.powi(1).is_nai());
+        assert!(DI::NAI.rootn(1).is_nai());
+        assert!(DI::NAI.rsqrt().is_nai());
+        assert!(DI::NAI.sec().is_nai());
+        assert!(DI::NAI.sech().is_nai());
         assert!(DI::NAI.sin().is_nai());
+        assert!(DI::NAI.sin_pi().is_nai());
         assert!(DI::NAI.sinh().is_nai());
         assert!(DI::NAI.tan().is_nai());
+        assert!(DI::NAI.tan_pi().is_nai());
         assert!(DI::NAI.tanh().is_nai());
     }
 
+    #[test]
+    fn exp_log_p1() {
+        let x = const_interval!(-1e-20, 1e-20);
+        assert!(x
+            .expm1()
+            .subset(const_interval!(-1.000001e-20, 1.000001e-20)));
+        assert!(x.exp2m1().subset(const_interval!(-1e-20, 1e-20)));
+        assert!(x.exp10m1().subset(const_interval!(-3e-20, 3e-20)));
+        assert!(x
+            .logp1()
+            .subset(const_interval!(-1.000001e-20, 1.000001e-20)));
+        assert!(x.log2p1().subset(const_interval!(-2e-20, 2e-20)));
+        assert!(x.log10p1().subset(const_interval!(-1e-20, 1e-20)));
+
+        assert_eq!(const_interval!(-1.0, 0.0).logp1().inf(), f64::NEG_INFINITY);
+        assert!(const_interval!(-3.0, -1.0).logp1().is_empty());
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 0.0)).logp1().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(-0.5, 0.0)).log2p1(),
+            DI::new(const_interval!(-1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn compound() {
+        let x = const_interval!(-1.0, 1.0);
+        assert_eq!(x.compound(3), const_interval!(0.0, 8.0));
+        assert_eq!(x.compound(0), const_interval!(1.0, 1.0));
+        assert_eq!(x.compound(-1), const_interval!(0.5, f64::INFINITY));
+        assert!(const_interval!(-2.0, -1.0).compound(-1).is_empty());
+        assert!(const_interval!(-3.0, -2.0).compound(1).is_empty());
+
+        assert_eq!(DI::new(x).compound(2).decoration(), Decoration::Com);
+        assert_eq!(DI::new(x).compound(-2).decoration(), Decoration::Trv);
+        assert_eq!(
+            DI::new(const_interval!(-2.0, 1.0)).compound(2).decoration(),
+            Decoration::Trv
+        );
+    }
+
+    #[test]
+    fn hypot_rsqrt() {
+        let x = const_interval!(-3.0, 3.0);
+        let y = const_interval!(4.0, 4.0);
+        assert_eq!(x.hypot(y), const_interval!(4.0, 5.0));
+        assert_eq!(y.hypot(x), const_interval!(4.0, 5.0));
+        assert!(I::EMPTY.hypot(y).is_empty());
+
+        assert_eq!(
+            const_interval!(0.25, 4.0).rsqrt(),
+            const_interval!(0.5, 2.0)
+        );
+        assert_eq!(
+            const_interval!(-1.0, 4.0).rsqrt(),
+            const_interval!(0.5, f64::INFINITY)
+        );
+        assert!(const_interval!(-1.0, 0.0).rsqrt().is_empty());
+        assert_eq!(
+            DI::new(const_interval!(0.0, 4.0)).rsqrt().decoration(),
+            Decoration::Trv
+        );
+    }
+
+    #[test]
+    fn reciprocal_trig() {
+        let x = DI::new(const_interval!(1.0, 2.0));
+        assert!(x.cot().interval().unwrap().is_common_interval());
+        assert_eq!(x.cot().decoration(), Decoration::Com);
+        assert!(const_interval!(3.2, 6.2).cot().is_common_interval());
+        assert_eq!(const_interval!(3.0, 3.2).cot(), I::ENTIRE);
+        assert_eq!(const_interval!(-1.0, 1.0).cot(), I::ENTIRE);
+        assert!(I::zero().cot().is_empty());
+        let y = DI::new(const_interval!(0.0, 1.0)).cot();
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert!(y.inf() > 0.64 && y.inf() < 0.65);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        let y = DI::new(const_interval!(-1.0, 0.0)).cot();
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 1.0)).cot().decoration(),
+            Decoration::Trv
+        );
+
+        let y = const_interval!(1.0, 3.0).csc();
+        assert_eq!(y.inf(), 1.0);
+        assert!(y.is_common_interval());
+        assert_eq!(const_interval!(4.0, 5.0).csc().sup(), -1.0);
+        assert_eq!(const_interval!(3.0, 4.0).csc(), I::ENTIRE);
+        assert!(I::zero().csc().is_empty());
+        let y = DI::new(const_interval!(-1.0, 0.0)).csc();
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert!(y.sup() < -1.0);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        assert_eq!(
+            DI::new(const_interval!(4.0, 5.0)).csc().decoration(),
+            Decoration::Com
+        );
+
+        assert_eq!(const_interval!(-1.0, 1.0).sec().inf(), 1.0);
+        assert_eq!(const_interval!(2.0, 4.0).sec().sup(), -1.0);
+        assert_eq!(const_interval!(1.0, 2.0).sec(), I::ENTIRE);
+        assert!(const_interval!(1e300, 1e300).sec().is_common_interval());
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 1.0)).sec().decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).sec().decoration(),
+            Decoration::Trv
+        );
+    }
+
+    #[test]
+    fn reciprocal_hyperbolic() {
+        let y = DI::new(const_interval!(0.0, 1.0)).coth();
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        assert_eq!(const_interval!(-1.0, 1.0).coth(), I::ENTIRE);
+        assert!(I::zero().coth().is_empty());
+        assert_eq!(const_interval!(1.0, f64::INFINITY).coth().inf(), 1.0);
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).coth().decoration(),
+            Decoration::Com
+        );
+
+        let y = DI::new(const_interval!(-1.0, 0.0)).csch();
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        assert_eq!(const_interval!(-1.0, 1.0).csch(), I::ENTIRE);
+        assert_eq!(
+            DI::new(const_interval!(-2.0, -1.0)).csch().decoration(),
+            Decoration::Com
+        );
+
+        assert_eq!(const_interval!(-1.0, 2.0).sech().sup(), 1.0);
+        assert_eq!(I::ENTIRE.sech(), const_interval!(0.0, 1.0));
+        assert!(const_interval!(1.0, 2.0).sech().is_common_interval());
+    }
+
+    #[test]
+    fn inverse_reciprocal() {
+        assert_eq!(I::zero().acot(), I::FRAC_PI_2);
+        assert_eq!(I::ENTIRE.acot(), interval!(0.0, I::PI.sup()).unwrap());
+
+        assert_eq!(const_interval!(1.0, 1.0).asec(), I::zero());
+        assert_eq!(const_interval!(-1.0, -1.0).asec(), I::PI);
+        assert_eq!(
+            const_interval!(-2.0, 2.0).asec(),
+            interval!(0.0, I::PI.sup()).unwrap()
+        );
+        assert!(const_interval!(-0.5, 0.5).asec().is_empty());
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).asec().decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(-2.0, 2.0)).asec().decoration(),
+            Decoration::Trv
+        );
+
+        assert_eq!(const_interval!(1.0, 1.0).acsc(), I::FRAC_PI_2);
+        assert_eq!(
+            const_interval!(-2.0, 2.0).acsc(),
+            interval!(-I::FRAC_PI_2.sup(), I::FRAC_PI_2.sup()).unwrap()
+        );
+        assert_eq!(
+            DI::new(const_interval!(-3.0, -1.0)).acsc().decoration(),
+            Decoration::Com
+        );
+
+        let y = DI::new(const_interval!(1.0, 2.0)).acoth();
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert_eq!(y.decoration(), Decoration::Trv);
+        assert_eq!(const_interval!(-2.0, 2.0).acoth(), I::ENTIRE);
+        assert!(const_interval!(-1.0, 1.0).acoth().is_empty());
+        assert_eq!(const_interval!(2.0, f64::INFINITY).acoth().inf(), 0.0);
+        assert_eq!(
+            DI::new(const_interval!(2.0, 3.0)).acoth().decoration(),
+            Decoration::Com
+        );
+    }
+
+    #[test]
+    fn rootn() {
+        assert_eq!(
+            const_interval!(-8.0, 27.0).rootn(3),
+            const_interval!(-2.0, 3.0)
+        );
+        assert_eq!(
+            const_interval!(-8.0, 16.0).rootn(4),
+            const_interval!(0.0, 2.0)
+        );
+        assert_eq!(
+            const_interval!(4.0, 16.0).rootn(-2),
+            const_interval!(0.25, 0.5)
+        );
+        assert_eq!(
+            const_interval!(-8.0, 0.0).rootn(-3),
+            const_interval!(f64::NEG_INFINITY, -0.5)
+        );
+        assert_eq!(const_interval!(-8.0, 8.0).rootn(-3), I::ENTIRE);
+        assert!(const_interval!(-8.0, 8.0).rootn(0).is_empty());
+        assert!(const_interval!(-8.0, -1.0).rootn(2).is_empty());
+        assert!(const_interval!(0.0, 0.0).rootn(-2).is_empty());
+
+        let x = DI::new(const_interval!(-8.0, 27.0));
+        assert_eq!(x.rootn(3).decoration(), Decoration::Com);
+        assert_eq!(x.rootn(2).decoration(), Decoration::Trv);
+        assert_eq!(x.rootn(-3).decoration(), Decoration::Trv);
+        assert_eq!(x.rootn(0), DI::EMPTY);
+    }
+
+    #[test]
+    fn trig_pi() {
+        // sin(πx) and cos(πx) at multiples of 1/2 are exact.
+        assert_eq!(const_interval!(1.0, 1.0).sin_pi(), I::zero());
+        assert_eq!(
+            const_interval!(0.5, 0.5).sin_pi(),
+            const_interval!(1.0, 1.0)
+        );
+        assert_eq!(
+            const_interval!(1.0, 1.5).sin_pi(),
+            const_interval!(-1.0, 0.0)
+        );
+        assert_eq!(const_interval!(0.25, 1.75).sin_pi().sup(), 1.0);
+        assert_eq!(
+            const_interval!(0.5, 1.0).cos_pi(),
+            const_interval!(-1.0, 0.0)
+        );
+        assert_eq!(
+            const_interval!(-0.5, 0.5).cos_pi(),
+            const_interval!(0.0, 1.0)
+        );
+        assert_eq!(
+            const_interval!(0.5, 2.5).cos_pi(),
+            const_interval!(-1.0, 1.0)
+        );
+        assert_eq!(const_interval!(1e300, 1e300).sin_pi(), I::zero());
+        for x in [1.5e308, f64::MAX, -f64::MAX] {
+            let x = I::try_from((x, x)).unwrap();
+            assert_eq!(x.sin_pi(), I::zero());
+            assert_eq!(x.cos_pi(), const_interval!(1.0, 1.0));
+        }
+        assert_eq!(
+            I::try_from((f64::MAX, f64::INFINITY)).unwrap().sin_pi(),
+            const_interval!(-1.0, 1.0)
+        );
+
+        let x = const_interval!(-0.25, 0.25);
+        assert_eq!(x.tan_pi(), const_interval!(-1.0, 1.0));
+        assert_eq!(DI::new(x).tan_pi().decoration(), Decoration::Com);
+        assert_eq!(
+            const_interval!(0.5, 0.75).tan_pi(),
+            const_interval!(f64::NEG_INFINITY, -1.0)
+        );
+        assert_eq!(
+            const_interval!(0.25, 0.5).tan_pi(),
+            const_interval!(1.0, f64::INFINITY)
+        );
+        assert_eq!(const_interval!(0.25, 0.75).tan_pi(), I::ENTIRE);
+        assert_eq!(const_interval!(0.5, 1.5).tan_pi(), I::ENTIRE);
+        assert!(const_interval!(-1.5, -1.5).tan_pi().is_empty());
+        assert_eq!(const_interval!(1e300, 1e300).tan_pi(), I::zero());
+        assert_eq!(
+            DI::new(const_interval!(0.5, 0.75)).tan_pi().decoration(),
+            Decoration::Trv
+        );
+
+        assert_eq!(
+            const_interval!(-1.0, 1.0).asin_pi(),
+            const_interval!(-0.5, 0.5)
+        );
+        assert_eq!(
+            const_interval!(-1.0, 1.0).acos_pi(),
+            const_interval!(0.0, 1.0)
+        );
+        assert_eq!(
+            const_interval!(-1.0, 1.0).atan_pi(),
+            const_interval!(-0.25, 0.25)
+        );
+        assert_eq!(
+            const_interval!(1.0, 1.0).atan2_pi(const_interval!(-1.0, -1.0)),
+            const_interval!(0.75, 0.75)
+        );
+        assert_eq!(
+            I::zero().atan2_pi(const_interval!(-1.0, -1.0)),
+            const_interval!(1.0, 1.0)
+        );
+        assert_eq!(
+            DI::new(const_interval!(0.0, 1.0))
+                .atan2_pi(DI::new(const_interval!(-1.0, -1.0)))
+                .decoration(),
+            Decoration::Dac
+        );
+    }
+
     #[test]
     fn tan() {
         // a, b ∈ (-π/2, π/2)
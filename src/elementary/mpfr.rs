@@ -0,0 +1,144 @@
+use gmp_mpfr_sys::mpfr;
+use rug::Float;
+
+macro_rules! mpfr_fn {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        pub(crate) fn $f_rd(x: f64) -> f64 {
+            mpfr_fn!($mpfr_f(x, RNDD))
+        }
+
+        pub(crate) fn $f_ru(x: f64) -> f64 {
+            mpfr_fn!($mpfr_f(x, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+macro_rules! mpfr_fn2 {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        pub(crate) fn $f_rd(x: f64, y: f64) -> f64 {
+            mpfr_fn2!($mpfr_f(x, y, RNDD))
+        }
+
+        pub(crate) fn $f_ru(x: f64, y: f64) -> f64 {
+            mpfr_fn2!($mpfr_f(x, y, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let y = Float::with_val(f64::MANTISSA_DIGITS, $y);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), y.as_raw(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+macro_rules! mpfr_fn_si {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        pub(crate) fn $f_rd(x: f64, y: i32) -> f64 {
+            mpfr_fn_si!($mpfr_f(x, y, RNDD))
+        }
+
+        pub(crate) fn $f_ru(x: f64, y: i32) -> f64 {
+            mpfr_fn_si!($mpfr_f(x, y, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), $y.into(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+// Defines the functions `x ↦ f(1/x)` rounded downward and upward, where the reciprocal is computed
+// with extra precision and rounded in the direction given for each of the bounds.
+macro_rules! mpfr_fn_recip {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident, $recip_rnd_d:ident, $recip_rnd_u:ident) => {
+        pub(crate) fn $f_rd(x: f64) -> f64 {
+            mpfr_fn_recip!($mpfr_f(x, $recip_rnd_d, RNDD))
+        }
+
+        pub(crate) fn $f_ru(x: f64) -> f64 {
+            mpfr_fn_recip!($mpfr_f(x, $recip_rnd_u, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $recip_rnd:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(RECIP_PREC, $x);
+        let mut y = Float::new(f64::MANTISSA_DIGITS);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::ui_div(x.as_raw_mut(), 1, x.as_raw(), mpfr::rnd_t::$recip_rnd);
+            mpfr::$mpfr_f(y.as_raw_mut(), x.as_raw(), rnd);
+            mpfr::get_d(y.as_raw(), rnd)
+        }
+    }};
+}
+
+// The precision of the reciprocal in `mpfr_fn_recip`, which is large enough to make up for
+// the amplification of the error by acos, asin and atanh near ±1.
+const RECIP_PREC: u32 = 128;
+
+mpfr_fn!(acos, acos_rd, acos_ru);
+mpfr_fn!(acospi, acos_pi_rd, acos_pi_ru);
+mpfr_fn!(acosh, acosh_rd, acosh_ru);
+mpfr_fn!(asin, asin_rd, asin_ru);
+mpfr_fn!(asinpi, asin_pi_rd, asin_pi_ru);
+mpfr_fn!(asinh, asinh_rd, asinh_ru);
+mpfr_fn!(atan, atan_rd, atan_ru);
+mpfr_fn2!(atan2, atan2_rd, atan2_ru);
+mpfr_fn2!(atan2pi, atan2_pi_rd, atan2_pi_ru);
+mpfr_fn!(atanpi, atan_pi_rd, atan_pi_ru);
+mpfr_fn!(atanh, atanh_rd, atanh_ru);
+mpfr_fn_si!(compound_si, compound_rd, compound_ru);
+mpfr_fn!(cos, cos_rd, cos_ru);
+mpfr_fn!(cospi, cos_pi_rd, cos_pi_ru);
+mpfr_fn!(cosh, cosh_rd, cosh_ru);
+mpfr_fn!(cot, cot_rd, cot_ru);
+mpfr_fn!(coth, coth_rd, coth_ru);
+mpfr_fn!(csc, csc_rd, csc_ru);
+mpfr_fn!(csch, csch_rd, csch_ru);
+mpfr_fn!(exp, exp_rd, exp_ru);
+mpfr_fn!(exp10, exp10_rd, exp10_ru);
+mpfr_fn!(exp10m1, exp10m1_rd, exp10m1_ru);
+mpfr_fn!(exp2, exp2_rd, exp2_ru);
+mpfr_fn!(exp2m1, exp2m1_rd, exp2m1_ru);
+mpfr_fn!(expm1, expm1_rd, expm1_ru);
+mpfr_fn2!(hypot, hypot_rd, hypot_ru);
+mpfr_fn!(log, ln_rd, ln_ru);
+mpfr_fn!(log10, log10_rd, log10_ru);
+mpfr_fn!(log10p1, log10p1_rd, log10p1_ru);
+mpfr_fn!(log1p, logp1_rd, logp1_ru);
+mpfr_fn!(log2, log2_rd, log2_ru);
+mpfr_fn!(log2p1, log2p1_rd, log2p1_ru);
+mpfr_fn2!(pow, pow_rd, pow_ru);
+mpfr_fn_si!(pow_si, pown_rd, pown_ru);
+mpfr_fn!(rec_sqrt, rsqrt_rd, rsqrt_ru);
+mpfr_fn_si!(rootn_si, rootn_rd, rootn_ru);
+mpfr_fn!(sec, sec_rd, sec_ru);
+mpfr_fn!(sech, sech_rd, sech_ru);
+mpfr_fn!(sin, sin_rd, sin_ru);
+mpfr_fn!(sinpi, sin_pi_rd, sin_pi_ru);
+mpfr_fn!(sinh, sinh_rd, sinh_ru);
+mpfr_fn!(tan, tan_rd, tan_ru);
+mpfr_fn!(tanpi, tan_pi_rd, tan_pi_ru);
+mpfr_fn!(tanh, tanh_rd, tanh_ru);
+
+mpfr_fn_recip!(acos, asec_rd, asec_ru, RNDU, RNDD);
+mpfr_fn_recip!(asin, acsc_rd, acsc_ru, RNDD, RNDU);
+mpfr_fn_recip!(atanh, acoth_rd, acoth_ru, RNDD, RNDU);
@@ -0,0 +1,1813 @@
+// Pure-Rust implementations of the elementary functions rounded downward and upward.
+//
+// Each function computes an enclosure of the exact result with `DdInterval` and rounds its
+// bounds outward. The enclosures are about 100 bits wide, so each bound is at most one ulp
+// away from the tightest one. The arguments and results near the underflow threshold, where
+// `DdInterval` loses precision, are handled by `near_linear`, `near_recip` and `atan2_tiny`.
+// The results that are exactly representable as `f64` are returned exactly in the following
+// cases:
+//
+// - The trivial cases, such as exp(0) = 1, ln(1) = 0 and atan2(y, x) = π or 0.
+// - exp2, log2, log2p1 and exp2m1 at integers and powers of two, and similarly for exp10,
+//   log10, log10p1 and exp10m1 at 10^k, 0 ≤ k ≤ 22.
+// - sinpi, cospi and tanpi at multiples of 1/4 where the result is exact.
+// - asinpi, acospi, atanpi and atan2pi where the result is a multiple of 1/4.
+// - hypot, rsqrt, pown, compound and rootn where the computation in double-double is exact,
+//   which includes the perfect squares and powers.
+//
+// Series are summed by `sum_series`, and the truncation errors are bounded using the fact that
+// the terms decrease at least geometrically with ratio 1/2 beyond the last term summed.
+//
+// The argument reduction of the trigonometric functions uses the Payne–Hanek method with
+// the bits of 2/π in `TWO_OVER_PI`, so it is accurate for all finite arguments.
+//
+// References:
+//
+// - Jean-Michel Muller. 2016. Elementary Functions: Algorithms and Implementation (3rd. ed.).
+//   Birkhäuser.
+
+use crate::{dd::DdInterval, interval::*, simd::*};
+use core::{f64::consts, mem::transmute};
+
+type Dd = DdInterval;
+
+// Creates the enclosure [hi + lo_rd, hi + lo_ru] of a constant.
+macro_rules! dd_const {
+    ($hi:expr, $lo_rd:expr, $lo_ru:expr) => {
+        // Safety: `DdInterval` is laid out as [-a; b] followed by [-a'; b'].
+        unsafe { transmute::<[f64; 4], DdInterval>([-$hi, $hi, -$lo_rd, $lo_ru]) }
+    };
+}
+
+const FRAC_1_PI: Dd = dd_const!(
+    consts::FRAC_1_PI,
+    -1.967867667518249e-17,
+    -1.9678676675182486e-17
+);
+const FRAC_PI_2: Dd = dd_const!(
+    consts::FRAC_PI_2,
+    6.123233995736765e-17,
+    6.123233995736766e-17
+);
+const FRAC_PI_4: Dd = dd_const!(
+    consts::FRAC_PI_4,
+    3.0616169978683824e-17,
+    3.061616997868383e-17
+);
+const LN_10: Dd = dd_const!(
+    consts::LN_10,
+    -2.1707562233822496e-16,
+    -2.1707562233822494e-16
+);
+const LN_2: Dd = dd_const!(consts::LN_2, 2.3190468138462996e-17, 2.3190468138463e-17);
+const LOG10_E: Dd = dd_const!(
+    consts::LOG10_E,
+    1.098319650216765e-17,
+    1.0983196502167652e-17
+);
+const LOG2_E: Dd = dd_const!(
+    consts::LOG2_E,
+    2.035527374093103e-17,
+    2.0355273740931033e-17
+);
+const PI: Dd = dd_const!(consts::PI, 1.224646799147353e-16, 1.2246467991473532e-16);
+
+// The first 1408 bits of the fractional part of 2/π.
+const TWO_OVER_PI: [u64; 22] = [
+    0xa2f9836e4e441529,
+    0xfc2757d1f534ddc0,
+    0xdb6295993c439041,
+    0xfe5163abdebbc561,
+    0xb7246e3a424dd2e0,
+    0x06492eea09d1921c,
+    0xfe1deb1cb129a73e,
+    0xe88235f52ebb4484,
+    0xe99c7026b45f7e41,
+    0x3991d639835339f4,
+    0x9c845f8bbdf9283b,
+    0x1ff897ffde05980f,
+    0xef2f118b5a0a6d1f,
+    0x6d367ecf27cb09b7,
+    0x4f463f669e5fea2d,
+    0x7527bac7ebe5f17b,
+    0x3d0739f78a5292ea,
+    0x6bfb5fb11f8d5d08,
+    0x56033046fc7b6bab,
+    0xf0cfbc209af4361d,
+    0xa9e391615ee61b08,
+    0x6599855f14a06840,
+];
+
+const INF: f64 = f64::INFINITY;
+const MAX: f64 = f64::MAX;
+const NAN: (f64, f64) = (f64::NAN, f64::NAN);
+// The smallest positive subnormal number.
+const TINY: f64 = 5e-324;
+// The largest number less than 1.
+const ONE_MINUS: f64 = 1.0 - f64::EPSILON / 2.0;
+
+macro_rules! pure_fn {
+    ($f:ident, $f_rd:ident, $f_ru:ident) => {
+        pub(crate) fn $f_rd(x: f64) -> f64 {
+            $f(x).0
+        }
+
+        pub(crate) fn $f_ru(x: f64) -> f64 {
+            $f(x).1
+        }
+    };
+}
+
+macro_rules! pure_fn2 {
+    ($f:ident, $f_rd:ident, $f_ru:ident, $t:ty) => {
+        pub(crate) fn $f_rd(x: f64, y: $t) -> f64 {
+            $f(x, y).0
+        }
+
+        pub(crate) fn $f_ru(x: f64, y: $t) -> f64 {
+            $f(x, y).1
+        }
+    };
+}
+
+pure_fn!(acos, acos_rd, acos_ru);
+pure_fn!(acos_pi, acos_pi_rd, acos_pi_ru);
+pure_fn!(acosh, acosh_rd, acosh_ru);
+pure_fn!(acoth, acoth_rd, acoth_ru);
+pure_fn!(acsc, acsc_rd, acsc_ru);
+pure_fn!(asec, asec_rd, asec_ru);
+pure_fn!(asin, asin_rd, asin_ru);
+pure_fn!(asin_pi, asin_pi_rd, asin_pi_ru);
+pure_fn!(asinh, asinh_rd, asinh_ru);
+pure_fn!(atan, atan_rd, atan_ru);
+pure_fn2!(atan2, atan2_rd, atan2_ru, f64);
+pure_fn2!(atan2_pi, atan2_pi_rd, atan2_pi_ru, f64);
+pure_fn!(atan_pi, atan_pi_rd, atan_pi_ru);
+pure_fn!(atanh, atanh_rd, atanh_ru);
+pure_fn2!(compound, compound_rd, compound_ru, i32);
+pure_fn!(cos, cos_rd, cos_ru);
+pure_fn!(cos_pi, cos_pi_rd, cos_pi_ru);
+pure_fn!(cosh, cosh_rd, cosh_ru);
+pure_fn!(cot, cot_rd, cot_ru);
+pure_fn!(coth, coth_rd, coth_ru);
+pure_fn!(csc, csc_rd, csc_ru);
+pure_fn!(csch, csch_rd, csch_ru);
+pure_fn!(exp, exp_rd, exp_ru);
+pure_fn!(exp10, exp10_rd, exp10_ru);
+pure_fn!(exp10m1, exp10m1_rd, exp10m1_ru);
+pure_fn!(exp2, exp2_rd, exp2_ru);
+pure_fn!(exp2m1, exp2m1_rd, exp2m1_ru);
+pure_fn!(expm1, expm1_rd, expm1_ru);
+pure_fn2!(hypot, hypot_rd, hypot_ru, f64);
+pure_fn!(ln, ln_rd, ln_ru);
+pure_fn!(log10, log10_rd, log10_ru);
+pure_fn!(log10p1, log10p1_rd, log10p1_ru);
+pure_fn!(logp1, logp1_rd, logp1_ru);
+pure_fn!(log2, log2_rd, log2_ru);
+pure_fn!(log2p1, log2p1_rd, log2p1_ru);
+pure_fn2!(pow, pow_rd, pow_ru, f64);
+pure_fn2!(pown, pown_rd, pown_ru, i32);
+pure_fn!(rsqrt, rsqrt_rd, rsqrt_ru);
+pure_fn2!(rootn, rootn_rd, rootn_ru, i32);
+pure_fn!(sec, sec_rd, sec_ru);
+pure_fn!(sech, sech_rd, sech_ru);
+pure_fn!(sin, sin_rd, sin_ru);
+pure_fn!(sin_pi, sin_pi_rd, sin_pi_ru);
+pure_fn!(sinh, sinh_rd, sinh_ru);
+pure_fn!(tan, tan_rd, tan_ru);
+pure_fn!(tan_pi, tan_pi_rd, tan_pi_ru);
+pure_fn!(tanh, tanh_rd, tanh_ru);
+
+// Utilities
+
+// The exact double-double interval [x, x].
+fn point(x: f64) -> Dd {
+    interval(x, x)
+}
+
+fn interval(a: f64, b: f64) -> Dd {
+    Dd::from(Interval::with_infsup_raw(a, b))
+}
+
+// Returns the bounds of `x` rounded outward.
+fn bounds(x: Dd) -> (f64, f64) {
+    let x = Interval::from(x);
+    (x.inf_raw(), x.sup_raw())
+}
+
+// Returns the bounds of `x` or `-x` rounded outward, where `x` is the enclosure of
+// a positive result.
+fn bounds_signed(neg: bool, x: Dd) -> (f64, f64) {
+    let (a, b) = bounds(x);
+    signed(neg, (a.max(0.0), b))
+}
+
+// Returns `(b, a)` if `neg` is `true`, where `(a, b)` is the enclosure of a positive result.
+fn signed(neg: bool, (a, b): (f64, f64)) -> (f64, f64) {
+    if neg {
+        (-b, -a)
+    } else {
+        (a, b)
+    }
+}
+
+// A number close to the numbers in `x`.
+fn approx(x: Dd) -> f64 {
+    x.inf().0
+}
+
+// Returns `Some(x)` if `x` is an interval [x, x] with an `f64` number x.
+fn as_f64(x: Dd) -> Option<f64> {
+    let (a, a2) = x.inf();
+    let (b, b2) = x.sup();
+    if a == b && a2 == 0.0 && b2 == 0.0 {
+        Some(a)
+    } else {
+        None
+    }
+}
+
+fn mag(x: Dd) -> f64 {
+    Interval::from(x).mag()
+}
+
+fn mig(x: Dd) -> f64 {
+    Interval::from(x).mig()
+}
+
+fn round1(x: f64) -> f64 {
+    extract0(round(splat(x)))
+}
+
+fn is_integer(x: f64) -> bool {
+    round1(x) == x
+}
+
+// Returns `true` if `x` is an odd integer.
+fn is_odd(x: f64) -> bool {
+    x.abs() < 9007199254740992.0 && is_integer(x) && !is_integer(x / 2.0)
+}
+
+// 2^k for -1022 ≤ k ≤ 1023.
+fn pow2(k: i32) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+// x 2^k, where the overflow and underflow are handled by `DdInterval`.
+fn ldexp(mut x: Dd, k: i64) -> Dd {
+    let mut k = k.clamp(-3000, 3000) as i32;
+    while k != 0 {
+        let j = k.clamp(-1000, 1000);
+        x *= point(pow2(j));
+        k -= j;
+    }
+    x
+}
+
+// Returns the bounds of x 2^k rounded outward. If the result is near or below the underflow
+// threshold, where `DdInterval` loses precision, x is scaled by 2^(k + 200) first and
+// the bounds are then scaled by 2^-200 with directed rounding.
+fn bounds_ldexp(x: Dd, k: i64) -> (f64, f64) {
+    let a = approx(x).abs();
+    if a != 0.0 && a.is_finite() && k + (frexp(a).1 as i64) < -960 {
+        let s = Interval::with_infsup_raw(pow2(-200), pow2(-200));
+        let y = Interval::from(ldexp(x, k + 200)) * s;
+        (y.inf_raw(), y.sup_raw())
+    } else {
+        bounds(ldexp(x, k))
+    }
+}
+
+// Returns `(m, e)` such that x = m 2^e and m ∈ [1, 2), for finite x > 0.
+fn frexp(x: f64) -> (f64, i32) {
+    let bits = x.to_bits();
+    let e = (bits >> 52) as i32;
+    if e == 0 {
+        let (m, e) = frexp(x * pow2(64));
+        return (m, e - 64);
+    }
+    (
+        f64::from_bits(bits & 0x000f_ffff_ffff_ffff | 0x3ff0_0000_0000_0000),
+        e - 1023,
+    )
+}
+
+// Returns the exponent k of 10^k = x, 0 ≤ k ≤ 22, if any.
+fn exact_log10(x: f64) -> Option<i32> {
+    let mut p = 1.0;
+    for k in 0..=22 {
+        if x == p {
+            return Some(k);
+        }
+        p *= 10.0;
+    }
+    None
+}
+
+// Returns an enclosure of t_0 + t_1 + ⋯, where t_{n+1} = `next`(t_n, n + 1).
+// The summation stops at the first term t_N that is negligible, and the remainder
+// t_{N+1} + t_{N+2} + ⋯ is bounded by 2|t_{N+1}|. Thus |t_{n+1}| ≤ |t_n| / 2 must hold
+// for all n ≥ N + 1.
+fn sum_series(t0: Dd, mut next: impl FnMut(Dd, u32) -> Dd) -> Dd {
+    let mut s = t0;
+    let mut t = t0;
+    let mut n = 1;
+    loop {
+        t = next(t, n);
+        let m = mag(t);
+        s += t;
+        if m <= TINY || m <= mig(s) * pow2(-110) || n == 200 {
+            break;
+        }
+        n += 1;
+    }
+    let m = 2.0 * mag(next(t, n + 1));
+    s + interval(-m, m)
+}
+
+// Returns the enclosure of c x (1 + δ), where δ ∈ [d_lo, d_hi] 2^-110, without the loss
+// of accuracy due to underflow. This is used for evaluating a function f(x) = c x (1 + O(x^2))
+// where `is_tiny(x)` holds, or f(x) = c x (1 + O(x)) where `is_very_tiny(x)` holds.
+// d_lo and d_hi are chosen from {-1, 0, 1} according to the sign of the error term.
+fn near_linear(x: f64, c: Dd, (d_lo, d_hi): (f64, f64)) -> (f64, f64) {
+    let e = pow2(-110);
+    let y = point(x * pow2(600)) * c * (point(1.0) + interval(d_lo * e, d_hi * e));
+    bounds_ldexp(y, -600)
+}
+
+// Returns the enclosure of (1/x)(1 + δ), where δ ∈ [d_lo, d_hi] 2^-110, for evaluating
+// a function f(x) = (1/x)(1 + O(1/x^2)) where `is_tiny(1 / x)` holds,
+// or f(x) = (1/x)(1 + O(x^2)) where `is_tiny(x)` holds.
+fn near_recip(x: f64, (d_lo, d_hi): (f64, f64)) -> (f64, f64) {
+    let e = pow2(-110);
+    let k = if x.abs() > 1.0 { 600 } else { 0 };
+    let y = point(1.0) / point(x * pow2(-k)) * (point(1.0) + interval(d_lo * e, d_hi * e));
+    bounds_ldexp(y, -k as i64)
+}
+
+// Returns the enclosure of 1 + δ, where 0 < δ < 2^-53 if `up` is `true`,
+// and -2^-54 < δ < 0 otherwise.
+fn near_one(up: bool) -> (f64, f64) {
+    if up {
+        (1.0, 1.0 + f64::EPSILON)
+    } else {
+        (ONE_MINUS, 1.0)
+    }
+}
+
+// Returns `true` if |x| < 2^-60.
+fn is_tiny(x: f64) -> bool {
+    x.abs() < pow2(-60)
+}
+
+// Returns `true` if |x| < 2^-120.
+fn is_very_tiny(x: f64) -> bool {
+    x.abs() < pow2(-120)
+}
+
+// Exponential functions
+
+// Returns e^r for |r| ≲ 0.35.
+fn exp_series(r: Dd) -> Dd {
+    sum_series(point(1.0), |t, n| t * r / point(n as f64))
+}
+
+// Returns e^r - 1 for |r| ≲ 0.35.
+fn expm1_series(r: Dd) -> Dd {
+    sum_series(r, |t, n| t * r / point((n + 1) as f64))
+}
+
+// Returns `(m, k)` such that e^t = m 2^k, for |t| ≲ 2^11.
+fn exp_split(t: Dd) -> (Dd, i64) {
+    let k = round1(approx(t) * consts::LOG2_E);
+    (exp_series(t - point(k) * LN_2), k as i64)
+}
+
+fn exp_dd(t: Dd) -> Dd {
+    let (m, k) = exp_split(t);
+    ldexp(m, k)
+}
+
+fn expm1_dd(t: Dd) -> Dd {
+    if approx(t).abs() <= 0.35 {
+        expm1_series(t)
+    } else {
+        exp_dd(t) - point(1.0)
+    }
+}
+
+// Returns `(m, k)` such that 2^x = m 2^k, for |x| ≲ 2^11.
+fn exp2_split(x: f64) -> (Dd, i64) {
+    let k = round1(x);
+    (exp_series(point(x - k) * LN_2), k as i64)
+}
+
+fn exp2_dd(x: f64) -> Dd {
+    let (m, k) = exp2_split(x);
+    ldexp(m, k)
+}
+
+// Returns `(m, k)` such that 10^x = m 2^k, for |x| ≲ 2^9.
+fn exp10_split(x: f64) -> (Dd, i64) {
+    if x >= 0.0 && is_integer(x) && x <= 22.0 {
+        let mut p = 1.0;
+        for _ in 0..x as i32 {
+            p *= 10.0;
+        }
+        return (point(p), 0);
+    }
+    exp_split(point(x) * LN_10)
+}
+
+fn exp10_dd(x: f64) -> Dd {
+    let (m, k) = exp10_split(x);
+    ldexp(m, k)
+}
+
+fn exp(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (0.0, 0.0)
+    } else if x == 0.0 {
+        (1.0, 1.0)
+    } else if is_tiny(x) {
+        near_one(x > 0.0)
+    } else if x > 709.8 {
+        (MAX, INF)
+    } else if x < -745.2 {
+        (0.0, TINY)
+    } else {
+        let (m, k) = exp_split(point(x));
+        bounds_ldexp(m, k)
+    }
+}
+
+fn exp2(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (0.0, 0.0)
+    } else if x != 0.0 && is_tiny(x) {
+        near_one(x > 0.0)
+    } else if x > 1024.0 {
+        (MAX, INF)
+    } else if x < -1076.0 {
+        (0.0, TINY)
+    } else {
+        let (m, k) = exp2_split(x);
+        bounds_ldexp(m, k)
+    }
+}
+
+fn exp10(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (0.0, 0.0)
+    } else if x != 0.0 && is_tiny(x) {
+        near_one(x > 0.0)
+    } else if x > 308.26 {
+        (MAX, INF)
+    } else if x < -323.61 {
+        (0.0, TINY)
+    } else {
+        let (m, k) = exp10_split(x);
+        bounds_ldexp(m, k)
+    }
+}
+
+fn expm1(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (-1.0, -1.0)
+    } else if x == 0.0 {
+        (x, x)
+    } else if is_very_tiny(x) {
+        near_linear(x, point(1.0), signed(x < 0.0, (0.0, 1.0)))
+    } else if x > 709.8 {
+        (MAX, INF)
+    } else if x < -40.0 {
+        (-1.0, -ONE_MINUS)
+    } else {
+        bounds(expm1_dd(point(x)))
+    }
+}
+
+fn exp2m1(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (-1.0, -1.0)
+    } else if x == 0.0 {
+        (x, x)
+    } else if is_very_tiny(x) {
+        near_linear(x, LN_2, signed(x < 0.0, (0.0, 1.0)))
+    } else if x > 1024.0 {
+        (MAX, INF)
+    } else if x < -60.0 {
+        (-1.0, -ONE_MINUS)
+    } else if x.abs() <= 0.5 {
+        bounds(expm1_series(point(x) * LN_2))
+    } else {
+        bounds(exp2_dd(x) - point(1.0))
+    }
+}
+
+fn exp10m1(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x == -INF {
+        (-1.0, -1.0)
+    } else if x == 0.0 {
+        (x, x)
+    } else if is_very_tiny(x) {
+        near_linear(x, LN_10, signed(x < 0.0, (0.0, 1.0)))
+    } else if x > 308.26 {
+        (MAX, INF)
+    } else if x < -17.0 {
+        (-1.0, -ONE_MINUS)
+    } else if x.abs() <= 0.15 {
+        bounds(expm1_series(point(x) * LN_10))
+    } else {
+        bounds(exp10_dd(x) - point(1.0))
+    }
+}
+
+// Logarithmic functions
+
+// Returns atanh(u) = u + u^3/3 + u^5/5 + ⋯ for |u| ≲ 0.7.
+fn atanh_series(u: Dd) -> Dd {
+    let u2 = u.sqr();
+    sum_series(u, |t, n| {
+        t * u2 * point((2 * n - 1) as f64) / point((2 * n + 1) as f64)
+    })
+}
+
+// Returns ln(m) for m ∈ [1/√2, √2].
+fn ln_reduced(m: Dd) -> Dd {
+    // ln(m) = 2 atanh(u), where u = (m - 1) / (m + 1) ∈ [-0.18, 0.18].
+    ldexp(atanh_series((m - point(1.0)) / (m + point(1.0))), 1)
+}
+
+// Returns `(m, e)` such that x = m 2^e and m ∈ [1/√2, √2], for x > 0.
+fn ln_split(x: Dd) -> (Dd, i64) {
+    let (_, e) = frexp(approx(x));
+    let m = ldexp(x, -e as i64);
+    if approx(m) > consts::SQRT_2 {
+        (ldexp(m, -1), e as i64 + 1)
+    } else {
+        (m, e as i64)
+    }
+}
+
+// Returns ln(x) for x > 0.
+fn ln_dd(x: Dd) -> Dd {
+    let (m, e) = ln_split(x);
+    point(e as f64) * LN_2 + ln_reduced(m)
+}
+
+// Returns ln(1 + u) for u > -1.
+fn log1p_dd(u: Dd) -> Dd {
+    if approx(u).abs() <= 0.4 {
+        // ln(1 + u) = 2 atanh(u / (2 + u)).
+        ldexp(atanh_series(u / (point(2.0) + u)), 1)
+    } else {
+        ln_dd(point(1.0) + u)
+    }
+}
+
+// Returns log2(x) for x > 0.
+fn log2_dd(x: Dd) -> Dd {
+    let (m, e) = ln_split(x);
+    if as_f64(m) == Some(1.0) {
+        return point(e as f64);
+    }
+    point(e as f64) + ln_reduced(m) * LOG2_E
+}
+
+// Returns log10(x) for x > 0.
+fn log10_dd(x: Dd) -> Dd {
+    match as_f64(x).and_then(exact_log10) {
+        Some(k) => point(k as f64),
+        _ => ln_dd(x) * LOG10_E,
+    }
+}
+
+// Returns the enclosure of f(x), where f is a logarithmic function, after handling the special
+// cases. `f_dd` is called with the double-double enclosure of x + `shift`.
+fn log_impl(x: f64, shift: f64, f_dd: impl Fn(Dd) -> Dd) -> (f64, f64) {
+    if x.is_nan() || x < -shift {
+        NAN
+    } else if x == -shift {
+        (-INF, -INF)
+    } else if x == INF {
+        (INF, INF)
+    } else {
+        bounds(f_dd(point(shift) + point(x)))
+    }
+}
+
+fn ln(x: f64) -> (f64, f64) {
+    log_impl(x, 0.0, ln_dd)
+}
+
+fn log2(x: f64) -> (f64, f64) {
+    log_impl(x, 0.0, log2_dd)
+}
+
+fn log10(x: f64) -> (f64, f64) {
+    log_impl(x, 0.0, log10_dd)
+}
+
+fn logp1(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_very_tiny(x) {
+        return near_linear(x, point(1.0), signed(x > 0.0, (0.0, 1.0)));
+    }
+    log_impl(x, 1.0, |_| log1p_dd(point(x)))
+}
+
+fn log2p1(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_very_tiny(x) {
+        return near_linear(x, LOG2_E, signed(x > 0.0, (0.0, 1.0)));
+    }
+    log_impl(x, 1.0, |y| match as_f64(y) {
+        Some(y) if frexp(y).0 == 1.0 => log2_dd(point(y)),
+        _ => log1p_dd(point(x)) * LOG2_E,
+    })
+}
+
+fn log10p1(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_very_tiny(x) {
+        return near_linear(x, LOG10_E, signed(x > 0.0, (0.0, 1.0)));
+    }
+    log_impl(x, 1.0, |y| match as_f64(y).and_then(exact_log10) {
+        Some(k) => point(k as f64),
+        _ => log1p_dd(point(x)) * LOG10_E,
+    })
+}
+
+// Trigonometric functions
+
+// Returns `(q, r)` such that x = (4n + q)π/2 + r for some integer n and |r| ≤ π/4,
+// for finite x.
+fn rem_pio2(x: f64) -> (u32, Dd) {
+    let a = x.abs();
+    if a <= 0.785 {
+        return (0, point(x));
+    }
+
+    // a = m 2^(e - 52), where m is a 53-bit integer.
+    let bits = a.to_bits();
+    let e = (bits >> 52) as i32 - 1023;
+    let m = bits & 0x000f_ffff_ffff_ffff | 0x0010_0000_0000_0000;
+
+    // Let b_i be the i-th bit of 2/π after the binary point. The bits with i ≤ e - 54 only
+    // contribute multiples of 4 to a (2/π), so we take the 320 bits b_i0, …, b_(i0 + 319)
+    // as an integer w and compute p = m w, so that a (2/π) ≡ p 2^-f + δ (mod 4),
+    // where 0 ≤ δ < 2^(53 - f).
+    let i0 = (e - 53).max(1) as usize;
+    let f = (i0 as i32 + 371 - e) as usize;
+    let (k, s) = ((i0 - 1) / 64, (i0 - 1) % 64);
+    // p in little-endian order.
+    let mut p = [0u64; 6];
+    let mut carry = 0u128;
+    for (j, pj) in p.iter_mut().take(5).enumerate() {
+        let hi = TWO_OVER_PI[k + 4 - j];
+        let lo = TWO_OVER_PI[k + 5 - j];
+        let w = if s == 0 { hi } else { hi << s | lo >> (64 - s) };
+        let t = m as u128 * w as u128 + carry;
+        *pj = t as u64;
+        carry = t >> 64;
+    }
+    p[5] = carry as u64;
+
+    let bit = |i: usize| (p[i / 64] >> (i % 64)) & 1;
+    let mut q = (bit(f) + 2 * bit(f + 1)) as u32;
+
+    // The fractional part p 2^-f mod 1, rounded to the nearest integer
+    // and represented by its absolute value.
+    let neg = bit(f - 1) == 1;
+    let mut frac = p;
+    for (i, w) in frac.iter_mut().enumerate() {
+        let lo = 64 * i;
+        if lo >= f {
+            *w = 0;
+        } else if f - lo < 64 {
+            *w &= (1 << (f - lo)) - 1;
+        }
+    }
+    if neg {
+        // 2^f - frac
+        q += 1;
+        let mut borrow = true;
+        for w in frac.iter_mut() {
+            let (v, b) = (!*w).overflowing_add(borrow as u64);
+            *w = v;
+            borrow = b;
+        }
+        for (i, w) in frac.iter_mut().enumerate() {
+            let lo = 64 * i;
+            if lo >= f {
+                *w = 0;
+            } else if f - lo < 64 {
+                *w &= (1 << (f - lo)) - 1;
+            }
+        }
+    }
+
+    let mut y = Dd::from(Interval::zero());
+    for (i, &w) in frac.iter().enumerate().rev() {
+        if w != 0 {
+            let w = point((w & 0xffff_ffff_0000_0000) as f64) + point((w & 0xffff_ffff) as f64);
+            y += ldexp(w, 64 * i as i64 - f as i64);
+        }
+    }
+    let d = pow2(53 - f as i32);
+    let r = (y + interval(-d, d)) * FRAC_PI_2;
+    let r = if neg { -r } else { r };
+
+    if x < 0.0 {
+        ((4 - q % 4) % 4, -r)
+    } else {
+        (q % 4, r)
+    }
+}
+
+// Returns `(q, r)` such that x = (4n + q)/2 + r for some integer n and |r| ≤ 1/4,
+// for finite x. r is computed exactly.
+fn rem_half(x: f64) -> (u32, f64) {
+    if x.abs() >= 9007199254740992.0 {
+        // x is an even integer.
+        return (0, 0.0);
+    }
+    let n = round1(2.0 * x);
+    ((n as i64).rem_euclid(4) as u32, x - n / 2.0)
+}
+
+fn sin_series(r: Dd) -> Dd {
+    let r2 = r.sqr();
+    sum_series(r, |t, n| -(t * r2) / point((2 * n * (2 * n + 1)) as f64))
+}
+
+fn cos_series(r: Dd) -> Dd {
+    let r2 = r.sqr();
+    sum_series(point(1.0), |t, n| {
+        -(t * r2) / point(((2 * n - 1) * 2 * n) as f64)
+    })
+}
+
+// Returns (sin(qπ/2 + r), cos(qπ/2 + r)).
+fn sin_cos(q: u32, r: Dd) -> (Dd, Dd) {
+    let s = sin_series(r);
+    let c = cos_series(r);
+    match q {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+fn clamp_unit((a, b): (f64, f64)) -> (f64, f64) {
+    (a.max(-1.0), b.min(1.0))
+}
+
+fn sin(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_tiny(x) {
+        return near_linear(x, point(1.0), (-1.0, 0.0));
+    }
+    let (q, r) = rem_pio2(x);
+    let y = match q {
+        0 => sin_series(r),
+        1 => cos_series(r),
+        2 => -sin_series(r),
+        _ => -cos_series(r),
+    };
+    clamp_unit(bounds(y))
+}
+
+fn cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (1.0, 1.0);
+    }
+    if is_tiny(x) {
+        return near_one(false);
+    }
+    let (q, r) = rem_pio2(x);
+    let y = match q {
+        0 => cos_series(r),
+        1 => -sin_series(r),
+        2 => -cos_series(r),
+        _ => sin_series(r),
+    };
+    clamp_unit(bounds(y))
+}
+
+fn tan(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_tiny(x) {
+        return near_linear(x, point(1.0), (0.0, 1.0));
+    }
+    let (s, c) = sin_cos_of(x);
+    bounds(s / c)
+}
+
+fn cot(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        let y = if x.is_sign_negative() { -INF } else { INF };
+        return (y, y);
+    }
+    if is_tiny(x) {
+        return near_recip(x, (-1.0, 0.0));
+    }
+    let (s, c) = sin_cos_of(x);
+    bounds(c / s)
+}
+
+fn sec(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (1.0, 1.0);
+    }
+    if is_tiny(x) {
+        return near_one(true);
+    }
+    let (_, c) = sin_cos_of(x);
+    bounds(point(1.0) / c)
+}
+
+fn csc(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        let y = if x.is_sign_negative() { -INF } else { INF };
+        return (y, y);
+    }
+    if is_tiny(x) {
+        return near_recip(x, (0.0, 1.0));
+    }
+    let (s, _) = sin_cos_of(x);
+    bounds(point(1.0) / s)
+}
+
+fn sin_cos_of(x: f64) -> (Dd, Dd) {
+    let (q, r) = rem_pio2(x);
+    sin_cos(q, r)
+}
+
+fn sin_pi(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_tiny(x) {
+        return near_linear(x, PI, (-1.0, 0.0));
+    }
+    let (q, r) = rem_half(x);
+    let (s, _) = sin_cos(q, point(r) * PI);
+    clamp_unit(bounds(s))
+}
+
+fn cos_pi(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x != 0.0 && is_tiny(x) {
+        return near_one(false);
+    }
+    let (q, r) = rem_half(x);
+    let (_, c) = sin_cos(q, point(r) * PI);
+    clamp_unit(bounds(c))
+}
+
+fn tan_pi(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return NAN;
+    }
+    if x == 0.0 {
+        return (x, x);
+    }
+    if is_tiny(x) {
+        return near_linear(x, PI, (0.0, 1.0));
+    }
+    let (q, r) = rem_half(x);
+    if r.abs() == 0.25 {
+        let y = if q % 2 == 0 { r.signum() } else { -r.signum() };
+        return (y, y);
+    }
+    if r == 0.0 && q % 2 == 1 {
+        // The pole at 2n + 1/2 is +∞ and the one at 2n + 3/2 is -∞.
+        let y = if q == 1 { INF } else { -INF };
+        return (y, y);
+    }
+    let (s, c) = sin_cos(q, point(r) * PI);
+    bounds(s / c)
+}
+
+// Inverse trigonometric functions
+
+// Returns atan(x) for finite x.
+fn atan_dd(x: Dd) -> Dd {
+    if approx(x).abs() > 1.0 {
+        let h = if approx(x) > 0.0 {
+            FRAC_PI_2
+        } else {
+            -FRAC_PI_2
+        };
+        return h - atan_reduced(point(1.0) / x);
+    }
+    atan_reduced(x)
+}
+
+// Returns atan(x) for |x| ≲ 1.
+fn atan_reduced(mut x: Dd) -> Dd {
+    // atan(x) = 2 atan(x / (1 + √(1 + x^2))).
+    let mut k = 0;
+    while approx(x).abs() > 0.125 {
+        x = x / (point(1.0) + (point(1.0) + x.sqr()).sqrt());
+        k += 1;
+    }
+    let x2 = x.sqr();
+    let s = sum_series(x, |t, n| {
+        -(t * x2) * point((2 * n - 1) as f64) / point((2 * n + 1) as f64)
+    });
+    ldexp(s, k)
+}
+
+// Returns `(k, a)` such that atan2(±y, ±x) = kπ/4 + a, where the signs of the arguments
+// are given by `y_neg` and `x_neg`. `y` and `x` must be nonnegative and finite.
+fn atan2_dd(y_neg: bool, y: Dd, x_neg: bool, x: Dd) -> (i32, Dd) {
+    let zero = Dd::from(Interval::zero());
+    let (k, a) = if y == zero {
+        (0, zero)
+    } else if x == zero {
+        (2, zero)
+    } else if y == x && as_f64(y).is_some() {
+        (1, zero)
+    } else if approx(y) <= approx(x) {
+        (0, atan_dd(y / x))
+    } else {
+        (2, -atan_dd(x / y))
+    };
+    let (k, a) = if x_neg { (4 - k, -a) } else { (k, a) };
+    if y_neg {
+        (-k, -a)
+    } else {
+        (k, a)
+    }
+}
+
+// atan2 for f64 arguments, including infinities.
+fn atan2_f64(y: f64, x: f64) -> (i32, Dd) {
+    let zero = Dd::from(Interval::zero());
+    let y_neg = y.is_sign_negative();
+    let x_neg = x.is_sign_negative();
+    let k = if y.is_infinite() {
+        if x.is_infinite() {
+            if x_neg {
+                3
+            } else {
+                1
+            }
+        } else {
+            2
+        }
+    } else if x.is_infinite() {
+        if x_neg {
+            4
+        } else {
+            0
+        }
+    } else {
+        // Scales the arguments so that the quotient is computed without underflow.
+        let s = if y.abs().max(x.abs()) < 1.0 {
+            pow2(600)
+        } else {
+            1.0
+        };
+        return atan2_dd(y_neg, point(s * y.abs()), x_neg, point(s * x.abs()));
+    };
+    if y_neg {
+        (-k, zero)
+    } else {
+        (k, zero)
+    }
+}
+
+// Returns the enclosure of kπ/4 + a.
+fn angle((k, a): (i32, Dd)) -> (f64, f64) {
+    bounds(point(k as f64) * FRAC_PI_4 + a)
+}
+
+// Returns the enclosure of k/4 + a/π.
+fn angle_pi((k, a): (i32, Dd)) -> (f64, f64) {
+    bounds(point(k as f64 / 4.0) + a * FRAC_1_PI)
+}
+
+// √((1 - x)(1 + x)) for 0 ≤ x ≤ 1.
+fn sqrt_one_minus_sqr(x: Dd) -> Dd {
+    ((point(1.0) - x) * (point(1.0) + x)).sqrt()
+}
+
+fn asin_impl(x: f64) -> Option<(i32, Dd)> {
+    if x.is_nan() || x.abs() > 1.0 {
+        return None;
+    }
+    let a = point(x.abs());
+    Some(atan2_dd(x < 0.0, a, false, sqrt_one_minus_sqr(a)))
+}
+
+fn acos_impl(x: f64) -> Option<(i32, Dd)> {
+    if x.is_nan() || x.abs() > 1.0 {
+        return None;
+    }
+    let a = point(x.abs());
+    Some(atan2_dd(false, sqrt_one_minus_sqr(a), x < 0.0, a))
+}
+
+fn acos(x: f64) -> (f64, f64) {
+    acos_impl(x).map_or(NAN, angle)
+}
+
+fn acos_pi(x: f64) -> (f64, f64) {
+    acos_impl(x).map_or(NAN, angle_pi)
+}
+
+fn asin(x: f64) -> (f64, f64) {
+    if is_tiny(x) {
+        return near_linear(x, point(1.0), (0.0, 1.0));
+    }
+    asin_impl(x).map_or(NAN, angle)
+}
+
+fn asin_pi(x: f64) -> (f64, f64) {
+    if is_tiny(x) {
+        return near_linear(x, FRAC_1_PI, (0.0, 1.0));
+    }
+    asin_impl(x).map_or(NAN, angle_pi)
+}
+
+fn atan(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return NAN;
+    }
+    if is_tiny(x) {
+        return near_linear(x, point(1.0), (-1.0, 0.0));
+    }
+    angle(atan2_f64(x, 1.0))
+}
+
+fn atan_pi(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return NAN;
+    }
+    if is_tiny(x) {
+        return near_linear(x, FRAC_1_PI, (-1.0, 0.0));
+    }
+    angle_pi(atan2_f64(x, 1.0))
+}
+
+// Returns `true` if 0 < |y| < x 2^-60, for which `atan2_tiny` should be used.
+fn is_atan2_tiny(y: f64, x: f64) -> bool {
+    y != 0.0 && x.is_finite() && y.abs() < x * pow2(-60)
+}
+
+// Returns the enclosure of (y/x) c (1 + δ), where δ ∈ [-1, 0] 2^-110, for evaluating
+// atan2(y, x) c, where `is_atan2_tiny(y, x)` holds.
+fn atan2_tiny(y: f64, x: f64, c: Dd) -> (f64, f64) {
+    let (my, ey) = frexp(y.abs());
+    let (mx, ex) = frexp(x);
+    let e = pow2(-110);
+    let q = point(my) / point(mx) * c * (point(1.0) + interval(-e, 0.0));
+    signed(y < 0.0, bounds_ldexp(q, (ey - ex) as i64))
+}
+
+fn atan2(y: f64, x: f64) -> (f64, f64) {
+    if x.is_nan() || y.is_nan() {
+        return NAN;
+    }
+    if is_atan2_tiny(y, x) {
+        return atan2_tiny(y, x, point(1.0));
+    }
+    angle(atan2_f64(y, x))
+}
+
+fn atan2_pi(y: f64, x: f64) -> (f64, f64) {
+    if x.is_nan() || y.is_nan() {
+        return NAN;
+    }
+    if is_atan2_tiny(y, x) {
+        return atan2_tiny(y, x, FRAC_1_PI);
+    }
+    angle_pi(atan2_f64(y, x))
+}
+
+// Returns `(s, c)` such that (1/x) = c / √(s^2 + c^2) and √(1 - (1/x)^2) = s / √(s^2 + c^2),
+// for |x| ≥ 1.
+fn recip_sin_cos(a: f64) -> (Dd, Dd) {
+    if a < 2.0 {
+        // s = √(a^2 - 1), c = 1.
+        let a = point(a);
+        (((a - point(1.0)) * (a + point(1.0))).sqrt(), point(1.0))
+    } else {
+        let v = point(1.0) / point(a);
+        (sqrt_one_minus_sqr(v), v)
+    }
+}
+
+fn asec(x: f64) -> (f64, f64) {
+    if x.is_nan() || x.abs() < 1.0 {
+        return NAN;
+    }
+    if x.is_infinite() {
+        return bounds(FRAC_PI_2);
+    }
+    let (s, c) = recip_sin_cos(x.abs());
+    angle(atan2_dd(false, s, x < 0.0, c))
+}
+
+fn acsc(x: f64) -> (f64, f64) {
+    if x.is_nan() || x.abs() < 1.0 {
+        return NAN;
+    }
+    if x.is_infinite() {
+        return (0.0, 0.0);
+    }
+    if is_tiny(1.0 / x) {
+        return near_recip(x, (0.0, 1.0));
+    }
+    let (s, c) = recip_sin_cos(x.abs());
+    angle(atan2_dd(x < 0.0, c, false, s))
+}
+
+// Hyperbolic functions
+
+fn sinh_series(x: Dd) -> Dd {
+    let x2 = x.sqr();
+    sum_series(x, |t, n| t * x2 / point((2 * n * (2 * n + 1)) as f64))
+}
+
+fn cosh_series(x: Dd) -> Dd {
+    let x2 = x.sqr();
+    sum_series(point(1.0), |t, n| {
+        t * x2 / point(((2 * n - 1) * 2 * n) as f64)
+    })
+}
+
+// Returns `(y, k)` such that (e^a ± e^-a) / 2 = y 2^k, for a ≥ 1.
+fn sinh_cosh_split(a: f64, plus: bool) -> (Dd, i64) {
+    let (m, k) = exp_split(point(a));
+    // e^-a = 2^-2k (2^k / m).
+    let r = ldexp(point(1.0) / m, -2 * k);
+    (if plus { m + r } else { m - r }, k - 1)
+}
+
+fn sinh(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if x == 0.0 || a == INF {
+        (x, x)
+    } else if is_tiny(x) {
+        near_linear(x, point(1.0), (0.0, 1.0))
+    } else if a > 710.5 {
+        signed(x < 0.0, (MAX, INF))
+    } else if a < 1.0 {
+        bounds(sinh_series(point(x)))
+    } else {
+        let (y, k) = sinh_cosh_split(a, false);
+        bounds_signed(x < 0.0, ldexp(y, k))
+    }
+}
+
+fn cosh(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if a == INF {
+        (INF, INF)
+    } else if a > 710.5 {
+        (MAX, INF)
+    } else if x != 0.0 && is_tiny(x) {
+        near_one(true)
+    } else if a < 1.0 {
+        bounds(cosh_series(point(x)))
+    } else {
+        let (y, k) = sinh_cosh_split(a, true);
+        bounds(ldexp(y, k))
+    }
+}
+
+fn tanh(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if x == 0.0 {
+        (x, x)
+    } else if is_tiny(x) {
+        near_linear(x, point(1.0), (-1.0, 0.0))
+    } else if a > 20.0 {
+        signed(x < 0.0, (ONE_MINUS, 1.0))
+    } else {
+        // tanh(a) = E / (E + 2), where E = e^2a - 1.
+        let e = expm1_dd(point(2.0 * a));
+        bounds_signed(x < 0.0, e / (e + point(2.0)))
+    }
+}
+
+fn coth(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if x == 0.0 {
+        signed(x.is_sign_negative(), (INF, INF))
+    } else if a > 20.0 {
+        signed(x < 0.0, (1.0, 1.0 + f64::EPSILON))
+    } else if a < pow2(-1000) {
+        // 0 < coth(a) - 1/a < a/3.
+        bounds_signed(x < 0.0, point(1.0) / point(a) + interval(0.0, a))
+    } else {
+        let e = expm1_dd(point(2.0 * a));
+        bounds_signed(x < 0.0, (e + point(2.0)) / e)
+    }
+}
+
+fn sech(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if a == INF {
+        (0.0, 0.0)
+    } else if a > 800.0 {
+        (0.0, TINY)
+    } else if x != 0.0 && is_tiny(x) {
+        near_one(false)
+    } else if a < 1.0 {
+        bounds(point(1.0) / cosh_series(point(x)))
+    } else {
+        let (y, k) = sinh_cosh_split(a, true);
+        bounds_ldexp(point(1.0) / y, -k)
+    }
+}
+
+fn csch(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if x == 0.0 {
+        signed(x.is_sign_negative(), (INF, INF))
+    } else if a == INF {
+        (0.0, 0.0)
+    } else if a > 800.0 {
+        signed(x < 0.0, (0.0, TINY))
+    } else if a < pow2(-1000) {
+        // 0 < 1/a - csch(a) < a/6.
+        bounds_signed(x < 0.0, point(1.0) / point(a) - interval(0.0, a))
+    } else if a < 1.0 {
+        bounds(point(1.0) / sinh_series(point(x)))
+    } else {
+        let (y, k) = sinh_cosh_split(a, false);
+        signed(x < 0.0, bounds_ldexp(point(1.0) / y, -k))
+    }
+}
+
+// Inverse hyperbolic functions
+
+fn asinh(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() {
+        NAN
+    } else if x == 0.0 || a == INF {
+        (x, x)
+    } else if is_tiny(x) {
+        near_linear(x, point(1.0), (-1.0, 0.0))
+    } else if a > pow2(60) {
+        // 0 < asinh(a) - ln(2a) < 1/4a^2.
+        let y = ln_dd(point(a)) + LN_2 + interval(0.0, pow2(-120));
+        bounds_signed(x < 0.0, y)
+    } else {
+        // asinh(a) = ln(1 + a + a^2 / (1 + √(1 + a^2))).
+        let s = point(a).sqr();
+        let y = log1p_dd(point(a) + s / (point(1.0) + (point(1.0) + s).sqrt()));
+        bounds_signed(x < 0.0, y)
+    }
+}
+
+fn acosh(x: f64) -> (f64, f64) {
+    if x.is_nan() || x < 1.0 {
+        NAN
+    } else if x == INF {
+        (INF, INF)
+    } else if x > pow2(60) {
+        // 0 < ln(2x) - acosh(x) < 1/x^2.
+        bounds(ln_dd(point(x)) + LN_2 - interval(0.0, pow2(-118)))
+    } else {
+        // acosh(x) = ln(1 + t + √(t (t + 2))), where t = x - 1.
+        let t = point(x) - point(1.0);
+        bounds(log1p_dd(t + (t * (t + point(2.0))).sqrt()))
+    }
+}
+
+fn atanh(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() || a > 1.0 {
+        NAN
+    } else if a == 1.0 {
+        (x * INF, x * INF)
+    } else if x == 0.0 {
+        (x, x)
+    } else if is_tiny(x) {
+        near_linear(x, point(1.0), (0.0, 1.0))
+    } else if a <= 0.2 {
+        bounds(atanh_series(point(x)))
+    } else {
+        // atanh(a) = ln(1 + 2a / (1 - a)) / 2.
+        let y = log1p_dd(point(2.0 * a) / (point(1.0) - point(a)));
+        bounds_signed(x < 0.0, ldexp(y, -1))
+    }
+}
+
+fn acoth(x: f64) -> (f64, f64) {
+    let a = x.abs();
+    if x.is_nan() || a < 1.0 {
+        NAN
+    } else if a == 1.0 {
+        (x * INF, x * INF)
+    } else if a == INF {
+        signed(x < 0.0, (0.0, 0.0))
+    } else if is_tiny(1.0 / x) {
+        near_recip(x, (0.0, 1.0))
+    } else if a >= 5.0 {
+        bounds_signed(x < 0.0, atanh_series(point(1.0) / point(a)))
+    } else {
+        // acoth(a) = ln(1 + 2 / (a - 1)) / 2.
+        let y = log1p_dd(point(2.0) / (point(a) - point(1.0)));
+        bounds_signed(x < 0.0, ldexp(y, -1))
+    }
+}
+
+// Other functions
+
+fn hypot(x: f64, y: f64) -> (f64, f64) {
+    let (a, b) = (x.abs(), y.abs());
+    if a == INF || b == INF {
+        (INF, INF)
+    } else if x.is_nan() || y.is_nan() {
+        NAN
+    } else if a == 0.0 {
+        (b, b)
+    } else if b == 0.0 {
+        (a, a)
+    } else {
+        let (_, e) = frexp(a.max(b));
+        let e = e as i64;
+        let s = ldexp(point(a), -e).sqr() + ldexp(point(b), -e).sqr();
+        bounds_ldexp(s.sqrt(), e)
+    }
+}
+
+fn rsqrt(x: f64) -> (f64, f64) {
+    if x.is_nan() || x < 0.0 {
+        NAN
+    } else if x == 0.0 {
+        (INF, INF)
+    } else if x == INF {
+        (0.0, 0.0)
+    } else {
+        // x = m 2^e, where m ∈ [1, 4) and e is even.
+        let (m, e) = frexp(x);
+        let (m, e) = if e % 2 == 0 { (m, e) } else { (2.0 * m, e - 1) };
+        bounds(ldexp(point(1.0) / point(m).sqrt(), -e as i64 / 2))
+    }
+}
+
+// Returns `(m, e)` such that x = m 2^e, where m ∈ [1, 2) approximately, for x > 0.
+fn normalize(x: Dd, e: i64) -> (Dd, i64) {
+    let (_, k) = frexp(approx(x));
+    (ldexp(x, -k as i64), e.saturating_add(k as i64))
+}
+
+// Returns `(m, e)` such that x^n = m 2^e, for x > 0 and n ≥ 1.
+fn pow_split(x: Dd, mut n: u64) -> (Dd, i64) {
+    let (mut base, mut be) = normalize(x, 0);
+    let (mut acc, mut ae) = (point(1.0), 0i64);
+    loop {
+        if n & 1 == 1 {
+            (acc, ae) = normalize(acc * base, ae.saturating_add(be));
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        (base, be) = normalize(base.sqr(), be.saturating_mul(2));
+    }
+    (acc, ae)
+}
+
+// Returns the enclosure of x^n for x > 0 and n ≠ 0.
+fn pown_pos(x: Dd, n: i64) -> (f64, f64) {
+    let (m, e) = pow_split(x, n.unsigned_abs());
+    let (m, e) = if n > 0 {
+        (m, e)
+    } else {
+        normalize(point(1.0) / m, e.saturating_neg())
+    };
+    // m ∈ [1, 2) up to rounding errors.
+    if e >= 1024 {
+        (MAX, INF)
+    } else if e < -1080 {
+        (0.0, TINY)
+    } else {
+        let (a, b) = bounds_ldexp(m, e);
+        (a.max(0.0), b)
+    }
+}
+
+// x^n for an integer n, where x^0 = 1 for any x.
+fn pown_f64(x: f64, n: i64) -> (f64, f64) {
+    let odd = n % 2 != 0;
+    if n == 0 {
+        (1.0, 1.0)
+    } else if x.is_nan() {
+        NAN
+    } else if x == 0.0 || x.is_infinite() {
+        // The sign of the result is the same as that of x if n is odd, and positive otherwise.
+        let y = if (n > 0) == (x == 0.0) { 0.0 } else { INF };
+        let y = if odd { y.copysign(x) } else { y };
+        (y, y)
+    } else {
+        signed(odd && x < 0.0, pown_pos(point(x.abs()), n))
+    }
+}
+
+fn pown(x: f64, n: i32) -> (f64, f64) {
+    pown_f64(x, n as i64)
+}
+
+fn compound(x: f64, n: i32) -> (f64, f64) {
+    if x < -1.0 {
+        NAN
+    } else if n == 0 {
+        (1.0, 1.0)
+    } else if x.is_nan() {
+        NAN
+    } else if x == -1.0 || x == INF {
+        let y = if (n > 0) == (x == INF) { INF } else { 0.0 };
+        (y, y)
+    } else if x == 0.0 {
+        (1.0, 1.0)
+    } else {
+        pown_pos(point(1.0) + point(x), n as i64)
+    }
+}
+
+fn rootn(x: f64, n: i32) -> (f64, f64) {
+    let odd = n % 2 != 0;
+    if x.is_nan() || n == 0 || x < 0.0 && !odd {
+        NAN
+    } else if x == 0.0 || x.is_infinite() {
+        let y = if (n > 0) == (x == 0.0) { 0.0 } else { INF };
+        let y = if odd { y.copysign(x) } else { y };
+        (y, y)
+    } else if n == 1 {
+        (x, x)
+    } else {
+        let a = x.abs();
+        let k = n.unsigned_abs() as u64;
+        let y = if k == 1 {
+            point(a)
+        } else {
+            let y = exp_dd(ln_dd(point(a)) / point(k as f64));
+            // Return the exact result if the root is exactly representable.
+            let c = approx(y);
+            let (m, e) = pow_split(point(c), k);
+            if as_f64(ldexp(m, e)) == Some(a) {
+                point(c)
+            } else {
+                y
+            }
+        };
+        let y = if n < 0 { point(1.0) / y } else { y };
+        bounds_signed(x < 0.0, y)
+    }
+}
+
+fn pow(x: f64, y: f64) -> (f64, f64) {
+    let y_int = y.is_finite() && is_integer(y);
+    let y_odd = is_odd(y);
+    if y == 0.0 || x == 1.0 {
+        (1.0, 1.0)
+    } else if x.is_nan() || y.is_nan() {
+        NAN
+    } else if y_int && y.abs() < 2147483648.0 {
+        pown_f64(x, y as i64)
+    } else if x == 0.0 || x.is_infinite() {
+        let z = if (y > 0.0) == (x == 0.0) { 0.0 } else { INF };
+        let z = if y_odd { z.copysign(x) } else { z };
+        (z, z)
+    } else if y.is_infinite() {
+        let a = x.abs();
+        let z = if a == 1.0 {
+            1.0
+        } else if (a < 1.0) == (y > 0.0) {
+            0.0
+        } else {
+            INF
+        };
+        (z, z)
+    } else if x < 0.0 && !y_int {
+        NAN
+    } else {
+        let t = point(y) * ln_dd(point(x.abs()));
+        let z = if approx(t) > 709.8 {
+            (MAX, INF)
+        } else if approx(t) < -745.2 {
+            (0.0, TINY)
+        } else {
+            let (m, k) = exp_split(t);
+            bounds_ldexp(m, k)
+        };
+        signed(x < 0.0 && y_odd, z)
+    }
+}
+
+#[cfg(all(test, feature = "gmp"))]
+mod tests {
+    use super::{super::mpfr, *};
+
+    // The position of `x` in the ordered sequence of `f64` numbers, where ±0 are the same.
+    fn ord(x: f64) -> i64 {
+        let bits = x.to_bits() as i64;
+        if bits < 0 {
+            i64::MIN - bits
+        } else {
+            bits
+        }
+    }
+
+    // Checks that [a, b] contains the tightest enclosure [c, d] and that each bound is at most
+    // one ulp away from the tightest one.
+    fn check((a, b): (f64, f64), (c, d): (f64, f64), what: &dyn Fn() -> String) {
+        if c.is_nan() || d.is_nan() {
+            assert!(a.is_nan() && b.is_nan(), "{}: ({:e}, {:e})", what(), a, b);
+            return;
+        }
+        assert!(
+            a <= c && d <= b && ord(c) - ord(a) <= 1 && ord(b) - ord(d) <= 1,
+            "{}: ({:e}, {:e}) vs ({:e}, {:e})",
+            what(),
+            a,
+            b,
+            c,
+            d
+        );
+    }
+
+    // Numbers across the whole range of `f64`, along with the ones in [2^-20, 2^20],
+    // the ones near 1, the ones near the overflow and underflow thresholds, the ones around
+    // the thresholds of the argument reductions and the approximations, the ones near
+    // the multiples of π/4 and huge ones.
+    fn samples() -> Vec<f64> {
+        let mut xs = vec![
+            0.0,
+            0.25,
+            0.5,
+            0.75,
+            1.0,
+            1.5,
+            2.0,
+            3.0,
+            10.0,
+            100.0,
+            1e22,
+            0.1,
+            1e-20,
+            1e-300,
+            1e300,
+            710.0,
+            709.78,
+            745.0,
+            1023.99,
+            308.25,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::EPSILON,
+            ONE_MINUS,
+            1.0 + f64::EPSILON,
+            f64::from_bits(1),
+            f64::from_bits(0x000f_ffff_ffff_ffff),
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        let mut bits = 0x1234_5678_9abc_def0u64;
+        for _ in 0..300 {
+            // xorshift64
+            bits ^= bits << 13;
+            bits ^= bits >> 7;
+            bits ^= bits << 17;
+            let m = bits & 0x000f_ffff_ffff_ffff;
+            let e = (bits >> 52) % 41;
+            let t = m as f64 / (1u64 << 52) as f64;
+            xs.push(f64::from_bits(bits & !(1 << 63)));
+            xs.push(f64::from_bits(m | (1003 + e) << 52));
+            xs.push(1.0 + f64::from_bits(m | (1023 - e) << 52));
+            xs.push(1.0 - f64::from_bits(m | (1023 - e) << 52));
+            xs.push(705.0 + 40.0 * t);
+            xs.push(f64::MIN_POSITIVE * (1.0 + 7.0 * t));
+        }
+        let thresholds = [
+            pow2(-120),
+            pow2(-60),
+            0.125,
+            0.15,
+            0.2,
+            0.35,
+            0.4,
+            0.5,
+            0.785,
+            2.0,
+            5.0,
+            20.0,
+            22.0,
+            308.26,
+            709.8,
+            710.5,
+            800.0,
+            1024.0,
+            2147483648.0,
+            9007199254740992.0,
+        ];
+        let multiples_of_pi_4 = (1..=64i64)
+            .chain([1000, 1 << 20, 1 << 40])
+            .map(|k| k as f64 * consts::FRAC_PI_4);
+        for t in thresholds.into_iter().chain(multiples_of_pi_4) {
+            for d in -2..=2 {
+                xs.push(f64::from_bits((t.to_bits() as i64 + d) as u64));
+            }
+        }
+        for e in (53..1024).step_by(10) {
+            xs.push(pow2(e));
+        }
+        // The number closest to a multiple of π/2 among the `f64` numbers.
+        xs.extend([1e22, 6381956970095103.0 * pow2(797)]);
+        let n = xs.len();
+        for i in 0..n {
+            xs.push(-xs[i]);
+        }
+        xs
+    }
+
+    macro_rules! check {
+        ($f_rd:ident, $f_ru:ident, $($x:ident),+) => {
+            check(
+                ($f_rd($($x),+), $f_ru($($x),+)),
+                (mpfr::$f_rd($($x),+), mpfr::$f_ru($($x),+)),
+                &|| format!("{}{:?}", stringify!($f_rd), ($($x),+)),
+            )
+        };
+    }
+
+    #[test]
+    fn exp_log() {
+        for x in samples() {
+            check!(exp_rd, exp_ru, x);
+            check!(exp10_rd, exp10_ru, x);
+            check!(exp10m1_rd, exp10m1_ru, x);
+            check!(exp2_rd, exp2_ru, x);
+            check!(exp2m1_rd, exp2m1_ru, x);
+            check!(expm1_rd, expm1_ru, x);
+            check!(ln_rd, ln_ru, x);
+            check!(log10_rd, log10_ru, x);
+            check!(log10p1_rd, log10p1_ru, x);
+            check!(log2_rd, log2_ru, x);
+            check!(log2p1_rd, log2p1_ru, x);
+            check!(logp1_rd, logp1_ru, x);
+        }
+    }
+
+    #[test]
+    fn trig() {
+        for x in samples() {
+            check!(cos_rd, cos_ru, x);
+            check!(cos_pi_rd, cos_pi_ru, x);
+            check!(cot_rd, cot_ru, x);
+            check!(csc_rd, csc_ru, x);
+            check!(sec_rd, sec_ru, x);
+            check!(sin_rd, sin_ru, x);
+            check!(sin_pi_rd, sin_pi_ru, x);
+            check!(tan_rd, tan_ru, x);
+            check!(tan_pi_rd, tan_pi_ru, x);
+        }
+    }
+
+    #[test]
+    fn inverse_trig() {
+        for x in samples() {
+            check!(acos_rd, acos_ru, x);
+            check!(acos_pi_rd, acos_pi_ru, x);
+            check!(acsc_rd, acsc_ru, x);
+            check!(asec_rd, asec_ru, x);
+            check!(asin_rd, asin_ru, x);
+            check!(asin_pi_rd, asin_pi_ru, x);
+            check!(atan_rd, atan_ru, x);
+            check!(atan_pi_rd, atan_pi_ru, x);
+        }
+        let xs = samples().into_iter().step_by(13).collect::<Vec<_>>();
+        for &x in &xs {
+            for &y in &xs {
+                check!(atan2_rd, atan2_ru, y, x);
+                check!(atan2_pi_rd, atan2_pi_ru, y, x);
+            }
+        }
+    }
+
+    #[test]
+    fn hyperbolic() {
+        for x in samples() {
+            check!(acosh_rd, acosh_ru, x);
+            check!(acoth_rd, acoth_ru, x);
+            check!(asinh_rd, asinh_ru, x);
+            check!(atanh_rd, atanh_ru, x);
+            check!(cosh_rd, cosh_ru, x);
+            check!(coth_rd, coth_ru, x);
+            check!(csch_rd, csch_ru, x);
+            check!(sech_rd, sech_ru, x);
+            check!(sinh_rd, sinh_ru, x);
+            check!(tanh_rd, tanh_ru, x);
+        }
+    }
+
+    #[test]
+    fn others() {
+        let xs = samples();
+        let ns = (-12..=12)
+            .chain([100, 1000, 1 << 20, (1 << 20) + 1, i32::MAX - 1, i32::MAX])
+            .flat_map(|n| [n, -n])
+            .chain([i32::MIN])
+            .collect::<Vec<_>>();
+        for &x in &xs {
+            check!(rsqrt_rd, rsqrt_ru, x);
+            for &n in &ns {
+                check!(compound_rd, compound_ru, x, n);
+                check!(pown_rd, pown_ru, x, n);
+                check!(rootn_rd, rootn_ru, x, n);
+            }
+        }
+        let xs = xs
+            .into_iter()
+            .step_by(13)
+            .chain([-0.5, -2.0, -2.5, 1e10, -1e10, 9007199254740994.0])
+            .collect::<Vec<_>>();
+        for &x in &xs {
+            for &y in &xs {
+                check!(hypot_rd, hypot_ru, x, y);
+                check!(pow_rd, pow_ru, x, y);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::interval::*;
+use crate::{interval::*, mp::MpInterval};
 use gmp_mpfr_sys::mpfr;
 use rug::Float;
 use std::{
@@ -33,6 +33,25 @@ fn mpfr_printf(template: &str, f: &Float) -> String {
 }
 
 fn fmt_impl(x: Interval, d: Option<Decoration>, f: &mut fmt::Formatter, conv: char) -> fmt::Result {
+    let bounds = if x.is_empty() {
+        None
+    } else {
+        Some((
+            Float::with_val(f64::MANTISSA_DIGITS, x.inf()),
+            Float::with_val(f64::MANTISSA_DIGITS, x.sup()),
+        ))
+    };
+    fmt_bounds(bounds.as_ref().map(|(a, b)| (a, b)), d, f, conv)
+}
+
+// Formats an interval given by its bounds, which is empty if `bounds` is `None`.
+// The bounds are printed in the given precision with rounding outward.
+fn fmt_bounds(
+    bounds: Option<(&Float, &Float)>,
+    d: Option<Decoration>,
+    f: &mut fmt::Formatter,
+    conv: char,
+) -> fmt::Result {
     let width = f.width().unwrap_or(0);
     let str_width = 2 * width + 1;
     if d == Some(Decoration::Ill) {
@@ -45,20 +64,20 @@ fn fmt_impl(x: Interval, d: Option<Decoration>, f: &mut fmt::Formatter, conv: ch
         Some(Decoration::Trv) => "_trv",
         _ => "",
     };
-    if x.is_empty() {
-        write!(f, "[{:^w$}]{}", "empty", sd, w = str_width)
-    } else if x.is_entire() {
-        write!(f, "[{:^w$}]{}", "entire", sd, w = str_width)
-    } else {
-        let prec = match f.precision() {
-            Some(p) => format!(".{}", p),
-            None => String::new(),
-        };
-        let fa = Float::with_val(f64::MANTISSA_DIGITS, x.inf());
-        let fb = Float::with_val(f64::MANTISSA_DIGITS, x.sup());
-        let sa = mpfr_printf(&format!("%{}RD{}", prec, conv), &fa);
-        let sb = mpfr_printf(&format!("%{}RU{}", prec, conv), &fb);
-        write!(f, "[{:>w$},{:>w$}]{}", sa, sb, sd, w = width)
+    match bounds {
+        None => write!(f, "[{:^w$}]{}", "empty", sd, w = str_width),
+        Some((fa, fb)) if *fa == f64::NEG_INFINITY && *fb == f64::INFINITY => {
+            write!(f, "[{:^w$}]{}", "entire", sd, w = str_width)
+        }
+        Some((fa, fb)) => {
+            let prec = match f.precision() {
+                Some(p) => format!(".{}", p),
+                None => String::new(),
+            };
+            let sa = mpfr_printf(&format!("%{}RD{}", prec, conv), fa);
+            let sb = mpfr_printf(&format!("%{}RU{}", prec, conv), fb);
+            write!(f, "[{:>w$},{:>w$}]{}", sa, sb, sd, w = width)
+        }
     }
 }
 
@@ -98,6 +117,25 @@ impl fmt::LowerHex for DecInterval {
     }
 }
 
+macro_rules! impl_fmt_mp {
+    ($Trait:ident, $conv:expr) => {
+        impl fmt::$Trait for MpInterval {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let bounds = if self.is_empty() {
+                    None
+                } else {
+                    Some((self.inf(), self.sup()))
+                };
+                fmt_bounds(bounds.as_ref().map(|(a, b)| (a, b)), None, f, $conv)
+            }
+        }
+    };
+}
+
+impl_fmt_mp!(Display, 'f');
+impl_fmt_mp!(LowerExp, 'e');
+impl_fmt_mp!(LowerHex, 'a');
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -0,0 +1,114 @@
+// The macros from the `forward_ref` crate, which is not `no_std`, along with `forward_owned_binop`
+// and `forward_owned_op_assign` for the types that are not `Copy`.
+
+macro_rules! forward_ref_unop {
+    (impl $imp:ident, $method:ident for $t:ty) => {
+        impl $imp for &$t {
+            type Output = <$t as $imp>::Output;
+
+            #[inline]
+            fn $method(self) -> <$t as $imp>::Output {
+                $imp::$method(*self)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a> $imp<$u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: $u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl $imp<&$u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: &$u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl $imp<&$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            #[inline]
+            fn $method(self, other: &$u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_op_assign {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl $imp<&$u> for $t {
+            #[inline]
+            fn $method(&mut self, other: &$u) {
+                $imp::$method(self, *other);
+            }
+        }
+    };
+}
+
+// Implements `$imp` for the combinations of owned and borrowed operands in terms of
+// the implementation for two borrowed operands. Unlike `forward_ref_binop`, this does not
+// require the types to be `Copy`.
+macro_rules! forward_owned_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty, $out:ty) => {
+        impl $imp<$u> for $t {
+            type Output = $out;
+
+            fn $method(self, rhs: $u) -> $out {
+                $imp::$method(&self, &rhs)
+            }
+        }
+
+        impl $imp<&$u> for $t {
+            type Output = $out;
+
+            fn $method(self, rhs: &$u) -> $out {
+                $imp::$method(&self, rhs)
+            }
+        }
+
+        impl $imp<$u> for &$t {
+            type Output = $out;
+
+            fn $method(self, rhs: $u) -> $out {
+                $imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+// Implements `$imp` for owned and borrowed right operands in terms of the method `$op`
+// on two borrowed operands.
+#[cfg(feature = "gmp")]
+macro_rules! forward_owned_op_assign {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty, $op:ident) => {
+        impl $imp<$u> for $t {
+            fn $method(&mut self, rhs: $u) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+
+        impl $imp<&$u> for $t {
+            fn $method(&mut self, rhs: &$u) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+    };
+}
+
+pub(crate) use forward_owned_binop;
+#[cfg(feature = "gmp")]
+pub(crate) use forward_owned_op_assign;
+pub(crate) use forward_ref_binop;
+pub(crate) use forward_ref_op_assign;
+pub(crate) use forward_ref_unop;
@@ -230,14 +230,13 @@ impl DecInterval {
 
     // Discontinuities: {x + 0.5 ∣ x ∈ ℤ}.
     impl_dec!(round, x, y, {
-        let abs_a = x.inf_raw().abs();
-        let abs_b = x.sup_raw().abs();
-        (abs_a - abs_a.trunc() == 0.5) || (abs_b - abs_b.trunc() == 0.5)
+        // [|-a - trunc(-a)|; |b - trunc(b)|]
+        let frac = abs(sub_rn(x.rep, trunc(x.rep)));
+        any(eq(frac, splat(0.5)))
     });
     impl_dec!(round_ties_even, x, y, {
-        let abs_a = x.inf_raw().abs();
-        let abs_b = x.sup_raw().abs();
-        (abs_a - abs_a.trunc() == 0.5) || (abs_b - abs_b.trunc() == 0.5)
+        let frac = abs(sub_rn(x.rep, trunc(x.rep)));
+        any(eq(frac, splat(0.5)))
     });
 
     // Discontinuities: {0}.
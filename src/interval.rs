@@ -1,5 +1,5 @@
 use crate::simd::*;
-use std::{
+use core::{
     error::Error,
     fmt,
     hash::{Hash, Hasher},
@@ -252,7 +252,7 @@ impl TryFrom<(f64, f64)> for DecInterval {
 #[macro_export]
 macro_rules! _interval {
     ($a:expr, $b:expr) => {{
-        use ::std::{convert::TryFrom, primitive::*};
+        use ::core::{convert::TryFrom, primitive::*};
         fn is_f64(_: f64) {}
         is_f64($a);
         is_f64($b);
@@ -297,7 +297,7 @@ macro_rules! interval {
 #[macro_export]
 macro_rules! interval {
     ($s:expr) => {{
-        use ::std::primitive::*;
+        use ::core::primitive::*;
         fn is_str(_: &str) {}
         is_str($s);
         $s.parse::<$crate::Interval>()
@@ -347,7 +347,7 @@ macro_rules! interval {
 #[macro_export]
 macro_rules! interval_exact {
     ($s:expr) => {{
-        use ::std::primitive::*;
+        use ::core::primitive::*;
         fn is_str(_: &str) {}
         is_str($s);
         $crate::Interval::_try_from_str_exact($s)
@@ -358,7 +358,7 @@ macro_rules! interval_exact {
 #[macro_export]
 macro_rules! _dec_interval {
     ($a:expr, $b:expr) => {{
-        use ::std::{convert::TryFrom, primitive::*};
+        use ::core::{convert::TryFrom, primitive::*};
         fn is_f64(_: f64) {}
         is_f64($a);
         is_f64($b);
@@ -404,7 +404,7 @@ macro_rules! dec_interval {
 #[macro_export]
 macro_rules! dec_interval {
     ($s:expr) => {{
-        use ::std::primitive::*;
+        use ::core::primitive::*;
         fn is_str(_: &str) {}
         is_str($s);
         $s.parse::<$crate::DecInterval>()
@@ -427,7 +427,7 @@ macro_rules! dec_interval {
 #[macro_export]
 macro_rules! const_interval {
     ($a:expr, $b:expr) => {{
-        use ::std::{mem::transmute, primitive::*};
+        use ::core::{mem::transmute, primitive::*};
 
         const _: () = assert!($a <= $b && $a != f64::INFINITY && $b != f64::NEG_INFINITY);
 
@@ -451,7 +451,7 @@ macro_rules! const_interval {
 #[macro_export]
 macro_rules! const_dec_interval {
     ($a:expr, $b:expr) => {{
-        use ::std::{mem::transmute, primitive::*};
+        use ::core::{mem::transmute, primitive::*};
 
         #[repr(C)]
         struct _DecInterval {
@@ -0,0 +1,767 @@
+use crate::{
+    classify::*,
+    forward_ref::*,
+    interval::*,
+    simd::{f32x2::*, sub1_ru},
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem::transmute,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// An interval with [`f32`] bounds.
+///
+/// An [`Interval32`] occupies 8 bytes, half the size of an [`Interval`], and is intended
+/// for storing a large number of intervals for which the precision of [`f32`] is sufficient.
+/// The bounds are rounded outward in all operations, just as in [`Interval`].
+///
+/// The conversion from [`Interval32`] to [`Interval`] is exact, and the one in the opposite
+/// direction rounds the bounds outward.
+///
+/// ```
+/// use inari::*;
+/// let x = Interval32::from(const_interval!(0.1, 0.2));
+/// assert!(const_interval!(0.1, 0.2).subset(Interval::from(x)));
+/// assert_eq!(x.inf(), 0.099999994);
+/// assert_eq!(x.sup(), 0.2);
+/// ```
+///
+/// The [`Debug`][std::fmt::Debug] output looks like `Interval32(a, b)`,
+/// in the same manner as [`Interval`].
+#[derive(Clone, Copy)]
+#[repr(C, align(8))]
+pub struct Interval32 {
+    // Stored in the neginf-sup-nan form, in the same manner as `Interval`.
+    rep: F32X2,
+}
+
+unsafe impl Send for Interval32 {}
+unsafe impl Sync for Interval32 {}
+impl Unpin for Interval32 {}
+
+impl fmt::Debug for Interval32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Interval32")
+            .field(&-extract0(self.rep))
+            .field(&extract1(self.rep))
+            .finish()
+    }
+}
+
+impl Interval32 {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = unsafe { transmute([f32::NAN, f32::NAN]) };
+
+    /// $\[-∞, +∞\]$.
+    pub const ENTIRE: Self = unsafe { transmute([f32::INFINITY, f32::INFINITY]) };
+
+    fn inf_raw(self) -> f32 {
+        -extract0(self.rep)
+    }
+
+    fn sup_raw(self) -> f32 {
+        extract1(self.rep)
+    }
+
+    fn with_infsup_raw(a: f32, b: f32) -> Self {
+        Self {
+            rep: constant(-a, b),
+        }
+    }
+
+    fn zero() -> Self {
+        Self { rep: splat(0.0) }
+    }
+
+    fn classify(self) -> IntervalClass {
+        let zero = splat(0.0);
+        let ge_zero = bitmask(ge(self.rep, zero)) as u8;
+        let le_zero = bitmask(le(self.rep, zero)) as u8;
+        unsafe { transmute((le_zero << 2) | ge_zero) }
+    }
+
+    fn classify2(self, rhs: Self) -> IntervalClass2 {
+        unsafe { transmute(((self.classify() as u8) << 4) | rhs.classify() as u8) }
+    }
+
+    fn either_empty(self, rhs: Self) -> bool {
+        self.is_empty() | rhs.is_empty()
+    }
+}
+
+// Rounds `x` to an `f32` number toward +∞.
+fn f32_ru(x: f64) -> f32 {
+    let y = x as f32;
+    if (y as f64) < x {
+        // `y` is either finite or -∞.
+        if y == 0.0 {
+            f32::from_bits(1)
+        } else if y > 0.0 {
+            f32::from_bits(y.to_bits() + 1)
+        } else {
+            f32::from_bits(y.to_bits() - 1)
+        }
+    } else {
+        y
+    }
+}
+
+impl PartialEq for Interval32 {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.is_empty() & rhs.is_empty() | all(eq(self.rep, rhs.rep))
+    }
+}
+
+impl Eq for Interval32 {}
+
+impl Hash for Interval32 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inf().to_bits().hash(state);
+        self.sup().to_bits().hash(state);
+    }
+}
+
+impl TryFrom<(f32, f32)> for Interval32 {
+    type Error = IntervalError;
+
+    fn try_from((a, b): (f32, f32)) -> Result<Self> {
+        if a <= b && a != f32::INFINITY && b != f32::NEG_INFINITY {
+            Ok(Self::with_infsup_raw(a, b))
+        } else {
+            Err(Self::Error {
+                kind: IntervalErrorKind::UndefinedOperation,
+            })
+        }
+    }
+}
+
+impl From<Interval32> for Interval {
+    fn from(x: Interval32) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self::with_infsup_raw(x.inf_raw() as f64, x.sup_raw() as f64)
+    }
+}
+
+impl From<Interval> for Interval32 {
+    fn from(x: Interval) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        // [a, b] ↦ [-(-a rounded up); b rounded up]
+        Self {
+            rep: constant(f32_ru(-x.inf_raw()), f32_ru(x.sup_raw())),
+        }
+    }
+}
+
+impl Interval32 {
+    /// The [`f32`] version of [`Interval::inf`].
+    pub fn inf(self) -> f32 {
+        let x = self.inf_raw();
+        if x.is_nan() {
+            f32::INFINITY
+        } else if x == 0.0 {
+            -0.0
+        } else {
+            x
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::mag`].
+    pub fn mag(self) -> f32 {
+        let abs = abs(self.rep);
+        extract0(max(abs, swap(abs)))
+    }
+
+    /// The [`f32`] version of [`Interval::mid`].
+    ///
+    /// [`f32::MIN`] and [`f32::MAX`] are returned in place of [`f64::MIN`] and [`f64::MAX`],
+    /// and the result is rounded to the nearest [`f32`] number, ties to even.
+    pub fn mid(self) -> f32 {
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+
+        match (a == f32::NEG_INFINITY, b == f32::INFINITY) {
+            (false, false) => {
+                // Rounding the sum to `f64` does not affect the final result.
+                let mid = (0.5 * (a as f64 + b as f64)) as f32;
+                if mid == 0.0 {
+                    0.0
+                } else {
+                    mid
+                }
+            }
+            (false, true) => f32::MAX,
+            (true, false) => f32::MIN,
+            (true, true) => 0.0,
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::mig`].
+    pub fn mig(self) -> f32 {
+        let zero = splat(0.0);
+        let contains_zero = all(ge(self.rep, zero));
+        if contains_zero {
+            return 0.0;
+        }
+
+        let abs = abs(self.rep);
+        extract0(min(abs, swap(abs)))
+    }
+
+    /// The [`f32`] version of [`Interval::rad`].
+    pub fn rad(self) -> f32 {
+        let m = self.mid() as f64;
+        let a = self.inf_raw() as f64;
+        let b = self.sup_raw() as f64;
+        f32_ru(f64::max(sub1_ru(m, a), sub1_ru(b, m)))
+    }
+
+    /// The [`f32`] version of [`Interval::sup`].
+    pub fn sup(self) -> f32 {
+        let x = self.sup_raw();
+        if x.is_nan() {
+            f32::NEG_INFINITY
+        } else if x == 0.0 {
+            0.0
+        } else {
+            x
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::wid`].
+    pub fn wid(self) -> f32 {
+        let wid = f32_ru(sub1_ru(self.sup_raw() as f64, self.inf_raw() as f64));
+        if wid == 0.0 {
+            0.0
+        } else {
+            wid
+        }
+    }
+}
+
+impl Interval32 {
+    /// The [`f32`] version of [`Interval::contains`].
+    pub fn contains(self, rhs: f32) -> bool {
+        rhs.is_finite() & all(le(neg0(splat(rhs)), self.rep))
+    }
+
+    /// The [`f32`] version of [`Interval::disjoint`].
+    pub fn disjoint(self, rhs: Self) -> bool {
+        self.either_empty(rhs)
+            | any(lt(
+                shuffle13(self.rep, rhs.rep),
+                neg(shuffle02(rhs.rep, self.rep)),
+            ))
+    }
+
+    /// The [`f32`] version of [`Interval::interior`].
+    pub fn interior(self, rhs: Self) -> bool {
+        let l = self.is_empty()
+            || self.sup_raw() < rhs.sup_raw()
+            || all(eq(shuffle13(self.rep, rhs.rep), splat(f32::INFINITY)));
+        let r = self.is_empty()
+            || rhs.inf_raw() < self.inf_raw()
+            || all(eq(shuffle02(self.rep, rhs.rep), splat(f32::INFINITY)));
+        l && r
+    }
+
+    /// The [`f32`] version of [`Interval::is_common_interval`].
+    pub fn is_common_interval(self) -> bool {
+        all(lt(self.rep, splat(f32::INFINITY)))
+    }
+
+    /// The [`f32`] version of [`Interval::is_empty`].
+    pub fn is_empty(self) -> bool {
+        extract0(self.rep).is_nan()
+    }
+
+    /// The [`f32`] version of [`Interval::is_entire`].
+    pub fn is_entire(self) -> bool {
+        all(eq(self.rep, splat(f32::INFINITY)))
+    }
+
+    /// The [`f32`] version of [`Interval::is_singleton`].
+    pub fn is_singleton(self) -> bool {
+        self.inf_raw() == self.sup_raw()
+    }
+
+    /// The [`f32`] version of [`Interval::subset`].
+    pub fn subset(self, rhs: Self) -> bool {
+        self.is_empty() | all(le(self.rep, rhs.rep))
+    }
+}
+
+impl Interval32 {
+    /// The [`f32`] version of [`Interval::convex_hull`].
+    #[must_use]
+    pub fn convex_hull(self, rhs: Self) -> Self {
+        if self.is_empty() {
+            return rhs;
+        }
+        if rhs.is_empty() {
+            return self;
+        }
+
+        Self {
+            rep: max(self.rep, rhs.rep),
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::intersection`].
+    #[must_use]
+    pub fn intersection(self, rhs: Self) -> Self {
+        if self.either_empty(rhs) {
+            return Self::EMPTY;
+        }
+
+        let i = Self {
+            rep: min(self.rep, rhs.rep),
+        };
+
+        if i.inf_raw() > i.sup_raw() {
+            Self::EMPTY
+        } else {
+            i
+        }
+    }
+}
+
+impl Interval32 {
+    /// The [`f32`] version of [`Interval::abs`].
+    #[must_use]
+    pub fn abs(self) -> Self {
+        use IntervalClass::*;
+        match self.classify() {
+            E | P0 | P1 | Z => self,
+            M => {
+                let x = self.rep; // [-a; b]
+                let r = max(x, swap(x)); // [max(-a, b); _]
+                Self {
+                    rep: shuffle02(splat(0.0), r),
+                }
+            }
+            N0 | N1 => Self {
+                rep: swap(self.rep),
+            },
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::max`].
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        if self.either_empty(rhs) {
+            return Self::EMPTY;
+        }
+
+        Self {
+            rep: shuffle03(min(self.rep, rhs.rep), max(self.rep, rhs.rep)),
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::min`].
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        if self.either_empty(rhs) {
+            return Self::EMPTY;
+        }
+
+        Self {
+            rep: shuffle03(max(self.rep, rhs.rep), min(self.rep, rhs.rep)),
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::sqr`].
+    #[must_use]
+    pub fn sqr(self) -> Self {
+        use IntervalClass::*;
+        match self.classify() {
+            E => Self::EMPTY,
+            Z => Self::zero(),
+            M => {
+                let x = self.rep; // [-a; b]
+                let r = mul_ru(x, x); // [a^2; b^2]
+                let r = max(r, swap(r)); // [max(a^2, b^2); _]
+                Self {
+                    rep: shuffle02(splat(0.0), r),
+                }
+            }
+            N0 | N1 => {
+                let x = swap(self.rep); // [b; -a]
+                let y = neg0(x); // [-b; -a]
+                Self { rep: mul_ru(x, y) }
+            }
+            P0 | P1 => {
+                let x = self.rep; // [-a; b]
+                let y = neg0(x); // [a; b]
+                Self { rep: mul_ru(x, y) }
+            }
+        }
+    }
+
+    /// The [`f32`] version of [`Interval::sqrt`].
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        if self.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+
+        if b < 0.0 {
+            Self::EMPTY
+        } else if a <= 0.0 {
+            Self::with_infsup_raw(0.0, sqrt1_ru(b))
+        } else {
+            Self::with_infsup_raw(sqrt1_rd(a), sqrt1_ru(b))
+        }
+    }
+}
+
+impl Neg for Interval32 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            rep: swap(self.rep),
+        }
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for Interval32);
+
+impl Add for Interval32 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            rep: add_ru(self.rep, rhs.rep),
+        }
+    }
+}
+
+forward_ref_binop!(impl Add, add for Interval32, Interval32);
+
+impl Sub for Interval32 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            rep: add_ru(self.rep, swap(rhs.rep)),
+        }
+    }
+}
+
+forward_ref_binop!(impl Sub, sub for Interval32, Interval32);
+
+impl Mul for Interval32 {
+    type Output = Self;
+
+    // See `Mul for Interval` for the derivation of each case.
+    fn mul(self, rhs: Self) -> Self {
+        use IntervalClass2::*;
+        match self.classify2(rhs) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | P0_E | P1_E | Z_E => {
+                Self::EMPTY
+            }
+            M_Z | N0_Z | N1_Z | P0_Z | P1_Z | Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 | Z_Z => Self::zero(),
+            M_M => {
+                let x = shuffle02(self.rep, self.rep); // [-a; -a]
+                let y = swap(rhs.rep); // [d; -c]
+                let z = shuffle13(self.rep, self.rep); // [b; b]
+                let w = rhs.rep; // [-c; d]
+                Self {
+                    rep: max(mul_ru(x, y), mul_ru(z, w)),
+                }
+            }
+            M_N0 | M_N1 => {
+                let x = swap(self.rep); // [b; -a]
+                let y = shuffle02(rhs.rep, rhs.rep); // [-c; -c]
+                Self { rep: mul_ru(x, y) }
+            }
+            M_P0 | M_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = shuffle13(rhs.rep, rhs.rep); // [d; d]
+                Self { rep: mul_ru(x, y) }
+            }
+            N0_M | N1_M => {
+                let x = shuffle02(self.rep, self.rep); // [-a; -a]
+                let y = swap(rhs.rep); // [d; -c]
+                Self { rep: mul_ru(x, y) }
+            }
+            N0_N0 | N0_N1 | N1_N0 | N1_N1 => {
+                let x = neg0(swap(self.rep)); // [-b; -a]
+                let y = swap(rhs.rep); // [d; -c]
+                Self { rep: mul_ru(x, y) }
+            }
+            N0_P0 | N0_P1 | N1_P0 | N1_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = swap(neg0(rhs.rep)); // [d; c]
+                Self { rep: mul_ru(x, y) }
+            }
+            P0_M | P1_M => {
+                let x = shuffle13(self.rep, self.rep); // [b; b]
+                let y = rhs.rep; // [-c; d]
+                Self { rep: mul_ru(x, y) }
+            }
+            P0_N0 | P0_N1 | P1_N0 | P1_N1 => {
+                let x = swap(neg0(self.rep)); // [b; a]
+                let y = rhs.rep; // [-c; d]
+                Self { rep: mul_ru(x, y) }
+            }
+            P0_P0 | P0_P1 | P1_P0 | P1_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = neg0(rhs.rep); // [c; d]
+                Self { rep: mul_ru(x, y) }
+            }
+        }
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Interval32, Interval32);
+
+impl Div for Interval32 {
+    type Output = Self;
+
+    // See `Div for Interval` and the division functions in basic.rs
+    // for the derivation of each case.
+    fn div(self, rhs: Self) -> Self {
+        use IntervalClass2::*;
+        let inf = splat(f32::INFINITY);
+        match self.classify2(rhs) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | M_Z | N0_E | N0_Z | N1_E | N1_Z
+            | P0_E | P0_Z | P1_E | P1_Z | Z_E | Z_Z => Self::EMPTY,
+            M_M | M_N0 | M_P0 | N0_M | N1_M | P0_M | P1_M => Self::ENTIRE,
+            Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 => Self::zero(),
+            M_N1 => {
+                let x = swap(self.rep); // [b; -a]
+                let y = neg0(swap(rhs.rep)); // [-d; -c]
+                let y = shuffle02(y, y); // [-d; -d]
+                Self { rep: div_ru(x, y) }
+            }
+            M_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = neg0(rhs.rep); // [c; d]
+                let y = shuffle02(y, y); // [c; c]
+                Self { rep: div_ru(x, y) }
+            }
+            N0_N0 | N1_N0 => {
+                let x = swap(self.rep); // [b; -a]
+                let y = rhs.rep; // [-c; d]
+                Self {
+                    rep: shuffle02(div_ru(x, y), inf),
+                }
+            }
+            N0_N1 | N1_N1 => {
+                let x = swap(neg0(self.rep)); // [b; a]
+                let y = rhs.rep; // [-c; d]
+                Self { rep: div_ru(x, y) }
+            }
+            N0_P0 | N1_P0 => {
+                let x = self.rep; // [-a; b]
+                let y = rhs.rep; // [-c; d]
+                Self {
+                    rep: shuffle03(inf, div_ru(x, y)),
+                }
+            }
+            N0_P1 | N1_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = neg0(rhs.rep); // [c; d]
+                Self { rep: div_ru(x, y) }
+            }
+            P0_N0 | P1_N0 => {
+                let x = self.rep; // [-a; b]
+                let y = rhs.rep; // [-c; d]
+                Self {
+                    rep: shuffle02(inf, div_ru(x, y)),
+                }
+            }
+            P0_N1 | P1_N1 => {
+                let x = neg0(swap(self.rep)); // [-b; -a]
+                let y = swap(rhs.rep); // [d; -c]
+                Self { rep: div_ru(x, y) }
+            }
+            P0_P0 | P1_P0 => {
+                let x = self.rep; // [-a; b]
+                let y = swap(rhs.rep); // [d; -c]
+                Self {
+                    rep: shuffle02(div_ru(x, y), inf),
+                }
+            }
+            P0_P1 | P1_P1 => {
+                let x = self.rep; // [-a; b]
+                let y = swap(neg0(rhs.rep)); // [d; c]
+                Self { rep: div_ru(x, y) }
+            }
+        }
+    }
+}
+
+forward_ref_binop!(impl Div, div for Interval32, Interval32);
+
+macro_rules! impl_op_assign {
+    ($OpAssign:ident, $op_assign:ident, $op:ident) => {
+        impl $OpAssign for Interval32 {
+            fn $op_assign(&mut self, rhs: Self) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        forward_ref_op_assign!(impl $OpAssign, $op_assign for Interval32, Interval32);
+    };
+}
+
+impl_op_assign!(AddAssign, add_assign, add);
+impl_op_assign!(SubAssign, sub_assign, sub);
+impl_op_assign!(MulAssign, mul_assign, mul);
+impl_op_assign!(DivAssign, div_assign, div);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    type I = Interval;
+    type I32 = Interval32;
+
+    fn i32(a: f32, b: f32) -> I32 {
+        I32::try_from((a, b)).unwrap()
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(std::mem::size_of::<I32>(), 8);
+        assert_eq!(std::mem::align_of::<I32>(), 8);
+    }
+
+    #[test]
+    fn conversion() {
+        assert!(I32::try_from((2.0, 1.0)).is_err());
+        assert!(I32::try_from((f32::INFINITY, f32::INFINITY)).is_err());
+
+        assert!(I32::from(I::EMPTY).is_empty());
+        assert!(I32::from(I::ENTIRE).is_entire());
+        assert!(I::from(I32::EMPTY).is_empty());
+        assert!(I::from(I32::ENTIRE).is_entire());
+
+        let x = i32(-1.5, 0.25);
+        assert_eq!(I::from(x), const_interval!(-1.5, 0.25));
+        assert_eq!(I32::from(I::from(x)), x);
+
+        let x = I32::from(I::PI);
+        assert_eq!(x, i32(3.1415925, std::f32::consts::PI));
+        assert!(I::PI.subset(I::from(x)));
+
+        let x = I32::from(-I::PI);
+        assert_eq!(x, i32(-std::f32::consts::PI, -3.1415925));
+
+        let x = I32::from(const_interval!(1e-300, 1e300));
+        assert_eq!(x, i32(0.0, f32::INFINITY));
+        let x = I32::from(const_interval!(-1e300, -1e-300));
+        assert_eq!(x, i32(f32::NEG_INFINITY, -0.0));
+        let x = I32::from(const_interval!(1e300, 1e300));
+        assert_eq!(x, i32(f32::MAX, f32::INFINITY));
+        let x = I32::from(const_interval!(1e-300, 1e-300));
+        assert_eq!(x, i32(0.0, f32::from_bits(1)));
+    }
+
+    #[test]
+    fn arith() {
+        let x = I32::from(const_interval!(0.1, 0.1));
+        let y = I32::from(const_interval!(0.2, 0.3));
+        let xs = I::from(x);
+        let ys = I::from(y);
+        for (z, zs) in [
+            (x + y, xs + ys),
+            (x - y, xs - ys),
+            (x * y, xs * ys),
+            (x / y, xs / ys),
+            (-x * y, -xs * ys),
+            (y / -x, ys / -xs),
+        ] {
+            assert!(zs.subset(I::from(z)));
+            assert_eq!(z, I32::from(zs));
+        }
+
+        let mut z = x;
+        z += y;
+        z -= y;
+        assert!(x.subset(z));
+        assert!(x.interior(z));
+
+        let m = i32(-2.0, 3.0);
+        let n = i32(-3.0, -2.0);
+        let p = i32(2.0, 4.0);
+        assert_eq!(m * m, i32(-6.0, 9.0));
+        assert_eq!(m * n, i32(-9.0, 6.0));
+        assert_eq!(n * p, i32(-12.0, -4.0));
+        assert_eq!(n * n, i32(4.0, 9.0));
+        assert_eq!(m / p, i32(-1.0, 1.5));
+        assert_eq!(p / n, I32::from(I::from(p) / I::from(n)));
+        assert!((m / m).is_entire());
+        assert!((p / I32::zero()).is_empty());
+        assert_eq!(p / i32(0.0, 2.0), i32(1.0, f32::INFINITY));
+        assert_eq!(p / i32(-2.0, 0.0), i32(f32::NEG_INFINITY, -1.0));
+        assert!((I32::EMPTY + p).is_empty());
+        assert!((p * I32::EMPTY).is_empty());
+
+        assert_eq!(m.abs(), i32(0.0, 3.0));
+        assert_eq!(n.abs(), i32(2.0, 3.0));
+        assert_eq!(m.sqr(), i32(0.0, 9.0));
+        assert_eq!(n.sqr(), i32(4.0, 9.0));
+        assert_eq!(m.max(n), i32(-2.0, 3.0));
+        assert_eq!(m.min(n), i32(-3.0, -2.0));
+        assert_eq!(p.sqrt(), I32::from(const_interval!(2.0, 4.0).sqrt()));
+        assert_eq!(m.sqrt(), I32::from(const_interval!(-2.0, 3.0).sqrt()));
+        assert!(n.sqrt().is_empty());
+    }
+
+    #[test]
+    fn numeric() {
+        let x = i32(-2.0, 3.0);
+        assert_eq!(x.inf(), -2.0);
+        assert_eq!(x.sup(), 3.0);
+        assert_eq!(x.mid(), 0.5);
+        assert_eq!(x.rad(), 2.5);
+        assert_eq!(x.wid(), 5.0);
+        assert_eq!(x.mag(), 3.0);
+        assert_eq!(x.mig(), 0.0);
+        assert_eq!(i32(2.0, 3.0).mig(), 2.0);
+        assert_eq!(i32(f32::NEG_INFINITY, 3.0).mid(), f32::MIN);
+        assert_eq!(i32(-2.0, f32::INFINITY).mid(), f32::MAX);
+        assert_eq!(i32(f32::MIN, f32::MAX).mid(), 0.0);
+        assert_eq!(i32(f32::MIN, f32::MAX).wid(), f32::INFINITY);
+        assert_eq!(I32::ENTIRE.mid(), 0.0);
+        assert_eq!(I32::ENTIRE.rad(), f32::INFINITY);
+        assert_eq!(I32::EMPTY.inf(), f32::INFINITY);
+        assert_eq!(I32::EMPTY.sup(), f32::NEG_INFINITY);
+        assert!(I32::EMPTY.mid().is_nan());
+        assert!(I32::EMPTY.wid().is_nan());
+    }
+
+    #[test]
+    fn boolean_set_ops() {
+        let x = i32(1.0, 2.0);
+        let y = i32(3.0, 4.0);
+        assert!(x.contains(1.0));
+        assert!(!x.contains(f32::NAN));
+        assert!(x.disjoint(y));
+        assert!(!x.disjoint(x));
+        assert!(x.subset(x));
+        assert!(!x.interior(x));
+        assert!(x.is_common_interval());
+        assert!(!I32::ENTIRE.is_common_interval());
+        assert!(i32(1.0, 1.0).is_singleton());
+        assert!(x.intersection(y).is_empty());
+        assert_eq!(x.convex_hull(y), i32(1.0, 4.0));
+        assert_eq!(x.convex_hull(I32::EMPTY), x);
+        assert_eq!(I32::EMPTY, I32::EMPTY);
+        assert_ne!(x, I32::EMPTY);
+        assert_eq!(format!("{:?}", x), "Interval32(1.0, 2.0)");
+    }
+}
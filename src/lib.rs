@@ -13,40 +13,72 @@
 //!
 //! See [Conformance to the standard][`_docs::conformance`] for details.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(clippy::float_cmp, clippy::needless_doctest_main)]
 
+extern crate alloc;
+
 pub use self::{
+    affine::AffineForm,
+    ball::Ball,
+    complex::{ComplexBall, ComplexInterval},
+    dd::DdInterval,
+    dual::{DecDualInterval, DualInterval},
     interval::{DecInterval, Decoration, Interval, IntervalError, IntervalErrorKind, Result},
+    interval32::Interval32,
+    matrix::IntervalMatrix,
     overlap::Overlap,
+    taylor::TaylorModel,
+    union::IntervalUnion,
+    vector::{IntervalBox, IntervalVector},
 };
 
+#[cfg(feature = "gmp")]
+pub use self::mp::MpInterval;
+
 pub mod _docs;
+pub mod linsolve;
+pub mod ode;
+pub mod optimize;
+pub mod reduction;
+pub mod roots;
 
 // List the constructors first in the documentation.
 mod interval;
 
 mod absmax;
+mod affine;
 mod arith;
+mod ball;
 mod basic;
 mod boolean;
 mod bytes;
 mod classify;
+mod complex;
 mod constants;
-#[cfg(feature = "gmp")]
+mod dd;
+mod dual;
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
 mod elementary;
 #[cfg(feature = "gmp")]
 mod format;
+mod forward_ref;
 mod integer;
+mod interval32;
+mod matrix;
+#[cfg(feature = "gmp")]
+mod mp;
 mod numeric;
 mod overlap;
 #[cfg(feature = "gmp")]
 mod parse;
+mod reverse;
 mod set_op;
-
-cfg_if::cfg_if! {
-    if #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", docsrs))] {
-        mod simd;
-    } else {
-        compile_error!("Only x86-64 and AArch64 (experimental) architectures are supported by this crate.");
-    }
-}
+mod simd;
+#[cfg(feature = "gmp")]
+mod slope;
+#[cfg(feature = "gmp")]
+mod special;
+mod taylor;
+mod union;
+mod vector;
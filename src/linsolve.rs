@@ -0,0 +1,576 @@
+//! Verified solvers for systems of linear equations $A x = b$ with interval coefficients.
+//!
+//! Each solver returns a [`LinearSolution`] that consists of an enclosure of the solution set
+//!
+//! $$
+//! Σ(A, b) = \set{x ∈ \R^n ∣ ∃ \tilde A ∈ A, ∃ \tilde b ∈ b : \tilde A x = \tilde b}
+//! $$
+//!
+//! and a flag that tells whether every matrix $\tilde A ∈ A$ has been proven to be nonsingular.
+//! If the regularity cannot be proven, the enclosure is the entire space
+//! (except for [`gauss_seidel`], which always encloses the solutions within the initial box).
+//!
+//! The solvers first multiply both sides of the system by an approximate inverse $R$
+//! of the midpoint matrix of $A$, which is known as preconditioning.
+//!
+//! # Examples
+//!
+//! ```
+//! use inari::{const_interval as c, linsolve, IntervalMatrix as M, IntervalVector as V};
+//! let a = M::from_points(2, 2, &[2.0, 1.0, 1.0, 3.0]);
+//! let b = V::from_points(&[4.0, 7.0]);
+//! let s = linsolve::krawczyk(&a, &b);
+//! assert!(s.is_regular());
+//! let x = s.enclosure();
+//! assert!(x[0].contains(1.0) && x[1].contains(2.0));
+//! assert!(x[0].wid() < 1e-15 && x[1].wid() < 1e-15);
+//! ```
+use crate::{interval::*, vector::point, IntervalMatrix, IntervalVector};
+use alloc::{vec, vec::Vec};
+
+// The maximum number of iterations performed by the iterative methods.
+const MAX_ITER: usize = 20;
+
+/// The result of a verified linear solver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinearSolution {
+    enclosure: IntervalVector,
+    regular: bool,
+}
+
+impl LinearSolution {
+    /// Returns the enclosure of the solution set.
+    pub fn enclosure(&self) -> &IntervalVector {
+        &self.enclosure
+    }
+
+    /// Converts `self` into the enclosure of the solution set.
+    pub fn into_enclosure(self) -> IntervalVector {
+        self.enclosure
+    }
+
+    /// Returns `true` if every matrix in the coefficient matrix has been proven to be
+    /// nonsingular.
+    ///
+    /// If it is `true`, each of the systems in the interval system has a unique solution,
+    /// and all of them are contained in the enclosure.
+    pub fn is_regular(&self) -> bool {
+        self.regular
+    }
+
+    fn empty(n: usize) -> Self {
+        Self {
+            enclosure: vec![Interval::EMPTY; n].into(),
+            regular: false,
+        }
+    }
+
+    fn unverified(n: usize) -> Self {
+        Self {
+            enclosure: vec![Interval::ENTIRE; n].into(),
+            regular: false,
+        }
+    }
+}
+
+/// Solves the system by interval Gaussian elimination with partial pivoting,
+/// applied to the preconditioned system.
+///
+/// The regularity is proven if none of the pivots contains zero.
+///
+/// # Panics
+///
+/// Panics if `a` is not square or the length of `b` does not match the size of `a`.
+pub fn gauss_elimination(a: &IntervalMatrix, b: &IntervalVector) -> LinearSolution {
+    let n = check_system(a, b);
+    if has_empty(a.as_slice()) || has_empty(b.as_slice()) {
+        return LinearSolution::empty(n);
+    }
+
+    let Some((ra, rb)) = precondition(a, b) else {
+        return LinearSolution::unverified(n);
+    };
+    match solve_gauss(ra.as_slice().to_vec(), rb.into_vec(), n, 1) {
+        Some(x) => LinearSolution {
+            enclosure: x.into(),
+            regular: true,
+        },
+        _ => LinearSolution::unverified(n),
+    }
+}
+
+/// Encloses the solutions within the box `x` by the preconditioned interval Gauss-Seidel
+/// iteration.
+///
+/// The result always encloses $Σ(A, b) ∩ 𝚡$. If the Gauss-Seidel operator maps a bounded box
+/// into its interior, the regularity is proven, and the result encloses the entire $Σ(A, b)$.
+/// If the result contains an empty element, it is proven that there are no solutions in `x`.
+///
+/// If the midpoint matrix of `a` is singular, the iteration is applied to the system
+/// without preconditioning.
+///
+/// # Panics
+///
+/// Panics if `a` is not square or the lengths of `b` and `x` do not match the size of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, linsolve, IntervalMatrix as M, IntervalVector as V};
+/// let a = M::new(2, 2, vec![c!(3.0, 4.0), c!(-1.0, 1.0), c!(-1.0, 1.0), c!(3.0, 4.0)]);
+/// let b = V::new(vec![c!(1.0, 2.0), c!(-2.0, 2.0)]);
+/// let x = V::new(vec![c!(-10.0, 10.0), c!(-10.0, 10.0)]);
+/// let s = linsolve::gauss_seidel(&a, &b, &x);
+/// assert!(s.is_regular());
+/// assert!(s.enclosure().subset(&V::new(vec![c!(-1.0, 2.0), c!(-2.0, 2.0)])));
+/// ```
+pub fn gauss_seidel(a: &IntervalMatrix, b: &IntervalVector, x: &IntervalVector) -> LinearSolution {
+    let n = check_system(a, b);
+    assert_eq!(x.len(), n, "the dimensions of the operands must match");
+    if has_empty(a.as_slice()) || has_empty(b.as_slice()) || x.is_empty_set() {
+        return LinearSolution::empty(n);
+    }
+
+    let (ra, rb) = precondition(a, b).unwrap_or_else(|| (a.clone(), b.clone()));
+    let mut x = x.clone();
+    let mut regular = false;
+    for _ in 0..MAX_ITER {
+        let x_old = x.clone();
+        let mut inner = true;
+        for i in 0..n {
+            let s = (0..n)
+                .filter(|&j| j != i)
+                .fold(rb[i], |s, j| (-ra[(i, j)]).mul_add(x[j], s));
+            let d = ra[(i, i)];
+            let y = if d.contains(0.0) {
+                Interval::ENTIRE
+            } else {
+                s / d
+            };
+            inner &= x[i].is_common_interval() && y.interior(x[i]);
+            x[i] = y.intersection(x[i]);
+        }
+        regular |= inner;
+        if x.is_empty_set() {
+            return LinearSolution::empty(n);
+        }
+        if x == x_old {
+            break;
+        }
+    }
+    LinearSolution {
+        enclosure: x,
+        regular,
+    }
+}
+
+/// Solves the system by the method of Hansen, Bliek, Rohn, Ning, Kearfott and Neumaier,
+/// applied to the preconditioned system.
+///
+/// The regularity is proven if the preconditioned matrix is verified to be an H-matrix.
+/// The enclosure is the interval hull of the solution set of the preconditioned system,
+/// up to rounding errors, if the midpoint of the preconditioned matrix is diagonal.
+///
+/// # Panics
+///
+/// Panics if `a` is not square or the length of `b` does not match the size of `a`.
+pub fn hansen_bliek(a: &IntervalMatrix, b: &IntervalVector) -> LinearSolution {
+    let n = check_system(a, b);
+    if has_empty(a.as_slice()) || has_empty(b.as_slice()) {
+        return LinearSolution::empty(n);
+    }
+
+    let Some((ra, rb)) = precondition(a, b) else {
+        return LinearSolution::unverified(n);
+    };
+    if !(has_finite_bounds(ra.as_slice()) && has_finite_bounds(rb.as_slice())) {
+        return LinearSolution::unverified(n);
+    }
+
+    // The comparison matrix ⟨RA⟩.
+    let mut c = ra.as_slice().iter().map(|x| -x.mag()).collect::<Vec<_>>();
+    for i in 0..n {
+        c[i * n + i] = ra[(i, i)].mig();
+    }
+    if !is_m_matrix(&c, n) {
+        return LinearSolution::unverified(n);
+    }
+
+    // Enclose u = ⟨RA⟩⁻¹ |Rb| and the diagonal of ⟨RA⟩⁻¹.
+    let ci = c.iter().map(|&x| point(x)).collect();
+    let mut rhs = vec![Interval::zero(); n * (n + 1)];
+    for i in 0..n {
+        rhs[i * (n + 1)] = point(rb[i].mag());
+        rhs[i * (n + 1) + i + 1] = point(1.0);
+    }
+    let Some(v) = solve_gauss(ci, rhs, n, n + 1) else {
+        return LinearSolution::unverified(n);
+    };
+
+    let mut x = Vec::with_capacity(n);
+    for i in 0..n {
+        let u = v[i * (n + 1)];
+        let d = v[i * (n + 1) + i + 1];
+        if d.inf() <= 0.0 {
+            return LinearSolution::unverified(n);
+        }
+        let alpha = (point(c[i * n + i]) - d.recip()).sup();
+        let beta = (u / d - point(rb[i].mag())).sup();
+        let num = rb[i] + Interval::with_infsup_raw(-beta, beta);
+        let den = ra[(i, i)] + Interval::with_infsup_raw(-alpha, alpha);
+        if den.contains(0.0) {
+            return LinearSolution::unverified(n);
+        }
+        x.push(num / den);
+    }
+    LinearSolution {
+        enclosure: x.into(),
+        regular: true,
+    }
+}
+
+/// Solves the system by the preconditioned Krawczyk method with $ε$-inflation.
+///
+/// An approximate solution $\tilde x$ is computed first, and then the Krawczyk operator
+/// $R (b - A \tilde x) + (I - R A) y$ is applied to inflated boxes $y$ until it maps one of
+/// them into its interior, which proves the regularity.
+/// The enclosure is refined further by intersecting it with its image under the operator.
+///
+/// # Panics
+///
+/// Panics if `a` is not square or the length of `b` does not match the size of `a`.
+pub fn krawczyk(a: &IntervalMatrix, b: &IntervalVector) -> LinearSolution {
+    let n = check_system(a, b);
+    if has_empty(a.as_slice()) || has_empty(b.as_slice()) {
+        return LinearSolution::empty(n);
+    }
+
+    let am = a.mid();
+    let Some(r) = approx_inverse(&am, n) else {
+        return LinearSolution::unverified(n);
+    };
+    // An approximate solution with one step of iterative refinement.
+    let bm = b.mid();
+    let mut xt = mul_approx(&r, &bm, n);
+    let res = bm
+        .iter()
+        .zip(mul_approx(&am, &xt, n))
+        .map(|(b, ax)| b - ax)
+        .collect::<Vec<_>>();
+    for (x, dx) in xt.iter_mut().zip(mul_approx(&r, &res, n)) {
+        *x += dx;
+    }
+    if !xt.iter().all(|x| x.is_finite()) {
+        return LinearSolution::unverified(n);
+    }
+
+    let r = IntervalMatrix::from_points(n, n, &r);
+    let xt = IntervalVector::from_points(&xt);
+    let z = &r * &(b - &(a * &xt));
+    let c = &IntervalMatrix::identity(n) - &r.mul_mid_rad(a);
+    if !(has_finite_bounds(z.as_slice()) && has_finite_bounds(c.as_slice())) {
+        return LinearSolution::unverified(n);
+    }
+
+    let mut y = z.clone();
+    for _ in 0..MAX_ITER {
+        let y_inflated = y.iter().map(|&y| inflate(y)).collect::<IntervalVector>();
+        y = &z + &(&c * &y_inflated);
+        if y.interior(&y_inflated) {
+            for _ in 0..MAX_ITER {
+                let y_new = (&z + &(&c * &y)).intersection(&y);
+                if y_new == y {
+                    break;
+                }
+                y = y_new;
+            }
+            return LinearSolution {
+                enclosure: &xt + &y,
+                regular: true,
+            };
+        }
+    }
+    LinearSolution::unverified(n)
+}
+
+fn check_system(a: &IntervalMatrix, b: &IntervalVector) -> usize {
+    let n = a.rows();
+    assert!(
+        a.cols() == n && b.len() == n,
+        "the dimensions of the operands must match"
+    );
+    n
+}
+
+fn has_empty(xs: &[Interval]) -> bool {
+    xs.iter().any(|x| x.is_empty())
+}
+
+fn has_finite_bounds(xs: &[Interval]) -> bool {
+    xs.iter().all(|x| x.is_common_interval())
+}
+
+// Returns an ε-inflation of `x`.
+fn inflate(x: Interval) -> Interval {
+    let x = x * Interval::with_infsup_raw(0.9, 1.1);
+    x + Interval::with_infsup_raw(-f64::MIN_POSITIVE, f64::MIN_POSITIVE)
+}
+
+// Multiplies both sides of the system by an approximate inverse of the midpoint matrix.
+fn precondition(
+    a: &IntervalMatrix,
+    b: &IntervalVector,
+) -> Option<(IntervalMatrix, IntervalVector)> {
+    let n = a.rows();
+    let r = IntervalMatrix::from_points(n, n, &approx_inverse(&a.mid(), n)?);
+    Some((r.mul_mid_rad(a), &r * b))
+}
+
+// Returns an approximate inverse of the n × n matrix `a` computed by Gauss-Jordan elimination
+// with partial pivoting, or `None` if the matrix is numerically singular.
+fn approx_inverse(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut a = a.to_vec();
+    let mut r = vec![0.0; n * n];
+    for i in 0..n {
+        r[i * n + i] = 1.0;
+    }
+    for k in 0..n {
+        let p = (k..n).max_by(|&i, &j| a[i * n + k].abs().total_cmp(&a[j * n + k].abs()))?;
+        let pivot = a[p * n + k];
+        if pivot == 0.0 || !pivot.is_finite() {
+            return None;
+        }
+        for j in 0..n {
+            a.swap(p * n + j, k * n + j);
+            r.swap(p * n + j, k * n + j);
+        }
+        for j in 0..n {
+            a[k * n + j] /= pivot;
+            r[k * n + j] /= pivot;
+        }
+        for i in (0..n).filter(|&i| i != k) {
+            let l = a[i * n + k];
+            for j in 0..n {
+                a[i * n + j] -= l * a[k * n + j];
+                r[i * n + j] -= l * r[k * n + j];
+            }
+        }
+    }
+    r.iter().all(|x| x.is_finite()).then_some(r)
+}
+
+// Returns the product of the n × n matrix `a` and the vector `x` computed approximately.
+fn mul_approx(a: &[f64], x: &[f64], n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| (0..n).map(|j| a[i * n + j] * x[j]).sum())
+        .collect()
+}
+
+// Returns `true` if the Z-matrix `c` (a matrix with nonpositive off-diagonal entries) is proven
+// to be an M-matrix, by finding a vector u > 0 such that c u > 0.
+fn is_m_matrix(c: &[f64], n: usize) -> bool {
+    let Some(ci) = approx_inverse(c, n) else {
+        return false;
+    };
+    let u = mul_approx(&ci, &vec![1.0; n], n);
+    u.iter().all(|&u| u > 0.0 && u.is_finite())
+        && (0..n).all(|i| {
+            (0..n)
+                .fold(Interval::zero(), |s, j| {
+                    point(c[i * n + j]).mul_add(point(u[j]), s)
+                })
+                .inf()
+                > 0.0
+        })
+}
+
+// Solves the system with the n × n matrix `a` and the n × p right-hand side `b`, both stored
+// in row-major order, by interval Gaussian elimination with partial pivoting.
+// Returns `None` if any of the pivots contains zero.
+fn solve_gauss(
+    mut a: Vec<Interval>,
+    mut b: Vec<Interval>,
+    n: usize,
+    p: usize,
+) -> Option<Vec<Interval>> {
+    for k in 0..n {
+        let piv = (k..n).max_by(|&i, &j| a[i * n + k].mig().total_cmp(&a[j * n + k].mig()))?;
+        if a[piv * n + k].contains(0.0) {
+            return None;
+        }
+        for j in 0..n {
+            a.swap(piv * n + j, k * n + j);
+        }
+        for j in 0..p {
+            b.swap(piv * p + j, k * p + j);
+        }
+        for i in k + 1..n {
+            let l = -(a[i * n + k] / a[k * n + k]);
+            for j in k + 1..n {
+                a[i * n + j] = l.mul_add(a[k * n + j], a[i * n + j]);
+            }
+            for j in 0..p {
+                b[i * p + j] = l.mul_add(b[k * p + j], b[i * p + j]);
+            }
+        }
+    }
+
+    let mut x = vec![Interval::zero(); n * p];
+    for i in (0..n).rev() {
+        for j in 0..p {
+            let s = (i + 1..n).fold(b[i * p + j], |s, l| {
+                (-a[i * n + l]).mul_add(x[l * p + j], s)
+            });
+            x[i * p + j] = s / a[i * n + i];
+        }
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use IntervalMatrix as M;
+    use IntervalVector as V;
+
+    type Solver = fn(&IntervalMatrix, &IntervalVector) -> LinearSolution;
+
+    fn solvers() -> [Solver; 4] {
+        [gauss_elimination, hansen_bliek, krawczyk, |a, b| {
+            gauss_seidel(a, b, &V::new(vec![const_interval!(-1e3, 1e3); b.len()]))
+        }]
+    }
+
+    // Checks that the enclosure contains the solutions of the vertex systems.
+    fn check_vertices(a: &IntervalMatrix, b: &IntervalVector, x: &IntervalVector) {
+        let n = b.len();
+        let m = a.as_slice().len() + n;
+        for bits in 0..1u32 << m {
+            let bound = |x: Interval, k: usize| if bits >> k & 1 == 0 { x.inf() } else { x.sup() };
+            let av = (0..n * n)
+                .map(|k| bound(a.as_slice()[k], k))
+                .collect::<Vec<_>>();
+            let bv = (0..n).map(|k| bound(b[k], n * n + k)).collect::<Vec<_>>();
+            let s = mul_approx(&approx_inverse(&av, n).unwrap(), &bv, n);
+            for i in 0..n {
+                let tol = 1e-12 * s[i].abs().max(1.0);
+                assert!(x[i].inf() - tol <= s[i] && s[i] <= x[i].sup() + tol);
+            }
+        }
+    }
+
+    #[test]
+    fn interval_system() {
+        let a = M::new(
+            2,
+            2,
+            vec![
+                const_interval!(3.0, 4.0),
+                const_interval!(-1.0, 1.0),
+                const_interval!(0.5, 1.0),
+                const_interval!(5.0, 6.0),
+            ],
+        );
+        let b = V::new(vec![const_interval!(1.0, 2.0), const_interval!(-1.0, 3.0)]);
+        for f in solvers() {
+            let s = f(&a, &b);
+            assert!(s.is_regular());
+            assert!(s.enclosure().iter().all(|x| x.wid() < 2.0));
+            check_vertices(&a, &b, s.enclosure());
+        }
+    }
+
+    #[test]
+    fn point_system() {
+        let n = 6;
+        // The Hilbert matrix scaled to have integer entries.
+        let a = (0..n * n)
+            .map(|k| 27720.0 / (k / n + k % n + 1) as f64)
+            .collect::<Vec<_>>();
+        let a = M::from_points(n, n, &a);
+        let x = V::from_points(&[1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
+        let b = &a * &x;
+        assert!(b.iter().all(|b| b.is_singleton()));
+        for f in solvers() {
+            let s = f(&a, &b);
+            assert!(s.is_regular());
+            assert!(x.subset(s.enclosure()));
+            assert!(s.enclosure().iter().all(|x| x.wid() < 1e-6));
+        }
+    }
+
+    #[test]
+    fn singular() {
+        let a = M::new(
+            2,
+            2,
+            vec![
+                const_interval!(1.0, 1.0),
+                const_interval!(1.0, 1.0),
+                const_interval!(1.0, 1.0),
+                const_interval!(0.5, 1.5),
+            ],
+        );
+        let b = V::from_points(&[1.0, 2.0]);
+        for f in solvers() {
+            let s = f(&a, &b);
+            assert!(!s.is_regular());
+        }
+        let s = krawczyk(&a, &b);
+        assert!(s.enclosure().iter().all(|&x| x == Interval::ENTIRE));
+
+        let a = M::from_points(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+        for f in solvers() {
+            assert!(!f(&a, &b).is_regular());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let a = M::new(1, 1, vec![Interval::EMPTY]);
+        let b = V::from_points(&[1.0]);
+        for f in solvers() {
+            let s = f(&a, &b);
+            assert!(!s.is_regular());
+            assert!(s.enclosure()[0].is_empty());
+        }
+
+        // No solutions in the initial box.
+        let a = M::from_points(1, 1, &[1.0]);
+        let s = gauss_seidel(&a, &b, &V::new(vec![const_interval!(2.0, 3.0)]));
+        assert!(s.enclosure()[0].is_empty());
+
+        let s = krawczyk(&M::zeros(0, 0), &V::zeros(0));
+        assert!(s.is_regular() && s.enclosure().is_empty());
+    }
+
+    #[test]
+    fn gauss_seidel_partial() {
+        // The solution set is unbounded, but the solutions in the box are enclosed.
+        let a = M::new(
+            2,
+            2,
+            vec![
+                const_interval!(1.0, 1.0),
+                const_interval!(0.0, 0.0),
+                const_interval!(0.0, 0.0),
+                const_interval!(-1.0, 1.0),
+            ],
+        );
+        let b = V::new(vec![const_interval!(1.0, 2.0), const_interval!(1.0, 1.0)]);
+        let x = V::new(vec![
+            const_interval!(0.0, 10.0),
+            const_interval!(-10.0, 10.0),
+        ]);
+        let s = gauss_seidel(&a, &b, &x);
+        assert!(!s.is_regular());
+        assert_eq!(s.enclosure()[0], const_interval!(1.0, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_square() {
+        let _ = krawczyk(&M::zeros(2, 3), &V::zeros(2));
+    }
+}
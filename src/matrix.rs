@@ -0,0 +1,507 @@
+use crate::{
+    forward_ref::*,
+    interval::*,
+    simd::*,
+    vector::{check_len, point},
+    IntervalVector,
+};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// A matrix of intervals stored in row-major order.
+///
+/// The operations on [`IntervalMatrix`] return enclosures of the exact results, in the same
+/// manner as the operations on [`Interval`]. The binary operations panic if the dimensions of
+/// the operands do not match.
+///
+/// The products by the operator `*` are computed with interval arithmetic on each entry, which
+/// gives the tightest enclosure obtainable by summing up the products in order. For large
+/// matrices, [`IntervalMatrix::mul_mid_rad`] is usually faster at the cost of some overestimation.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, IntervalMatrix as M, IntervalVector as V};
+/// let a = M::new(2, 2, vec![c!(1.0, 2.0), c!(0.0, 0.0), c!(-1.0, 1.0), c!(3.0, 3.0)]);
+/// let x = V::from_points(&[1.0, 2.0]);
+/// assert_eq!(&a * &x, V::new(vec![c!(1.0, 2.0), c!(5.0, 7.0)]));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalMatrix {
+    rows: usize,
+    cols: usize,
+    xs: Vec<Interval>,
+}
+
+impl IntervalMatrix {
+    /// Creates a `rows` × `cols` matrix from the entries given in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs.len() != rows * cols`.
+    pub fn new(rows: usize, cols: usize, xs: Vec<Interval>) -> Self {
+        check_len(xs.len(), rows * cols);
+        Self { rows, cols, xs }
+    }
+
+    /// Creates a `rows` × `cols` matrix whose entries are the singleton intervals $\set{𝚡_i}$,
+    /// where the numbers are given in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x.len() != rows * cols` or any of the numbers is not finite.
+    pub fn from_points(rows: usize, cols: usize, x: &[f64]) -> Self {
+        Self::new(rows, cols, x.iter().map(|&x| point(x)).collect())
+    }
+
+    /// Creates the $n × n$ identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut a = Self::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = Interval::with_infsup_raw(1.0, 1.0);
+        }
+        a
+    }
+
+    /// Creates a `rows` × `cols` matrix whose entries are all $\[0, 0\]$.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, vec![Interval::zero(); rows * cols])
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entries in row-major order.
+    pub fn as_slice(&self) -> &[Interval] {
+        &self.xs
+    }
+
+    /// Returns the entries in row-major order as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [Interval] {
+        &mut self.xs
+    }
+
+    /// Returns the `i`-th row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.rows()`.
+    pub fn row(&self, i: usize) -> &[Interval] {
+        assert!(i < self.rows, "the row index is out of bounds");
+        &self.xs[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the `j`-th column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `j >= self.cols()`.
+    pub fn col(&self, j: usize) -> IntervalVector {
+        assert!(j < self.cols, "the column index is out of bounds");
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    /// Returns the entrywise convex hull of `self` and `rhs`.
+    pub fn convex_hull(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, Interval::convex_hull)
+    }
+
+    /// Returns the entrywise intersection of `self` and `rhs`.
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, Interval::intersection)
+    }
+
+    /// Returns the entrywise midpoints in row-major order.
+    ///
+    /// See [`Interval::mid`].
+    pub fn mid(&self) -> Vec<f64> {
+        self.xs.iter().map(|x| x.mid()).collect()
+    }
+
+    /// Returns an enclosure of the product `self * rhs` computed with the midpoint-radius
+    /// representations of the operands (Rump's algorithm).
+    ///
+    /// The product of the midpoints $\mid(\self) \mid(\rhs)$ is enclosed with directed rounding,
+    /// and the radius is bounded by $|\mid(\self)| \rad(\rhs) + \rad(\self) (|\mid(\rhs)| + \rad(\rhs))$.
+    /// The radius of the result can be larger than that of `self * rhs` by a factor of at most
+    /// 1.5, plus rounding errors. Only operations on floating-point numbers are performed in the
+    /// inner loop, which makes it considerably faster than `self * rhs`.
+    ///
+    /// If any of the entries of the operands is empty or unbounded, the result is the same as
+    /// `self * rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.cols() != rhs.rows()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalMatrix as M};
+    /// let a = M::new(1, 2, vec![c!(1.0, 3.0), c!(-1.0, 1.0)]);
+    /// let b = M::from_points(2, 1, &[1.0, 1.0]);
+    /// assert_eq!(a.mul_mid_rad(&b), M::new(1, 1, vec![c!(0.0, 4.0)]));
+    /// ```
+    pub fn mul_mid_rad(&self, rhs: &Self) -> Self {
+        check_len(self.cols, rhs.rows);
+        if !self.is_common() || !rhs.is_common() {
+            return self * rhs;
+        }
+
+        let (n, k, m) = (self.rows, self.cols, rhs.cols);
+        // For each entry of `self`: [-mid; mid] and [|mid|; rad].
+        let a = self
+            .xs
+            .iter()
+            .map(|x| {
+                let (m, r) = (x.mid(), x.rad());
+                (constant(-m, m), constant(m.abs(), r))
+            })
+            .collect::<Vec<_>>();
+        // For each entry of `rhs`: [mid; mid] and [rad; |mid| + rad].
+        // |mid| + rad can overflow for finite entries, which would lead to 0 × ∞ below.
+        let b = rhs
+            .xs
+            .iter()
+            .map(|x| {
+                let (m, r) = (x.mid(), x.rad());
+                let mr = extract0(add_ru(splat(m.abs()), splat(r)));
+                mr.is_finite().then(|| (splat(m), constant(r, mr)))
+            })
+            .collect::<Option<Vec<_>>>();
+        let b = match b {
+            Some(b) => b,
+            None => return self * rhs,
+        };
+
+        let mut xs = Vec::with_capacity(n * m);
+        for i in 0..n {
+            let a = &a[i * k..(i + 1) * k];
+            for j in 0..m {
+                // [-inf; sup] of the midpoint product.
+                let mut c = splat(0.0);
+                // [|mid a| rad b; rad a (|mid b| + rad b)].
+                let mut r = splat(0.0);
+                for (l, &(am, ar)) in a.iter().enumerate() {
+                    let (bm, br) = b[l * m + j];
+                    c = mul_add_ru(am, bm, c);
+                    r = mul_add_ru(ar, br, r);
+                }
+                let r = add_ru(r, swap(r));
+                if !extract0(r).is_finite() {
+                    return self * rhs;
+                }
+                xs.push(Interval {
+                    rep: add_ru(c, shuffle02(r, r)),
+                });
+            }
+        }
+        Self::new(n, m, xs)
+    }
+
+    /// Returns an enclosure of the maximum absolute row sum norm $\max_i \sum_j |\self_{ij}|$.
+    pub fn norm_inf(&self) -> Interval {
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .fold(Interval::zero(), |s, x| s + x.abs())
+            })
+            .fold(Interval::zero(), Interval::max)
+    }
+
+    /// Returns an enclosure of the maximum absolute column sum norm $\max_j \sum_i |\self_{ij}|$.
+    pub fn norm1(&self) -> Interval {
+        self.transpose().norm_inf()
+    }
+
+    /// Returns the entrywise radii in row-major order.
+    ///
+    /// See [`Interval::rad`].
+    pub fn rad(&self) -> Vec<f64> {
+        self.xs.iter().map(|x| x.rad()).collect()
+    }
+
+    /// Returns $𝚊 \self$.
+    pub fn scale(&self, a: Interval) -> Self {
+        Self::new(
+            self.rows,
+            self.cols,
+            self.xs.iter().map(|&x| a * x).collect(),
+        )
+    }
+
+    /// Returns `true` if every entry of `self` is a subset of the corresponding entry of `rhs`.
+    ///
+    /// See [`Interval::subset`].
+    pub fn subset(&self, rhs: &Self) -> bool {
+        self.check_dims(rhs);
+        self.xs.iter().zip(rhs.xs.iter()).all(|(x, &y)| x.subset(y))
+    }
+
+    /// Returns the transpose of `self`.
+    pub fn transpose(&self) -> Self {
+        let mut xs = Vec::with_capacity(self.xs.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                xs.push(self[(i, j)]);
+            }
+        }
+        Self::new(self.cols, self.rows, xs)
+    }
+
+    fn check_dims(&self, rhs: &Self) {
+        check_len(self.rows, rhs.rows);
+        check_len(self.cols, rhs.cols);
+    }
+
+    fn is_common(&self) -> bool {
+        self.xs.iter().all(|x| x.is_common_interval())
+    }
+
+    fn zip_map(&self, rhs: &Self, f: impl Fn(Interval, Interval) -> Interval) -> Self {
+        self.check_dims(rhs);
+        let xs = self
+            .xs
+            .iter()
+            .zip(rhs.xs.iter())
+            .map(|(&x, &y)| f(x, y))
+            .collect();
+        Self::new(self.rows, self.cols, xs)
+    }
+}
+
+impl Index<(usize, usize)> for IntervalMatrix {
+    type Output = Interval;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Interval {
+        assert!(i < self.rows && j < self.cols, "the index is out of bounds");
+        &self.xs[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for IntervalMatrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Interval {
+        assert!(i < self.rows && j < self.cols, "the index is out of bounds");
+        &mut self.xs[i * self.cols + j]
+    }
+}
+
+impl Neg for &IntervalMatrix {
+    type Output = IntervalMatrix;
+
+    fn neg(self) -> IntervalMatrix {
+        IntervalMatrix::new(self.rows, self.cols, self.xs.iter().map(|&x| -x).collect())
+    }
+}
+
+impl Neg for IntervalMatrix {
+    type Output = IntervalMatrix;
+
+    fn neg(self) -> IntervalMatrix {
+        -&self
+    }
+}
+
+impl Add for &IntervalMatrix {
+    type Output = IntervalMatrix;
+
+    fn add(self, rhs: Self) -> IntervalMatrix {
+        self.zip_map(rhs, |x, y| x + y)
+    }
+}
+
+forward_owned_binop!(impl Add, add for IntervalMatrix, IntervalMatrix, IntervalMatrix);
+
+impl Sub for &IntervalMatrix {
+    type Output = IntervalMatrix;
+
+    fn sub(self, rhs: Self) -> IntervalMatrix {
+        self.zip_map(rhs, |x, y| x - y)
+    }
+}
+
+forward_owned_binop!(impl Sub, sub for IntervalMatrix, IntervalMatrix, IntervalMatrix);
+
+impl Mul<&IntervalVector> for &IntervalMatrix {
+    type Output = IntervalVector;
+
+    fn mul(self, rhs: &IntervalVector) -> IntervalVector {
+        check_len(self.cols, rhs.len());
+        (0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(rhs.iter())
+                    .fold(Interval::zero(), |s, (&a, &x)| a.mul_add(x, s))
+            })
+            .collect()
+    }
+}
+
+forward_owned_binop!(impl Mul, mul for IntervalMatrix, IntervalVector, IntervalVector);
+
+impl Mul for &IntervalMatrix {
+    type Output = IntervalMatrix;
+
+    fn mul(self, rhs: Self) -> IntervalMatrix {
+        check_len(self.cols, rhs.rows);
+        let (n, m) = (self.rows, rhs.cols);
+        let mut xs = Vec::with_capacity(n * m);
+        for i in 0..n {
+            let a = self.row(i);
+            for j in 0..m {
+                xs.push(
+                    a.iter()
+                        .enumerate()
+                        .fold(Interval::zero(), |s, (l, &a)| a.mul_add(rhs[(l, j)], s)),
+                );
+            }
+        }
+        IntervalMatrix::new(n, m, xs)
+    }
+}
+
+forward_owned_binop!(impl Mul, mul for IntervalMatrix, IntervalMatrix, IntervalMatrix);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+    use IntervalMatrix as M;
+    use IntervalVector as V;
+
+    #[test]
+    fn arith() {
+        let a = M::new(
+            2,
+            2,
+            vec![
+                const_interval!(1.0, 2.0),
+                const_interval!(0.0, 1.0),
+                const_interval!(-1.0, 1.0),
+                const_interval!(3.0, 3.0),
+            ],
+        );
+        let i = M::identity(2);
+        assert_eq!(&a * &i, a);
+        assert_eq!(&i * &a, a);
+        assert_eq!(&a + &M::zeros(2, 2), a);
+        assert!(M::zeros(2, 2).subset(&(&a - &a)));
+        assert_eq!(
+            a.transpose(),
+            M::new(2, 2, vec![a[(0, 0)], a[(1, 0)], a[(0, 1)], a[(1, 1)]])
+        );
+        assert_eq!(a.col(1), V::new(a.transpose().row(1).to_vec()));
+        assert_eq!(
+            &a * &V::from_points(&[1.0, -1.0]),
+            V::new(vec![const_interval!(0.0, 2.0), const_interval!(-4.0, -2.0)])
+        );
+        assert_eq!(a.norm_inf(), const_interval!(3.0, 4.0));
+        assert_eq!(a.norm1(), const_interval!(3.0, 4.0));
+        assert!(a.subset(&a.convex_hull(&-&a)));
+        assert_eq!(a.intersection(&a), a);
+    }
+
+    #[test]
+    fn mul_mid_rad() {
+        let mut seed = 1u64;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        let (n, k, m) = (5, 7, 3);
+        let a_pts = (0..n * k).map(|_| rand()).collect::<Vec<_>>();
+        let b_pts = (0..k * m).map(|_| rand()).collect::<Vec<_>>();
+        let a = M::from_points(n, k, &a_pts);
+        let b = M::new(
+            k,
+            m,
+            b_pts
+                .iter()
+                .map(|&x| interval!(x - 1e-10, x + 1e-10).unwrap())
+                .collect(),
+        );
+        let c = &a * &b;
+        let d = a.mul_mid_rad(&b);
+        for i in 0..n {
+            for j in 0..m {
+                let x = &a_pts[i * k..(i + 1) * k];
+                let y = (0..k).map(|l| b_pts[l * m + j]).collect::<Vec<_>>();
+                let exact = reduction::dot_interval(x, &y);
+                assert!(exact.subset(c[(i, j)]));
+                assert!(exact.subset(d[(i, j)]));
+                assert!(d[(i, j)].rad() <= 1.5 * c[(i, j)].rad() + 1e-14);
+            }
+        }
+
+        let p = M::from_points(2, 2, &[0.1, 0.2, 0.3, 0.4]);
+        let q = p.mul_mid_rad(&p);
+        assert!(q.rad().iter().all(|&r| r < 1e-15));
+        for i in 0..2 {
+            for j in 0..2 {
+                let exact = reduction::dot_interval(
+                    &p.mid()[2 * i..2 * i + 2],
+                    &[p.mid()[j], p.mid()[2 + j]],
+                );
+                assert!(exact.subset(q[(i, j)]));
+            }
+        }
+    }
+
+    #[test]
+    fn mul_mid_rad_enclosure() {
+        let a = M::new(
+            2,
+            2,
+            vec![
+                const_interval!(1.0, 2.0),
+                const_interval!(-3.0, -1.0),
+                const_interval!(-1.0, 1.0),
+                const_interval!(0.5, 0.5),
+            ],
+        );
+        let b = M::new(
+            2,
+            1,
+            vec![const_interval!(-2.0, 1.0), const_interval!(4.0, 5.0)],
+        );
+        assert!((&a * &b).subset(&a.mul_mid_rad(&b)));
+
+        let e = M::new(1, 2, vec![I::ENTIRE, I::EMPTY]);
+        assert_eq!(e.mul_mid_rad(&b), &e * &b);
+    }
+
+    #[test]
+    fn mul_mid_rad_overflow() {
+        // |mid b| + rad b overflows, while rad a = 0.
+        let b = M::new(1, 1, vec![const_interval!(-1.0, f64::MAX)]);
+        for a in [0.0, 1.0] {
+            let a = M::from_points(1, 1, &[a]);
+            assert_eq!(a.mul_mid_rad(&b), &a * &b);
+        }
+
+        let a = M::from_points(1, 2, &[f64::MAX, f64::MAX]);
+        let b = M::new(2, 1, vec![const_interval!(1.0, 3.0); 2]);
+        assert!((&a * &b).subset(&a.mul_mid_rad(&b)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimension_mismatch() {
+        let _ = M::zeros(2, 3) * M::zeros(2, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _ = M::zeros(2, 3)[(0, 3)];
+    }
+}
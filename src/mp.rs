@@ -0,0 +1,2014 @@
+use crate::{classify::*, forward_ref::*, interval::*, overlap::*};
+use gmp_mpfr_sys::mpfr;
+use rug::{
+    float::{Round, Special},
+    ops::AssignRound,
+    Float,
+};
+use std::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// An interval with arbitrary-precision bounds.
+///
+/// The bounds are [`rug::Float`] values of a precision chosen by the caller, and every operation
+/// rounds the bounds of the result outward to that precision. The result of an operation
+/// on two intervals has the larger of their precisions.
+///
+/// The type provides the same operations as [`Interval`]. Each operation is documented
+/// only briefly; see the counterpart in [`Interval`] for the details.
+///
+/// # Examples
+///
+/// ```
+/// use inari::*;
+/// let x = MpInterval::from(const_interval!(2.0, 2.0)).with_prec(256);
+/// let y = x.sqrt();
+/// assert!(y.wid() < 1e-75);
+/// assert!(Interval::from(&y).subset(const_interval!(1.414, 1.415)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MpInterval {
+    // The bounds [a, b] of a nonempty interval, or two NaNs for the empty interval.
+    // Both bounds have the same precision.
+    inf: Float,
+    sup: Float,
+}
+
+fn rd<T>(prec: u32, val: T) -> Float
+where
+    Float: AssignRound<T, Round = Round, Ordering = Ordering>,
+{
+    Float::with_val_round(prec, val, Round::Down).0
+}
+
+fn ru<T>(prec: u32, val: T) -> Float
+where
+    Float: AssignRound<T, Round = Round, Ordering = Ordering>,
+{
+    Float::with_val_round(prec, val, Round::Up).0
+}
+
+// Multiplications of bounds, where zero times infinity is zero.
+fn mul_rd(prec: u32, x: &Float, y: &Float) -> Float {
+    if x.is_zero() || y.is_zero() {
+        Float::new(prec)
+    } else {
+        rd(prec, x * y)
+    }
+}
+
+fn mul_ru(prec: u32, x: &Float, y: &Float) -> Float {
+    if x.is_zero() || y.is_zero() {
+        Float::new(prec)
+    } else {
+        ru(prec, x * y)
+    }
+}
+
+// Returns `x` modulo 4, where `x` must be an integer. NaN is returned if `x` is infinite.
+fn rem_euclid_4(x: &Float) -> f64 {
+    let r = 4.0 * Float::with_val(x.prec(), x / 4u32).fract().to_f64();
+    if r < 0.0 {
+        r + 4.0
+    } else {
+        r
+    }
+}
+
+// Returns `y - x` rounded upward to `f64`, which is compared only with small integers.
+fn diff_ru(x: &Float, y: &Float) -> f64 {
+    ru(f64::MANTISSA_DIGITS, y - x).to_f64()
+}
+
+macro_rules! mpfr_fn {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(x: &Float) -> Float {
+            mpfr_fn!($mpfr_f(x, RNDD))
+        }
+
+        fn $f_ru(x: &Float) -> Float {
+            mpfr_fn!($mpfr_f(x, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $rnd:ident)) => {{
+        let mut y = Float::new($x.prec());
+        unsafe {
+            mpfr::$mpfr_f(y.as_raw_mut(), $x.as_raw(), mpfr::rnd_t::$rnd);
+        }
+        y
+    }};
+}
+
+macro_rules! mpfr_fn2 {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(x: &Float, y: &Float, prec: u32) -> Float {
+            mpfr_fn2!($mpfr_f(x, y, prec, RNDD))
+        }
+
+        fn $f_ru(x: &Float, y: &Float, prec: u32) -> Float {
+            mpfr_fn2!($mpfr_f(x, y, prec, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $y:ident, $prec:ident, $rnd:ident)) => {{
+        let mut z = Float::new($prec);
+        unsafe {
+            mpfr::$mpfr_f(z.as_raw_mut(), $x.as_raw(), $y.as_raw(), mpfr::rnd_t::$rnd);
+        }
+        z
+    }};
+}
+
+macro_rules! mpfr_fn_si {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(x: &Float, y: i32) -> Float {
+            mpfr_fn_si!($mpfr_f(x, y, RNDD))
+        }
+
+        fn $f_ru(x: &Float, y: i32) -> Float {
+            mpfr_fn_si!($mpfr_f(x, y, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
+        let mut z = Float::new($x.prec());
+        unsafe {
+            mpfr::$mpfr_f(z.as_raw_mut(), $x.as_raw(), $y.into(), mpfr::rnd_t::$rnd);
+        }
+        z
+    }};
+}
+
+// See `mpfr_fn_recip` in elementary.rs.
+macro_rules! mpfr_fn_recip {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident, $recip_rnd_d:ident, $recip_rnd_u:ident) => {
+        fn $f_rd(x: &Float) -> Float {
+            mpfr_fn_recip!($mpfr_f(x, $recip_rnd_d, RNDD))
+        }
+
+        fn $f_ru(x: &Float) -> Float {
+            mpfr_fn_recip!($mpfr_f(x, $recip_rnd_u, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $recip_rnd:ident, $rnd:ident)) => {{
+        let mut r = Float::new(recip_prec($x.prec()));
+        let mut y = Float::new($x.prec());
+        unsafe {
+            mpfr::ui_div(r.as_raw_mut(), 1, $x.as_raw(), mpfr::rnd_t::$recip_rnd);
+            mpfr::$mpfr_f(y.as_raw_mut(), r.as_raw(), mpfr::rnd_t::$rnd);
+        }
+        y
+    }};
+}
+
+// The precision of the reciprocal in `mpfr_fn_recip`, which is `RECIP_PREC` in elementary.rs
+// for the precision of `f64`.
+fn recip_prec(prec: u32) -> u32 {
+    2 * prec + 22
+}
+
+mpfr_fn!(acos, acos_rd, acos_ru);
+mpfr_fn!(acospi, acos_pi_rd, acos_pi_ru);
+mpfr_fn!(acosh, acosh_rd, acosh_ru);
+mpfr_fn!(asin, asin_rd, asin_ru);
+mpfr_fn!(asinpi, asin_pi_rd, asin_pi_ru);
+mpfr_fn!(asinh, asinh_rd, asinh_ru);
+mpfr_fn!(atan, atan_rd, atan_ru);
+mpfr_fn2!(atan2, atan2_rd, atan2_ru);
+mpfr_fn2!(atan2pi, atan2_pi_rd, atan2_pi_ru);
+mpfr_fn!(atanpi, atan_pi_rd, atan_pi_ru);
+mpfr_fn!(atanh, atanh_rd, atanh_ru);
+mpfr_fn_si!(compound_si, compound_rd, compound_ru);
+mpfr_fn!(cos, cos_rd, cos_ru);
+mpfr_fn!(cospi, cos_pi_rd, cos_pi_ru);
+mpfr_fn!(cosh, cosh_rd, cosh_ru);
+mpfr_fn!(cot, cot_rd, cot_ru);
+mpfr_fn!(coth, coth_rd, coth_ru);
+mpfr_fn!(csc, csc_rd, csc_ru);
+mpfr_fn!(csch, csch_rd, csch_ru);
+mpfr_fn!(exp, exp_rd, exp_ru);
+mpfr_fn!(exp10, exp10_rd, exp10_ru);
+mpfr_fn!(exp10m1, exp10m1_rd, exp10m1_ru);
+mpfr_fn!(exp2, exp2_rd, exp2_ru);
+mpfr_fn!(exp2m1, exp2m1_rd, exp2m1_ru);
+mpfr_fn!(expm1, expm1_rd, expm1_ru);
+mpfr_fn2!(hypot, hypot_rd, hypot_ru);
+mpfr_fn!(log, ln_rd, ln_ru);
+mpfr_fn!(log10, log10_rd, log10_ru);
+mpfr_fn!(log10p1, log10p1_rd, log10p1_ru);
+mpfr_fn!(log1p, logp1_rd, logp1_ru);
+mpfr_fn!(log2, log2_rd, log2_ru);
+mpfr_fn!(log2p1, log2p1_rd, log2p1_ru);
+mpfr_fn2!(pow, pow_rd, pow_ru);
+mpfr_fn_si!(pow_si, pown_rd, pown_ru);
+mpfr_fn!(rec_sqrt, rsqrt_rd, rsqrt_ru);
+mpfr_fn_si!(rootn_si, rootn_rd, rootn_ru);
+mpfr_fn!(sec, sec_rd, sec_ru);
+mpfr_fn!(sech, sech_rd, sech_ru);
+mpfr_fn!(sin, sin_rd, sin_ru);
+mpfr_fn!(sinpi, sin_pi_rd, sin_pi_ru);
+mpfr_fn!(sinh, sinh_rd, sinh_ru);
+mpfr_fn!(sqrt, sqrt_rd, sqrt_ru);
+mpfr_fn!(tan, tan_rd, tan_ru);
+mpfr_fn!(tanpi, tan_pi_rd, tan_pi_ru);
+mpfr_fn!(tanh, tanh_rd, tanh_ru);
+
+mpfr_fn_recip!(acos, asec_rd, asec_ru, RNDU, RNDD);
+mpfr_fn_recip!(asin, acsc_rd, acsc_ru, RNDD, RNDU);
+mpfr_fn_recip!(atanh, acoth_rd, acoth_ru, RNDD, RNDU);
+
+impl MpInterval {
+    // Creates an interval from the bounds, which must be of the same precision.
+    fn with_infsup_raw(a: Float, b: Float) -> Self {
+        debug_assert_eq!(a.prec(), b.prec());
+        Self { inf: a, sup: b }
+    }
+
+    // Creates an interval from the bounds, which must be exactly representable in `prec` bits.
+    fn with_f64s(prec: u32, a: f64, b: f64) -> Self {
+        Self::with_infsup_raw(Float::with_val(prec, a), Float::with_val(prec, b))
+    }
+
+    // Returns the interval whose bounds have the same signs as those of `self`,
+    // which has the same class as `self`.
+    fn sign_proxy(&self) -> Interval {
+        if self.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        let sign = |x: &Float| {
+            if x.is_zero() {
+                0.0
+            } else if x.is_sign_negative() {
+                -1.0
+            } else {
+                1.0
+            }
+        };
+        Interval::with_infsup_raw(sign(&self.inf), sign(&self.sup))
+    }
+
+    fn classify2(&self, rhs: &Self) -> IntervalClass2 {
+        self.sign_proxy().classify2(rhs.sign_proxy())
+    }
+
+    fn either_empty(&self, rhs: &Self) -> bool {
+        self.is_empty() || rhs.is_empty()
+    }
+
+    /// Returns the empty interval of the given precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prec` is out of the range allowed for [`rug::Float`].
+    pub fn empty(prec: u32) -> Self {
+        Self::with_f64s(prec, f64::NAN, f64::NAN)
+    }
+
+    /// Returns $\R$, the interval of all real numbers, of the given precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prec` is out of the range allowed for [`rug::Float`].
+    pub fn entire(prec: u32) -> Self {
+        Self::with_f64s(prec, f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Returns the tightest enclosure of π of the given precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prec` is out of the range allowed for [`rug::Float`].
+    pub fn pi(prec: u32) -> Self {
+        Self::with_infsup_raw(
+            rd(prec, rug::float::Constant::Pi),
+            ru(prec, rug::float::Constant::Pi),
+        )
+    }
+
+    fn frac_pi_2(prec: u32) -> Self {
+        let pi = Self::pi(prec);
+        Self::with_infsup_raw(pi.inf / 2u32, pi.sup / 2u32)
+    }
+
+    fn zero(prec: u32) -> Self {
+        Self::with_f64s(prec, 0.0, 0.0)
+    }
+
+    /// Returns the precision of the bounds of `self` in bits.
+    pub fn prec(&self) -> u32 {
+        self.inf.prec()
+    }
+
+    /// Returns the tightest interval of the given precision that contains `self`.
+    ///
+    /// The result equals `self` as a set if `prec` is not less than the precision of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prec` is out of the range allowed for [`rug::Float`].
+    #[must_use]
+    pub fn with_prec(&self, prec: u32) -> Self {
+        Self::with_infsup_raw(rd(prec, &self.inf), ru(prec, &self.sup))
+    }
+
+    // Returns the larger of the precisions of `self` and `rhs`.
+    fn max_prec(&self, rhs: &Self) -> u32 {
+        self.prec().max(rhs.prec())
+    }
+}
+
+impl TryFrom<(Float, Float)> for MpInterval {
+    type Error = IntervalError;
+
+    /// Creates an interval $\[a, b\]$ from the bounds, whose precision is the larger of those of
+    /// $a$ and $b$.
+    ///
+    /// An error is returned under the same condition as for [`Interval`].
+    fn try_from((a, b): (Float, Float)) -> Result<Self> {
+        if a <= b && a != f64::INFINITY && b != f64::NEG_INFINITY {
+            let prec = a.prec().max(b.prec());
+            Ok(Self::with_infsup_raw(rd(prec, a), ru(prec, b)))
+        } else {
+            Err(Self::Error {
+                kind: IntervalErrorKind::UndefinedOperation,
+            })
+        }
+    }
+}
+
+impl From<Interval> for MpInterval {
+    /// Converts the interval into one with the precision of `f64` without loss.
+    fn from(x: Interval) -> Self {
+        if x.is_empty() {
+            return Self::empty(f64::MANTISSA_DIGITS);
+        }
+
+        Self::with_f64s(f64::MANTISSA_DIGITS, x.inf_raw(), x.sup_raw())
+    }
+}
+
+impl From<&MpInterval> for Interval {
+    /// Returns the tightest [`Interval`] that contains the interval.
+    fn from(x: &MpInterval) -> Self {
+        if x.is_empty() {
+            return Self::EMPTY;
+        }
+
+        Self::with_infsup_raw(
+            x.inf.to_f64_round(Round::Down),
+            x.sup.to_f64_round(Round::Up),
+        )
+    }
+}
+
+impl From<MpInterval> for Interval {
+    /// Returns the tightest [`Interval`] that contains the interval.
+    fn from(x: MpInterval) -> Self {
+        Self::from(&x)
+    }
+}
+
+impl PartialEq for MpInterval {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.is_empty() && rhs.is_empty() || self.inf == rhs.inf && self.sup == rhs.sup
+    }
+}
+
+impl Eq for MpInterval {}
+
+// Numeric functions
+
+impl MpInterval {
+    /// The arbitrary-precision version of [`Interval::inf`].
+    pub fn inf(&self) -> Float {
+        if self.is_empty() {
+            Float::with_val(self.prec(), Special::Infinity)
+        } else if self.inf.is_zero() {
+            Float::with_val(self.prec(), Special::NegZero)
+        } else {
+            self.inf.clone()
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::mag`].
+    pub fn mag(&self) -> Float {
+        if self.is_empty() {
+            return self.inf.clone();
+        }
+
+        Float::with_val(self.prec(), self.inf.abs_ref()).max(&self.sup.as_abs())
+    }
+
+    /// The arbitrary-precision version of [`Interval::mid`].
+    ///
+    /// The midpoint is rounded to the nearest. If `self` is unbounded in one direction,
+    /// the finite number of the greatest magnitude with the sign of that direction is returned.
+    pub fn mid(&self) -> Float {
+        let prec = self.prec();
+        let a = &self.inf;
+        let b = &self.sup;
+        match (*a == f64::NEG_INFINITY, *b == f64::INFINITY) {
+            (false, false) => {
+                // The halves are exact unless they underflow.
+                let ha = Float::with_val(prec, a / 2u32);
+                let hb = Float::with_val(prec, b / 2u32);
+                let mid = Float::with_val(prec, &ha + &hb);
+                if mid.is_zero() {
+                    Float::new(prec)
+                } else {
+                    mid
+                }
+            }
+            (false, true) => {
+                let mut max = Float::with_val(prec, Special::Infinity);
+                max.next_down();
+                max
+            }
+            (true, false) => {
+                let mut min = Float::with_val(prec, Special::NegInfinity);
+                min.next_up();
+                min
+            }
+            (true, true) => Float::new(prec),
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::mig`].
+    pub fn mig(&self) -> Float {
+        if self.is_empty() {
+            return self.inf.clone();
+        }
+
+        if self.inf <= 0 && self.sup >= 0 {
+            Float::new(self.prec())
+        } else {
+            Float::with_val(self.prec(), self.inf.abs_ref()).min(&self.sup.as_abs())
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::rad`].
+    pub fn rad(&self) -> Float {
+        let prec = self.prec();
+        let m = self.mid();
+        ru(prec, &m - &self.inf).max(&ru(prec, &self.sup - &m))
+    }
+
+    /// The arbitrary-precision version of [`Interval::sup`].
+    pub fn sup(&self) -> Float {
+        if self.is_empty() {
+            Float::with_val(self.prec(), Special::NegInfinity)
+        } else if self.sup.is_zero() {
+            Float::new(self.prec())
+        } else {
+            self.sup.clone()
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::wid`].
+    pub fn wid(&self) -> Float {
+        let wid = ru(self.prec(), &self.sup - &self.inf);
+        if wid.is_zero() {
+            Float::new(self.prec())
+        } else {
+            wid
+        }
+    }
+}
+
+// Boolean functions
+
+impl MpInterval {
+    /// The arbitrary-precision version of [`Interval::contains`].
+    pub fn contains(&self, rhs: &Float) -> bool {
+        rhs.is_finite() && self.inf <= *rhs && *rhs <= self.sup
+    }
+
+    /// The arbitrary-precision version of [`Interval::disjoint`].
+    pub fn disjoint(&self, rhs: &Self) -> bool {
+        self.either_empty(rhs) || self.sup < rhs.inf || rhs.sup < self.inf
+    }
+
+    /// The arbitrary-precision version of [`Interval::interior`].
+    pub fn interior(&self, rhs: &Self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        if rhs.is_empty() {
+            return false;
+        }
+
+        let l = rhs.inf < self.inf || self.inf == f64::NEG_INFINITY && rhs.inf == f64::NEG_INFINITY;
+        let r = self.sup < rhs.sup || self.sup == f64::INFINITY && rhs.sup == f64::INFINITY;
+        l && r
+    }
+
+    /// The arbitrary-precision version of [`Interval::is_common_interval`].
+    pub fn is_common_interval(&self) -> bool {
+        self.inf.is_finite() && self.sup.is_finite()
+    }
+
+    /// The arbitrary-precision version of [`Interval::is_empty`].
+    pub fn is_empty(&self) -> bool {
+        self.inf.is_nan()
+    }
+
+    /// The arbitrary-precision version of [`Interval::is_entire`].
+    pub fn is_entire(&self) -> bool {
+        self.inf == f64::NEG_INFINITY && self.sup == f64::INFINITY
+    }
+
+    /// The arbitrary-precision version of [`Interval::is_singleton`].
+    pub fn is_singleton(&self) -> bool {
+        self.inf == self.sup
+    }
+
+    /// The arbitrary-precision version of [`Interval::less`].
+    pub fn less(&self, rhs: &Self) -> bool {
+        if self.either_empty(rhs) {
+            return self.is_empty() && rhs.is_empty();
+        }
+
+        self.inf <= rhs.inf && self.sup <= rhs.sup
+    }
+
+    /// The arbitrary-precision version of [`Interval::overlap`].
+    pub fn overlap(&self, rhs: &Self) -> Overlap {
+        use Overlap::*;
+
+        match (self.is_empty(), rhs.is_empty()) {
+            (true, true) => BothEmpty,
+            (true, false) => FirstEmpty,
+            (false, true) => SecondEmpty,
+            (false, false) => overlap_nonempty(&self.inf, &self.sup, &rhs.inf, &rhs.sup),
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::precedes`].
+    pub fn precedes(&self, rhs: &Self) -> bool {
+        self.either_empty(rhs) || self.sup <= rhs.inf
+    }
+
+    /// The arbitrary-precision version of [`Interval::strict_less`].
+    pub fn strict_less(&self, rhs: &Self) -> bool {
+        if self.either_empty(rhs) {
+            return self.is_empty() && rhs.is_empty();
+        }
+
+        let l = self.inf < rhs.inf || self.inf == f64::NEG_INFINITY && rhs.inf == f64::NEG_INFINITY;
+        let r = self.sup < rhs.sup || self.sup == f64::INFINITY && rhs.sup == f64::INFINITY;
+        l && r
+    }
+
+    /// The arbitrary-precision version of [`Interval::strict_precedes`].
+    pub fn strict_precedes(&self, rhs: &Self) -> bool {
+        self.either_empty(rhs) || self.sup < rhs.inf
+    }
+
+    /// The arbitrary-precision version of [`Interval::subset`].
+    pub fn subset(&self, rhs: &Self) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        if rhs.is_empty() {
+            return false;
+        }
+
+        rhs.inf <= self.inf && self.sup <= rhs.sup
+    }
+}
+
+// Set operations
+
+impl MpInterval {
+    /// The arbitrary-precision version of [`Interval::complement`].
+    #[must_use]
+    pub fn complement(&self) -> [Self; 2] {
+        Self::entire(self.prec()).difference(self)
+    }
+
+    /// The arbitrary-precision version of [`Interval::convex_hull`].
+    #[must_use]
+    pub fn convex_hull(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.is_empty() {
+            return rhs.with_prec(prec);
+        }
+        if rhs.is_empty() {
+            return self.with_prec(prec);
+        }
+
+        Self::with_infsup_raw(
+            rd(prec, &self.inf).min(&rhs.inf),
+            ru(prec, &self.sup).max(&rhs.sup),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::difference`].
+    #[must_use]
+    pub fn difference(&self, rhs: &Self) -> [Self; 2] {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return [self.with_prec(prec), Self::empty(prec)];
+        }
+
+        let a = &self.inf;
+        let b = &self.sup;
+        let c = &rhs.inf;
+        let d = &rhs.sup;
+        if c == d && a < b {
+            // The closure of the difference is `self` itself.
+            return [self.with_prec(prec), Self::empty(prec)];
+        }
+
+        let left = if a < c {
+            Self::with_infsup_raw(rd(prec, a), ru(prec, c).min(b))
+        } else {
+            Self::empty(prec)
+        };
+        let right = if d < b {
+            Self::with_infsup_raw(rd(prec, d).max(a), ru(prec, b))
+        } else {
+            Self::empty(prec)
+        };
+        if left.is_empty() {
+            [right, Self::empty(prec)]
+        } else {
+            [left, right]
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::intersection`].
+    #[must_use]
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        let a = rd(prec, &self.inf).max(&rhs.inf);
+        let b = ru(prec, &self.sup).min(&rhs.sup);
+        if a > b {
+            Self::empty(prec)
+        } else {
+            Self::with_infsup_raw(a, b)
+        }
+    }
+}
+
+// Integer functions
+
+macro_rules! impl_integer {
+    ($f:ident, $f_ref:ident) => {
+        #[doc = concat!("The arbitrary-precision version of [`Interval::", stringify!($f), "`].")]
+        #[must_use]
+        pub fn $f(&self) -> Self {
+            if self.is_empty() {
+                return self.clone();
+            }
+
+            // The results are exact, as the precision of the integer part of a number
+            // never exceeds that of the number.
+            let prec = self.prec();
+            Self::with_infsup_raw(rd(prec, self.inf.$f_ref()), ru(prec, self.sup.$f_ref()))
+        }
+    };
+}
+
+impl MpInterval {
+    impl_integer!(ceil, ceil_ref);
+    impl_integer!(floor, floor_ref);
+    impl_integer!(round, round_ref);
+    impl_integer!(round_ties_even, round_even_ref);
+
+    /// The arbitrary-precision version of [`Interval::sign`].
+    #[must_use]
+    pub fn sign(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let sign = |x: &Float| {
+            if x.is_zero() {
+                0.0
+            } else if x.is_sign_negative() {
+                -1.0
+            } else {
+                1.0
+            }
+        };
+        Self::with_f64s(self.prec(), sign(&self.inf), sign(&self.sup))
+    }
+
+    impl_integer!(trunc, trunc_ref);
+}
+
+// Arithmetic operations
+
+impl MpInterval {
+    /// The arbitrary-precision version of [`Interval::abs`].
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        if self.is_empty() || self.inf >= 0 {
+            self.clone()
+        } else if self.sup <= 0 {
+            -self
+        } else {
+            let b = Float::with_val(self.prec(), -&self.inf).max(&self.sup);
+            Self::with_infsup_raw(Float::new(self.prec()), b)
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::cancel_minus`].
+    #[must_use]
+    pub fn cancel_minus(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.is_empty() && (rhs.is_empty() || rhs.is_common_interval()) {
+            return Self::empty(prec);
+        }
+        if !self.is_common_interval() || rhs.is_empty() || !rhs.is_common_interval() {
+            return Self::entire(prec);
+        }
+
+        // The widths are compared exactly, as finite bounds are rational numbers.
+        let exact = |x: &Float| x.to_rational().unwrap();
+        let wx = exact(&self.sup) - exact(&self.inf);
+        let wy = exact(&rhs.sup) - exact(&rhs.inf);
+        if wx < wy {
+            return Self::entire(prec);
+        }
+
+        Self::with_infsup_raw(
+            rd(prec, &self.inf - &rhs.inf),
+            ru(prec, &self.sup - &rhs.sup),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::cancel_plus`].
+    #[must_use]
+    pub fn cancel_plus(&self, rhs: &Self) -> Self {
+        self.cancel_minus(&-rhs)
+    }
+
+    /// The arbitrary-precision version of [`Interval::max`].
+    #[must_use]
+    pub fn max(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        Self::with_infsup_raw(
+            rd(prec, &self.inf).max(&rhs.inf),
+            ru(prec, &self.sup).max(&rhs.sup),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::min`].
+    #[must_use]
+    pub fn min(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        Self::with_infsup_raw(
+            rd(prec, &self.inf).min(&rhs.inf),
+            ru(prec, &self.sup).min(&rhs.sup),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::mul_add`].
+    ///
+    /// The result has the largest of the precisions of the three intervals.
+    #[must_use]
+    pub fn mul_add(&self, rhs: &Self, addend: &Self) -> Self {
+        let prec = self.max_prec(rhs).max(addend.prec());
+        // The product of bounds is exact with the sum of their precisions.
+        let xy = self.mul_impl(rhs, self.prec() + rhs.prec());
+        if xy.either_empty(addend) {
+            return Self::empty(prec);
+        }
+
+        Self::with_infsup_raw(
+            rd(prec, &xy.inf + &addend.inf),
+            ru(prec, &xy.sup + &addend.sup),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::mul_rev_to_pair`].
+    #[must_use]
+    pub fn mul_rev_to_pair(&self, numerator: &Self) -> [Self; 2] {
+        let prec = self.max_prec(numerator);
+        let empty = || Self::empty(prec);
+        let (x, y) = (numerator, self);
+        let (a, b, c, d) = (&x.inf, &x.sup, &y.inf, &y.sup);
+        let inf = || Float::with_val(prec, Special::Infinity);
+        let neg_inf = || Float::with_val(prec, Special::NegInfinity);
+
+        use IntervalClass2::*;
+        match x.classify2(y) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | N1_Z | P0_E
+            | P1_E | P1_Z | Z_E => [empty(), empty()],
+            M_Z | N0_Z | P0_Z | Z_Z | M_M | M_N0 | M_P0 | N0_M | P0_M | Z_M | Z_N0 | Z_P0
+            | N0_N0 | N0_P0 | P0_N0 | P0_P0 => [Self::entire(prec), empty()],
+            Z_N1 | Z_P1 => [Self::zero(prec), empty()],
+            // N1 / M => [-∞, b/d] ∪ [b/c, +∞]
+            N1_M => [
+                Self::with_infsup_raw(neg_inf(), ru(prec, b / d)),
+                Self::with_infsup_raw(rd(prec, b / c), inf()),
+            ],
+            // P1 / M => [-∞, a/c] ∪ [a/d, +∞]
+            P1_M => [
+                Self::with_infsup_raw(neg_inf(), ru(prec, a / c)),
+                Self::with_infsup_raw(rd(prec, a / d), inf()),
+            ],
+            _ => [x.div_impl(y, prec), empty()],
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::recip`].
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        &Self::with_f64s(self.prec(), 1.0, 1.0) / self
+    }
+
+    /// The arbitrary-precision version of [`Interval::sqr`].
+    #[must_use]
+    pub fn sqr(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let mig = self.mig();
+        let mag = self.mag();
+        Self::with_infsup_raw(rd(prec, mig.square_ref()), ru(prec, mag.square_ref()))
+    }
+
+    /// The arbitrary-precision version of [`Interval::sqrt`].
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        let x = self.intersection(&Self::with_f64s(self.prec(), 0.0, f64::INFINITY));
+        if x.is_empty() {
+            return x;
+        }
+
+        Self::with_infsup_raw(sqrt_rd(&x.inf), sqrt_ru(&x.sup))
+    }
+
+    fn mul_impl(&self, rhs: &Self, prec: u32) -> Self {
+        if self.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        let (a, b, c, d) = (&self.inf, &self.sup, &rhs.inf, &rhs.sup);
+        let inf = mul_rd(prec, a, c)
+            .min(&mul_rd(prec, a, d))
+            .min(&mul_rd(prec, b, c))
+            .min(&mul_rd(prec, b, d));
+        let sup = mul_ru(prec, a, c)
+            .max(&mul_ru(prec, a, d))
+            .max(&mul_ru(prec, b, c))
+            .max(&mul_ru(prec, b, d));
+        Self::with_infsup_raw(inf, sup)
+    }
+
+    fn div_impl(&self, rhs: &Self, prec: u32) -> Self {
+        // [a, b] / [c, d] =
+        //
+        //    |  M  |     N0    |     N1     |     P0    |     P1     | Z
+        // ---+-----+-----------+------------+-----------+------------+---
+        //  M |  ℝ  |     ℝ     | [b/d, a/d] |     ℝ     | [a/c, b/c] | ∅
+        //  N |  ℝ  | [b/c, +∞] | [b/c, a/d] | [-∞, b/d] | [a/c, b/d] | ∅
+        //  P |  ℝ  | [-∞, a/c] | [b/d, a/c] | [a/d, +∞] | [a/d, b/c] | ∅
+        //  Z | {0} |    {0}    |     {0}    |    {0}    |     {0}    | ∅
+        let (a, b, c, d) = (&self.inf, &self.sup, &rhs.inf, &rhs.sup);
+        let inf = || Float::with_val(prec, Special::Infinity);
+        let neg_inf = || Float::with_val(prec, Special::NegInfinity);
+        let div = |x: &Float, y: &Float, z: &Float, w: &Float| {
+            Self::with_infsup_raw(rd(prec, x / y), ru(prec, z / w))
+        };
+
+        use IntervalClass2::*;
+        match self.classify2(rhs) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | M_Z | N0_E | N0_Z | N1_E | N1_Z
+            | P0_E | P0_Z | P1_E | P1_Z | Z_E | Z_Z => Self::empty(prec),
+            M_M | M_N0 | M_P0 | N0_M | N1_M | P0_M | P1_M => Self::entire(prec),
+            Z_M | Z_N0 | Z_N1 | Z_P0 | Z_P1 => Self::zero(prec),
+            M_N1 => div(b, d, a, d),
+            M_P1 => div(a, c, b, c),
+            N0_N0 | N1_N0 => Self::with_infsup_raw(rd(prec, b / c), inf()),
+            N0_N1 | N1_N1 => div(b, c, a, d),
+            N0_P0 | N1_P0 => Self::with_infsup_raw(neg_inf(), ru(prec, b / d)),
+            N0_P1 | N1_P1 => div(a, c, b, d),
+            P0_N0 | P1_N0 => Self::with_infsup_raw(neg_inf(), ru(prec, a / c)),
+            P0_N1 | P1_N1 => div(b, d, a, c),
+            P0_P0 | P1_P0 => Self::with_infsup_raw(rd(prec, a / d), inf()),
+            P0_P1 | P1_P1 => div(a, d, b, c),
+        }
+    }
+}
+
+impl Neg for &MpInterval {
+    type Output = MpInterval;
+
+    fn neg(self) -> MpInterval {
+        MpInterval::with_infsup_raw(-self.sup.clone(), -self.inf.clone())
+    }
+}
+
+impl Neg for MpInterval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::with_infsup_raw(-self.sup, -self.inf)
+    }
+}
+
+impl Add for &MpInterval {
+    type Output = MpInterval;
+
+    fn add(self, rhs: Self) -> MpInterval {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return MpInterval::empty(prec);
+        }
+
+        MpInterval::with_infsup_raw(
+            rd(prec, &self.inf + &rhs.inf),
+            ru(prec, &self.sup + &rhs.sup),
+        )
+    }
+}
+
+impl Sub for &MpInterval {
+    type Output = MpInterval;
+
+    fn sub(self, rhs: Self) -> MpInterval {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return MpInterval::empty(prec);
+        }
+
+        MpInterval::with_infsup_raw(
+            rd(prec, &self.inf - &rhs.sup),
+            ru(prec, &self.sup - &rhs.inf),
+        )
+    }
+}
+
+impl Mul for &MpInterval {
+    type Output = MpInterval;
+
+    fn mul(self, rhs: Self) -> MpInterval {
+        self.mul_impl(rhs, self.max_prec(rhs))
+    }
+}
+
+impl Div for &MpInterval {
+    type Output = MpInterval;
+
+    fn div(self, rhs: Self) -> MpInterval {
+        self.div_impl(rhs, self.max_prec(rhs))
+    }
+}
+
+forward_owned_binop!(impl Add, add for MpInterval, MpInterval, MpInterval);
+forward_owned_binop!(impl Sub, sub for MpInterval, MpInterval, MpInterval);
+forward_owned_binop!(impl Mul, mul for MpInterval, MpInterval, MpInterval);
+forward_owned_binop!(impl Div, div for MpInterval, MpInterval, MpInterval);
+forward_owned_op_assign!(impl AddAssign, add_assign for MpInterval, MpInterval, add);
+forward_owned_op_assign!(impl SubAssign, sub_assign for MpInterval, MpInterval, sub);
+forward_owned_op_assign!(impl MulAssign, mul_assign for MpInterval, MpInterval, mul);
+forward_owned_op_assign!(impl DivAssign, div_assign for MpInterval, MpInterval, div);
+
+// Elementary functions
+
+macro_rules! impl_log {
+    ($f:ident, $f_rd:ident, $f_ru:ident) => {
+        impl_log!($f, $f_rd, $f_ru, 0.0);
+    };
+
+    // The domain of the point function is `($dom_inf, ∞)`.
+    ($f:ident, $f_rd:ident, $f_ru:ident, $dom_inf:expr) => {
+        #[doc = concat!("The arbitrary-precision version of [`Interval::", stringify!($f), "`].")]
+        #[must_use]
+        pub fn $f(&self) -> Self {
+            let prec = self.prec();
+            let x = self.intersection(&Self::with_f64s(prec, $dom_inf, f64::INFINITY));
+            if x.is_empty() || x.sup <= $dom_inf {
+                return Self::empty(prec);
+            }
+
+            Self::with_infsup_raw($f_rd(&x.inf), $f_ru(&x.sup))
+        }
+    };
+}
+
+macro_rules! impl_mono_inc {
+    ($f:ident, $f_rd:ident, $f_ru:ident) => {
+        #[doc = concat!("The arbitrary-precision version of [`Interval::", stringify!($f), "`].")]
+        #[must_use]
+        pub fn $f(&self) -> Self {
+            if self.is_empty() {
+                return self.clone();
+            }
+
+            Self::with_infsup_raw($f_rd(&self.inf), $f_ru(&self.sup))
+        }
+    };
+}
+
+// Defines a function whose domain is `[-1, 1]` and that is monotonic.
+macro_rules! impl_unit_dom {
+    ($f:ident, $f_rd:ident, $f_ru:ident, increasing) => {
+        impl_unit_dom!($f, |x: Self| ($f_rd(&x.inf), $f_ru(&x.sup)));
+    };
+
+    ($f:ident, $f_rd:ident, $f_ru:ident, decreasing) => {
+        impl_unit_dom!($f, |x: Self| ($f_rd(&x.sup), $f_ru(&x.inf)));
+    };
+
+    ($f:ident, $bounds:expr) => {
+        #[doc = concat!("The arbitrary-precision version of [`Interval::", stringify!($f), "`].")]
+        #[must_use]
+        pub fn $f(&self) -> Self {
+            let x = self.intersection(&Self::with_f64s(self.prec(), -1.0, 1.0));
+            if x.is_empty() {
+                return x;
+            }
+
+            let (a, b) = $bounds(x);
+            Self::with_infsup_raw(a, b)
+        }
+    };
+}
+
+impl MpInterval {
+    impl_unit_dom!(acos, acos_rd, acos_ru, decreasing);
+    impl_unit_dom!(acos_pi, acos_pi_rd, acos_pi_ru, decreasing);
+
+    /// The arbitrary-precision version of [`Interval::acosh`].
+    #[must_use]
+    pub fn acosh(&self) -> Self {
+        let x = self.intersection(&Self::with_f64s(self.prec(), 1.0, f64::INFINITY));
+        if x.is_empty() {
+            return x;
+        }
+
+        Self::with_infsup_raw(acosh_rd(&x.inf), acosh_ru(&x.sup))
+    }
+
+    /// The arbitrary-precision version of [`Interval::acot`].
+    #[must_use]
+    pub fn acot(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        // acot(x) = atan2(1, x), which is monotonically decreasing.
+        let prec = self.prec();
+        let one = Float::with_val(prec, 1);
+        Self::with_infsup_raw(
+            atan2_rd(&one, &self.sup, prec),
+            atan2_ru(&one, &self.inf, prec),
+        )
+    }
+
+    /// The arbitrary-precision version of [`Interval::acoth`].
+    #[must_use]
+    pub fn acoth(&self) -> Self {
+        let prec = self.prec();
+        let neg = self.intersection(&Self::with_f64s(prec, f64::NEG_INFINITY, -1.0));
+        let pos = self.intersection(&Self::with_f64s(prec, 1.0, f64::INFINITY));
+        let has_neg = !neg.is_empty() && neg.inf < -1;
+        let has_pos = !pos.is_empty() && pos.sup > 1;
+
+        // The point function is decreasing on both branches,
+        // and is negative on the left branch and positive on the right one.
+        match (has_neg, has_pos) {
+            (false, false) => Self::empty(prec),
+            (true, false) => Self::with_infsup_raw(acoth_rd(&neg.sup), acoth_ru(&neg.inf)),
+            (false, true) => Self::with_infsup_raw(acoth_rd(&pos.sup), acoth_ru(&pos.inf)),
+            (true, true) => Self::entire(prec),
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::acsc`].
+    #[must_use]
+    pub fn acsc(&self) -> Self {
+        let prec = self.prec();
+        let neg = self.intersection(&Self::with_f64s(prec, f64::NEG_INFINITY, -1.0));
+        let pos = self.intersection(&Self::with_f64s(prec, 1.0, f64::INFINITY));
+
+        // The point function is decreasing on both branches,
+        // and is negative on the left branch and positive on the right one.
+        match (neg.is_empty(), pos.is_empty()) {
+            (true, true) => Self::empty(prec),
+            (false, true) => Self::with_infsup_raw(acsc_rd(&neg.sup), acsc_ru(&neg.inf)),
+            (true, false) => Self::with_infsup_raw(acsc_rd(&pos.sup), acsc_ru(&pos.inf)),
+            (false, false) => Self::with_infsup_raw(acsc_rd(&neg.sup), acsc_ru(&pos.inf)),
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::asec`].
+    #[must_use]
+    pub fn asec(&self) -> Self {
+        let prec = self.prec();
+        let neg = self.intersection(&Self::with_f64s(prec, f64::NEG_INFINITY, -1.0));
+        let pos = self.intersection(&Self::with_f64s(prec, 1.0, f64::INFINITY));
+
+        // The point function is increasing on both branches,
+        // and takes larger values on the left branch than on the right one.
+        match (neg.is_empty(), pos.is_empty()) {
+            (true, true) => Self::empty(prec),
+            (false, true) => Self::with_infsup_raw(asec_rd(&neg.inf), asec_ru(&neg.sup)),
+            (true, false) => Self::with_infsup_raw(asec_rd(&pos.inf), asec_ru(&pos.sup)),
+            (false, false) => Self::with_infsup_raw(asec_rd(&pos.inf), asec_ru(&neg.sup)),
+        }
+    }
+
+    impl_unit_dom!(asin, asin_rd, asin_ru, increasing);
+    impl_unit_dom!(asin_pi, asin_pi_rd, asin_pi_ru, increasing);
+    impl_mono_inc!(asinh, asinh_rd, asinh_ru);
+    impl_mono_inc!(atan, atan_rd, atan_ru);
+
+    /// The arbitrary-precision version of [`Interval::atan2`].
+    #[must_use]
+    pub fn atan2(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        let frac_pi_2 = Self::frac_pi_2(prec);
+        self.atan2_impl_with(rhs, atan2_rd, atan2_ru, Self::pi(prec), frac_pi_2)
+    }
+
+    /// The arbitrary-precision version of [`Interval::atan2_pi`].
+    #[must_use]
+    pub fn atan2_pi(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        self.atan2_impl_with(
+            rhs,
+            atan2_pi_rd,
+            atan2_pi_ru,
+            Self::with_f64s(prec, 1.0, 1.0),
+            Self::with_f64s(prec, 0.5, 0.5),
+        )
+    }
+
+    // See `Interval::atan2_impl_with`.
+    #[allow(clippy::many_single_char_names)]
+    fn atan2_impl_with(
+        &self,
+        rhs: &Self,
+        f_rd: fn(&Float, &Float, u32) -> Float,
+        f_ru: fn(&Float, &Float, u32) -> Float,
+        pi: Self,
+        frac_pi_2: Self,
+    ) -> Self {
+        let prec = self.max_prec(rhs);
+        let (x, y) = (rhs, self);
+        let (a, b, c, d) = (&x.inf, &x.sup, &y.inf, &y.sup);
+        let zero = || Float::new(prec);
+        let with = |lo: Float, hi: Float| Self::with_infsup_raw(lo, hi);
+
+        use IntervalClass2::*;
+        match x.classify2(y) {
+            E_E | E_M | E_N0 | E_N1 | E_P0 | E_P1 | E_Z | M_E | N0_E | N1_E | P0_E | P1_E | Z_E
+            | Z_Z => Self::empty(prec),
+            M_M | M_N0 | N0_M | N0_N0 | N1_M | N1_N0 => with(-pi.sup.clone(), pi.sup),
+
+            // First quadrant
+            P0_P0 => with(zero(), frac_pi_2.sup),
+            P0_P1 | P1_P0 | P1_P1 | P1_Z | Z_P1 => with(f_rd(c, b, prec), f_ru(d, a, prec)),
+
+            // First & second quadrant
+            M_P0 | M_Z => with(zero(), pi.sup),
+            M_P1 => with(f_rd(c, b, prec), f_ru(c, a, prec)),
+
+            // Second quadrant
+            N0_P0 => with(frac_pi_2.inf, pi.sup),
+            N0_P1 | N1_P1 => with(f_rd(d, b, prec), f_ru(c, a, prec)),
+            N1_P0 => with(f_rd(d, b, prec), pi.sup),
+
+            // Third quadrant
+            N0_N1 | N1_N1 => with(f_rd(d, a, prec), f_ru(c, b, prec)),
+
+            // Third & fourth quadrant
+            M_N1 => with(f_rd(d, a, prec), f_ru(d, b, prec)),
+
+            // Fourth quadrant
+            P0_N0 => with(-frac_pi_2.sup, zero()),
+            P0_N1 | P1_N0 | P1_N1 | Z_N1 => with(f_rd(c, a, prec), f_ru(d, b, prec)),
+
+            // Fourth & first quadrant
+            P0_M | Z_M => with(-frac_pi_2.sup.clone(), frac_pi_2.sup),
+            P1_M => with(f_rd(c, a, prec), f_ru(d, a, prec)),
+
+            // X axis
+            N0_Z | N1_Z => pi,
+            P0_Z => Self::zero(prec),
+
+            // Y axis
+            Z_N0 => -frac_pi_2,
+            Z_P0 => frac_pi_2,
+        }
+    }
+
+    impl_mono_inc!(atan_pi, atan_pi_rd, atan_pi_ru);
+
+    /// The arbitrary-precision version of [`Interval::atanh`].
+    #[must_use]
+    pub fn atanh(&self) -> Self {
+        // As in `Interval::atanh`, ±1 are included in the domain.
+        let x = self.intersection(&Self::with_f64s(self.prec(), -1.0, 1.0));
+        if x.is_empty() || x.sup <= -1 || x.inf >= 1 {
+            return Self::empty(self.prec());
+        }
+
+        Self::with_infsup_raw(atanh_rd(&x.inf), atanh_ru(&x.sup))
+    }
+
+    /// The arbitrary-precision version of [`Interval::compound`].
+    #[must_use]
+    pub fn compound(&self, rhs: i32) -> Self {
+        let x = self.intersection(&Self::with_f64s(self.prec(), -1.0, f64::INFINITY));
+        if x.is_empty() || rhs < 0 && x.sup <= -1 {
+            return Self::empty(self.prec());
+        }
+
+        if rhs < 0 {
+            Self::with_infsup_raw(compound_rd(&x.sup, rhs), compound_ru(&x.inf, rhs))
+        } else {
+            Self::with_infsup_raw(compound_rd(&x.inf, rhs), compound_ru(&x.sup, rhs))
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::cos`].
+    #[must_use]
+    pub fn cos(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        let q_nowrap = (self / &Self::pi(prec)).floor();
+        let n = if a == b {
+            0.0
+        } else {
+            diff_ru(&q_nowrap.inf, &q_nowrap.sup)
+        };
+        let q = rem_euclid_4(&q_nowrap.inf) % 2.0;
+
+        if n == 0.0 {
+            if q == 0.0 {
+                // monotonically decreasing
+                Self::with_infsup_raw(cos_rd(b), cos_ru(a))
+            } else {
+                // monotonically increasing
+                Self::with_infsup_raw(cos_rd(a), cos_ru(b))
+            }
+        } else if n <= 1.0 {
+            if q == 0.0 {
+                // decreasing, then increasing
+                Self::with_infsup_raw(Float::with_val(prec, -1), cos_ru(a).max(&cos_ru(b)))
+            } else {
+                // increasing, then decreasing
+                Self::with_infsup_raw(cos_rd(a).min(&cos_rd(b)), Float::with_val(prec, 1))
+            }
+        } else {
+            Self::with_f64s(prec, -1.0, 1.0)
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::cos_pi`].
+    #[must_use]
+    pub fn cos_pi(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        // The half-periods can be computed exactly.
+        let qa = a.clone().floor();
+        let qb = b.clone().floor();
+        let n = diff_ru(&qa, &qb);
+        let q = rem_euclid_4(&qa) % 2.0;
+
+        if n == 0.0 {
+            if q == 0.0 {
+                // monotonically decreasing
+                Self::with_infsup_raw(cos_pi_rd(b), cos_pi_ru(a))
+            } else {
+                // monotonically increasing
+                Self::with_infsup_raw(cos_pi_rd(a), cos_pi_ru(b))
+            }
+        } else if n <= 1.0 {
+            if q == 0.0 {
+                // decreasing, then increasing
+                Self::with_infsup_raw(Float::with_val(prec, -1), cos_pi_ru(a).max(&cos_pi_ru(b)))
+            } else {
+                // increasing, then decreasing
+                Self::with_infsup_raw(cos_pi_rd(a).min(&cos_pi_rd(b)), Float::with_val(prec, 1))
+            }
+        } else {
+            Self::with_f64s(prec, -1.0, 1.0)
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::cosh`].
+    #[must_use]
+    pub fn cosh(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let (a, b) = (&self.inf, &self.sup);
+        if *b < 0 {
+            Self::with_infsup_raw(cosh_rd(b), cosh_ru(a))
+        } else if *a > 0 {
+            Self::with_infsup_raw(cosh_rd(a), cosh_ru(b))
+        } else {
+            Self::with_infsup_raw(Float::with_val(self.prec(), 1), cosh_ru(&self.mag()))
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::cot`].
+    #[must_use]
+    pub fn cot(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let (a, b) = (&self.inf, &self.sup);
+        if a == b {
+            return if a.is_zero() {
+                Self::empty(self.prec())
+            } else {
+                Self::with_infsup_raw(cot_rd(a), cot_ru(a))
+            };
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(&qa) % 2.0;
+        let (a, b) = Self::pole_at_zero_bounds(a, b);
+
+        if q == 0.0 && n < 2.0 || q == 1.0 && n < 1.0 {
+            // monotonically decreasing
+            Self::with_infsup_raw(cot_rd(&b), cot_ru(&a))
+        } else {
+            Self::entire(self.prec())
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::coth`].
+    #[must_use]
+    pub fn coth(&self) -> Self {
+        self.pole_at_zero_impl_with(coth_rd, coth_ru)
+    }
+
+    /// The arbitrary-precision version of [`Interval::csc`].
+    #[must_use]
+    pub fn csc(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        if a == b {
+            return if a.is_zero() {
+                Self::empty(prec)
+            } else {
+                Self::with_infsup_raw(csc_rd(a), csc_ru(a))
+            };
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(&qa);
+        let (a, b) = Self::pole_at_zero_bounds(a, b);
+
+        if (q == 1.0 || q == 2.0) && n < 1.0 {
+            // monotonically increasing
+            Self::with_infsup_raw(csc_rd(&a), csc_ru(&b))
+        } else if (q == 0.0 || q == 3.0) && n < 1.0 {
+            // monotonically decreasing
+            Self::with_infsup_raw(csc_rd(&b), csc_ru(&a))
+        } else if q == 0.0 && n < 2.0 {
+            // decreasing, then increasing
+            Self::with_infsup_raw(Float::with_val(prec, 1), csc_ru(&a).max(&csc_ru(&b)))
+        } else if q == 2.0 && n < 2.0 {
+            // increasing, then decreasing
+            Self::with_infsup_raw(csc_rd(&a).min(&csc_rd(&b)), Float::with_val(prec, -1))
+        } else {
+            Self::entire(prec)
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::csch`].
+    #[must_use]
+    pub fn csch(&self) -> Self {
+        self.pole_at_zero_impl_with(csch_rd, csch_ru)
+    }
+
+    impl_mono_inc!(exp, exp_rd, exp_ru);
+    impl_mono_inc!(exp10, exp10_rd, exp10_ru);
+    impl_mono_inc!(exp2, exp2_rd, exp2_ru);
+    impl_mono_inc!(exp10m1, exp10m1_rd, exp10m1_ru);
+    impl_mono_inc!(exp2m1, exp2m1_rd, exp2m1_ru);
+    impl_mono_inc!(expm1, expm1_rd, expm1_ru);
+
+    /// The arbitrary-precision version of [`Interval::hypot`].
+    #[must_use]
+    pub fn hypot(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        if self.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        let x = self.abs();
+        let y = rhs.abs();
+        Self::with_infsup_raw(
+            hypot_rd(&x.inf, &y.inf, prec),
+            hypot_ru(&x.sup, &y.sup, prec),
+        )
+    }
+
+    impl_log!(ln, ln_rd, ln_ru);
+    impl_log!(log10, log10_rd, log10_ru);
+    impl_log!(log2, log2_rd, log2_ru);
+    impl_log!(log10p1, log10p1_rd, log10p1_ru, -1.0);
+    impl_log!(log2p1, log2p1_rd, log2p1_ru, -1.0);
+    impl_log!(logp1, logp1_rd, logp1_ru, -1.0);
+
+    /// The arbitrary-precision version of [`Interval::pow`].
+    #[allow(clippy::many_single_char_names)]
+    #[must_use]
+    pub fn pow(&self, rhs: &Self) -> Self {
+        let prec = self.max_prec(rhs);
+        let x = self.intersection(&Self::with_f64s(prec, 0.0, f64::INFINITY));
+        if x.either_empty(rhs) {
+            return Self::empty(prec);
+        }
+
+        let (a, b, c, d) = (&x.inf, &x.sup, &rhs.inf, &rhs.sup);
+        let with = |lo: Float, hi: Float| Self::with_infsup_raw(lo, hi);
+        if *d <= 0 {
+            if b.is_zero() {
+                return Self::empty(prec);
+            }
+
+            if *b < 1 {
+                with(pow_rd(b, d, prec), pow_ru(a, c, prec))
+            } else if *a > 1 {
+                with(pow_rd(b, c, prec), pow_ru(a, d, prec))
+            } else {
+                with(pow_rd(b, c, prec), pow_ru(a, c, prec))
+            }
+        } else if *c > 0 {
+            if *b < 1 {
+                with(pow_rd(a, d, prec), pow_ru(b, c, prec))
+            } else if *a > 1 {
+                with(pow_rd(a, c, prec), pow_ru(b, d, prec))
+            } else {
+                with(pow_rd(a, d, prec), pow_ru(b, d, prec))
+            }
+        } else {
+            if b.is_zero() {
+                return Self::zero(prec);
+            }
+
+            let z_ac = pow_ru(a, c, prec);
+            let z_ad = pow_rd(a, d, prec);
+            let z_bc = pow_rd(b, c, prec);
+            let z_bd = pow_ru(b, d, prec);
+            with(z_ad.min(&z_bc), z_ac.max(&z_bd))
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::powi`].
+    #[must_use]
+    pub fn powi(&self, rhs: i32) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        if rhs < 0 {
+            if a.is_zero() && b.is_zero() {
+                return Self::empty(prec);
+            }
+
+            if rhs % 2 == 0 {
+                Self::with_infsup_raw(pown_rd(&self.mag(), rhs), pown_ru(&self.mig(), rhs))
+            } else if *a < 0 && *b > 0 {
+                Self::entire(prec)
+            } else {
+                let (a, b) = Self::pole_at_zero_bounds(a, b);
+                Self::with_infsup_raw(pown_rd(&b, rhs), pown_ru(&a, rhs))
+            }
+        } else if rhs % 2 == 0 {
+            Self::with_infsup_raw(pown_rd(&self.mig(), rhs), pown_ru(&self.mag(), rhs))
+        } else {
+            Self::with_infsup_raw(pown_rd(a, rhs), pown_ru(b, rhs))
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::rootn`].
+    #[must_use]
+    pub fn rootn(&self, rhs: i32) -> Self {
+        let prec = self.prec();
+        if self.is_empty() || rhs == 0 {
+            return Self::empty(prec);
+        }
+
+        if rhs % 2 == 0 {
+            let x = self.intersection(&Self::with_f64s(prec, 0.0, f64::INFINITY));
+            if x.is_empty() || rhs < 0 && x.sup <= 0 {
+                return Self::empty(prec);
+            }
+
+            if rhs < 0 {
+                Self::with_infsup_raw(rootn_rd(&x.sup, rhs), rootn_ru(&x.inf, rhs))
+            } else {
+                Self::with_infsup_raw(rootn_rd(&x.inf, rhs), rootn_ru(&x.sup, rhs))
+            }
+        } else {
+            let (a, b) = (&self.inf, &self.sup);
+            if rhs < 0 {
+                if a.is_zero() && b.is_zero() {
+                    Self::empty(prec)
+                } else if *a < 0 && *b > 0 {
+                    Self::entire(prec)
+                } else {
+                    let (a, b) = Self::pole_at_zero_bounds(a, b);
+                    Self::with_infsup_raw(rootn_rd(&b, rhs), rootn_ru(&a, rhs))
+                }
+            } else {
+                Self::with_infsup_raw(rootn_rd(a, rhs), rootn_ru(b, rhs))
+            }
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::rsqrt`].
+    #[must_use]
+    pub fn rsqrt(&self) -> Self {
+        let x = self.intersection(&Self::with_f64s(self.prec(), 0.0, f64::INFINITY));
+        if x.is_empty() || x.sup <= 0 {
+            return Self::empty(self.prec());
+        }
+
+        Self::with_infsup_raw(rsqrt_rd(&x.sup), rsqrt_ru(&x.inf))
+    }
+
+    /// The arbitrary-precision version of [`Interval::sec`].
+    #[must_use]
+    pub fn sec(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        if a == b {
+            return Self::with_infsup_raw(sec_rd(a), sec_ru(a));
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(&qa);
+
+        if (q == 0.0 || q == 1.0) && n < 1.0 {
+            // monotonically increasing
+            Self::with_infsup_raw(sec_rd(a), sec_ru(b))
+        } else if (q == 2.0 || q == 3.0) && n < 1.0 {
+            // monotonically decreasing
+            Self::with_infsup_raw(sec_rd(b), sec_ru(a))
+        } else if q == 1.0 && n < 2.0 {
+            // increasing, then decreasing
+            Self::with_infsup_raw(sec_rd(a).min(&sec_rd(b)), Float::with_val(prec, -1))
+        } else if q == 3.0 && n < 2.0 {
+            // decreasing, then increasing
+            Self::with_infsup_raw(Float::with_val(prec, 1), sec_ru(a).max(&sec_ru(b)))
+        } else {
+            Self::entire(prec)
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::sech`].
+    #[must_use]
+    pub fn sech(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let (a, b) = (&self.inf, &self.sup);
+        if *b < 0 {
+            Self::with_infsup_raw(sech_rd(a), sech_ru(b))
+        } else if *a > 0 {
+            Self::with_infsup_raw(sech_rd(b), sech_ru(a))
+        } else {
+            Self::with_infsup_raw(sech_rd(&self.mag()), Float::with_val(self.prec(), 1))
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::sin`].
+    #[must_use]
+    pub fn sin(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        let q_nowrap = (self / &Self::frac_pi_2(prec)).floor();
+        let n = if a == b {
+            0.0
+        } else {
+            diff_ru(&q_nowrap.inf, &q_nowrap.sup)
+        };
+        let q = rem_euclid_4(&q_nowrap.inf);
+        self.sin_impl_with(q, n, sin_rd, sin_ru)
+    }
+
+    /// The arbitrary-precision version of [`Interval::sin_pi`].
+    #[must_use]
+    pub fn sin_pi(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        // The quadrants can be computed exactly.
+        let qa = Float::with_val(prec, &self.inf * 2u32).floor();
+        let qb = Float::with_val(prec, &self.sup * 2u32).floor();
+        let n = diff_ru(&qa, &qb);
+        let q = rem_euclid_4(&qa);
+        self.sin_impl_with(q, n, sin_pi_rd, sin_pi_ru)
+    }
+
+    // Evaluates sin or sin_pi, where `q` is the index of the quadrant that contains
+    // the lower bound of `self`, and `n` is the number of the boundaries of quadrants
+    // between the bounds of `self`.
+    fn sin_impl_with(
+        &self,
+        q: f64,
+        n: f64,
+        f_rd: fn(&Float) -> Float,
+        f_ru: fn(&Float) -> Float,
+    ) -> Self {
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        if q == 0.0 && n < 1.0 || q == 3.0 && n < 2.0 {
+            // monotonically increasing
+            Self::with_infsup_raw(f_rd(a), f_ru(b))
+        } else if q == 1.0 && n < 2.0 || q == 2.0 && n < 1.0 {
+            // monotonically decreasing
+            Self::with_infsup_raw(f_rd(b), f_ru(a))
+        } else if q == 0.0 && n < 3.0 || q == 3.0 && n < 4.0 {
+            // increasing, then decreasing
+            Self::with_infsup_raw(f_rd(a).min(&f_rd(b)), Float::with_val(prec, 1))
+        } else if q == 1.0 && n < 4.0 || q == 2.0 && n < 3.0 {
+            // decreasing, then increasing
+            Self::with_infsup_raw(Float::with_val(prec, -1), f_ru(a).max(&f_ru(b)))
+        } else {
+            Self::with_f64s(prec, -1.0, 1.0)
+        }
+    }
+
+    impl_mono_inc!(sinh, sinh_rd, sinh_ru);
+
+    /// The arbitrary-precision version of [`Interval::tan`].
+    #[must_use]
+    pub fn tan(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let (a, b) = (&self.inf, &self.sup);
+        if a == b {
+            return Self::with_infsup_raw(tan_rd(a), tan_ru(a));
+        }
+
+        let (qa, n) = self.quadrant_span();
+        let q = rem_euclid_4(&qa) % 2.0;
+
+        if q == 0.0 && n < 1.0 || q == 1.0 && n < 2.0 {
+            Self::with_infsup_raw(tan_rd(a), tan_ru(b))
+        } else {
+            Self::entire(self.prec())
+        }
+    }
+
+    /// The arbitrary-precision version of [`Interval::tan_pi`].
+    #[must_use]
+    pub fn tan_pi(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+
+        let prec = self.prec();
+        // Returns `true` if x/2 is a pole of the point function, i.e., x is an odd integer.
+        let is_pole = |x: &Float| x.is_integer() && rem_euclid_4(x) % 2.0 == 1.0;
+
+        let (a, b) = (&self.inf, &self.sup);
+        let a2 = Float::with_val(prec, a * 2u32);
+        let b2 = Float::with_val(prec, b * 2u32);
+        if a == b {
+            return if is_pole(&a2) {
+                Self::empty(prec)
+            } else {
+                Self::with_infsup_raw(tan_pi_rd(a), tan_pi_ru(a))
+            };
+        }
+        // Every number whose magnitude is at least this bound is an even integer.
+        let max_exact = Float::with_val(prec, 1) << prec;
+        if *a2.as_abs() >= max_exact || *b2.as_abs() >= max_exact {
+            // There is a pole between a and b.
+            return Self::entire(prec);
+        }
+
+        // The least odd integer greater than 2a, which is computed exactly.
+        let k = Float::with_val(prec, a2.floor_ref()) + 1u32;
+        let k = if rem_euclid_4(&k) % 2.0 == 0.0 {
+            k + 1u32
+        } else {
+            k
+        };
+        if k < b2 {
+            return Self::entire(prec);
+        }
+
+        let inf = || Float::with_val(prec, Special::Infinity);
+        let neg_inf = || Float::with_val(prec, Special::NegInfinity);
+        match (is_pole(&a2), is_pole(&b2)) {
+            (false, false) => Self::with_infsup_raw(tan_pi_rd(a), tan_pi_ru(b)),
+            (true, false) => Self::with_infsup_raw(neg_inf(), tan_pi_ru(b)),
+            (false, true) => Self::with_infsup_raw(tan_pi_rd(a), inf()),
+            (true, true) => Self::entire(prec),
+        }
+    }
+
+    impl_mono_inc!(tanh, tanh_rd, tanh_ru);
+
+    // See `Interval::quadrant_span`. The index is returned as is.
+    fn quadrant_span(&self) -> (Float, f64) {
+        let prec = self.prec();
+        let frac_pi_2 = Self::frac_pi_2(prec);
+        let q_nowrap = (self / &frac_pi_2).floor();
+        let qa = q_nowrap.inf;
+        let qb = q_nowrap.sup;
+        // If the condition holds, b lies in the quadrant qb - 1 (or is zero).
+        let cont = qb.is_finite()
+            && self.sup <= (&Self::with_infsup_raw(qb.clone(), qb.clone()) * &frac_pi_2).inf;
+        let n = diff_ru(&qa, &qb);
+        let n = if cont { n - 1.0 } else { n };
+        (qa, n)
+    }
+
+    // See `Interval::pole_at_zero_bounds`.
+    fn pole_at_zero_bounds(a: &Float, b: &Float) -> (Float, Float) {
+        let a = if a.is_zero() {
+            Float::new(a.prec())
+        } else {
+            a.clone()
+        };
+        let b = if b.is_zero() {
+            Float::with_val(b.prec(), Special::NegZero)
+        } else {
+            b.clone()
+        };
+        (a, b)
+    }
+
+    // See `Interval::pole_at_zero_impl_with`.
+    fn pole_at_zero_impl_with(&self, f_rd: fn(&Float) -> Float, f_ru: fn(&Float) -> Float) -> Self {
+        let prec = self.prec();
+        let (a, b) = (&self.inf, &self.sup);
+        if self.is_empty() || a.is_zero() && b.is_zero() {
+            return Self::empty(prec);
+        }
+        if *a < 0 && *b > 0 {
+            return Self::entire(prec);
+        }
+
+        let (a, b) = Self::pole_at_zero_bounds(a, b);
+        Self::with_infsup_raw(f_rd(&b), f_ru(&a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rug::Float;
+    use Interval as I;
+    use MpInterval as M;
+
+    fn m(prec: u32, a: f64, b: f64) -> M {
+        M::try_from((Float::with_val(prec, a), Float::with_val(prec, b))).unwrap()
+    }
+
+    #[test]
+    fn conversion() {
+        let x = const_interval!(0.1, 0.2);
+        let y = M::from(x);
+        assert_eq!(y.prec(), 53);
+        assert_eq!(I::from(&y), x);
+        assert_eq!(I::from(y.with_prec(200)), x);
+        assert!(y.with_prec(10).subset(&m(10, 0.09, 0.21)));
+        assert!(y.subset(&y.with_prec(10)));
+        assert_eq!(I::from(M::from(I::EMPTY)), I::EMPTY);
+        assert_eq!(I::from(M::from(I::ENTIRE)), I::ENTIRE);
+        assert!(M::try_from((Float::with_val(53, 2), Float::with_val(53, 1))).is_err());
+
+        // Bounds beyond the range of `f64` are rounded outward.
+        let huge = Float::with_val(53, f64::MAX) * 4u32;
+        let x = M::try_from((huge.clone(), huge)).unwrap();
+        assert_eq!(I::from(&x), const_interval!(f64::MAX, f64::INFINITY));
+    }
+
+    #[test]
+    fn arith() {
+        let prec = 200;
+        let x = m(prec, 1.0, 2.0);
+        let y = m(prec, -3.0, 4.0);
+        assert_eq!(&x + &y, m(prec, -2.0, 6.0));
+        assert_eq!(&x - &y, m(prec, -3.0, 5.0));
+        assert_eq!(&x * &y, m(prec, -6.0, 8.0));
+        assert_eq!(&y / &x, m(prec, -3.0, 4.0));
+        assert_eq!(&x / &y, M::entire(prec));
+        assert_eq!(-x.clone(), m(prec, -2.0, -1.0));
+        assert_eq!(y.sqr(), m(prec, 0.0, 16.0));
+        assert_eq!(y.abs(), m(prec, 0.0, 4.0));
+        assert_eq!(x.recip(), m(prec, 0.5, 1.0));
+        assert_eq!(m(prec, 0.0, 0.0) * M::entire(prec), m(prec, 0.0, 0.0));
+        assert_eq!(
+            m(prec, 1.0, 2.0).mul_rev_to_pair(&m(prec, -1.0, 1.0)),
+            [m(prec, -1.0, 1.0), M::empty(prec)]
+        );
+        assert_eq!(
+            m(prec, -1.0, 1.0).mul_rev_to_pair(&m(prec, 1.0, 2.0)),
+            [
+                m(prec, f64::NEG_INFINITY, -1.0),
+                m(prec, 1.0, f64::INFINITY)
+            ]
+        );
+        assert_eq!(m(prec, 1.0, 2.0).mul_add(&y, &x), m(prec, -5.0, 10.0));
+        assert_eq!(m(prec, 4.0, 6.0).cancel_minus(&x), m(prec, 3.0, 4.0));
+        assert_eq!(m(prec, 4.0, 4.5).cancel_minus(&x), M::entire(prec));
+        assert!(M::empty(prec).sqrt().is_empty());
+
+        // The enclosure of 1/3 is as tight as the precision allows.
+        let third = m(prec, 1.0, 1.0) / m(prec, 3.0, 3.0);
+        assert_eq!(third.prec(), prec);
+        assert!(third.wid() < 1e-59);
+        assert!(!third.is_singleton());
+
+        // The precision of the result is the larger one.
+        let mut z = m(10, 1.0, 1.0);
+        z += m(100, 3.0, 3.0);
+        assert_eq!(z.prec(), 100);
+    }
+
+    #[test]
+    fn elementary() {
+        let prec = 256;
+        let sqrt2 = m(prec, 2.0, 2.0).sqrt();
+        assert!(sqrt2.sqr().contains(&Float::with_val(prec, 2)));
+        assert!(sqrt2.wid() < 1e-75);
+
+        // Each function agrees with the f64 version in the sense that the results intersect,
+        // and the results are tighter.
+        let xs = [
+            const_interval!(-0.5, 0.25),
+            const_interval!(0.5, 0.75),
+            const_interval!(1.5, 3.0),
+            const_interval!(-10.0, -4.0),
+            const_interval!(-1.0, 1.0),
+            const_interval!(2.0, 2.0),
+            const_interval!(0.0, 0.0),
+            const_interval!(0.0, f64::INFINITY),
+            I::ENTIRE,
+            I::EMPTY,
+        ];
+        macro_rules! check {
+            ($($f:ident),*) => {
+                $(
+                    for x in xs {
+                        let y = M::from(x).with_prec(prec).$f();
+                        let z = x.$f();
+                        assert_eq!(y.is_empty(), z.is_empty(), "{}({})", stringify!($f), x);
+                        assert!(I::from(&y).subset(z), "{}({})", stringify!($f), x);
+                    }
+                )*
+            };
+        }
+        check!(
+            acos, acos_pi, acosh, acot, acoth, acsc, asec, asin, asin_pi, asinh, atan, atan_pi,
+            atanh, cos, cos_pi, cosh, cot, coth, csc, csch, exp, exp10, exp10m1, exp2, exp2m1,
+            expm1, ln, log10, log10p1, log2, log2p1, logp1, rsqrt, sec, sech, sin, sin_pi, sinh,
+            tan, tan_pi, tanh
+        );
+        for x in xs {
+            for y in xs {
+                let (mx, my) = (M::from(x).with_prec(prec), M::from(y).with_prec(prec));
+                assert!(I::from(mx.atan2(&my)).subset(x.atan2(y)));
+                assert!(I::from(mx.atan2_pi(&my)).subset(x.atan2_pi(y)));
+                assert!(I::from(mx.hypot(&my)).subset(x.hypot(y)));
+                assert!(I::from(mx.pow(&my)).subset(x.pow(y)));
+                assert!(I::from(&mx * &my).subset(x * y));
+                assert!(I::from(&mx / &my).subset(x / y));
+            }
+            for n in -3..=3 {
+                let mx = M::from(x).with_prec(prec);
+                assert!(I::from(mx.powi(n)).subset(x.powi(n)));
+                assert!(I::from(mx.rootn(n)).subset(x.rootn(n)));
+                assert!(I::from(mx.compound(n)).subset(x.compound(n)));
+            }
+        }
+
+        // Huge arguments are handled with the precision of the interval.
+        let x = M::parse("[1e30, 1e30]", prec).unwrap();
+        let y = x.sin();
+        assert!(y.wid() < 1e-70);
+        assert!(!y.is_singleton());
+        let x = M::parse("[1e30, 1.0000000001e30]", prec).unwrap();
+        assert_eq!(x.sin(), m(prec, -1.0, 1.0));
+    }
+
+    #[test]
+    fn set_ops() {
+        let prec = 100;
+        let x = m(prec, 0.0, 3.0);
+        let y = m(prec, 1.0, 2.0);
+        assert_eq!(x.difference(&y), [m(prec, 0.0, 1.0), m(prec, 2.0, 3.0)]);
+        assert_eq!(
+            x.complement(),
+            [m(prec, f64::NEG_INFINITY, 0.0), m(prec, 3.0, f64::INFINITY)]
+        );
+        assert_eq!(x.intersection(&y), y);
+        assert_eq!(y.convex_hull(&m(prec, 5.0, 6.0)), m(prec, 1.0, 6.0));
+        assert!(x.intersection(&m(prec, 4.0, 5.0)).is_empty());
+        assert_eq!(x.overlap(&y), Overlap::Contains);
+        assert_eq!(M::empty(prec).overlap(&y), Overlap::FirstEmpty);
+        assert!(y.subset(&x));
+        assert!(y.interior(&x));
+        assert!(!x.interior(&x));
+        assert!(M::entire(prec).interior(&M::entire(prec)));
+        assert!(y.less(&m(prec, 1.0, 5.0)));
+        assert!(!y.strict_less(&m(prec, 1.0, 5.0)));
+        assert!(m(prec, 0.0, 1.0).precedes(&y));
+        assert!(!m(prec, 0.0, 1.0).strict_precedes(&y));
+        assert!(M::empty(prec).less(&M::empty(prec)));
+        assert!(!M::empty(prec).less(&y));
+        assert!(x.contains(&Float::with_val(prec, 3)));
+        assert!(!M::entire(prec).contains(&Float::with_val(prec, f64::INFINITY)));
+        assert_eq!(m(prec, -1.5, 2.5).floor(), m(prec, -2.0, 2.0));
+        assert_eq!(m(prec, -1.5, 2.5).round(), m(prec, -2.0, 3.0));
+        assert_eq!(m(prec, -1.5, 2.5).round_ties_even(), m(prec, -2.0, 2.0));
+        assert_eq!(m(prec, -1.5, 0.0).sign(), m(prec, -1.0, 0.0));
+    }
+
+    #[test]
+    fn numeric() {
+        let prec = 100;
+        let x = m(prec, -1.0, 3.0);
+        assert_eq!(x.inf(), -1);
+        assert_eq!(x.sup(), 3);
+        assert_eq!(x.mid(), 1);
+        assert_eq!(x.wid(), 4);
+        assert_eq!(x.rad(), 2);
+        assert_eq!(x.mag(), 3);
+        assert_eq!(x.mig(), 0);
+        let x = m(prec, 0.0, f64::INFINITY);
+        assert!(x.inf().is_zero() && x.inf().is_sign_negative());
+        assert!(x.mid().is_finite() && x.mid() > f64::MAX);
+        assert!(M::empty(prec).mid().is_nan());
+        assert_eq!(M::empty(prec).inf(), f64::INFINITY);
+    }
+
+    #[test]
+    fn parse_format() {
+        let x = M::parse("[0.1, 0.2]", 100).unwrap();
+        assert_eq!(x.prec(), 100);
+        assert!(I::from(&x).subset(interval!("[0.1, 0.2]").unwrap()));
+        assert_eq!(format!("{:.6}", x), "[0.099999,0.200001]");
+        assert_eq!(
+            format!("{:.30}", x),
+            "[0.099999999999999999999999999999,0.200000000000000000000000000001]"
+        );
+        assert_eq!(format!("{}", M::parse("[empty]", 100).unwrap()), "[empty]");
+        assert_eq!(format!("{}", M::entire(100)), "[entire]");
+        assert_eq!(
+            format!("{:.6e}", m(100, 0.0, 1.0)),
+            "[-0.000000e+00,1.000000e+00]"
+        );
+        assert!(M::parse("[1, 0]", 100).is_err());
+        assert!(M::parse("[", 100).is_err());
+    }
+}
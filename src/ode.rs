@@ -0,0 +1,359 @@
+//! Validated integration of autonomous ordinary differential equations.
+//!
+//! # Examples
+//!
+//! ```
+//! use inari::{const_interval as c, ode, DualInterval, IntervalVector as V};
+//! // x' = y, y' = -x
+//! let f = |x: &[DualInterval<2>; 2]| [x[1], -x[0]];
+//! let x0 = V::new(vec![c!(1.0, 1.0), c!(0.0, 0.0)]);
+//! let tr = ode::lohner(f, &x0, 0.0, 1.0, 0.01);
+//! assert!(tr.is_complete());
+//! let x1 = tr.steps().last().unwrap().end();
+//! assert!(x1[0].contains(1f64.cos()) && x1[1].contains(-1f64.sin()));
+//! assert!(x1[0].wid() < 1e-4 && x1[1].wid() < 1e-4);
+//! ```
+use crate::{
+    interval::*,
+    simd::sqrt1_ru,
+    vector::{check_len, point},
+    DualInterval, IntervalMatrix, IntervalVector,
+};
+use alloc::{vec, vec::Vec};
+
+// The step size is never less than `h` / 2^MAX_HALVING.
+const MAX_HALVING: u32 = 20;
+
+// The maximum number of iterations for finding an a priori enclosure.
+const MAX_PICARD_ITER: usize = 10;
+
+/// A step of a validated integration.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Step {
+    time: Interval,
+    enclosure: IntervalVector,
+    end: IntervalVector,
+}
+
+impl Step {
+    /// Returns the enclosure of the solutions at the end of the step.
+    pub fn end(&self) -> &IntervalVector {
+        &self.end
+    }
+
+    /// Returns the enclosure of the solutions over the whole step.
+    pub fn enclosure(&self) -> &IntervalVector {
+        &self.enclosure
+    }
+
+    /// Returns the time interval $\[t_j, t_{j+1}\]$ of the step.
+    pub fn time(&self) -> Interval {
+        self.time
+    }
+}
+
+/// The steps of a validated integration.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Trajectory {
+    steps: Vec<Step>,
+    complete: bool,
+}
+
+impl Trajectory {
+    /// Consumes `self` and returns the steps.
+    pub fn into_steps(self) -> Vec<Step> {
+        self.steps
+    }
+
+    /// Returns `true` if the integration has reached the final time.
+    ///
+    /// Otherwise, the steps cover only the beginning of the time interval.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Returns the steps in chronological order.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+}
+
+/// Integrates the autonomous system $x' = f(x)$ from time `t0` to `t1` with the initial values
+/// in the box `x0` by the Lohner method, and returns enclosures of all solutions.
+///
+/// The closure `f` must evaluate the vector field $f$ with [`DualInterval`], so that enclosures
+/// of the values and the Jacobian matrix of $f$ are obtained at once. A nonautonomous system can
+/// be integrated by adding the time as a state variable with $t' = 1$.
+///
+/// Each step from $t_j$ to $t_{j+1} = t_j + h$ is performed as follows:
+///
+/// 1. An a priori enclosure $B$ of the solutions over $\[t_j, t_{j+1}\]$ is computed by
+///    verifying $X_j + \[0, h\] f(B) ⊆ B$ (the Picard–Lindelöf operator), where $X_j$ is the
+///    enclosure at $t_j$. If the verification fails, $h$ is halved.
+/// 2. The solutions at $t_{j+1}$ are enclosed by the Taylor expansion of order one
+///    $$
+///    x(t_{j+1}) ∈ m + h f(m) + (I + h J(X_j)) (x(t_j) - m) + \frac{h^2}{2} J(B) f(B),
+///    $$
+///    where $m$ is a point, and $J$ is the Jacobian matrix of $f$.
+/// 3. To reduce the wrapping effect, the set $x(t_j) - m$ is represented as $A_j r_j$,
+///    where $A_j$ is a matrix and $r_j$ is an interval vector. The matrix $A_{j+1}$ is
+///    obtained by the QR decomposition of the midpoint of $(I + h J(X_j)) A_j$ with the columns
+///    sorted in descending order of their norms multiplied by the widths of $r_j$.
+///
+/// After a successful step, the step size is doubled up to `h`.
+/// The integration stops if the step size becomes less than $2^{-20}$ times `h` or the enclosure
+/// becomes unbounded, which is reported by [`Trajectory::is_complete`].
+///
+/// # Panics
+///
+/// Panics if `x0.len() != N`, `t0` or `t1` is not finite, `t0 > t1`, or `h` is not positive.
+pub fn lohner<const N: usize, F>(f: F, x0: &IntervalVector, t0: f64, t1: f64, h: f64) -> Trajectory
+where
+    F: Fn(&[DualInterval<N>; N]) -> [DualInterval<N>; N],
+{
+    check_len(x0.len(), N);
+    assert!(
+        t0.is_finite() && t1.is_finite() && t0 <= t1,
+        "the time interval must be bounded and nonempty"
+    );
+    assert!(h > 0.0, "the step size must be positive");
+
+    let eval = |x: &IntervalVector| {
+        let xs = core::array::from_fn(|i| x[i]);
+        let ys = f(&DualInterval::variables(xs));
+        let fx = ys.iter().map(|y| y.value()).collect::<IntervalVector>();
+        let jx = IntervalMatrix::new(N, N, ys.iter().flat_map(|y| y.grad()).collect());
+        (fx, jx)
+    };
+
+    let mut steps = vec![];
+    if !x0.iter().all(|x| x.is_common_interval()) {
+        return Trajectory {
+            steps,
+            complete: false,
+        };
+    }
+
+    let mut x = x0.clone();
+    let mut m = x.mid();
+    let mut a = IntervalMatrix::identity(N);
+    let mut r = x0 - &IntervalVector::from_points(&m);
+    let mut t = t0;
+    let mut step = h;
+    let min_step = h / (1u32 << MAX_HALVING) as f64;
+    while t < t1 {
+        let mut halved = false;
+        let (t_next, hh, b) = loop {
+            let t_next = if step >= t1 - t { t1 } else { t + step };
+            let hh = point(t_next) - point(t);
+            if t_next > t {
+                if let Some(b) = a_priori(&eval, &x, hh) {
+                    break (t_next, hh, b);
+                }
+            }
+            step /= 2.0;
+            halved = true;
+            if step < min_step {
+                return Trajectory {
+                    steps,
+                    complete: false,
+                };
+            }
+        };
+
+        let (fb, jb) = eval(&b);
+        let mi = IntervalVector::from_points(&m);
+        let (fm, _) = eval(&mi);
+        let (_, jx) = eval(&x.convex_hull(&mi));
+
+        // c = m + h f(m) + h²/2 J(B) f(B)
+        let mut c = &jb * &fb;
+        c = c.scale(hh.sqr() * Interval::with_infsup_raw(0.5, 0.5));
+        c.axpy(hh, &fm);
+        c = &c + &mi;
+        let s = &IntervalMatrix::identity(N) + &jx.scale(hh);
+        let sa = &s * &a;
+        let m_next = c.mid();
+        if !m_next.iter().all(|x| x.is_finite()) {
+            break;
+        }
+        let mi_next = IntervalVector::from_points(&m_next);
+        let v = &c - &mi_next;
+        let direct = &(&mi_next + &(&sa * &r)) + &v;
+
+        let (a_next, a_inv) = basis(&sa, &r);
+        let r_next = &(&(&a_inv * &sa) * &r) + &(&a_inv * &v);
+        let x_next = (&mi_next + &(&a_next * &r_next))
+            .intersection(&direct)
+            .intersection(&b);
+        if !x_next.iter().all(|x| x.is_common_interval()) {
+            break;
+        }
+
+        steps.push(Step {
+            time: Interval::with_infsup_raw(t, t_next),
+            enclosure: b,
+            end: x_next.clone(),
+        });
+        x = x_next;
+        m = m_next;
+        a = a_next;
+        r = r_next;
+        t = t_next;
+        if !halved {
+            step = f64::min(2.0 * step, h);
+        }
+    }
+
+    Trajectory {
+        complete: t == t1,
+        steps,
+    }
+}
+
+// Returns an a priori enclosure of the solutions over [0, h] with the initial values in `x`.
+fn a_priori<E>(eval: &E, x: &IntervalVector, h: Interval) -> Option<IntervalVector>
+where
+    E: Fn(&IntervalVector) -> (IntervalVector, IntervalMatrix),
+{
+    let h0 = h.convex_hull(Interval::zero());
+    let mut b = x + &eval(x).0.scale(h0);
+    for _ in 0..MAX_PICARD_ITER {
+        if !b.iter().all(|x| x.is_common_interval()) {
+            return None;
+        }
+        let b_inflated = b.iter().map(|&x| inflate(x)).collect::<IntervalVector>();
+        b = x + &eval(&b_inflated).0.scale(h0);
+        if b.subset(&b_inflated) {
+            // The solutions stay in `b_inflated`, and thus in `b`.
+            return Some(b);
+        }
+    }
+    None
+}
+
+// Returns a basis matrix and an enclosure of its inverse for representing the set `a r`.
+fn basis(a: &IntervalMatrix, r: &IntervalVector) -> (IntervalMatrix, IntervalMatrix) {
+    let n = r.len();
+    let identity = || (IntervalMatrix::identity(n), IntervalMatrix::identity(n));
+    let ma = a.mid();
+    let col = |j: usize| (0..n).map(|i| ma[i * n + j]).collect::<Vec<_>>();
+    let norm = |x: &[f64]| sqrt1_ru(x.iter().map(|x| x * x).sum::<f64>());
+
+    let mut order = (0..n)
+        .map(|j| (j, norm(&col(j)) * r[j].wid()))
+        .collect::<Vec<_>>();
+    order.sort_by(|(_, x), (_, y)| y.total_cmp(x));
+
+    // The modified Gram–Schmidt orthogonalization applied twice.
+    let mut qs = Vec::<Vec<f64>>::with_capacity(n);
+    for (j, _) in order {
+        let mut v = col(j);
+        for _ in 0..2 {
+            for q in &qs {
+                let d = q.iter().zip(&v).map(|(q, v)| q * v).sum::<f64>();
+                for (v, q) in v.iter_mut().zip(q) {
+                    *v -= d * q;
+                }
+            }
+        }
+        let nv = norm(&v);
+        if !nv.is_finite() || nv == 0.0 {
+            return identity();
+        }
+        qs.push(v.iter().map(|v| v / nv).collect());
+    }
+
+    let q = IntervalMatrix::from_points(
+        n,
+        n,
+        &(0..n * n).map(|k| qs[k % n][k / n]).collect::<Vec<_>>(),
+    );
+    let y = q.transpose();
+
+    // Since Q⁻¹ = (I - E)⁻¹ Y, where E = I - Y Q, ‖Q⁻¹ - Y‖ ≤ ‖E‖ ‖Y‖ / (1 - ‖E‖) holds.
+    let e = (&IntervalMatrix::identity(n) - &(&y * &q)).norm_inf().sup();
+    if e.is_nan() || e >= 1.0 {
+        return identity();
+    }
+    let d = (point(e) * y.norm_inf() / (point(1.0) - point(e))).sup();
+    let d = Interval::with_infsup_raw(-d, d);
+    let q_inv = IntervalMatrix::new(n, n, y.as_slice().iter().map(|&y| y + d).collect());
+    (q, q_inv)
+}
+
+fn inflate(x: Interval) -> Interval {
+    let d = point(0.1) * point(x.wid()) + point(f64::MIN_POSITIVE);
+    x + Interval::with_infsup_raw(-d.sup(), d.sup())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use Interval as I;
+    use IntervalVector as V;
+
+    #[test]
+    fn linear() {
+        // x' = -x
+        let f = |x: &[DualInterval; 1]| [-x[0]];
+        let x0 = V::new(vec![const_interval!(1.0, 1.0)]);
+        let tr = lohner(f, &x0, 0.0, 1.0, 0.01);
+        assert!(tr.is_complete());
+        let steps = tr.steps();
+        assert_eq!(steps[0].time(), const_interval!(0.0, 0.01));
+        assert_eq!(steps.last().unwrap().time().sup(), 1.0);
+        for s in steps {
+            assert!(s.end().subset(s.enclosure()));
+            for t in [s.time().inf(), s.time().sup()] {
+                assert!(s.enclosure()[0].contains((-t).exp()));
+            }
+        }
+        let x1 = steps.last().unwrap().end()[0];
+        assert!(x1.contains((-1f64).exp()));
+        assert!(x1.wid() < 1e-4);
+    }
+
+    #[test]
+    fn rotation() {
+        // x' = y, y' = -x, for which the naive interval method suffers from the wrapping effect.
+        let f = |x: &[DualInterval<2>; 2]| [x[1], -x[0]];
+        let x0 = V::new(vec![const_interval!(0.9, 1.1), const_interval!(-0.1, 0.1)]);
+        let t1 = 2.0 * std::f64::consts::PI;
+        let tr = lohner(f, &x0, 0.0, t1, 0.05);
+        assert!(tr.is_complete());
+        let x1 = tr.steps().last().unwrap().end();
+        assert!(x1[0].wid() < 0.35 && x1[1].wid() < 0.35);
+        assert!(x1[0].contains(1.0) && x1[1].contains(0.0));
+    }
+
+    #[test]
+    fn blow_up() {
+        // x' = x², whose solution x(t) = 1 / (1 - t) blows up at t = 1.
+        let f = |x: &[DualInterval; 1]| [x[0].sqr()];
+        let x0 = V::new(vec![const_interval!(1.0, 1.0)]);
+        let tr = lohner(f, &x0, 0.0, 2.0, 0.01);
+        assert!(!tr.is_complete());
+        let last = tr.steps().last().unwrap();
+        assert!(last.time().sup() < 1.0);
+        let t = last.time().sup();
+        assert!(last.end()[0].contains(1.0 / (1.0 - t)));
+        // The step size does not fall below h / 2²⁰ in total.
+        assert!(tr
+            .steps()
+            .iter()
+            .all(|s| s.time().wid() >= 0.01 / (1 << 20) as f64));
+
+        let tr = lohner(f, &V::new(vec![I::ENTIRE]), 0.0, 1.0, 0.01);
+        assert!(!tr.is_complete());
+        assert!(tr.steps().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimension_mismatch() {
+        let f = |x: &[DualInterval<2>; 2]| [x[1], -x[0]];
+        let _ = lohner(f, &V::zeros(3), 0.0, 1.0, 0.01);
+    }
+}
@@ -0,0 +1,261 @@
+//! Rigorous global minimization of multivariate functions by branch and bound.
+//!
+//! # Examples
+//!
+//! ```
+//! use inari::{const_interval as c, optimize, IntervalBox as B};
+//! // f(x, y) = (x - 1)² + (y + 2)² + 3
+//! let f = |x: &B| (x[0] - c!(1.0, 1.0)).sqr() + (x[1] + c!(2.0, 2.0)).sqr() + c!(3.0, 3.0);
+//! let m = optimize::minimize(f, &B::new(vec![c!(-10.0, 10.0); 2]), 1e-6);
+//! assert!(m.enclosure().contains(3.0));
+//! assert!(m.enclosure().wid() < 1e-10);
+//! assert!(m.boxes().iter().any(|x| x[0].contains(1.0) && x[1].contains(-2.0)));
+//! ```
+use crate::{interval::*, IntervalVector};
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+
+/// An enclosure of the global minimum of a function, along with the boxes that may contain its
+/// global minimizers.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GlobalMinimum {
+    enclosure: Interval,
+    boxes: Vec<IntervalVector>,
+}
+
+impl GlobalMinimum {
+    /// Returns the boxes that may contain global minimizers.
+    ///
+    /// Every global minimizer of the function is contained in at least one of the boxes.
+    pub fn boxes(&self) -> &[IntervalVector] {
+        &self.boxes
+    }
+
+    /// Returns the interval that contains the global minimum.
+    pub fn enclosure(&self) -> Interval {
+        self.enclosure
+    }
+
+    /// Consumes `self` and returns the boxes that may contain global minimizers.
+    pub fn into_boxes(self) -> Vec<IntervalVector> {
+        self.boxes
+    }
+}
+
+/// Returns an enclosure of the global minimum of the function over the box `x`.
+///
+/// The closure `f` must return an enclosure of the range of the function $f$ over the given box.
+/// Boxes on which `f` returns an empty interval are regarded as being outside the domain of the
+/// function, and are discarded.
+///
+/// The boxes are processed in ascending order of the lower bounds of $F(X)$ (the Moore–Skelboe
+/// algorithm) as follows, starting from `x`:
+///
+/// - A box $X$ is discarded if $F(X)$ lies strictly above the least upper bound $\sup F(m)$
+///   found so far ([`Interval::strict_precedes`]), where $F$ is the closure `f` and $m$ is the
+///   midpoint of a box that has been processed.
+/// - Otherwise, $X$ is reported as a candidate if the width of each element of $X$ is less than
+///   or equal to `tol`, or $X$ cannot be bisected any further.
+/// - Otherwise, $X$ is bisected along its widest axis ([`IntervalVector::widest_axis`]).
+///
+/// The enclosure of the global minimum is the intersection of the convex hull of $F(X)$ over
+/// all candidate boxes $X$ and $\[-∞, \sup F(m)\]$.
+///
+/// If `x` is empty, the result has no boxes and the enclosure is empty.
+pub fn minimize<F>(f: F, x: &IntervalVector, tol: f64) -> GlobalMinimum
+where
+    F: Fn(&IntervalVector) -> Interval,
+{
+    branch_and_bound(f, x, tol, IntervalVector::widest_axis)
+}
+
+/// Returns an enclosure of the global minimum of the function over the box `x`, where the boxes
+/// are bisected along the axes chosen by the smear heuristic.
+///
+/// The closure `df` must return an enclosure of the gradient of the function over the given box.
+/// The axis is chosen by [`IntervalVector::smear_axis`], unless the width of the box along it
+/// is less than or equal to `tol`, in which case the widest axis is chosen instead. See
+/// [`minimize`] for the other details.
+pub fn minimize_smear<F, DF>(f: F, df: DF, x: &IntervalVector, tol: f64) -> GlobalMinimum
+where
+    F: Fn(&IntervalVector) -> Interval,
+    DF: Fn(&IntervalVector) -> IntervalVector,
+{
+    branch_and_bound(f, x, tol, |x| x.smear_axis(&df(x)))
+}
+
+fn branch_and_bound<F, A>(f: F, x: &IntervalVector, tol: f64, axis: A) -> GlobalMinimum
+where
+    F: Fn(&IntervalVector) -> Interval,
+    A: Fn(&IntervalVector) -> Option<usize>,
+{
+    // The value of the function at the midpoint of a box that has the least upper bound.
+    let mut best = Interval::ENTIRE;
+    let mut candidates = vec![];
+    let mut queue = BinaryHeap::new();
+    queue.push(Entry(x.clone(), f(x)));
+    while let Some(Entry(x, fx)) = queue.pop() {
+        if best.strict_precedes(fx) {
+            // The remaining boxes can be discarded as well.
+            break;
+        }
+        if x.is_empty_set() || fx.is_empty() {
+            continue;
+        }
+
+        let fm = f(&IntervalVector::from_points(&x.mid()));
+        if !fm.is_empty() && fm.sup() < best.sup() {
+            best = fm;
+        }
+
+        let split = match axis(&x) {
+            Some(i) if x.wid().iter().any(|&w| w > tol) => {
+                // The chosen axis can be narrower than `tol` while some others are not.
+                let i = if x[i].wid() > tol {
+                    i
+                } else {
+                    x.widest_axis().unwrap()
+                };
+                let [y, z] = x.bisect(i);
+                if y == x || z == x {
+                    None
+                } else {
+                    Some([y, z])
+                }
+            }
+            _ => None,
+        };
+        match split {
+            Some([y, z]) => {
+                let fy = f(&y);
+                queue.push(Entry(y, fy));
+                let fz = f(&z);
+                queue.push(Entry(z, fz));
+            }
+            None => candidates.push((x, fx)),
+        }
+    }
+
+    let candidates = candidates
+        .into_iter()
+        .filter(|(_, fx)| !best.strict_precedes(*fx))
+        .collect::<Vec<_>>();
+    let enclosure = candidates
+        .iter()
+        .fold(Interval::EMPTY, |s, &(_, fx)| s.convex_hull(fx))
+        .intersection(Interval::with_infsup_raw(f64::NEG_INFINITY, best.sup()));
+    GlobalMinimum {
+        enclosure,
+        boxes: candidates.into_iter().map(|(x, _)| x).collect(),
+    }
+}
+
+// A box and the enclosure of the range of the function over it, ordered so that
+// the one with the least lower bound of the range is popped first from a `BinaryHeap`.
+struct Entry(IntervalVector, Interval);
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.1.inf().total_cmp(&self.1.inf())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use Interval as I;
+    use IntervalBox as B;
+
+    // f(x, y) = (x² - 1)² + y², which has the global minimizers (±1, 0).
+    fn f(x: &B) -> I {
+        (x[0].sqr() - const_interval!(1.0, 1.0)).sqr() + x[1].sqr()
+    }
+
+    fn df(x: &B) -> B {
+        B::new(vec![
+            const_interval!(4.0, 4.0) * x[0] * (x[0].sqr() - const_interval!(1.0, 1.0)),
+            const_interval!(2.0, 2.0) * x[1],
+        ])
+    }
+
+    #[test]
+    fn two_minimizers() {
+        let x = B::new(vec![const_interval!(-3.0, 3.0), const_interval!(-2.0, 5.0)]);
+        for m in [minimize(f, &x, 1e-5), minimize_smear(f, df, &x, 1e-5)] {
+            assert!(m.enclosure().contains(0.0));
+            assert!(m.enclosure().wid() < 1e-8);
+            for z in [-1.0, 1.0] {
+                assert!(m
+                    .boxes()
+                    .iter()
+                    .any(|x| x[0].contains(z) && x[1].contains(0.0)));
+            }
+            assert!(m.boxes().iter().all(|x| x.subset(&B::new(vec![
+                const_interval!(-1.001, 1.001),
+                const_interval!(-0.001, 0.001)
+            ]))));
+            assert!(m.boxes().iter().all(|x| !x[0].contains(0.0)));
+        }
+    }
+
+    #[test]
+    fn domain() {
+        // √x + y², where the boxes with x < 0 are outside the domain.
+        let g = |x: &B| x[0].sqrt() + x[1].sqr();
+        let x = B::new(vec![const_interval!(-1.0, 1.0), const_interval!(-1.0, 1.0)]);
+        let m = minimize(g, &x, 1e-4);
+        assert!(m.enclosure().contains(0.0));
+        assert!(m.enclosure().sup() < 0.01);
+        assert!(!m.boxes().is_empty());
+        assert!(m.boxes().iter().all(|x| x[0].sup() >= 0.0));
+
+        let m = minimize(f, &B::new(vec![I::EMPTY, const_interval!(0.0, 1.0)]), 1e-3);
+        assert!(m.enclosure().is_empty());
+        assert!(m.boxes().is_empty());
+    }
+
+    #[test]
+    fn narrow_smear_axis() {
+        // 10²⁰(x - 1) + y², where the smear along x dominates even though x is narrower than `tol`.
+        let g =
+            |x: &B| const_interval!(1e20, 1e20) * (x[0] - const_interval!(1.0, 1.0)) + x[1].sqr();
+        let dg = |x: &B| {
+            B::new(vec![
+                const_interval!(1e20, 1e20),
+                const_interval!(2.0, 2.0) * x[1],
+            ])
+        };
+        let x = B::new(vec![
+            const_interval!(1.0, 1.0000000000009095),
+            const_interval!(-1.0, 1.0),
+        ]);
+        let m = minimize_smear(g, dg, &x, 1e-3);
+        assert!(m.enclosure().contains(0.0));
+        assert!(!m.boxes().is_empty());
+        assert!(m.boxes().iter().all(|x| x[1].wid() <= 1e-3));
+        assert!(m.boxes().iter().any(|x| x[1].contains(0.0)));
+    }
+
+    #[test]
+    fn unbounded() {
+        let x = B::new(vec![I::ENTIRE, const_interval!(-1.0, 1.0)]);
+        let m = minimize(f, &x, 1e-5);
+        assert!(m.enclosure().contains(0.0));
+        assert!(m.enclosure().wid() < 1e-8);
+    }
+}
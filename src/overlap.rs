@@ -377,68 +377,75 @@ impl Interval {
             return SecondEmpty;
         }
 
-        let a = self.inf_raw();
-        let b = self.sup_raw();
-        let c = rhs.inf_raw();
-        let d = rhs.sup_raw();
+        overlap_nonempty(
+            &self.inf_raw(),
+            &self.sup_raw(),
+            &rhs.inf_raw(),
+            &rhs.sup_raw(),
+        )
+    }
+}
 
-        //     |  aRc  |  aRd  |  bRc  |  bRd
-        //     | < = > | < = > | < = > | < = >
-        // ----+-------+-------+-------+-------
-        //   B | x     | x     | x     | x
-        //   M | x     | x     |   x   | x
-        //   O | x     | x     |     x | x
-        //   S |   x   | x     |   ? ? | x
-        //  Cb |     x | x     |     x | x
-        //   F |     x | ? ?   |     x |   x
-        //   E |   x   | ? ?   |   ? ? |   x
-        //  Fb | x     | x     |   ? ? |   x
-        //   C | x     | x     |     x |     x
-        //  Sb |   x   | ? ?   |     x |     x
-        //  Ob |     x | x     |     x |     x
-        //  Mb |     x |   x   |     x |     x
-        //   A |     x |     x |     x |     x
+// Returns the overlapping state of the nonempty intervals $\[a, b\]$ and $\[c, d\]$.
+pub(crate) fn overlap_nonempty<T: PartialOrd>(a: &T, b: &T, c: &T, d: &T) -> Overlap {
+    use Overlap::*;
 
-        #[allow(clippy::collapsible_else_if, clippy::collapsible_if)]
-        if b < d {
-            if a < c {
-                if b < c {
-                    Before
-                } else if b == c {
-                    Meets
-                } else {
-                    Overlaps
-                }
+    //     |  aRc  |  aRd  |  bRc  |  bRd
+    //     | < = > | < = > | < = > | < = >
+    // ----+-------+-------+-------+-------
+    //   B | x     | x     | x     | x
+    //   M | x     | x     |   x   | x
+    //   O | x     | x     |     x | x
+    //   S |   x   | x     |   ? ? | x
+    //  Cb |     x | x     |     x | x
+    //   F |     x | ? ?   |     x |   x
+    //   E |   x   | ? ?   |   ? ? |   x
+    //  Fb | x     | x     |   ? ? |   x
+    //   C | x     | x     |     x |     x
+    //  Sb |   x   | ? ?   |     x |     x
+    //  Ob |     x | x     |     x |     x
+    //  Mb |     x |   x   |     x |     x
+    //   A |     x |     x |     x |     x
+
+    #[allow(clippy::collapsible_else_if, clippy::collapsible_if)]
+    if b < d {
+        if a < c {
+            if b < c {
+                Before
+            } else if b == c {
+                Meets
             } else {
-                if a == c {
-                    Starts
-                } else {
-                    ContainedBy
-                }
+                Overlaps
             }
-        } else if b == d {
-            if a > c {
-                Finishes
-            } else if a == c {
-                Equals
+        } else {
+            if a == c {
+                Starts
+            } else {
+                ContainedBy
+            }
+        }
+    } else if b == d {
+        if a > c {
+            Finishes
+        } else if a == c {
+            Equals
+        } else {
+            FinishedBy
+        }
+    } else {
+        if a <= c {
+            if a < c {
+                Contains
             } else {
-                FinishedBy
+                StartedBy
             }
         } else {
-            if a <= c {
-                if a < c {
-                    Contains
-                } else {
-                    StartedBy
-                }
+            if a < d {
+                OverlappedBy
+            } else if a == d {
+                MetBy
             } else {
-                if a < d {
-                    OverlappedBy
-                } else if a == d {
-                    MetBy
-                } else {
-                    After
-                }
+                After
             }
         }
     }
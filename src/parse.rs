@@ -1,4 +1,4 @@
-use crate::interval::*;
+use crate::{interval::*, mp::MpInterval};
 use gmp_mpfr_sys::{gmp, mpfr};
 use nom::{
     branch::alt,
@@ -8,7 +8,7 @@ use nom::{
     sequence::{delimited, pair, preceded, separated_pair, tuple},
     IResult,
 };
-use rug::{Float, Integer, Rational};
+use rug::{float::Special, Float, Integer, Rational};
 use std::{cmp::Ordering, result, str::FromStr};
 
 #[derive(Clone, Debug)]
@@ -546,6 +546,20 @@ fn number_to_f64(n: &Number, infsup: InfSup) -> F64 {
     }
 }
 
+fn number_to_float(n: &Number, infsup: InfSup, prec: u32) -> Float {
+    match n {
+        Number::NegInfinity => Float::with_val(prec, Special::NegInfinity),
+        Number::Rational(r) => {
+            let mut f = Float::new(prec);
+            unsafe {
+                mpfr::set_q(f.as_raw_mut(), r.as_raw(), infsup.as_rnd_t());
+            }
+            f
+        }
+        Number::Infinity => Float::with_val(prec, Special::Infinity),
+    }
+}
+
 impl From<NInterval> for Interval {
     fn from(x: NInterval) -> Self {
         DecInterval::from(DecNInterval::new(x)).x
@@ -628,6 +642,36 @@ impl Interval {
     }
 }
 
+impl MpInterval {
+    /// Parses an interval literal in the same syntax as [`Interval::from_str`], rounding
+    /// the bounds outward to `prec` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::*;
+    /// let x = MpInterval::parse("[0.1]", 200).unwrap();
+    /// assert!(x.contains(&rug::Float::with_val(400, rug::Float::parse("0.1").unwrap())));
+    /// assert!(x.wid() < 1e-60);
+    /// ```
+    pub fn parse(s: &str, prec: u32) -> Result<Self> {
+        match interval(s) {
+            Ok(("", x)) => match x {
+                Ok(x) => {
+                    let a = number_to_float(x.inf(), InfSup::Inf, prec);
+                    let b = number_to_float(x.sup(), InfSup::Sup, prec);
+                    // The empty interval cannot be constructed with `MpInterval::try_from`.
+                    Ok(Self::try_from((a, b)).unwrap_or_else(|_| Self::empty(prec)))
+                }
+                Err(e) => Err(IntervalError { kind: e.kind }),
+            },
+            _ => Err(IntervalError {
+                kind: IntervalErrorKind::UndefinedOperation,
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
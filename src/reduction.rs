@@ -0,0 +1,586 @@
+//! Exact reduction operations.
+//!
+//! The functions in this module compute the sum, the dot product, the sum of absolute values, or
+//! the sum of squares of a list of [`f64`] numbers as if with unbounded range and precision,
+//! and round the result only once. These are the recommended operations of IEEE Std 1788-2015.
+//!
+//! For each operation, two functions are provided: one that rounds the exact result
+//! in the given [`RoundingDirection`] (e.g., [`sum`]), and one that returns the tightest
+//! [`Interval`] that contains the exact result (e.g., [`sum_interval`]).
+//!
+//! # Special values
+//!
+//! The functions that return an [`f64`] follow the rules of IEEE Std 754-2019 for special values:
+//!
+//! - The result is NaN if any of the numbers is NaN, or an infinity is added to another infinity
+//!   of the opposite sign, or an infinity is multiplied by zero.
+//! - Otherwise, the result is an infinity if any of the numbers is an infinity.
+//! - If the exact result is zero, its sign is determined in the same manner as the addition of
+//!   two numbers.
+//!
+//! The functions that return an [`Interval`] return [`Interval::EMPTY`] if any of the numbers is
+//! not finite, since the exact result is not a real number.
+//!
+//! # Examples
+//!
+//! ```
+//! use inari::reduction::{sum, sum_interval, RoundingDirection};
+//! let xs = [1e300, 1.0, -1e300];
+//! assert_eq!(xs.iter().sum::<f64>(), 0.0);
+//! assert_eq!(sum(&xs, RoundingDirection::TiesToEven), 1.0);
+//! assert_eq!(sum_interval(&[0.1, 0.2]).inf(), 0.3);
+//! assert_eq!(sum_interval(&[0.1, 0.2]).sup(), 0.30000000000000004);
+//! ```
+use crate::interval::*;
+
+/// A rounding-direction attribute defined in IEEE Std 754-2019.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingDirection {
+    /// Rounds to the nearest number; ties are rounded to the one with an even significand.
+    TiesToEven,
+    /// Rounds to the nearest number; ties are rounded to the one with the larger magnitude.
+    TiesToAway,
+    /// Rounds toward $+∞$.
+    TowardPositive,
+    /// Rounds toward $-∞$.
+    TowardNegative,
+    /// Rounds toward zero.
+    TowardZero,
+}
+
+/// Returns the sum $\sum_i 𝚡_i$ rounded in the given direction.
+///
+/// # Examples
+///
+/// ```
+/// use inari::reduction::{sum, RoundingDirection as R};
+/// assert_eq!(sum(&[1.0, 2.0, 3.0], R::TiesToEven), 6.0);
+/// assert_eq!(sum(&[1.0, 1e-300], R::TowardPositive), 1.0000000000000002);
+/// assert!(sum(&[f64::INFINITY, f64::NEG_INFINITY], R::TiesToEven).is_nan());
+/// ```
+pub fn sum(x: &[f64], rnd: RoundingDirection) -> f64 {
+    reduce(x.iter().map(|&x| (x, 1.0)), rnd)
+}
+
+/// Returns the dot product $\sum_i 𝚡_i 𝚢_i$ rounded in the given direction.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use inari::reduction::{dot, RoundingDirection as R};
+/// let x = [4503599627370497.0, 2f64.powi(104)];
+/// let y = [4503599627370495.0, -1.0];
+/// assert_eq!(dot(&x, &y, R::TiesToEven), -1.0);
+/// ```
+pub fn dot(x: &[f64], y: &[f64], rnd: RoundingDirection) -> f64 {
+    assert_eq!(x.len(), y.len(), "the lengths of the slices must be equal");
+    reduce(x.iter().copied().zip(y.iter().copied()), rnd)
+}
+
+/// Returns the sum of absolute values $\sum_i |𝚡_i|$ rounded in the given direction.
+///
+/// # Examples
+///
+/// ```
+/// use inari::reduction::{sum_abs, RoundingDirection as R};
+/// assert_eq!(sum_abs(&[1.0, -2.0, 3.0], R::TiesToEven), 6.0);
+/// assert_eq!(sum_abs(&[1.0, f64::NEG_INFINITY, f64::INFINITY], R::TiesToEven), f64::INFINITY);
+/// ```
+pub fn sum_abs(x: &[f64], rnd: RoundingDirection) -> f64 {
+    reduce(x.iter().map(|&x| (x.abs(), 1.0)), rnd)
+}
+
+/// Returns the sum of squares $\sum_i 𝚡_i^2$ rounded in the given direction.
+///
+/// # Examples
+///
+/// ```
+/// use inari::reduction::{sum_square, RoundingDirection as R};
+/// assert_eq!(sum_square(&[1.0, 2.0, 3.0], R::TiesToEven), 14.0);
+/// assert_eq!(sum_square(&[1e200, 1.0], R::TowardZero), f64::MAX);
+/// ```
+pub fn sum_square(x: &[f64], rnd: RoundingDirection) -> f64 {
+    reduce(x.iter().map(|&x| (x, x)), rnd)
+}
+
+/// Returns the tightest interval that contains the sum $\sum_i 𝚡_i$.
+///
+/// [`Interval::EMPTY`] is returned if any of the numbers is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{reduction::sum_interval, Interval as I, const_interval as c};
+/// assert_eq!(sum_interval(&[1.0, 2.0, 3.0]), c!(6.0, 6.0));
+/// assert_eq!(sum_interval(&[1.0, 1e-300]), c!(1.0, 1.0000000000000002));
+/// assert_eq!(sum_interval(&[f64::MAX, f64::MAX]), c!(f64::MAX, f64::INFINITY));
+/// assert_eq!(sum_interval(&[1.0, f64::INFINITY]), I::EMPTY);
+/// ```
+pub fn sum_interval(x: &[f64]) -> Interval {
+    reduce_interval(x.iter().map(|&x| (x, 1.0)))
+}
+
+/// Returns the tightest interval that contains the dot product $\sum_i 𝚡_i 𝚢_i$.
+///
+/// [`Interval::EMPTY`] is returned if any of the numbers is not finite.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{reduction::dot_interval, const_interval as c};
+/// assert_eq!(dot_interval(&[1.0, 2.0], &[3.0, 4.0]), c!(11.0, 11.0));
+/// assert_eq!(dot_interval(&[0.1], &[0.1]), c!(0.01, 0.010000000000000002));
+/// ```
+pub fn dot_interval(x: &[f64], y: &[f64]) -> Interval {
+    assert_eq!(x.len(), y.len(), "the lengths of the slices must be equal");
+    reduce_interval(x.iter().copied().zip(y.iter().copied()))
+}
+
+/// Returns the tightest interval that contains the sum of absolute values $\sum_i |𝚡_i|$.
+///
+/// [`Interval::EMPTY`] is returned if any of the numbers is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{reduction::sum_abs_interval, const_interval as c};
+/// assert_eq!(sum_abs_interval(&[1.0, -2.0, 3.0]), c!(6.0, 6.0));
+/// ```
+pub fn sum_abs_interval(x: &[f64]) -> Interval {
+    reduce_interval(x.iter().map(|&x| (x.abs(), 1.0)))
+}
+
+/// Returns the tightest interval that contains the sum of squares $\sum_i 𝚡_i^2$.
+///
+/// [`Interval::EMPTY`] is returned if any of the numbers is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{reduction::sum_square_interval, const_interval as c};
+/// assert_eq!(sum_square_interval(&[1.0, 2.0, 3.0]), c!(14.0, 14.0));
+/// ```
+pub fn sum_square_interval(x: &[f64]) -> Interval {
+    reduce_interval(x.iter().map(|&x| (x, x)))
+}
+
+fn reduce_interval<I>(terms: I) -> Interval
+where
+    I: Iterator<Item = (f64, f64)> + Clone,
+{
+    if terms.clone().any(|(x, y)| !x.is_finite() || !y.is_finite()) {
+        return Interval::EMPTY;
+    }
+
+    let a = reduce(terms.clone(), RoundingDirection::TowardNegative);
+    let b = reduce(terms, RoundingDirection::TowardPositive);
+    Interval::with_infsup_raw(a, b)
+}
+
+/// Returns $\sum_i x_i y_i$ rounded in the given direction, where $(x_i, y_i)$ are the items of
+/// `terms`.
+#[cfg(feature = "gmp")]
+fn reduce<I>(terms: I, rnd: RoundingDirection) -> f64
+where
+    I: Iterator<Item = (f64, f64)>,
+{
+    use rug::{float::Round, Float};
+
+    // The precision that is sufficient for representing the exact dot product of
+    // up to 2^64 pairs of `f64` numbers, which spans from 2^-2148 to 2^2112.
+    const EXACT_PREC: u32 = 4288;
+
+    let terms = terms
+        .map(|(x, y)| (Float::with_val(53, x), Float::with_val(53, y)))
+        .collect::<Vec<_>>();
+    // The result is exact; the rounding direction only affects the sign of zero.
+    let exact_rnd = if rnd == RoundingDirection::TowardNegative {
+        Round::Down
+    } else {
+        Round::Nearest
+    };
+    let s = Float::with_val_round(
+        EXACT_PREC,
+        Float::dot(terms.iter().map(|(x, y)| (x, y))),
+        exact_rnd,
+    )
+    .0;
+
+    match rnd {
+        RoundingDirection::TiesToEven => s.to_f64_round(Round::Nearest),
+        RoundingDirection::TiesToAway => {
+            let a = s.to_f64_round(Round::Down);
+            let b = s.to_f64_round(Round::Up);
+            if a != b && Float::with_val(EXACT_PREC, &s - a) == Float::with_val(EXACT_PREC, b - &s)
+            {
+                if s.is_sign_positive() {
+                    b
+                } else {
+                    a
+                }
+            } else {
+                s.to_f64_round(Round::Nearest)
+            }
+        }
+        RoundingDirection::TowardPositive => s.to_f64_round(Round::Up),
+        RoundingDirection::TowardNegative => s.to_f64_round(Round::Down),
+        RoundingDirection::TowardZero => s.to_f64_round(Round::Zero),
+    }
+}
+
+/// Returns $\sum_i x_i y_i$ rounded in the given direction, where $(x_i, y_i)$ are the items of
+/// `terms`.
+#[cfg(not(feature = "gmp"))]
+fn reduce<I>(terms: I, rnd: RoundingDirection) -> f64
+where
+    I: Iterator<Item = (f64, f64)>,
+{
+    let mut acc = Accumulator::new();
+    for (x, y) in terms {
+        acc.add_product(x, y);
+    }
+    acc.round(rnd)
+}
+
+/// A fixed-point accumulator that can hold the exact sum of products of `f64` numbers.
+///
+/// Each product is computed exactly as the product of the integral significands of the factors
+/// and added to the digits without rounding.
+#[cfg(not(feature = "gmp"))]
+struct Accumulator {
+    /// The base-2^32 digits of the sum, the `i`th of which has the weight 2^(32i + [`Self::LOW`]).
+    /// The digits are allowed to exceed the range [0, 2^32) until normalized.
+    digits: [i64; Self::LEN],
+    /// The number of products added since the last normalization.
+    pending: u32,
+    nan: bool,
+    pos_inf: bool,
+    neg_inf: bool,
+    /// Whether any of the products is +0, -0, or nonzero, respectively.
+    pos_zero: bool,
+    neg_zero: bool,
+    nonzero: bool,
+}
+
+#[cfg(not(feature = "gmp"))]
+impl Accumulator {
+    /// The exponent of the least significant bit of the accumulator,
+    /// which is below that of the product of two smallest subnormal numbers, 2^-2148.
+    const LOW: i32 = -2176;
+    /// The number of the digits, which is sufficient for representing the sum of
+    /// up to 2^64 products of two largest `f64` numbers.
+    const LEN: usize = 136;
+    /// The number of products that can be added before the digits may overflow.
+    const CAPACITY: u32 = 1 << 28;
+
+    fn new() -> Self {
+        Self {
+            digits: [0; Self::LEN],
+            pending: 0,
+            nan: false,
+            pos_inf: false,
+            neg_inf: false,
+            pos_zero: false,
+            neg_zero: false,
+            nonzero: false,
+        }
+    }
+
+    fn add_product(&mut self, x: f64, y: f64) {
+        let neg = x.is_sign_negative() != y.is_sign_negative();
+        if x.is_nan() || y.is_nan() {
+            self.nan = true;
+            return;
+        }
+        if x.is_infinite() || y.is_infinite() {
+            if x == 0.0 || y == 0.0 {
+                self.nan = true;
+            } else if neg {
+                self.neg_inf = true;
+            } else {
+                self.pos_inf = true;
+            }
+            return;
+        }
+        if x == 0.0 || y == 0.0 {
+            if neg {
+                self.neg_zero = true;
+            } else {
+                self.pos_zero = true;
+            }
+            return;
+        }
+        self.nonzero = true;
+
+        // x y = m 2^e exactly, where m < 2^106.
+        let (mx, ex) = decompose(x);
+        let (my, ey) = decompose(y);
+        let m = mx as u128 * my as u128;
+        let pos = (ex + ey - Self::LOW) as u32;
+        let (i, off) = ((pos / 32) as usize, pos % 32);
+
+        // Split m into two parts so that the shifted values fit in u128.
+        let lo = (m & u64::MAX as u128) << off;
+        let hi = (m >> 64) << off;
+        let sign = if neg { -1 } else { 1 };
+        for k in 0..3 {
+            self.digits[i + k] += sign * ((lo >> (32 * k)) as u32 as i64);
+            self.digits[i + 2 + k] += sign * ((hi >> (32 * k)) as u32 as i64);
+        }
+
+        self.pending += 1;
+        if self.pending == Self::CAPACITY {
+            self.normalize();
+        }
+    }
+
+    /// Propagates the carries so that all digits but the last one are in [0, 2^32).
+    fn normalize(&mut self) {
+        for i in 0..Self::LEN - 1 {
+            let carry = self.digits[i] >> 32;
+            self.digits[i] -= carry << 32;
+            self.digits[i + 1] += carry;
+        }
+        self.pending = 0;
+    }
+
+    fn round(mut self, rnd: RoundingDirection) -> f64 {
+        use RoundingDirection::*;
+
+        if self.nan || self.pos_inf && self.neg_inf {
+            return f64::NAN;
+        }
+        if self.pos_inf {
+            return f64::INFINITY;
+        }
+        if self.neg_inf {
+            return f64::NEG_INFINITY;
+        }
+
+        self.normalize();
+        let neg = self.digits[Self::LEN - 1] < 0;
+        if neg {
+            for d in &mut self.digits {
+                *d = -*d;
+            }
+            self.normalize();
+        }
+
+        let Some(h) = self.digits.iter().rposition(|&d| d != 0) else {
+            // Same as IEEE 754 addition: -0 if all products are -0, or if the signs are mixed
+            // and the rounding direction is toward -∞; +0 otherwise.
+            let all_neg_zero = self.neg_zero && !self.pos_zero && !self.nonzero;
+            let mixed = self.nonzero || self.neg_zero && self.pos_zero;
+            return if all_neg_zero || mixed && rnd == TowardNegative {
+                -0.0
+            } else {
+                0.0
+            };
+        };
+
+        // The exponent of the most significant bit.
+        let t = 32 * h as i32 + (63 - self.digits[h].leading_zeros() as i32) + Self::LOW;
+        let round_up = match rnd {
+            TiesToEven | TiesToAway => true,
+            TowardPositive => !neg,
+            TowardNegative => neg,
+            TowardZero => false,
+        };
+        if t > f64::MAX_EXP - 1 {
+            let inf = if round_up { f64::INFINITY } else { f64::MAX };
+            return if neg { -inf } else { inf };
+        }
+
+        // The exponent of the unit in the last place of the result.
+        let q = (t - 52).max(f64::MIN_EXP - 53);
+        let mut m = 0u64;
+        for p in (q..=t).rev() {
+            m = (m << 1) | self.bit(p) as u64;
+        }
+        let half = self.bit(q - 1);
+        let sticky = self.any_bit_below(q - 1);
+        let inc = match rnd {
+            TiesToEven => half && (sticky || m & 1 == 1),
+            TiesToAway => half,
+            _ => round_up && (half || sticky),
+        };
+        if inc {
+            m += 1;
+        }
+
+        // m 2^q is exact unless it overflows.
+        let scale = if q >= f64::MIN_EXP - 1 {
+            f64::from_bits(((q + 1023) as u64) << 52)
+        } else {
+            f64::from_bits(1 << (q + 1074))
+        };
+        let r = m as f64 * scale;
+        if neg {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Returns the bit of the accumulator at the exponent `p`.
+    fn bit(&self, p: i32) -> bool {
+        if p < Self::LOW {
+            return false;
+        }
+        let pos = (p - Self::LOW) as usize;
+        (self.digits[pos / 32] >> (pos % 32)) & 1 == 1
+    }
+
+    /// Returns `true` if any of the bits of the accumulator below the exponent `p` is set.
+    fn any_bit_below(&self, p: i32) -> bool {
+        if p <= Self::LOW {
+            return false;
+        }
+        let pos = (p - Self::LOW) as usize;
+        let (i, off) = (pos / 32, pos % 32);
+        self.digits[..i].iter().any(|&d| d != 0) || self.digits[i] & ((1 << off) - 1) != 0
+    }
+}
+
+/// Returns `(m, e)` such that |x| = m 2^e, where `x` is finite and nonzero.
+#[cfg(not(feature = "gmp"))]
+fn decompose(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32;
+    let f = bits & ((1 << 52) - 1);
+    if e == 0 {
+        (f, -1074)
+    } else {
+        (f | (1 << 52), e - 1075)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RoundingDirection::*, *};
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn cancellation() {
+        let x = [1e300, 1.0, -1e300, 1e-300];
+        assert_eq!(sum(&x, TiesToEven), 1.0);
+        assert_eq!(sum(&x, TowardPositive), 1.0000000000000002);
+        assert_eq!(sum(&x, TowardNegative), 1.0);
+        assert_eq!(sum_interval(&x), const_interval!(1.0, 1.0000000000000002));
+
+        let x = [-1.0, f64::MIN_POSITIVE * f64::EPSILON];
+        assert_eq!(sum(&x, TiesToEven), -1.0);
+        assert_eq!(sum(&x, TowardNegative), -1.0);
+        assert_eq!(sum(&x, TowardPositive), -0.9999999999999999);
+        assert_eq!(sum(&x, TowardZero), -0.9999999999999999);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sum(&[], TiesToEven), 0.0);
+        assert!(sum(&[], TiesToEven).is_sign_positive());
+        assert!(sum(&[], TowardNegative).is_sign_positive());
+        assert_eq!(sum_interval(&[]), const_interval!(0.0, 0.0));
+    }
+
+    #[test]
+    fn overflow() {
+        let x = [f64::MAX, f64::MAX, -f64::MAX];
+        assert_eq!(sum(&x, TiesToEven), f64::MAX);
+        assert_eq!(sum(&x[..2], TiesToEven), f64::INFINITY);
+        assert_eq!(sum(&x[..2], TowardZero), f64::MAX);
+        assert_eq!(sum(&x[..2], TowardNegative), f64::MAX);
+        assert_eq!(sum(&[-f64::MAX, -f64::MAX], TowardPositive), -f64::MAX);
+        assert_eq!(sum(&[-f64::MAX, -f64::MAX], TiesToAway), f64::NEG_INFINITY);
+        assert_eq!(sum_square(&[1e300, 1e300], TowardZero), f64::MAX);
+        assert_eq!(
+            sum_square_interval(&[1e300]),
+            const_interval!(f64::MAX, f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        let inf = f64::INFINITY;
+        assert!(sum(&[1.0, f64::NAN], TiesToEven).is_nan());
+        assert!(sum(&[inf, -inf], TiesToEven).is_nan());
+        assert_eq!(sum(&[inf, 1.0, inf], TiesToEven), inf);
+        assert_eq!(sum(&[-inf, 1.0], TowardPositive), -inf);
+        assert!(dot(&[inf], &[0.0], TiesToEven).is_nan());
+        assert_eq!(dot(&[inf, 1.0], &[-1.0, 1.0], TiesToEven), -inf);
+        assert_eq!(sum_abs(&[inf, -inf], TiesToEven), inf);
+        assert_eq!(sum_square(&[-inf, 1.0], TiesToEven), inf);
+
+        assert_eq!(sum_interval(&[1.0, f64::NAN]), I::EMPTY);
+        assert_eq!(sum_interval(&[inf]), I::EMPTY);
+        assert_eq!(dot_interval(&[inf], &[0.0]), I::EMPTY);
+        assert_eq!(sum_abs_interval(&[-inf]), I::EMPTY);
+        assert_eq!(sum_square_interval(&[f64::NAN]), I::EMPTY);
+    }
+
+    #[test]
+    fn subnormal() {
+        let min = f64::MIN_POSITIVE * f64::EPSILON; // 2^-1074
+        assert_eq!(sum(&[min, min, min], TiesToEven), 3.0 * min);
+        assert_eq!(dot(&[min], &[0.5], TiesToEven), 0.0);
+        assert_eq!(dot(&[min], &[0.5], TiesToAway), min);
+        assert_eq!(dot(&[min], &[0.5], TowardPositive), min);
+        assert_eq!(dot(&[min, min], &[0.5, 1.0], TiesToEven), 2.0 * min);
+        assert_eq!(dot(&[min], &[min], TowardNegative), 0.0);
+        assert_eq!(dot(&[min], &[-min], TowardNegative), -min);
+        assert_eq!(dot_interval(&[min], &[min]), interval!(0.0, min).unwrap());
+        assert_eq!(
+            sum(&[f64::MIN_POSITIVE, -min], TiesToEven),
+            f64::MIN_POSITIVE - min
+        );
+    }
+
+    #[test]
+    fn ties() {
+        // 1 + 2^-53 is a tie.
+        let x = [1.0, f64::EPSILON / 2.0];
+        assert_eq!(sum(&x, TiesToEven), 1.0);
+        assert_eq!(sum(&x, TiesToAway), 1.0000000000000002);
+        assert_eq!(sum(&x, TowardZero), 1.0);
+        let x = [-1.0, -f64::EPSILON / 2.0];
+        assert_eq!(sum(&x, TiesToEven), -1.0);
+        assert_eq!(sum(&x, TiesToAway), -1.0000000000000002);
+
+        // 1 + 3 × 2^-53 is a tie.
+        let x = [1.0, f64::EPSILON, f64::EPSILON / 2.0];
+        assert_eq!(sum(&x, TiesToEven), 1.0000000000000004);
+        assert_eq!(sum(&x, TiesToAway), 1.0000000000000004);
+
+        // 1 + 2^-53 + 2^-1074 is not a tie.
+        let x = [1.0, f64::EPSILON / 2.0, f64::MIN_POSITIVE * f64::EPSILON];
+        assert_eq!(sum(&x, TiesToEven), 1.0000000000000002);
+    }
+
+    #[test]
+    fn zero_sign() {
+        assert!(sum(&[-0.0, -0.0], TiesToEven).is_sign_negative());
+        assert!(sum(&[-0.0, 0.0], TiesToEven).is_sign_positive());
+        assert!(sum(&[-0.0, 0.0], TowardNegative).is_sign_negative());
+        assert!(sum(&[1.0, -1.0], TiesToEven).is_sign_positive());
+        assert!(sum(&[1.0, -1.0], TowardNegative).is_sign_negative());
+        assert!(dot(&[-1.0, 0.0], &[0.0, -1.0], TiesToEven).is_sign_negative());
+        assert!(sum(&[0.0, 0.0], TowardNegative).is_sign_positive());
+        assert!(sum_abs(&[-0.0], TiesToEven).is_sign_positive());
+        assert!(sum_square(&[-0.0], TiesToEven).is_sign_positive());
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_length_mismatch() {
+        let _ = dot(&[1.0, 2.0], &[1.0], TiesToEven);
+    }
+}
@@ -0,0 +1,1000 @@
+#[cfg(feature = "gmp")]
+use crate::elementary::{acosh_rd, acosh_ru, rootn_rd, rootn_ru, tan_rd, tan_ru};
+use crate::{const_interval, interval::*};
+#[cfg(feature = "gmp")]
+use rug::{
+    float::{Constant, Round},
+    Float,
+};
+
+// The reverse functions return an enclosure of the set
+//
+//   {x ∈ 𝒙 ∣ f(x) ∈ 𝒄}
+//
+// for a unary point function f, where 𝒄 is the range of f and 𝒙 is the interval passed as
+// the last argument, which is typically the current enclosure of the variable.
+// Binary point functions have two reverse functions, one for each argument.
+
+impl Interval {
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ |x| ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(1.0, 2.0).abs_rev(I::ENTIRE), c!(-2.0, 2.0));
+    /// assert_eq!(c!(1.0, 2.0).abs_rev(c!(0.0, 5.0)), c!(1.0, 2.0));
+    /// assert_eq!(c!(-2.0, -1.0).abs_rev(I::ENTIRE), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn abs_rev(self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let y = self.intersection(DOM);
+        Self::rev_symmetric(y, x)
+    }
+
+    /// Returns the tightest interval that contains
+    /// $\set{x ∈ 𝚡 ∣ ∃y ∈ \self : xy ∈ 𝚌}$ (beware the order of the arguments).
+    ///
+    /// The result is the convex hull of the intersections of `x` and the two intervals returned by
+    /// [`self.mul_rev_to_pair(c)`](`Interval::mul_rev_to_pair`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(2.0, 4.0).mul_rev(c!(4.0, 8.0), I::ENTIRE), c!(1.0, 4.0));
+    /// assert_eq!(c!(-1.0, 1.0).mul_rev(c!(1.0, 2.0), c!(-3.0, 2.0)), c!(-3.0, 2.0));
+    /// assert_eq!(c!(-1.0, 1.0).mul_rev(c!(1.0, 2.0), c!(-0.5, 0.5)), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn mul_rev(self, c: Self, x: Self) -> Self {
+        let [z1, z2] = self.mul_rev_to_pair(c);
+        z1.intersection(x).convex_hull(z2.intersection(x))
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ x^2 ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(1.0, 4.0).sqr_rev(I::ENTIRE), c!(-2.0, 2.0));
+    /// assert_eq!(c!(1.0, 4.0).sqr_rev(c!(0.0, 5.0)), c!(1.0, 2.0));
+    /// assert_eq!(c!(-4.0, -1.0).sqr_rev(I::ENTIRE), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn sqr_rev(self, x: Self) -> Self {
+        Self::rev_symmetric(self.sqrt(), x)
+    }
+
+    /// Returns $\hull((y ∪ -y) ∩ x)$, where $y ⊆ \[0, +∞\]$.
+    fn rev_symmetric(y: Self, x: Self) -> Self {
+        y.intersection(x).convex_hull((-y).intersection(x))
+    }
+}
+
+#[cfg(feature = "gmp")]
+impl Interval {
+    /// Returns an interval that contains
+    /// $\set{y ∈ 𝚡 ∣ ∃x ∈ \self : \operatorname{atan2}(y, x) ∈ 𝚌}$.
+    ///
+    /// In other words, it solves [`Interval::atan2`] for the first argument (`y.atan2(self)`)
+    /// given the second one and the result.
+    ///
+    /// The result is not always the tightest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// let y = c!(1.0, 1.0).atan2_rev1(I::FRAC_PI_4, I::ENTIRE);
+    /// assert!(y.contains(1.0));
+    /// assert!(y.wid() < 1e-15);
+    /// ```
+    #[must_use]
+    pub fn atan2_rev1(self, c: Self, x: Self) -> Self {
+        let b = self;
+        if b.is_empty() || c.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let [c1, c2, c3, c4] = Self::atan2_rev_split(c);
+        let y1 = Self::atan2_rev_first_quadrant(b, c1);
+        let y2 = Self::atan2_rev_first_quadrant(-b, Self::PI - c2);
+        let y3 = -Self::atan2_rev_first_quadrant(-b, Self::PI + c3);
+        let y4 = -Self::atan2_rev_first_quadrant(b, -c4);
+        y1.convex_hull(y2)
+            .convex_hull(y3)
+            .convex_hull(y4)
+            .intersection(x)
+    }
+
+    /// Returns an interval that contains
+    /// $\set{x ∈ 𝚡 ∣ ∃y ∈ \self : \operatorname{atan2}(y, x) ∈ 𝚌}$.
+    ///
+    /// In other words, it solves [`Interval::atan2`] for the second argument (`self.atan2(x)`)
+    /// given the first one and the result.
+    ///
+    /// The result is not always the tightest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// let x = c!(1.0, 1.0).atan2_rev2(I::FRAC_PI_4, I::ENTIRE);
+    /// assert!(x.contains(1.0));
+    /// assert!(x.wid() < 1e-15);
+    /// ```
+    #[must_use]
+    pub fn atan2_rev2(self, c: Self, x: Self) -> Self {
+        let a = self;
+        if a.is_empty() || c.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let [c1, c2, c3, c4] = Self::atan2_rev_split(c);
+        let x1 = Self::atan2_rev_first_quadrant(a, Self::FRAC_PI_2 - c1);
+        let x2 = -Self::atan2_rev_first_quadrant(a, c2 - Self::FRAC_PI_2);
+        let x3 = -Self::atan2_rev_first_quadrant(-a, -Self::FRAC_PI_2 - c3);
+        let x4 = Self::atan2_rev_first_quadrant(-a, Self::FRAC_PI_2 + c4);
+        x1.convex_hull(x2)
+            .convex_hull(x3)
+            .convex_hull(x4)
+            .intersection(x)
+    }
+
+    /// Splits the angles `c` into the four quadrants of the plane.
+    fn atan2_rev_split(c: Self) -> [Self; 4] {
+        let pi_2 = Self::FRAC_PI_2;
+        let pi = Self::PI;
+        [
+            c.intersection(Self::with_infsup_raw(0.0, pi_2.sup_raw())),
+            c.intersection(Self::with_infsup_raw(pi_2.inf_raw(), pi.sup_raw())),
+            c.intersection(Self::with_infsup_raw(-pi.sup_raw(), -pi_2.inf_raw())),
+            c.intersection(Self::with_infsup_raw(-pi_2.sup_raw(), 0.0)),
+        ]
+    }
+
+    /// Returns an enclosure of $\set{k \tan ψ ∣ k ∈ 𝒌 ∩ \[0, ∞), ψ ∈ 𝝍 ∩ \[0, π/2)}$
+    /// together with $(0, ∞)$ when $0 ∈ 𝒌$ and $π/2 ∈ 𝝍$.
+    ///
+    /// The set is the projection onto the $y$-axis of the points in the first quadrant
+    /// whose $x$-coordinates are in $𝒌$ and angles are in $𝝍$.
+    fn atan2_rev_first_quadrant(k: Self, psi: Self) -> Self {
+        const K_DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let pi_2_sup = Self::FRAC_PI_2.sup_raw();
+        let k = k.intersection(K_DOM);
+        let psi = psi.intersection(Self::with_infsup_raw(0.0, pi_2_sup));
+
+        if k.either_empty(psi) || psi.inf_raw() >= pi_2_sup {
+            return Self::EMPTY;
+        }
+
+        // Whether ψ can be π/2, where the x-coordinate is zero.
+        let reaches_pole = psi.sup_raw() >= pi_2_sup;
+        let t = Self::with_infsup_raw(
+            tan_rd(psi.inf_raw()),
+            if reaches_pole {
+                f64::INFINITY
+            } else {
+                tan_ru(psi.sup_raw())
+            },
+        );
+
+        // The origin is excluded from the domain of atan2.
+        let y = if k.sup_raw() > 0.0 {
+            k * t
+        } else {
+            Self::EMPTY
+        };
+        if reaches_pole && k.inf_raw() <= 0.0 {
+            y.convex_hull(K_DOM)
+        } else {
+            y
+        }
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ \cos x ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(1.0, 1.0).cos_rev(c!(-1.0, 1.0)), c!(0.0, 0.0));
+    /// assert_eq!(c!(-1.0, -1.0).cos_rev(c!(0.0, 4.0)), I::PI);
+    /// assert_eq!(c!(2.0, 3.0).cos_rev(I::ENTIRE), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn cos_rev(self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(-1.0, 1.0);
+        let c = self.intersection(DOM);
+        if c.either_empty(x) {
+            return Self::EMPTY;
+        }
+
+        // cos is even.
+        let a = c.cos_rev_inf(x.inf_raw());
+        let b = -c.cos_rev_inf(-x.sup_raw());
+        if a > b {
+            Self::EMPTY
+        } else {
+            Self::with_infsup_raw(a, b)
+        }
+    }
+
+    /// Returns a lower bound of the least $x ≥ a$ that satisfies $\cos x ∈ \self$,
+    /// where $\self ⊆ \[-1, 1\]$ is nonempty.
+    fn cos_rev_inf(self, a: f64) -> f64 {
+        if a == f64::NEG_INFINITY {
+            return a;
+        }
+
+        // The solutions in [-π, π] are [-β, -α] ∪ [α, β], where [α, β] = acos(self).
+        let prec = periodic_rev_prec(a);
+        let acos = |x: f64, round| {
+            let x = Float::with_val(f64::MANTISSA_DIGITS, x);
+            Float::with_val_round(prec, x.acos_ref(), round).0
+        };
+        let alpha = acos(self.sup_raw(), Round::Down);
+        let beta = acos(self.inf_raw(), Round::Up);
+        periodic_rev_inf(
+            &[(-beta.clone(), -alpha.clone()), (alpha, beta)],
+            2,
+            prec,
+            a,
+        )
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ \cosh x ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(1.0, 1.0).cosh_rev(I::ENTIRE), c!(0.0, 0.0));
+    /// assert_eq!(c!(-1.0, 0.5).cosh_rev(I::ENTIRE), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn cosh_rev(self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(1.0, f64::INFINITY);
+        let c = self.intersection(DOM);
+        if c.is_empty() {
+            return Self::EMPTY;
+        }
+
+        let y = Self::with_infsup_raw(acosh_rd(c.inf_raw()), acosh_ru(c.sup_raw()));
+        Self::rev_symmetric(y, x)
+    }
+
+    /// Returns an interval that contains
+    /// $\set{x ∈ 𝚡 ∣ ∃y ∈ \self : x^y ∈ 𝚌}$.
+    ///
+    /// In other words, it solves [`Interval::pow`] for the base (`x.pow(self)`)
+    /// given the exponent and the result.
+    ///
+    /// The result is not always the tightest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// let x = c!(2.0, 2.0).pow_rev1(c!(4.0, 9.0), I::ENTIRE);
+    /// assert!(c!(2.0, 3.0).subset(x));
+    /// assert!(x.subset(c!(1.999999999999, 3.000000000001)));
+    /// ```
+    #[must_use]
+    pub fn pow_rev1(self, c: Self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let b = self;
+        let x = x.intersection(DOM);
+        if x.either_empty(b) || c.is_empty() {
+            return Self::EMPTY;
+        }
+
+        // 0^y = 0 for y > 0.
+        let z = if c.contains(0.0) && b.sup_raw() > 0.0 && x.contains(0.0) {
+            Self::zero()
+        } else {
+            Self::EMPTY
+        };
+
+        // x^y = e^(y ln x) for x > 0.
+        let [t1, t2] = b.mul_rev_to_pair(c.ln());
+        z.convex_hull(t1.exp().intersection(x))
+            .convex_hull(t2.exp().intersection(x))
+    }
+
+    /// Returns an interval that contains
+    /// $\set{y ∈ 𝚡 ∣ ∃x ∈ \self : x^y ∈ 𝚌}$.
+    ///
+    /// In other words, it solves [`Interval::pow`] for the exponent (`self.pow(y)`)
+    /// given the base and the result.
+    ///
+    /// The result is not always the tightest one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// let y = c!(2.0, 2.0).pow_rev2(c!(4.0, 8.0), I::ENTIRE);
+    /// assert!(c!(2.0, 3.0).subset(y));
+    /// assert!(y.subset(c!(1.999999999999, 3.000000000001)));
+    /// ```
+    #[must_use]
+    pub fn pow_rev2(self, c: Self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let a = self.intersection(DOM);
+        if a.either_empty(x) || c.is_empty() {
+            return Self::EMPTY;
+        }
+
+        // 0^y = 0 for y > 0.
+        let z = if a.contains(0.0) && c.contains(0.0) && x.sup_raw() > 0.0 {
+            x.intersection(DOM)
+        } else {
+            Self::EMPTY
+        };
+
+        // x^y = e^(y ln x) for x > 0.
+        let [t1, t2] = a.ln().mul_rev_to_pair(c.ln());
+        z.convex_hull(t1.intersection(x))
+            .convex_hull(t2.intersection(x))
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ x^n ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// See [`Interval::powi`] for the definition of the point functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(8.0, 27.0).powi_rev(I::ENTIRE, 3), c!(2.0, 3.0));
+    /// assert_eq!(c!(4.0, 9.0).powi_rev(I::ENTIRE, 2), c!(-3.0, 3.0));
+    /// assert_eq!(c!(0.25, 4.0).powi_rev(c!(0.0, 10.0), -2), c!(0.5, 2.0));
+    /// ```
+    #[must_use]
+    pub fn powi_rev(self, x: Self, n: i32) -> Self {
+        if self.either_empty(x) {
+            return Self::EMPTY;
+        }
+
+        if n == 0 {
+            return if self.contains(1.0) { x } else { Self::EMPTY };
+        }
+
+        const POS: Interval = const_interval!(0.0, f64::INFINITY);
+        const NEG: Interval = const_interval!(f64::NEG_INFINITY, 0.0);
+        if n % 2 == 0 {
+            let c = self.intersection(POS);
+            if c.is_empty() {
+                return Self::EMPTY;
+            }
+
+            let mut a = c.inf_raw();
+            let b = c.sup_raw();
+            if a == 0.0 {
+                a = 0.0; // [0, b]
+            }
+            let y = if n > 0 {
+                Self::with_infsup_raw(rootn_rd(a, n), rootn_ru(b, n))
+            } else {
+                if b == 0.0 {
+                    // x^n > 0 for n < 0.
+                    return Self::EMPTY;
+                }
+                Self::with_infsup_raw(rootn_rd(b, n), rootn_ru(a, n))
+            };
+            Self::rev_symmetric(y, x)
+        } else if n > 0 {
+            let y = Self::with_infsup_raw(rootn_rd(self.inf_raw(), n), rootn_ru(self.sup_raw(), n));
+            y.intersection(x)
+        } else {
+            let pos = self.intersection(POS);
+            let y_pos = if pos.is_empty() || pos.sup_raw() == 0.0 {
+                Self::EMPTY
+            } else {
+                let mut a = pos.inf_raw();
+                if a == 0.0 {
+                    a = 0.0; // [0, b]
+                }
+                Self::with_infsup_raw(rootn_rd(pos.sup_raw(), n), rootn_ru(a, n))
+            };
+
+            let neg = self.intersection(NEG);
+            let y_neg = if neg.is_empty() || neg.inf_raw() == 0.0 {
+                Self::EMPTY
+            } else {
+                let mut b = neg.sup_raw();
+                if b == 0.0 {
+                    b = -0.0; // [a, 0]
+                }
+                Self::with_infsup_raw(rootn_rd(b, n), rootn_ru(neg.inf_raw(), n))
+            };
+
+            y_pos.intersection(x).convex_hull(y_neg.intersection(x))
+        }
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ \sin x ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(0.0, 0.0).sin_rev(c!(-1.0, 1.0)), c!(0.0, 0.0));
+    /// assert_eq!(c!(0.0, 0.0).sin_rev(c!(2.0, 4.0)), I::PI);
+    /// assert_eq!(c!(2.0, 3.0).sin_rev(I::ENTIRE), I::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn sin_rev(self, x: Self) -> Self {
+        const DOM: Interval = const_interval!(-1.0, 1.0);
+        let c = self.intersection(DOM);
+        if c.either_empty(x) {
+            return Self::EMPTY;
+        }
+
+        // sin is odd.
+        let a = c.sin_rev_inf(x.inf_raw());
+        let b = -(-c).sin_rev_inf(-x.sup_raw());
+        if a > b {
+            Self::EMPTY
+        } else {
+            Self::with_infsup_raw(a, b)
+        }
+    }
+
+    /// Returns a lower bound of the least $x ≥ a$ that satisfies $\sin x ∈ \self$,
+    /// where $\self ⊆ \[-1, 1\]$ is nonempty.
+    fn sin_rev_inf(self, a: f64) -> f64 {
+        if a == f64::NEG_INFINITY {
+            return a;
+        }
+
+        // The solutions in [-π/2, 3π/2] are [α, β] ∪ [π - β, π - α], where [α, β] = asin(self).
+        let prec = periodic_rev_prec(a);
+        let asin = |x: f64, round| {
+            let x = Float::with_val(f64::MANTISSA_DIGITS, x);
+            Float::with_val_round(prec, x.asin_ref(), round).0
+        };
+        let alpha = asin(self.inf_raw(), Round::Down);
+        let beta = asin(self.sup_raw(), Round::Up);
+        let pi_lo = Float::with_val_round(prec, Constant::Pi, Round::Down).0;
+        let pi_hi = Float::with_val_round(prec, Constant::Pi, Round::Up).0;
+        let pi_minus_beta = Float::with_val_round(prec, &pi_lo - &beta, Round::Down).0;
+        let pi_minus_alpha = Float::with_val_round(prec, &pi_hi - &alpha, Round::Up).0;
+        periodic_rev_inf(
+            &[(alpha, beta), (pi_minus_beta, pi_minus_alpha)],
+            2,
+            prec,
+            a,
+        )
+    }
+
+    /// Returns the tightest interval that contains $\set{x ∈ 𝚡 ∣ \tan x ∈ \self}$.
+    ///
+    /// Pass [`Interval::ENTIRE`] as `x` to get all solutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{Interval as I, const_interval as c};
+    /// assert_eq!(c!(0.0, 0.0).tan_rev(c!(-1.0, 1.0)), c!(0.0, 0.0));
+    /// assert_eq!(c!(0.0, 0.0).tan_rev(c!(2.0, 4.0)), I::PI);
+    /// assert_eq!(I::ENTIRE.tan_rev(c!(-1.0, 1.0)), c!(-1.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn tan_rev(self, x: Self) -> Self {
+        let c = self;
+        if c.either_empty(x) {
+            return Self::EMPTY;
+        }
+
+        // tan is odd.
+        let a = c.tan_rev_inf(x.inf_raw());
+        let b = -(-c).tan_rev_inf(-x.sup_raw());
+        if a > b {
+            Self::EMPTY
+        } else {
+            Self::with_infsup_raw(a, b)
+        }
+    }
+
+    /// Returns a lower bound of the least $x ≥ a$ that satisfies $\tan x ∈ \self$,
+    /// where $\self$ is nonempty.
+    fn tan_rev_inf(self, a: f64) -> f64 {
+        if a == f64::NEG_INFINITY {
+            return a;
+        }
+
+        // The solutions in (-π/2, π/2) are [α, β], where [α, β] = atan(self).
+        let prec = periodic_rev_prec(a);
+        let atan = |x: f64, round| {
+            let x = Float::with_val(f64::MANTISSA_DIGITS, x);
+            Float::with_val_round(prec, x.atan_ref(), round).0
+        };
+        let alpha = atan(self.inf_raw(), Round::Down);
+        let beta = atan(self.sup_raw(), Round::Up);
+        periodic_rev_inf(&[(alpha, beta)], 1, prec, a)
+    }
+}
+
+/// Returns the precision that is sufficient for computing $p + kT$ without cancellation,
+/// where $|kT|$ is about $|a|$.
+#[cfg(feature = "gmp")]
+fn periodic_rev_prec(a: f64) -> u32 {
+    let e = if a.abs() > 1.0 {
+        a.abs().log2().ceil() as u32
+    } else {
+        0
+    };
+    e + 2 * f64::MANTISSA_DIGITS
+}
+
+/// Returns a lower bound of the least $x ≥ a$ in the set
+///
+/// $$
+/// \bigcup_{k ∈ \Z} \bigcup_i \[p_i + kT, q_i + kT\],
+/// $$
+///
+/// where $a$ is finite, $T = mπ$, $(p_i, q_i)$ are given by `pieces`,
+/// and every piece lies within $\[-T, T\]$.
+///
+/// $p_i$ and $q_i$ must be rounded downward and upward, respectively.
+#[cfg(feature = "gmp")]
+fn periodic_rev_inf(pieces: &[(Float, Float)], m: u32, prec: u32, a: f64) -> f64 {
+    let pi_lo = Float::with_val_round(prec, Constant::Pi, Round::Down).0;
+    let pi_hi = Float::with_val_round(prec, Constant::Pi, Round::Up).0;
+    let t_lo = Float::with_val_round(prec, &pi_lo * m, Round::Down).0;
+    let t_hi = Float::with_val_round(prec, &pi_hi * m, Round::Up).0;
+
+    // Overestimation of the range of k is fine.
+    let n = (Float::with_val(prec, a) / &t_lo).floor();
+    let mut inf = f64::INFINITY;
+    for i in -2..=2 {
+        let k = Float::with_val(prec, &n + i);
+        let (kt_lo, kt_hi) = if k.is_sign_positive() {
+            (
+                Float::with_val_round(prec, &k * &t_lo, Round::Down).0,
+                Float::with_val_round(prec, &k * &t_hi, Round::Up).0,
+            )
+        } else {
+            (
+                Float::with_val_round(prec, &k * &t_hi, Round::Down).0,
+                Float::with_val_round(prec, &k * &t_lo, Round::Up).0,
+            )
+        };
+        for (p, q) in pieces {
+            let q = Float::with_val_round(prec, q + &kt_hi, Round::Up).0;
+            if q >= a {
+                let p = Float::with_val_round(prec, p + &kt_lo, Round::Down).0;
+                inf = inf.min(p.to_f64_round(Round::Down).max(a));
+            }
+        }
+    }
+    inf
+}
+
+macro_rules! impl_dec {
+    ($f:ident) => {
+        #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
+        ///
+        /// A NaI is returned if `self` or `x` is NaI.
+        /// Otherwise, the result is decorated with [`Decoration::Trv`].
+        #[must_use]
+        pub fn $f(self, x: Self) -> Self {
+            if self.is_nai() || x.is_nai() {
+                return Self::NAI;
+            }
+
+            Self::set_dec(self.x.$f(x.x), Decoration::Trv)
+        }
+    };
+}
+
+macro_rules! impl_dec2 {
+    ($f:ident) => {
+        #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
+        ///
+        /// A NaI is returned if `self`, `c` or `x` is NaI.
+        /// Otherwise, the result is decorated with [`Decoration::Trv`].
+        #[must_use]
+        pub fn $f(self, c: Self, x: Self) -> Self {
+            if self.is_nai() || c.is_nai() || x.is_nai() {
+                return Self::NAI;
+            }
+
+            Self::set_dec(self.x.$f(c.x, x.x), Decoration::Trv)
+        }
+    };
+}
+
+impl DecInterval {
+    impl_dec!(abs_rev);
+    impl_dec2!(mul_rev);
+    impl_dec!(sqr_rev);
+}
+
+#[cfg(feature = "gmp")]
+impl DecInterval {
+    impl_dec2!(atan2_rev1);
+    impl_dec2!(atan2_rev2);
+    impl_dec!(cos_rev);
+    impl_dec!(cosh_rev);
+    impl_dec2!(pow_rev1);
+    impl_dec2!(pow_rev2);
+
+    /// The decorated version of [`Interval::powi_rev`].
+    ///
+    /// A NaI is returned if `self` or `x` is NaI.
+    /// Otherwise, the result is decorated with [`Decoration::Trv`].
+    #[must_use]
+    pub fn powi_rev(self, x: Self, n: i32) -> Self {
+        if self.is_nai() || x.is_nai() {
+            return Self::NAI;
+        }
+
+        Self::set_dec(self.x.powi_rev(x.x, n), Decoration::Trv)
+    }
+
+    impl_dec!(sin_rev);
+    impl_dec!(tan_rev);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use DecInterval as DI;
+    use Interval as I;
+
+    #[test]
+    fn empty() {
+        assert!(I::EMPTY.abs_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.abs_rev(I::EMPTY).is_empty());
+        assert!(I::EMPTY.mul_rev(I::ENTIRE, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.mul_rev(I::EMPTY, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.mul_rev(I::ENTIRE, I::EMPTY).is_empty());
+        assert!(I::EMPTY.sqr_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.sqr_rev(I::EMPTY).is_empty());
+
+        assert!(DI::EMPTY.abs_rev(DI::ENTIRE).is_empty());
+        assert!(DI::ENTIRE.abs_rev(DI::EMPTY).is_empty());
+        assert!(DI::EMPTY.mul_rev(DI::ENTIRE, DI::ENTIRE).is_empty());
+        assert!(DI::ENTIRE.mul_rev(DI::EMPTY, DI::ENTIRE).is_empty());
+        assert!(DI::ENTIRE.mul_rev(DI::ENTIRE, DI::EMPTY).is_empty());
+        assert!(DI::EMPTY.sqr_rev(DI::ENTIRE).is_empty());
+        assert!(DI::ENTIRE.sqr_rev(DI::EMPTY).is_empty());
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn empty_gmp() {
+        assert!(I::EMPTY.atan2_rev1(I::ENTIRE, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.atan2_rev1(I::EMPTY, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.atan2_rev1(I::ENTIRE, I::EMPTY).is_empty());
+        assert!(I::EMPTY.atan2_rev2(I::ENTIRE, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.atan2_rev2(I::EMPTY, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.atan2_rev2(I::ENTIRE, I::EMPTY).is_empty());
+        assert!(I::EMPTY.cos_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.cos_rev(I::EMPTY).is_empty());
+        assert!(I::EMPTY.cosh_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.cosh_rev(I::EMPTY).is_empty());
+        assert!(I::EMPTY.pow_rev1(I::ENTIRE, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.pow_rev1(I::EMPTY, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.pow_rev1(I::ENTIRE, I::EMPTY).is_empty());
+        assert!(I::EMPTY.pow_rev2(I::ENTIRE, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.pow_rev2(I::EMPTY, I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.pow_rev2(I::ENTIRE, I::EMPTY).is_empty());
+        assert!(I::EMPTY.powi_rev(I::ENTIRE, 2).is_empty());
+        assert!(I::ENTIRE.powi_rev(I::EMPTY, 2).is_empty());
+        assert!(I::EMPTY.sin_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.sin_rev(I::EMPTY).is_empty());
+        assert!(I::EMPTY.tan_rev(I::ENTIRE).is_empty());
+        assert!(I::ENTIRE.tan_rev(I::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn nai() {
+        assert!(DI::NAI.abs_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.abs_rev(DI::NAI).is_nai());
+        assert!(DI::NAI.mul_rev(DI::ENTIRE, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.mul_rev(DI::NAI, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.mul_rev(DI::ENTIRE, DI::NAI).is_nai());
+        assert!(DI::NAI.sqr_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.sqr_rev(DI::NAI).is_nai());
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn nai_gmp() {
+        assert!(DI::NAI.atan2_rev1(DI::ENTIRE, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.atan2_rev1(DI::NAI, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.atan2_rev1(DI::ENTIRE, DI::NAI).is_nai());
+        assert!(DI::NAI.atan2_rev2(DI::ENTIRE, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.atan2_rev2(DI::NAI, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.atan2_rev2(DI::ENTIRE, DI::NAI).is_nai());
+        assert!(DI::NAI.cos_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.cos_rev(DI::NAI).is_nai());
+        assert!(DI::NAI.cosh_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.cosh_rev(DI::NAI).is_nai());
+        assert!(DI::NAI.pow_rev1(DI::ENTIRE, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.pow_rev1(DI::NAI, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.pow_rev1(DI::ENTIRE, DI::NAI).is_nai());
+        assert!(DI::NAI.pow_rev2(DI::ENTIRE, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.pow_rev2(DI::NAI, DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.pow_rev2(DI::ENTIRE, DI::NAI).is_nai());
+        assert!(DI::NAI.powi_rev(DI::ENTIRE, 2).is_nai());
+        assert!(DI::ENTIRE.powi_rev(DI::NAI, 2).is_nai());
+        assert!(DI::NAI.sin_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.sin_rev(DI::NAI).is_nai());
+        assert!(DI::NAI.tan_rev(DI::ENTIRE).is_nai());
+        assert!(DI::ENTIRE.tan_rev(DI::NAI).is_nai());
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn accuracy() {
+        // `r` must contain the tightest enclosure `t` and be only slightly wider.
+        fn check(r: I, t: I) {
+            let close = |x: f64, y: f64| x == y || (x - y).abs() <= 64.0 * f64::EPSILON * y.abs();
+            assert!(t.subset(r), "{:?} is not a subset of {:?}", t, r);
+            assert!(
+                close(r.inf(), t.inf()) && close(r.sup(), t.sup()),
+                "{:?} is too wide compared to {:?}",
+                r,
+                t
+            );
+        }
+
+        check(
+            const_interval!(1.0, 1.0).pow_rev1(const_interval!(2.0, 3.0), I::ENTIRE),
+            const_interval!(2.0, 3.0),
+        );
+        check(
+            const_interval!(1.0, 1.0)
+                .pow_rev1(const_interval!(2.0, 3.0), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(2.0, 2.0).pow_rev1(const_interval!(4.0, 9.0), I::ENTIRE),
+            const_interval!(2.0, 3.0),
+        );
+        check(
+            const_interval!(2.0, 2.0)
+                .pow_rev1(const_interval!(4.0, 9.0), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(2.0, 3.0).pow_rev1(const_interval!(8.0, 8.0), I::ENTIRE),
+            const_interval!(2.0, 2.8284271247461903),
+        );
+        check(
+            const_interval!(2.0, 3.0)
+                .pow_rev1(const_interval!(8.0, 8.0), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(0.5, 0.5).pow_rev1(const_interval!(4.0, 4.0), I::ENTIRE),
+            const_interval!(16.0, 16.0),
+        );
+        check(
+            const_interval!(0.5, 0.5)
+                .pow_rev1(const_interval!(4.0, 4.0), const_interval!(0.0, 2.5)),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(-1.0, -1.0).pow_rev1(const_interval!(0.25, 0.5), I::ENTIRE),
+            const_interval!(2.0, 4.0),
+        );
+        check(
+            const_interval!(-1.0, -1.0)
+                .pow_rev1(const_interval!(0.25, 0.5), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(-2.0, -1.0).pow_rev1(const_interval!(0.5, 4.0), I::ENTIRE),
+            const_interval!(0.25, 2.0),
+        );
+        check(
+            const_interval!(-2.0, -1.0)
+                .pow_rev1(const_interval!(0.5, 4.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.25, 2.0),
+        );
+        check(
+            const_interval!(3.0, 3.0).pow_rev1(const_interval!(27.0, 27.0), I::ENTIRE),
+            const_interval!(3.0, 3.0),
+        );
+        check(
+            const_interval!(3.0, 3.0)
+                .pow_rev1(const_interval!(27.0, 27.0), const_interval!(0.0, 2.5)),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(1.0, 4.0).pow_rev1(const_interval!(1.0, 16.0), I::ENTIRE),
+            const_interval!(1.0, 16.0),
+        );
+        check(
+            const_interval!(1.0, 4.0)
+                .pow_rev1(const_interval!(1.0, 16.0), const_interval!(0.0, 2.5)),
+            const_interval!(1.0, 2.5),
+        );
+        check(
+            const_interval!(10.0, 10.0).pow_rev1(const_interval!(10000000000.0, 1e+100), I::ENTIRE),
+            const_interval!(10.0, 10000000000.000002),
+        );
+        check(
+            const_interval!(10.0, 10.0).pow_rev1(
+                const_interval!(10000000000.0, 1e+100),
+                const_interval!(0.0, 2.5),
+            ),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(2.0, 2.0).pow_rev2(const_interval!(4.0, 8.0), I::ENTIRE),
+            const_interval!(2.0, 3.0),
+        );
+        check(
+            const_interval!(2.0, 2.0)
+                .pow_rev2(const_interval!(4.0, 8.0), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(2.0, 4.0).pow_rev2(const_interval!(16.0, 16.0), I::ENTIRE),
+            const_interval!(2.0, 4.0),
+        );
+        check(
+            const_interval!(2.0, 4.0)
+                .pow_rev2(const_interval!(16.0, 16.0), const_interval!(0.0, 2.5)),
+            const_interval!(2.0, 2.5),
+        );
+        check(
+            const_interval!(0.5, 0.5).pow_rev2(const_interval!(0.25, 4.0), I::ENTIRE),
+            const_interval!(-2.0, 2.0),
+        );
+        check(
+            const_interval!(0.5, 0.5)
+                .pow_rev2(const_interval!(0.25, 4.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 2.0),
+        );
+        check(
+            const_interval!(10.0, 10.0).pow_rev2(const_interval!(1e-10, 10000000000.0), I::ENTIRE),
+            const_interval!(-10.0, 10.0),
+        );
+        check(
+            const_interval!(10.0, 10.0).pow_rev2(
+                const_interval!(1e-10, 10000000000.0),
+                const_interval!(0.0, 2.5),
+            ),
+            const_interval!(0.0, 2.5),
+        );
+        check(
+            const_interval!(2.0, 2.0).pow_rev2(const_interval!(0.5, 0.5), I::ENTIRE),
+            const_interval!(-1.0, -1.0),
+        );
+        check(
+            const_interval!(2.0, 2.0)
+                .pow_rev2(const_interval!(0.5, 0.5), const_interval!(0.0, 2.5)),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(4.0, 8.0).pow_rev2(const_interval!(2.0, 2.0), I::ENTIRE),
+            const_interval!(0.3333333333333333, 0.5),
+        );
+        check(
+            const_interval!(4.0, 8.0)
+                .pow_rev2(const_interval!(2.0, 2.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.3333333333333333, 0.5),
+        );
+        check(
+            const_interval!(0.25, 0.5).pow_rev2(const_interval!(0.5, 2.0), I::ENTIRE),
+            const_interval!(-1.0, 1.0),
+        );
+        check(
+            const_interval!(0.25, 0.5)
+                .pow_rev2(const_interval!(0.5, 2.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 1.0),
+        );
+        check(
+            const_interval!(1.0, 1.0).atan2_rev1(const_interval!(0.5, 0.5), I::ENTIRE),
+            const_interval!(0.5463024898437905, 0.5463024898437906),
+        );
+        check(
+            const_interval!(1.0, 1.0)
+                .atan2_rev1(const_interval!(0.5, 0.5), const_interval!(0.0, 2.5)),
+            const_interval!(0.5463024898437905, 0.5463024898437906),
+        );
+        check(
+            const_interval!(1.0, 2.0).atan2_rev1(const_interval!(0.1, 0.2), I::ENTIRE),
+            const_interval!(0.10033467208545055, 0.405420071017345),
+        );
+        check(
+            const_interval!(1.0, 2.0)
+                .atan2_rev1(const_interval!(0.1, 0.2), const_interval!(0.0, 2.5)),
+            const_interval!(0.10033467208545055, 0.405420071017345),
+        );
+        check(
+            const_interval!(3.0, 3.0).atan2_rev1(const_interval!(-1.0, 1.0), I::ENTIRE),
+            const_interval!(-4.672223173964707, 4.672223173964707),
+        );
+        check(
+            const_interval!(3.0, 3.0)
+                .atan2_rev1(const_interval!(-1.0, 1.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 2.5),
+        );
+        check(
+            const_interval!(0.001, 1000.0).atan2_rev1(const_interval!(-0.5, 0.25), I::ENTIRE),
+            const_interval!(-546.3024898437906, 255.34192122103627),
+        );
+        check(
+            const_interval!(0.001, 1000.0)
+                .atan2_rev1(const_interval!(-0.5, 0.25), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 2.5),
+        );
+        check(
+            const_interval!(1.0, 4.0).atan2_rev1(const_interval!(-1.5, -1.0), I::ENTIRE),
+            const_interval!(-56.40567978868688, -1.557407724654902),
+        );
+        check(
+            const_interval!(1.0, 4.0)
+                .atan2_rev1(const_interval!(-1.5, -1.0), const_interval!(0.0, 2.5)),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(1.0, 1.0).atan2_rev2(const_interval!(0.5, 0.5), I::ENTIRE),
+            const_interval!(1.8304877217124518, 1.830487721712452),
+        );
+        check(
+            const_interval!(1.0, 1.0)
+                .atan2_rev2(const_interval!(0.5, 0.5), const_interval!(0.0, 2.5)),
+            const_interval!(1.8304877217124518, 1.830487721712452),
+        );
+        check(
+            const_interval!(1.0, 2.0).atan2_rev2(const_interval!(0.1, 0.2), I::ENTIRE),
+            const_interval!(4.933154875586893, 19.933288846518476),
+        );
+        check(
+            const_interval!(1.0, 2.0)
+                .atan2_rev2(const_interval!(0.1, 0.2), const_interval!(0.0, 2.5)),
+            I::EMPTY,
+        );
+        check(
+            const_interval!(3.0, 3.0).atan2_rev2(const_interval!(1.0, 2.0), I::ENTIRE),
+            const_interval!(-1.3729726630808574, 1.9262778478029923),
+        );
+        check(
+            const_interval!(3.0, 3.0)
+                .atan2_rev2(const_interval!(1.0, 2.0), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 1.9262778478029923),
+        );
+        check(
+            const_interval!(0.001, 1000.0).atan2_rev2(const_interval!(0.5, 2.5), I::ENTIRE),
+            const_interval!(-1338.6481283041514, 1830.487721712452),
+        );
+        check(
+            const_interval!(0.001, 1000.0)
+                .atan2_rev2(const_interval!(0.5, 2.5), const_interval!(0.0, 2.5)),
+            const_interval!(0.0, 2.5),
+        );
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn sin_rev_huge() {
+        let x = const_interval!(1e300, 1e300 + 1e285);
+        let y = const_interval!(-1.0, 1.0).sin_rev(x);
+        assert_eq!(y, x);
+    }
+}
@@ -0,0 +1,208 @@
+//! Verified root finding of univariate functions by the interval Newton method.
+//!
+//! # Examples
+//!
+//! ```
+//! use inari::{const_interval as c, roots};
+//! // f(x) = x² - 2
+//! let f = |x: inari::Interval| x.sqr() - c!(2.0, 2.0);
+//! let df = |x: inari::Interval| c!(2.0, 2.0) * x;
+//! let rs = roots::newton(f, df, c!(-3.0, 3.0), 1e-12);
+//! assert_eq!(rs.len(), 2);
+//! assert!(rs.iter().all(|r| r.is_unique()));
+//! assert!(rs[0].enclosure().contains(-std::f64::consts::SQRT_2));
+//! assert!(rs[1].enclosure().contains(std::f64::consts::SQRT_2));
+//! ```
+use crate::{interval::*, vector::point};
+use alloc::{vec, vec::Vec};
+
+// The maximum number of Newton steps applied to an interval after the existence of
+// a unique zero has been proven in it.
+const MAX_ITER: usize = 64;
+
+/// An enclosure of zeros of a function.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Root {
+    enclosure: Interval,
+    unique: bool,
+}
+
+impl Root {
+    /// Returns the interval that contains the zeros.
+    pub fn enclosure(&self) -> Interval {
+        self.enclosure
+    }
+
+    /// Returns `true` if the enclosure is proven to contain exactly one zero of the function.
+    ///
+    /// Otherwise, the enclosure may contain no zeros, a single zero, or multiple zeros.
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+}
+
+/// Returns enclosures of all zeros of the function in the interval `x`.
+///
+/// The closures `f` and `df` must return enclosures of the ranges of the function $f$ and its
+/// derivative $f'$ over the given interval, respectively, where $f$ must be continuously
+/// differentiable on `x`.
+///
+/// The interval is split either by the extended interval Newton operator
+/// $N(X) = m - f(m) / F'(X)$, where $m$ is the midpoint of $X$ and $F'(X)$ is the enclosure of
+/// the range of $f'$ over $X$ ([`Interval::mul_rev_to_pair`]), or by bisection at $m$ when the
+/// Newton step does not reduce the width enough. Any interval $X$ such that $N(X)$ is
+/// contained in the interior of $X$ contains exactly one zero ([`Interval::interior`]), and is
+/// narrowed further by Newton steps. An interval whose width has become less than or equal to
+/// `tol` without being proven to contain a unique zero is reported as unresolved.
+///
+/// The enclosures are sorted in ascending order. Overlapping enclosures, which can arise when
+/// a zero lies at a point of bisection, are merged into their convex hull and reported as
+/// unresolved.
+///
+/// If `x` is empty, an empty [`Vec`] is returned.
+pub fn newton<F, DF>(f: F, df: DF, x: Interval, tol: f64) -> Vec<Root>
+where
+    F: Fn(Interval) -> Interval,
+    DF: Fn(Interval) -> Interval,
+{
+    let mut roots = Vec::<Root>::new();
+    let mut stack = vec![x];
+    'outer: while let Some(mut x) = stack.pop() {
+        let mut unique = false;
+        let mut n_refine = 0;
+        loop {
+            if x.is_empty() || !f(x).contains(0.0) {
+                continue 'outer;
+            }
+
+            let m = point(x.mid());
+            let [q1, q2] = df(x).mul_rev_to_pair(f(m));
+            let n1 = m - q1;
+            let n2 = (m - q2).intersection(x);
+            if !n2.is_empty() {
+                stack.push(n2);
+                stack.push(n1.intersection(x));
+                continue 'outer;
+            }
+
+            unique |= x.is_common_interval() && n1.interior(x);
+            let n = n1.intersection(x);
+            if unique {
+                n_refine += 1;
+                if n == x || n_refine > MAX_ITER {
+                    x = n;
+                    break;
+                }
+            } else if n.wid() <= tol {
+                x = n;
+                break;
+            } else if n == x || n.wid() > 0.5 * x.wid() {
+                // Bisect `n` since the Newton step did not make enough progress.
+                let mid = n.mid();
+                if mid == n.inf() || mid == n.sup() {
+                    x = n;
+                    break;
+                }
+                stack.push(Interval::with_infsup_raw(mid, n.sup()));
+                stack.push(Interval::with_infsup_raw(n.inf(), mid));
+                continue 'outer;
+            }
+            x = n;
+        }
+        if !x.is_empty() && f(x).contains(0.0) {
+            roots.push(Root {
+                enclosure: x,
+                unique,
+            });
+        }
+    }
+
+    roots.sort_by(|r, s| r.enclosure.inf().total_cmp(&s.enclosure.inf()));
+    let mut merged = Vec::<Root>::with_capacity(roots.len());
+    for r in roots {
+        match merged.last_mut() {
+            Some(last) if !last.enclosure.disjoint(r.enclosure) => {
+                *last = Root {
+                    enclosure: last.enclosure.convex_hull(r.enclosure),
+                    unique: false,
+                };
+            }
+            _ => merged.push(r),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use Interval as I;
+
+    fn cubic(x: I) -> I {
+        // (x - 1)(x - 2)(x - 3) = ((x - 6) x + 11) x - 6
+        ((x - const_interval!(6.0, 6.0)) * x + const_interval!(11.0, 11.0)) * x
+            - const_interval!(6.0, 6.0)
+    }
+
+    fn d_cubic(x: I) -> I {
+        // 3x² - 12x + 11
+        (const_interval!(3.0, 3.0) * x - const_interval!(12.0, 12.0)) * x
+            + const_interval!(11.0, 11.0)
+    }
+
+    #[test]
+    fn simple_roots() {
+        let rs = newton(cubic, d_cubic, const_interval!(-10.0, 10.0), 1e-10);
+        assert_eq!(rs.len(), 3);
+        for (r, z) in rs.iter().zip([1.0, 2.0, 3.0]) {
+            assert!(r.is_unique());
+            assert!(r.enclosure().contains(z));
+            assert!(r.enclosure().wid() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn unbounded() {
+        let f = |x: I| x.sqr() - const_interval!(2.0, 2.0);
+        let df = |x: I| const_interval!(2.0, 2.0) * x;
+        let rs = newton(f, df, I::ENTIRE, 1e-10);
+        assert_eq!(rs.len(), 2);
+        assert!(rs.iter().all(|r| r.is_unique()));
+        assert!(rs[0].enclosure().contains(-std::f64::consts::SQRT_2));
+        assert!(rs[1].enclosure().contains(std::f64::consts::SQRT_2));
+    }
+
+    #[test]
+    fn multiple_root() {
+        // (x - 1)²
+        let f = |x: I| (x - const_interval!(1.0, 1.0)).sqr();
+        let df = |x: I| const_interval!(2.0, 2.0) * (x - const_interval!(1.0, 1.0));
+        let rs = newton(f, df, const_interval!(-3.0, 4.0), 1e-8);
+        assert!(!rs.is_empty());
+        assert!(rs
+            .iter()
+            .all(|r| !r.is_unique() && r.enclosure().wid() <= 1e-7));
+        assert!(rs.iter().any(|r| r.enclosure().contains(1.0)));
+    }
+
+    #[test]
+    fn root_at_bisection_point() {
+        // A zero at the midpoint of the initial interval.
+        let f = |x: I| (x.sqr() - const_interval!(1.0, 1.0)) * x;
+        let df = |x: I| const_interval!(3.0, 3.0) * x.sqr() - const_interval!(1.0, 1.0);
+        let rs = newton(f, df, const_interval!(-2.0, 2.0), 1e-10);
+        assert_eq!(rs.len(), 3);
+        for (r, z) in rs.iter().zip([-1.0, 0.0, 1.0]) {
+            assert!(r.enclosure().contains(z));
+        }
+    }
+
+    #[test]
+    fn no_roots() {
+        let f = |x: I| x.sqr() + const_interval!(1.0, 1.0);
+        let df = |x: I| const_interval!(2.0, 2.0) * x;
+        assert!(newton(f, df, const_interval!(-5.0, 5.0), 1e-10).is_empty());
+        assert!(newton(f, df, I::EMPTY, 1e-10).is_empty());
+    }
+}
@@ -1,6 +1,28 @@
 use crate::{interval::*, simd::*};
 
 impl Interval {
+    /// Returns the closure of $\R ∖ \self$, the complement of `self`, as a pair of intervals.
+    ///
+    /// The result is in the same format as [`Interval::difference`]. For $\self = \[a, b\]$,
+    /// it is $\[-∞, a\]$ and $\[b, +∞\]$, with the empty parts removed, or $\R$ if $a = b$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, Interval as I};
+    /// assert_eq!(
+    ///     c!(1.0, 2.0).complement(),
+    ///     [c!(f64::NEG_INFINITY, 1.0), c!(2.0, f64::INFINITY)]
+    /// );
+    /// assert_eq!(c!(1.0, f64::INFINITY).complement(), [c!(f64::NEG_INFINITY, 1.0), I::EMPTY]);
+    /// assert_eq!(I::ENTIRE.complement(), [I::EMPTY; 2]);
+    /// assert_eq!(I::EMPTY.complement(), [I::ENTIRE, I::EMPTY]);
+    /// ```
+    #[must_use]
+    pub fn complement(self) -> [Self; 2] {
+        Self::ENTIRE.difference(self)
+    }
+
     /// Returns $\hull(\self ∪ \rhs)$, the tightest interval that contains both `self` and `rhs` as its subsets.
     ///
     /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$                      |
@@ -23,6 +45,57 @@ impl Interval {
         }
     }
 
+    /// Returns the closure of $\self ∖ \rhs$, the set difference of `self` and `rhs`,
+    /// as a pair of intervals.
+    ///
+    /// Since intervals are closed, the closure of the difference is returned instead of
+    /// the difference itself, which is generally not closed.
+    /// The intervals are returned in ascending order. If the result consists of fewer than two
+    /// intervals, the rest of the elements are filled with $∅$ from the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, Interval as I};
+    /// let x = c!(0.0, 3.0);
+    /// assert_eq!(x.difference(c!(1.0, 2.0)), [c!(0.0, 1.0), c!(2.0, 3.0)]);
+    /// assert_eq!(x.difference(c!(2.0, 5.0)), [c!(0.0, 2.0), I::EMPTY]);
+    /// assert_eq!(x.difference(c!(-1.0, 1.0)), [c!(1.0, 3.0), I::EMPTY]);
+    /// assert_eq!(x.difference(c!(1.0, 1.0)), [x, I::EMPTY]);
+    /// assert_eq!(x.difference(c!(-1.0, 5.0)), [I::EMPTY; 2]);
+    /// ```
+    #[must_use]
+    pub fn difference(self, rhs: Self) -> [Self; 2] {
+        if self.either_empty(rhs) {
+            return [self, Self::EMPTY];
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        let c = rhs.inf_raw();
+        let d = rhs.sup_raw();
+        if c == d && a < b {
+            // The closure of the difference is `self` itself.
+            return [self, Self::EMPTY];
+        }
+
+        let left = if a < c {
+            Self::with_infsup_raw(a, c.min(b))
+        } else {
+            Self::EMPTY
+        };
+        let right = if d < b {
+            Self::with_infsup_raw(d.max(a), b)
+        } else {
+            Self::EMPTY
+        };
+        if left.is_empty() {
+            [right, Self::EMPTY]
+        } else {
+            [left, right]
+        }
+    }
+
     /// Returns $\self ∩ \rhs$, the intersection of `self` and `rhs`.
     ///
     /// |                    | $\rhs = ∅$ | $\rhs = \[c, d\]$                      |
@@ -69,6 +142,36 @@ macro_rules! impl_dec {
 impl DecInterval {
     impl_dec!(convex_hull);
     impl_dec!(intersection);
+
+    /// Applies [`Interval::complement`] to the interval part of `self` and returns the results
+    /// decorated with [`Decoration::Trv`].
+    ///
+    /// `[`[`DecInterval::NAI`]`; 2]` is returned if `self` is NaI.
+    #[must_use]
+    pub fn complement(self) -> [Self; 2] {
+        if self.is_nai() {
+            return [Self::NAI; 2];
+        }
+
+        self.x
+            .complement()
+            .map(|x| Self::new_unchecked(x, Decoration::Trv))
+    }
+
+    /// Applies [`Interval::difference`] to the interval parts of `self` and `rhs` and returns
+    /// the results decorated with [`Decoration::Trv`].
+    ///
+    /// `[`[`DecInterval::NAI`]`; 2]` is returned if `self` or `rhs` is NaI.
+    #[must_use]
+    pub fn difference(self, rhs: Self) -> [Self; 2] {
+        if self.is_nai() || rhs.is_nai() {
+            return [Self::NAI; 2];
+        }
+
+        self.x
+            .difference(rhs.x)
+            .map(|x| Self::new_unchecked(x, Decoration::Trv))
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +193,51 @@ mod tests {
 
         assert!(DI::EMPTY.intersection(DI::PI).is_empty());
         assert!(DI::PI.intersection(DI::EMPTY).is_empty());
+
+        assert_eq!(I::EMPTY.difference(I::PI), [I::EMPTY; 2]);
+        assert_eq!(I::PI.difference(I::EMPTY), [I::PI, I::EMPTY]);
+        assert_eq!(I::EMPTY.complement(), [I::ENTIRE, I::EMPTY]);
+
+        let [x, y] = DI::EMPTY.complement();
+        assert_eq!(x.interval(), Some(I::ENTIRE));
+        assert_eq!(x.decoration(), Decoration::Trv);
+        assert!(y.is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let x = const_interval!(0.0, 3.0);
+        assert_eq!(x.difference(x), [I::EMPTY; 2]);
+        assert_eq!(x.difference(I::ENTIRE), [I::EMPTY; 2]);
+        assert_eq!(
+            x.difference(const_interval!(0.0, 1.0)),
+            [const_interval!(1.0, 3.0), I::EMPTY]
+        );
+        assert_eq!(
+            x.difference(const_interval!(3.0, 4.0)),
+            [const_interval!(0.0, 3.0), I::EMPTY]
+        );
+        assert_eq!(x.difference(const_interval!(4.0, 5.0)), [x, I::EMPTY]);
+        let one = const_interval!(1.0, 1.0);
+        assert_eq!(one.difference(one), [I::EMPTY; 2]);
+        assert_eq!(one.difference(x), [I::EMPTY; 2]);
+        assert_eq!(
+            I::ENTIRE.difference(const_interval!(f64::NEG_INFINITY, 0.0)),
+            [const_interval!(0.0, f64::INFINITY), I::EMPTY]
+        );
+
+        assert_eq!(
+            const_interval!(1.0, 1.0).complement(),
+            [I::ENTIRE, I::EMPTY]
+        );
+        assert_eq!(
+            const_interval!(f64::NEG_INFINITY, 1.0).complement(),
+            [const_interval!(1.0, f64::INFINITY), I::EMPTY]
+        );
+
+        let [y, z] = DI::new(x).difference(DI::new(const_interval!(1.0, 2.0)));
+        assert_eq!(y, DI::set_dec(const_interval!(0.0, 1.0), Decoration::Trv));
+        assert_eq!(z, DI::set_dec(const_interval!(2.0, 3.0), Decoration::Trv));
     }
 
     #[test]
@@ -99,5 +247,9 @@ mod tests {
 
         assert!(DI::NAI.intersection(DI::PI).is_nai());
         assert!(DI::PI.intersection(DI::NAI).is_nai());
+
+        assert!(DI::NAI.complement().iter().all(|x| x.is_nai()));
+        assert!(DI::NAI.difference(DI::PI).iter().all(|x| x.is_nai()));
+        assert!(DI::PI.difference(DI::NAI).iter().all(|x| x.is_nai()));
     }
 }
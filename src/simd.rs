@@ -7,17 +7,31 @@
 //
 //   r[63:0] = x0, r[127:64] = x1.
 
+// Besides being a backend, provides the fallbacks for the CPUs that lack some instruction set
+// extensions and `next_up`/`next_down` used by `special`.
+#[cfg_attr(
+    not(all(
+        not(docsrs),
+        any(
+            feature = "portable",
+            not(any(target_arch = "aarch64", target_arch = "x86_64"))
+        )
+    )),
+    allow(dead_code)
+)]
+pub(crate) mod portable;
+
 cfg_if::cfg_if! {
     if #[cfg(docsrs)] {
         mod unimplemented;
         pub(crate) use unimplemented::*;
+    } else if #[cfg(any(feature = "portable", not(any(target_arch = "aarch64", target_arch = "x86_64"))))] {
+        pub(crate) use portable::*;
     } else if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
         pub(crate) use aarch64::*;
-    } else if #[cfg(target_arch = "x86_64")] {
+    } else {
         mod x86_64;
         pub(crate) use x86_64::*;
-    } else {
-        unreachable!();
     }
 }
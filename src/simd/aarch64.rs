@@ -1,4 +1,4 @@
-use std::{
+use core::{
     arch::{aarch64::*, asm},
     mem::transmute,
 };
@@ -212,3 +212,5 @@ pub(crate) fn mul_add_ru(x: F64X2, y: F64X2, mut z: F64X2) -> F64X2 {
     }
     z
 }
+
+pub(crate) mod f32x2;
@@ -0,0 +1,93 @@
+use core::{
+    arch::{aarch64::*, asm},
+    mem::transmute,
+};
+
+pub(crate) type F32X2 = float32x2_t;
+
+pub(crate) fn abs(x: F32X2) -> F32X2 {
+    unsafe { vabs_f32(x) }
+}
+
+pub(crate) fn all(x: F32X2) -> bool {
+    unsafe { transmute::<F32X2, u64>(x) == !0u64 }
+}
+
+pub(crate) fn any(x: F32X2) -> bool {
+    unsafe { transmute::<F32X2, u64>(x) != 0u64 }
+}
+
+pub(crate) fn bitmask(x: F32X2) -> u32 {
+    let [a, b] = unsafe { transmute::<F32X2, [u32; 2]>(x) };
+    b & 0x2 | a & 0x1
+}
+
+pub(crate) fn constant(x: f32, y: f32) -> F32X2 {
+    unsafe { transmute([x, y]) }
+}
+
+pub(crate) fn eq(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { transmute(vceq_f32(x, y)) }
+}
+
+pub(crate) fn extract0(x: F32X2) -> f32 {
+    unsafe { transmute::<F32X2, [f32; 2]>(x)[0] }
+}
+
+pub(crate) fn extract1(x: F32X2) -> f32 {
+    unsafe { transmute::<F32X2, [f32; 2]>(x)[1] }
+}
+
+pub(crate) fn ge(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { transmute(vcge_f32(x, y)) }
+}
+
+pub(crate) fn le(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { transmute(vcle_f32(x, y)) }
+}
+
+pub(crate) fn lt(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { transmute(vclt_f32(x, y)) }
+}
+
+pub(crate) fn max(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { vmaxnm_f32(x, y) }
+}
+
+pub(crate) fn min(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { vminnm_f32(x, y) }
+}
+
+pub(crate) fn neg(x: F32X2) -> F32X2 {
+    unsafe { vneg_f32(x) }
+}
+
+pub(crate) fn neg0(x: F32X2) -> F32X2 {
+    constant(-extract0(x), extract1(x))
+}
+
+pub(crate) fn shuffle02(x: F32X2, y: F32X2) -> F32X2 {
+    constant(extract0(x), extract0(y))
+}
+
+pub(crate) fn shuffle03(x: F32X2, y: F32X2) -> F32X2 {
+    constant(extract0(x), extract1(y))
+}
+
+pub(crate) fn shuffle13(x: F32X2, y: F32X2) -> F32X2 {
+    constant(extract1(x), extract1(y))
+}
+
+pub(crate) fn splat(x: f32) -> F32X2 {
+    unsafe { transmute([x, x]) }
+}
+
+pub(crate) fn swap(x: F32X2) -> F32X2 {
+    unsafe { vrev64_f32(x) }
+}
+
+impl_op_round!(f32, sqrt1_rd(x), "fsqrt {x:s}, {x:s}", rd);
+impl_op_round!(f32, sqrt1_ru(x), "fsqrt {x:s}, {x:s}", ru);
+impl_op_round!(F32X2, add_ru(x, y), "fadd.2s {x:v}, {x:v}, {y:v}", ru);
+impl_op_round!(F32X2, mul_ru(x, y), "fmul.2s {x:v}, {x:v}, {y:v}", ru);
+impl_op_round!(F32X2, div_ru(x, y), "fdiv.2s {x:v}, {x:v}, {y:v}", ru);
@@ -0,0 +1,692 @@
+// A portable implementation in pure Rust, which does not depend on the rounding mode
+// of the floating-point unit.
+//
+// An operation rounded toward +∞ (or -∞) first computes the result rounded to nearest,
+// then corrects it by one ulp if the rounding error, which is computed exactly by
+// an error-free transformation (TwoSum or TwoProduct), has the wrong sign.
+// Multiplication, division and square root are performed on the significands
+// scaled into [1, 2) so that the error terms never underflow.
+//
+// A mask lane is either all ones (a NaN) or all zeros (+0).
+
+pub(crate) const HAS_MAXIMUM: bool = true;
+
+pub(crate) type F64X2 = [f64; 2];
+
+pub(crate) mod f32x2;
+
+pub(crate) fn abs(x: F64X2) -> F64X2 {
+    map(x, abs1)
+}
+
+/// `add_rn([x0, x1], [y0, y1]) = [x0 + y0, x1 + y1]` rounded to nearest.
+pub(crate) fn add_rn(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| x + y)
+}
+
+/// `add_ru([x0, x1], [y0, y1]) = [x0 + y0, x1 + y1]` rounded toward +∞.
+pub(crate) fn add_ru(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, add1_ru)
+}
+
+pub(crate) fn all(x: F64X2) -> bool {
+    bitmask(x) == 3
+}
+
+pub(crate) fn and(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| f64::from_bits(x.to_bits() & y.to_bits()))
+}
+
+pub(crate) fn any(x: F64X2) -> bool {
+    bitmask(x) != 0
+}
+
+pub(crate) fn bitmask(x: F64X2) -> u32 {
+    ((x[1].to_bits() >> 62) & 0x2 | x[0].to_bits() >> 63) as u32
+}
+
+pub(crate) fn ceil(x: F64X2) -> F64X2 {
+    map(x, |x| {
+        let t = trunc1(x);
+        if t < x {
+            t + 1.0
+        } else {
+            t
+        }
+    })
+}
+
+pub(crate) fn constant(x: f64, y: f64) -> F64X2 {
+    [x, y]
+}
+
+/// `div_ru([x0, x1], [y0, y1]) = [x0 / y0, x1 / y1]` rounded toward +∞.
+pub(crate) fn div_ru(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, div1_ru)
+}
+
+pub(crate) fn eq(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| mask(x == y))
+}
+
+pub(crate) fn extract(x: F64X2) -> [f64; 2] {
+    x
+}
+
+pub(crate) fn extract0(x: F64X2) -> f64 {
+    x[0]
+}
+
+pub(crate) fn extract1(x: F64X2) -> f64 {
+    x[1]
+}
+
+pub(crate) fn floor(x: F64X2) -> F64X2 {
+    map(x, |x| {
+        let t = trunc1(x);
+        if t > x {
+            t - 1.0
+        } else {
+            t
+        }
+    })
+}
+
+pub(crate) fn ge(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| mask(x >= y))
+}
+
+pub(crate) fn gt(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| mask(x > y))
+}
+
+/// `hadd_rn([x0, x1], [y0, y1]) = [x0 + x1, y0 + y1]` rounded to nearest.
+pub(crate) fn hadd_rn(x: F64X2, y: F64X2) -> F64X2 {
+    [x[0] + x[1], y[0] + y[1]]
+}
+
+pub(crate) fn le(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| mask(x <= y))
+}
+
+pub(crate) fn lt(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| mask(x < y))
+}
+
+// The vector version of the `maximumNumber` operation defined in IEEE 754-2019,
+// which does not propagate NaNs.
+pub(crate) fn max(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| {
+        if x.is_nan() {
+            y
+        } else if y.is_nan() {
+            x
+        } else {
+            maximum1(x, y)
+        }
+    })
+}
+
+// The vector version of the `maximum` operation defined in IEEE 754-2019,
+// which propagates NaNs.
+pub(crate) fn maximum(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, maximum1)
+}
+
+pub(crate) fn min(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| {
+        if x.is_nan() {
+            y
+        } else if y.is_nan() {
+            x
+        } else {
+            minimum1(x, y)
+        }
+    })
+}
+
+pub(crate) fn minimum(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, minimum1)
+}
+
+/// `mul_add_ru([x0, x1], [y0, y1], [z0, z1]) = [x0 × y0 + z0, x1 × y1 + z1]`
+/// rounded toward +∞.
+pub(crate) fn mul_add_ru(x: F64X2, y: F64X2, z: F64X2) -> F64X2 {
+    [mul_add1_ru(x[0], y[0], z[0]), mul_add1_ru(x[1], y[1], z[1])]
+}
+
+/// `mul_ru([x0, x1], [y0, y1]) = [x0 × y0, x1 × y1]` rounded toward +∞.
+pub(crate) fn mul_ru(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, mul1_ru)
+}
+
+pub(crate) fn neg(x: F64X2) -> F64X2 {
+    [-x[0], -x[1]]
+}
+
+pub(crate) fn neg0(x: F64X2) -> F64X2 {
+    [-x[0], x[1]]
+}
+
+pub(crate) fn or(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| f64::from_bits(x.to_bits() | y.to_bits()))
+}
+
+pub(crate) fn round(x: F64X2) -> F64X2 {
+    map(x, |x| {
+        let t = trunc1(x);
+        if abs1(x - t) >= 0.5 {
+            t + copysign1(1.0, x)
+        } else {
+            t
+        }
+    })
+}
+
+pub(crate) fn round_ties_even(x: F64X2) -> F64X2 {
+    map(x, |x| {
+        let t = trunc1(x);
+        let d = abs1(x - t);
+        if d > 0.5 || d == 0.5 && t % 2.0 != 0.0 {
+            t + copysign1(1.0, x)
+        } else {
+            t
+        }
+    })
+}
+
+/// `shuffle02([x0, x1], [x2, x3]) = [x0, x2]`
+pub(crate) fn shuffle02(x: F64X2, y: F64X2) -> F64X2 {
+    [x[0], y[0]]
+}
+
+/// `shuffle03([x0, x1], [x2, x3]) = [x0, x3]`
+pub(crate) fn shuffle03(x: F64X2, y: F64X2) -> F64X2 {
+    [x[0], y[1]]
+}
+
+/// `shuffle13([x0, x1], [x2, x3]) = [x1, x3]`
+pub(crate) fn shuffle13(x: F64X2, y: F64X2) -> F64X2 {
+    [x[1], y[1]]
+}
+
+pub(crate) fn splat(x: f64) -> F64X2 {
+    [x, x]
+}
+
+/// `sqrt1_rd(x) = √x` rounded toward -∞.
+pub(crate) fn sqrt1_rd(x: f64) -> f64 {
+    sqrt1_round(x, false)
+}
+
+/// `sqrt1_ru(x) = √x` rounded toward +∞.
+pub(crate) fn sqrt1_ru(x: f64) -> f64 {
+    sqrt1_round(x, true)
+}
+
+/// `sub_rn([x0, x1], [y0, y1]) = [x0 - y0, x1 - y1]` rounded to nearest.
+pub(crate) fn sub_rn(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, |x, y| x - y)
+}
+
+/// `sub_ru([x0, x1], [y0, y1]) = [x0 - y0, x1 - y1]` rounded toward +∞.
+pub(crate) fn sub_ru(x: F64X2, y: F64X2) -> F64X2 {
+    zip(x, y, sub1_ru)
+}
+
+/// `sub1_ru(x, y) = x - y` rounded toward +∞.
+pub(crate) fn sub1_ru(x: f64, y: f64) -> f64 {
+    add1_ru(x, -y)
+}
+
+pub(crate) fn swap(x: F64X2) -> F64X2 {
+    [x[1], x[0]]
+}
+
+pub(crate) fn trunc(x: F64X2) -> F64X2 {
+    map(x, trunc1)
+}
+
+fn map(x: F64X2, f: impl Fn(f64) -> f64) -> F64X2 {
+    [f(x[0]), f(x[1])]
+}
+
+fn zip(x: F64X2, y: F64X2, f: impl Fn(f64, f64) -> f64) -> F64X2 {
+    [f(x[0], y[0]), f(x[1], y[1])]
+}
+
+fn mask(b: bool) -> f64 {
+    f64::from_bits(if b { !0 } else { 0 })
+}
+
+// The scalar functions below are implemented with bit operations rather than
+// the inherent methods of `f64`, some of which are not available in `core`.
+
+const SIGN: u64 = 1 << 63;
+
+fn abs1(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !SIGN)
+}
+
+fn copysign1(x: f64, y: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !SIGN | y.to_bits() & SIGN)
+}
+
+fn maximum1(x: f64, y: f64) -> f64 {
+    if x > y || x.is_nan() {
+        x
+    } else if x < y || y.is_nan() {
+        y
+    } else {
+        // +0 is greater than -0.
+        f64::from_bits(x.to_bits() & y.to_bits())
+    }
+}
+
+fn minimum1(x: f64, y: f64) -> f64 {
+    if x < y || x.is_nan() {
+        x
+    } else if x > y || y.is_nan() {
+        y
+    } else {
+        // -0 is less than +0.
+        f64::from_bits(x.to_bits() | y.to_bits())
+    }
+}
+
+fn trunc1(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    if e >= 52 {
+        // Integers, infinities and NaNs.
+        x
+    } else if e < 0 {
+        f64::from_bits(bits & SIGN)
+    } else {
+        f64::from_bits(bits & !((1u64 << (52 - e)) - 1))
+    }
+}
+
+// Returns the least number greater than `x`.
+pub(crate) fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+// Returns the greatest number less than `x`.
+pub(crate) fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+// Returns 2^k for -1022 ≤ k ≤ 1023.
+fn pow2(k: i32) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+// Returns x × 2^k rounded to nearest.
+//
+// The result is correctly rounded if 1/2 ≤ |x| < 4 or if it is exact.
+fn ldexp(x: f64, k: i32) -> f64 {
+    if k > 1023 {
+        x * pow2(1023) * pow2((k - 1023).min(1023))
+    } else if k < -1022 {
+        x * pow2(-1020) * pow2((k + 1020).max(-1022))
+    } else {
+        x * pow2(k)
+    }
+}
+
+// Returns (m, k) such that x = m × 2^k and 1 ≤ |m| < 2, for a finite nonzero `x`.
+fn frexp(x: f64) -> (f64, i32) {
+    let (x, k0) = if abs1(x) < f64::MIN_POSITIVE {
+        (x * pow2(64), -64)
+    } else {
+        (x, 0)
+    };
+    let bits = x.to_bits();
+    let k = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let m = f64::from_bits(bits & !(0x7ff << 52) | 1023 << 52);
+    (m, k + k0)
+}
+
+// `two_sum(x, y) = (s, e)`, where `s` = x + y rounded to nearest and `e` = x + y - s exactly,
+// unless `s` overflows.
+fn two_sum(x: f64, y: f64) -> (f64, f64) {
+    let s = x + y;
+    let y2 = s - x;
+    let x2 = s - y2;
+    (s, (x - x2) + (y - y2))
+}
+
+// `two_prod(x, y) = (p, e)`, where `p` = x × y rounded to nearest and `e` = x × y - p exactly,
+// if 1 ≤ |x|, |y| < 4 (Dekker's algorithm).
+fn two_prod(x: f64, y: f64) -> (f64, f64) {
+    fn split(x: f64) -> (f64, f64) {
+        let c = 134217729.0 * x; // 2^27 + 1
+        let h = c - (c - x);
+        (h, x - h)
+    }
+
+    let p = x * y;
+    let (xh, xl) = split(x);
+    let (yh, yl) = split(y);
+    let e = ((xh * yh - p) + xh * yl + xl * yh) + xl * yl;
+    (p, e)
+}
+
+// Returns (m + e) × 2^k rounded toward +∞ if `up` is `true`, or toward -∞ otherwise,
+// where 1/2 ≤ |m| < 4 and |e| < ulp(m) / 2. Only the sign of `e` is used.
+fn round_scaled(m: f64, e: f64, k: i32, up: bool) -> f64 {
+    let q = ldexp(m, k);
+    if q.is_infinite() {
+        return match (q > 0.0, up) {
+            (true, false) => f64::MAX,
+            (false, true) => f64::MIN,
+            _ => q,
+        };
+    }
+
+    // The sign of q - (m + e) × 2^k.
+    let back = ldexp(q, -k);
+    let d = if back != m { back - m } else { -e };
+    if up && d < 0.0 {
+        next_up(q)
+    } else if !up && d > 0.0 {
+        next_down(q)
+    } else {
+        q
+    }
+}
+
+fn add1_ru(x: f64, y: f64) -> f64 {
+    let (s, e) = two_sum(x, y);
+    if s.is_finite() {
+        if e > 0.0 {
+            next_up(s)
+        } else {
+            s
+        }
+    } else if s == f64::NEG_INFINITY && x.is_finite() && y.is_finite() {
+        f64::MIN
+    } else {
+        s
+    }
+}
+
+fn mul1_ru(x: f64, y: f64) -> f64 {
+    if !x.is_finite() || !y.is_finite() || x == 0.0 || y == 0.0 {
+        return x * y;
+    }
+
+    let (mx, kx) = frexp(x);
+    let (my, ky) = frexp(y);
+    let (p, e) = two_prod(mx, my);
+    round_scaled(p, e, kx + ky, true)
+}
+
+fn div1_ru(x: f64, y: f64) -> f64 {
+    if !x.is_finite() || !y.is_finite() || x == 0.0 || y == 0.0 {
+        return x / y;
+    }
+
+    let (mx, kx) = frexp(x);
+    let (my, ky) = frexp(y);
+    let q = mx / my;
+    let (p, e) = two_prod(q, my);
+    // mx - p is exact by Sterbenz's lemma, and the sign of r is that of mx - q × my.
+    let r = (mx - p) - e;
+    round_scaled(q, if my > 0.0 { r } else { -r }, kx - ky, true)
+}
+
+fn mul_add1_ru(x: f64, y: f64, z: f64) -> f64 {
+    if z.is_infinite() && x.is_finite() && y.is_finite() {
+        return z;
+    }
+    if !x.is_finite() || !y.is_finite() || !z.is_finite() || x == 0.0 || y == 0.0 {
+        // The result is exact, an infinity or a NaN.
+        return x * y + z;
+    }
+    if z == 0.0 {
+        return mul1_ru(x, y);
+    }
+
+    // Normalizes m so that 2^125 ≤ m < 2^126.
+    fn normalize(m: u128, e: i32) -> (u128, i32) {
+        let k = m.leading_zeros() - 2;
+        (m << k, e - k as i32)
+    }
+
+    let (sx, mx, ex) = decompose(x);
+    let (sy, my, ey) = decompose(y);
+    let (sz, mz, ez) = decompose(z);
+    let p = normalize(mx as u128 * my as u128, ex + ey);
+    let z = normalize(mz as u128, ez);
+    let ((a, ea, sa), (b, eb, sb)) = if p.1 >= z.1 {
+        ((p.0, p.1, sx ^ sy), (z.0, z.1, sz))
+    } else {
+        ((z.0, z.1, sz), (p.0, p.1, sx ^ sy))
+    };
+
+    // Aligns b to a. At least 20 low bits of a and b are zero, so bits are shifted out
+    // only if d > 20, in which case |a ± b| ≥ 2^124. Then a sticky bit below them is enough
+    // for directed rounding.
+    let d = (ea - eb) as u32;
+    let bs = b.checked_shr(d).unwrap_or(0);
+    let sticky = bs.checked_shl(d).unwrap_or(0) != b;
+    let a = a << 1;
+    let b = bs << 1 | sticky as u128;
+    let (m, neg) = if sa == sb {
+        (a + b, sa)
+    } else if a >= b {
+        (a - b, sa)
+    } else {
+        (b - a, sb)
+    };
+    if m == 0 {
+        // An exact zero is +0 when rounded toward +∞.
+        return 0.0;
+    }
+
+    // x × y + z = ±m × 2^e, where m has n bits. Now round it to f64 with ulp 2^k.
+    let e = ea - 1;
+    let n = 128 - m.leading_zeros() as i32;
+    let k = (e + n - 53).max(-1074);
+    let q = if k <= e {
+        m << (e - k)
+    } else {
+        let sh = (k - e) as u32;
+        let q = m.checked_shr(sh).unwrap_or(0);
+        let inexact = q.checked_shl(sh).unwrap_or(0) != m;
+        // Round the magnitude up for a positive result, and down for a negative one.
+        q + (inexact && !neg) as u128
+    };
+    let mag = if k + (128 - q.leading_zeros() as i32) - 1 > 1023 {
+        if neg {
+            f64::MAX
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        // q ≤ 2^53, so the conversion and the scaling are exact.
+        ldexp(q as f64, k)
+    };
+    if neg {
+        -mag
+    } else {
+        mag
+    }
+}
+
+// Returns (s, m, e) such that x = (-1)^s × m × 2^e, for a finite `x`.
+fn decompose(x: f64) -> (bool, u64, i32) {
+    let bits = x.to_bits();
+    let s = bits & SIGN != 0;
+    let f = bits & 0x000f_ffff_ffff_ffff;
+    match ((bits >> 52) & 0x7ff) as i32 {
+        0 => (s, f, -1074),
+        k => (s, f | 1 << 52, k - 1075),
+    }
+}
+
+fn sqrt1_round(x: f64, up: bool) -> f64 {
+    if x == 0.0 || x == f64::INFINITY || x.is_nan() {
+        return x;
+    }
+    if x < 0.0 {
+        return f64::NAN;
+    }
+
+    let (m, k) = frexp(x);
+    // m ∈ [1, 4), k is even.
+    let (m, k) = if k % 2 != 0 { (2.0 * m, k - 1) } else { (m, k) };
+    // A number that has the same sign as m - s².
+    let residual = |s: f64| {
+        let (p, e) = two_prod(s, s);
+        (m - p) - e
+    };
+    let mut s = sqrt_approx(m);
+    if up {
+        while residual(s) > 0.0 {
+            s = next_up(s);
+        }
+        while residual(next_down(s)) <= 0.0 {
+            s = next_down(s);
+        }
+    } else {
+        while residual(s) < 0.0 {
+            s = next_down(s);
+        }
+        while residual(next_up(s)) >= 0.0 {
+            s = next_up(s);
+        }
+    }
+    // s ∈ [1, 2], so the scaling is exact.
+    ldexp(s, k / 2)
+}
+
+// Returns an approximation of √m for m ∈ [1, 4) that is accurate to a few ulps.
+#[cfg(feature = "std")]
+fn sqrt_approx(m: f64) -> f64 {
+    m.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+fn sqrt_approx(m: f64) -> f64 {
+    // The initial guess is within 7% of √m, and the error is squared by each step.
+    let mut s = f64::from_bits((m.to_bits() >> 1) + (1023 << 51));
+    for _ in 0..5 {
+        s = 0.5 * (s + m / s);
+    }
+    s
+}
+
+#[cfg(all(test, feature = "gmp"))]
+mod tests {
+    use super::*;
+    use rug::{float::Round, Float};
+
+    fn ru(x: Float) -> f64 {
+        x.to_f64_round(Round::Up)
+    }
+
+    fn rd(x: Float) -> f64 {
+        x.to_f64_round(Round::Down)
+    }
+
+    fn same(x: f64, y: f64) -> bool {
+        x.to_bits() == y.to_bits() || x.is_nan() && y.is_nan()
+    }
+
+    // Numbers across the whole range of `f64`, including subnormal numbers, the boundaries of
+    // the binades and the numbers that produce ties.
+    fn samples() -> Vec<f64> {
+        let mut xs = vec![
+            0.0,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::EPSILON,
+            1.0,
+            1.5,
+            3.0,
+            0.1,
+            1.0 / 3.0,
+            1.0 + f64::EPSILON,
+            2.0 - f64::EPSILON,
+            f64::from_bits(1),
+            f64::from_bits(0x000f_ffff_ffff_ffff),
+            f64::INFINITY,
+        ];
+        let mut bits = 0x1234_5678_9abc_def0u64;
+        for _ in 0..200 {
+            // xorshift64
+            bits ^= bits << 13;
+            bits ^= bits >> 7;
+            bits ^= bits << 17;
+            xs.push(f64::from_bits(bits & !SIGN));
+        }
+        let n = xs.len();
+        for i in 0..n {
+            xs.push(-xs[i]);
+        }
+        xs
+    }
+
+    #[test]
+    fn arith() {
+        let xs = samples();
+        let f = |x: f64| Float::with_val(53, x);
+        for &x in &xs {
+            for &y in &xs {
+                let exact = x.is_finite() && y.is_finite();
+                let s = if exact {
+                    ru(Float::with_val(2200, &f(x) + &f(y)))
+                } else {
+                    x + y
+                };
+                assert!(same(add1_ru(x, y), s), "{:e} + {:e}", x, y);
+                let p = if exact {
+                    ru(Float::with_val(106, &f(x) * &f(y)))
+                } else {
+                    x * y
+                };
+                assert!(same(mul1_ru(x, y), p), "{:e} × {:e}", x, y);
+                if exact && y != 0.0 {
+                    let q = ru(Float::with_val_round(53, &f(x) / &f(y), Round::Up).0);
+                    assert!(same(div1_ru(x, y), q), "{:e} / {:e}", x, y);
+                }
+            }
+            if x > 0.0 && x.is_finite() {
+                let s = Float::with_val_round(53, f(x).sqrt_ref(), Round::Up).0;
+                assert!(same(sqrt1_ru(x), ru(s)), "√{:e}", x);
+                let s = Float::with_val_round(53, f(x).sqrt_ref(), Round::Down).0;
+                assert!(same(sqrt1_rd(x), rd(s)), "√{:e}", x);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_add() {
+        let xs = samples();
+        let f = |x: f64| Float::with_val(53, x);
+        for &x in xs.iter().step_by(3) {
+            for &y in xs.iter().step_by(5) {
+                for &z in xs.iter().step_by(7) {
+                    let r = mul_add1_ru(x, y, z);
+                    if !x.is_finite() || !y.is_finite() || !z.is_finite() {
+                        continue;
+                    }
+                    let exact = Float::with_val(4000, &f(x) * &f(y)) + &f(z);
+                    let t = ru(exact);
+                    assert!(same(r, t), "{:e} × {:e} + {:e}", x, y, z);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+// The operations are performed in `f64` rounded in the same direction, and then the results
+// are rounded to `f32`. Since every `f32` number is an `f64` number, the two roundings
+// give the same result as a single rounding.
+
+use super::{add1_ru, div1_ru, mul1_ru, sqrt1_rd as sqrt1_rd_f64, sqrt1_ru as sqrt1_ru_f64};
+
+pub(crate) type F32X2 = [f32; 2];
+
+pub(crate) fn abs(x: F32X2) -> F32X2 {
+    map(x, |x| f32::from_bits(x.to_bits() & !SIGN))
+}
+
+/// `add_ru([x0, x1], [y0, y1]) = [x0 + y0, x1 + y1]` rounded toward +∞.
+pub(crate) fn add_ru(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| f32_ru(add1_ru(x as f64, y as f64)))
+}
+
+pub(crate) fn all(x: F32X2) -> bool {
+    bitmask(x) == 3
+}
+
+pub(crate) fn any(x: F32X2) -> bool {
+    bitmask(x) != 0
+}
+
+pub(crate) fn bitmask(x: F32X2) -> u32 {
+    (x[1].to_bits() >> 30) & 0x2 | x[0].to_bits() >> 31
+}
+
+pub(crate) fn constant(x: f32, y: f32) -> F32X2 {
+    [x, y]
+}
+
+pub(crate) fn div_ru(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| f32_ru(div1_ru(x as f64, y as f64)))
+}
+
+pub(crate) fn eq(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| mask(x == y))
+}
+
+pub(crate) fn extract0(x: F32X2) -> f32 {
+    x[0]
+}
+
+pub(crate) fn extract1(x: F32X2) -> f32 {
+    x[1]
+}
+
+pub(crate) fn ge(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| mask(x >= y))
+}
+
+pub(crate) fn le(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| mask(x <= y))
+}
+
+pub(crate) fn lt(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| mask(x < y))
+}
+
+pub(crate) fn max(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| {
+        if x > y || y.is_nan() {
+            x
+        } else if x < y || x.is_nan() {
+            y
+        } else {
+            // +0 is greater than -0.
+            f32::from_bits(x.to_bits() & y.to_bits())
+        }
+    })
+}
+
+pub(crate) fn min(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| {
+        if x < y || y.is_nan() {
+            x
+        } else if x > y || x.is_nan() {
+            y
+        } else {
+            // -0 is less than +0.
+            f32::from_bits(x.to_bits() | y.to_bits())
+        }
+    })
+}
+
+pub(crate) fn mul_ru(x: F32X2, y: F32X2) -> F32X2 {
+    zip(x, y, |x, y| f32_ru(mul1_ru(x as f64, y as f64)))
+}
+
+pub(crate) fn neg(x: F32X2) -> F32X2 {
+    [-x[0], -x[1]]
+}
+
+pub(crate) fn neg0(x: F32X2) -> F32X2 {
+    [-x[0], x[1]]
+}
+
+/// `shuffle02([x0, x1], [x2, x3]) = [x0, x2]`
+pub(crate) fn shuffle02(x: F32X2, y: F32X2) -> F32X2 {
+    [x[0], y[0]]
+}
+
+/// `shuffle03([x0, x1], [x2, x3]) = [x0, x3]`
+pub(crate) fn shuffle03(x: F32X2, y: F32X2) -> F32X2 {
+    [x[0], y[1]]
+}
+
+/// `shuffle13([x0, x1], [x2, x3]) = [x1, x3]`
+pub(crate) fn shuffle13(x: F32X2, y: F32X2) -> F32X2 {
+    [x[1], y[1]]
+}
+
+pub(crate) fn splat(x: f32) -> F32X2 {
+    [x, x]
+}
+
+pub(crate) fn sqrt1_rd(x: f32) -> f32 {
+    -f32_ru(-sqrt1_rd_f64(x as f64))
+}
+
+pub(crate) fn sqrt1_ru(x: f32) -> f32 {
+    f32_ru(sqrt1_ru_f64(x as f64))
+}
+
+pub(crate) fn swap(x: F32X2) -> F32X2 {
+    [x[1], x[0]]
+}
+
+const SIGN: u32 = 1 << 31;
+
+fn map(x: F32X2, f: impl Fn(f32) -> f32) -> F32X2 {
+    [f(x[0]), f(x[1])]
+}
+
+fn zip(x: F32X2, y: F32X2, f: impl Fn(f32, f32) -> f32) -> F32X2 {
+    [f(x[0], y[0]), f(x[1], y[1])]
+}
+
+fn mask(b: bool) -> f32 {
+    f32::from_bits(if b { !0 } else { 0 })
+}
+
+// Rounds `x` to an `f32` number toward +∞.
+fn f32_ru(x: f64) -> f32 {
+    let y = x as f32;
+    if (y as f64) < x {
+        // `y` is either finite or -∞.
+        if y == 0.0 {
+            f32::from_bits(1)
+        } else if y > 0.0 {
+            f32::from_bits(y.to_bits() + 1)
+        } else {
+            f32::from_bits(y.to_bits() - 1)
+        }
+    } else {
+        y
+    }
+}
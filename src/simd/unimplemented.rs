@@ -158,6 +158,10 @@ pub(crate) fn sub1_ru(_: f64, _: f64) -> f64 {
     unimplemented!()
 }
 
+pub(crate) fn sub_ru(_: F64X2, _: F64X2) -> F64X2 {
+    unimplemented!()
+}
+
 pub(crate) fn swap(_: F64X2) -> F64X2 {
     unimplemented!()
 }
@@ -165,3 +169,5 @@ pub(crate) fn swap(_: F64X2) -> F64X2 {
 pub(crate) fn trunc(_: F64X2) -> F64X2 {
     unimplemented!()
 }
+
+pub(crate) mod f32x2;
@@ -0,0 +1,101 @@
+pub(crate) type F32X2 = [f32; 2];
+
+pub(crate) fn abs(_: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn add_ru(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn all(_: F32X2) -> bool {
+    unimplemented!()
+}
+
+pub(crate) fn any(_: F32X2) -> bool {
+    unimplemented!()
+}
+
+pub(crate) fn bitmask(_: F32X2) -> u32 {
+    unimplemented!()
+}
+
+pub(crate) fn constant(_: f32, _: f32) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn div_ru(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn eq(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn extract0(_: F32X2) -> f32 {
+    unimplemented!()
+}
+
+pub(crate) fn extract1(_: F32X2) -> f32 {
+    unimplemented!()
+}
+
+pub(crate) fn ge(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn le(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn lt(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn max(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn min(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn mul_ru(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn neg(_: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn neg0(_: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn shuffle02(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn shuffle03(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn shuffle13(_: F32X2, _: F32X2) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn splat(_: f32) -> F32X2 {
+    unimplemented!()
+}
+
+pub(crate) fn sqrt1_rd(_: f32) -> f32 {
+    unimplemented!()
+}
+
+pub(crate) fn sqrt1_ru(_: f32) -> f32 {
+    unimplemented!()
+}
+
+pub(crate) fn swap(_: F32X2) -> F32X2 {
+    unimplemented!()
+}
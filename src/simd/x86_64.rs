@@ -1,9 +1,16 @@
-use std::{arch::x86_64::*, mem::transmute};
+use super::portable;
+use core::{arch::x86_64::*, mem::transmute};
 
 pub(crate) const HAS_MAXIMUM: bool = false;
 
 pub(crate) type F64X2 = __m128d;
 
+// SSE has no 64-bit vector of floats, so a `F32X2` is processed
+// in the lower half of a `F32X4`. See f32x2.rs.
+pub(crate) type F32X4 = __m128;
+
+pub(crate) mod f32x2;
+
 pub(crate) fn abs(x: F64X2) -> F64X2 {
     // Drop sign bits.
     unsafe { _mm_andnot_pd(splat(-0.0), x) }
@@ -30,10 +37,6 @@ pub(crate) fn bitmask(x: F64X2) -> u32 {
     unsafe { _mm_movemask_pd(x) as u32 }
 }
 
-pub(crate) fn ceil(x: F64X2) -> F64X2 {
-    unsafe { _mm_ceil_pd(x) }
-}
-
 pub(crate) fn constant(x: f64, y: f64) -> F64X2 {
     unsafe { transmute([x, y]) }
 }
@@ -55,10 +58,6 @@ pub(crate) fn extract1(x: F64X2) -> f64 {
     unsafe { transmute::<F64X2, [f64; 2]>(x)[1] }
 }
 
-pub(crate) fn floor(x: F64X2) -> F64X2 {
-    unsafe { _mm_floor_pd(x) }
-}
-
 pub(crate) fn ge(x: F64X2, y: F64X2) -> F64X2 {
     unsafe { _mm_cmpge_pd(x, y) }
 }
@@ -67,11 +66,6 @@ pub(crate) fn gt(x: F64X2, y: F64X2) -> F64X2 {
     unsafe { _mm_cmpgt_pd(x, y) }
 }
 
-/// `hadd_rn([x0, x1], [y0, y1]) = [x0 + x1, y0 + y1]` rounded to nearest.
-pub(crate) fn hadd_rn(x: F64X2, y: F64X2) -> F64X2 {
-    unsafe { _mm_hadd_pd(x, y) }
-}
-
 pub(crate) fn le(x: F64X2, y: F64X2) -> F64X2 {
     unsafe { _mm_cmple_pd(x, y) }
 }
@@ -115,11 +109,7 @@ pub(crate) fn or(x: F64X2, y: F64X2) -> F64X2 {
 // This one is hard to implement correctly.
 // https://www.cockroachlabs.com/blog/rounding-implementations-in-go/
 pub(crate) fn round(x: F64X2) -> F64X2 {
-    constant(extract0(x).round(), extract1(x).round())
-}
-
-pub(crate) fn round_ties_even(x: F64X2) -> F64X2 {
-    unsafe { _mm_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC) }
+    lift(x, portable::round)
 }
 
 /// `shuffle02([x0, x1], [x2, x3]) = [x0, x2]`
@@ -150,10 +140,6 @@ pub(crate) fn swap(x: F64X2) -> F64X2 {
     shuffle12(x, x)
 }
 
-pub(crate) fn trunc(x: F64X2) -> F64X2 {
-    unsafe { _mm_round_pd(x, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC) }
-}
-
 /// `shuffle12([x0, x1], [x2, x3]) = [x1, x2]`
 fn shuffle12(x: F64X2, y: F64X2) -> F64X2 {
     unsafe { _mm_shuffle_pd(x, y, 1) }
@@ -163,6 +149,63 @@ fn xor(x: F64X2, y: F64X2) -> F64X2 {
     unsafe { _mm_xor_pd(x, y) }
 }
 
+// Applies a function of the portable implementation.
+fn lift(x: F64X2, f: fn([f64; 2]) -> [f64; 2]) -> F64X2 {
+    let [a, b] = f(extract(x));
+    constant(a, b)
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_feature = "sse4.1")] {
+        pub(crate) fn ceil(x: F64X2) -> F64X2 {
+            unsafe { _mm_ceil_pd(x) }
+        }
+
+        pub(crate) fn floor(x: F64X2) -> F64X2 {
+            unsafe { _mm_floor_pd(x) }
+        }
+
+        pub(crate) fn round_ties_even(x: F64X2) -> F64X2 {
+            unsafe { _mm_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC) }
+        }
+
+        pub(crate) fn trunc(x: F64X2) -> F64X2 {
+            unsafe { _mm_round_pd(x, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC) }
+        }
+    } else {
+        // SSE4.1 is not available in the baseline x86-64.
+        pub(crate) fn ceil(x: F64X2) -> F64X2 {
+            lift(x, portable::ceil)
+        }
+
+        pub(crate) fn floor(x: F64X2) -> F64X2 {
+            lift(x, portable::floor)
+        }
+
+        pub(crate) fn round_ties_even(x: F64X2) -> F64X2 {
+            lift(x, portable::round_ties_even)
+        }
+
+        pub(crate) fn trunc(x: F64X2) -> F64X2 {
+            lift(x, portable::trunc)
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_feature = "sse3")] {
+        /// `hadd_rn([x0, x1], [y0, y1]) = [x0 + x1, y0 + y1]` rounded to nearest.
+        pub(crate) fn hadd_rn(x: F64X2, y: F64X2) -> F64X2 {
+            unsafe { _mm_hadd_pd(x, y) }
+        }
+    } else {
+        /// `hadd_rn([x0, x1], [y0, y1]) = [x0 + x1, y0 + y1]` rounded to nearest.
+        pub(crate) fn hadd_rn(x: F64X2, y: F64X2) -> F64X2 {
+            unsafe { _mm_add_pd(_mm_unpacklo_pd(x, y), _mm_unpackhi_pd(x, y)) }
+        }
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(target_feature = "avx512f")] {
         mod avx512f;
@@ -171,6 +214,10 @@ cfg_if::cfg_if! {
         mod avx_fma;
         pub(crate) use avx_fma::*;
     } else {
-        compile_error!("RUSTFLAGS='-Ctarget-cpu=haswell' or later is required. See https://doc.rust-lang.org/rustc/codegen-options/#target-cpu");
+        mod avx512f;
+        mod avx_fma;
+        mod dispatch;
+        mod sse2;
+        pub(crate) use dispatch::*;
     }
 }
@@ -1,6 +1,15 @@
-use std::arch::asm;
+use core::arch::asm;
 
 macro_rules! impl_op_round {
+    (@asm $inst:literal, $er:literal, $reg:ident, $x:ident $(,$y:ident)*) => {
+        asm!(
+            concat!($inst, ", ", $er),
+            $x = inout($reg) $x,
+            $($y = in($reg) $y,)*
+            options(pure, nomem, nostack, preserves_flags)
+        )
+    };
+
     ($t:ty, $reg:ident, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, rd) => {
         impl_op_round!($t, $reg, $f ($x $(,$y)*), $inst, "{{rd-sae}}");
     };
@@ -10,16 +19,20 @@ macro_rules! impl_op_round {
     };
 
     ($t:ty, $reg:ident, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, $er:literal) => {
+        #[cfg(target_feature = "avx512f")]
         pub(crate) fn $f(mut $x: $t, $($y: $t,)*) -> $t {
             unsafe {
-                asm!(
-                    concat!($inst, ", ", $er),
-                    $x = inout($reg) $x,
-                    $($y = in($reg) $y,)*
-                    options(pure, nomem, nostack, preserves_flags)
-                );
-                $x
+                impl_op_round!(@asm $inst, $er, $reg, $x $(,$y)*);
             }
+            $x
+        }
+
+        // The caller must ensure that the CPU supports AVX-512F. See dispatch.rs.
+        #[cfg(not(target_feature = "avx512f"))]
+        #[target_feature(enable = "avx512f")]
+        pub(crate) unsafe fn $f(mut $x: $t, $($y: $t,)*) -> $t {
+            impl_op_round!(@asm $inst, $er, $reg, $x $(,$y)*);
+            $x
         }
     };
 }
@@ -27,38 +40,64 @@ macro_rules! impl_op_round {
 impl_op_round!(f64, xmm_reg, sqrt1_rd(x), "vsqrtsd {x}, {x}, {x}", rd);
 impl_op_round!(f64, xmm_reg, sqrt1_ru(x), "vsqrtsd {x}, {x}, {x}", ru);
 impl_op_round!(f64, xmm_reg, sub1_ru(x, y), "vsubsd {x}, {x}, {y}", ru);
+// The vector operands are restricted to xmm0–15. With `zmm_reg` and AVX-512VL enabled at compile
+// time, a `F64X2` can be allocated to xmm16–31, which is printed with the modifier `z` as
+// an invalid operand such as `vaddpd R20BH, R20BH, zmm3, {ru-sae}` and fails to assemble.
 impl_op_round!(
     super::F64X2,
-    zmm_reg,
+    xmm_reg,
     add_ru(x, y),
     "vaddpd {x:z}, {x:z}, {y:z}",
     ru
 );
 impl_op_round!(
     super::F64X2,
-    zmm_reg,
+    xmm_reg,
     sub_ru(x, y),
     "vsubpd {x:z}, {x:z}, {y:z}",
     ru
 );
 impl_op_round!(
     super::F64X2,
-    zmm_reg,
+    xmm_reg,
     mul_ru(x, y),
     "vmulpd {x:z}, {x:z}, {y:z}",
     ru
 );
 impl_op_round!(
     super::F64X2,
-    zmm_reg,
+    xmm_reg,
     div_ru(x, y),
     "vdivpd {x:z}, {x:z}, {y:z}",
     ru
 );
 impl_op_round!(
     super::F64X2,
-    zmm_reg,
+    xmm_reg,
     mul_add_ru(x, y, z),
     "vfmadd213pd {x:z}, {y:z}, {z:z}",
     ru
 );
+impl_op_round!(f32, xmm_reg, sqrt1_rd_f32(x), "vsqrtss {x}, {x}, {x}", rd);
+impl_op_round!(f32, xmm_reg, sqrt1_ru_f32(x), "vsqrtss {x}, {x}, {x}", ru);
+impl_op_round!(
+    super::F32X4,
+    xmm_reg,
+    add_ru_f32x4(x, y),
+    "vaddps {x:z}, {x:z}, {y:z}",
+    ru
+);
+impl_op_round!(
+    super::F32X4,
+    xmm_reg,
+    mul_ru_f32x4(x, y),
+    "vmulps {x:z}, {x:z}, {y:z}",
+    ru
+);
+impl_op_round!(
+    super::F32X4,
+    xmm_reg,
+    div_ru_f32x4(x, y),
+    "vdivps {x:z}, {x:z}, {y:z}",
+    ru
+);
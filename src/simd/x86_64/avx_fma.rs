@@ -1,6 +1,22 @@
-use std::arch::asm;
+use core::arch::asm;
 
 macro_rules! impl_op_round {
+    (@asm $inst:literal, $mxcsr:literal, $x:ident $(,$y:ident)*) => {
+        asm!(
+            "push {rax}", // Same as "sub rsp, 8", but does not modify flags.
+            "vstmxcsr [rsp]",
+            concat!("mov dword ptr [rsp + 4], ", $mxcsr),
+            "vldmxcsr [rsp + 4]",
+            $inst,
+            "vldmxcsr [rsp]",
+            "pop {rax}", // Same as "add rsp, 8", but does not modify flags.
+            $x = inout(xmm_reg) $x,
+            $($y = in(xmm_reg) $y,)*
+            rax = out(reg) _, // Any 64-bit general-purpose register.
+            options(pure, nomem, preserves_flags)
+        )
+    };
+
     ($t:ty, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, rd) => {
         impl_op_round!($t, $f ($x $(,$y)*), $inst, "16256"); // _MM_ROUND_DOWN | _MM_MASK_MASK
     };
@@ -10,24 +26,21 @@ macro_rules! impl_op_round {
     };
 
     ($t:ty, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, $mxcsr:literal) => {
+        #[cfg(all(target_feature = "avx", target_feature = "fma"))]
         pub(crate) fn $f(mut $x: $t, $($y: $t,)*) -> $t {
             unsafe {
-                asm!(
-                    "push {rax}", // Same as "sub rsp, 8", but does not modify flags.
-                    "vstmxcsr [rsp]",
-                    concat!("mov dword ptr [rsp + 4], ", $mxcsr),
-                    "vldmxcsr [rsp + 4]",
-                    $inst,
-                    "vldmxcsr [rsp]",
-                    "pop {rax}", // Same as "add rsp, 8", but does not modify flags.
-                    $x = inout(xmm_reg) $x,
-                    $($y = in(xmm_reg) $y,)*
-                    rax = out(reg) _, // Any 64-bit general-purpose register.
-                    options(pure, nomem, preserves_flags)
-                );
+                impl_op_round!(@asm $inst, $mxcsr, $x $(,$y)*);
             }
             $x
         }
+
+        // The caller must ensure that the CPU supports AVX and FMA. See dispatch.rs.
+        #[cfg(not(all(target_feature = "avx", target_feature = "fma")))]
+        #[target_feature(enable = "avx,fma")]
+        pub(crate) unsafe fn $f(mut $x: $t, $($y: $t,)*) -> $t {
+            impl_op_round!(@asm $inst, $mxcsr, $x $(,$y)*);
+            $x
+        }
     };
 }
 
@@ -44,3 +57,8 @@ impl_op_round!(
     "vfmadd213pd {x}, {y}, {z}",
     ru
 );
+impl_op_round!(f32, sqrt1_rd_f32(x), "vsqrtss {x}, {x}, {x}", rd);
+impl_op_round!(f32, sqrt1_ru_f32(x), "vsqrtss {x}, {x}, {x}", ru);
+impl_op_round!(super::F32X4, add_ru_f32x4(x, y), "vaddps {x}, {x}, {y}", ru);
+impl_op_round!(super::F32X4, mul_ru_f32x4(x, y), "vmulps {x}, {x}, {y}", ru);
+impl_op_round!(super::F32X4, div_ru_f32x4(x, y), "vdivps {x}, {x}, {y}", ru);
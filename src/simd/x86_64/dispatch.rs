@@ -0,0 +1,204 @@
+// Selects the fastest implementation supported by the CPU at runtime.
+// This is used only if neither AVX-512F nor AVX and FMA are enabled at compile time.
+
+use super::{avx512f, avx_fma, sse2, F32X4, F64X2};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+const UNKNOWN: u8 = 0;
+const SSE2: u8 = 1;
+const AVX_FMA: u8 = 2;
+const AVX512F: u8 = 3;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+fn level() -> u8 {
+    let level = LEVEL.load(Ordering::Relaxed);
+    if level != UNKNOWN {
+        return level;
+    }
+
+    let level = detect();
+    LEVEL.store(level, Ordering::Relaxed);
+    level
+}
+
+#[cfg(feature = "std")]
+fn detect() -> u8 {
+    if is_x86_feature_detected!("avx512f") {
+        AVX512F
+    } else if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+        AVX_FMA
+    } else {
+        SSE2
+    }
+}
+
+// `is_x86_feature_detected!` is only available in `std`.
+#[cfg(not(feature = "std"))]
+#[allow(unused_unsafe)] // `__cpuid_count` is safe in recent versions of Rust.
+fn detect() -> u8 {
+    use core::arch::{asm, x86_64::__cpuid_count};
+
+    let leaf1 = unsafe { __cpuid_count(1, 0) };
+    let osxsave = leaf1.ecx & (1 << 27) != 0;
+    let avx = leaf1.ecx & (1 << 28) != 0;
+    let fma = leaf1.ecx & (1 << 12) != 0;
+    if !osxsave || !avx {
+        return SSE2;
+    }
+
+    // Check if the OS saves the YMM and ZMM registers on context switches.
+    let xcr0: u32;
+    unsafe {
+        asm!(
+            "xgetbv",
+            in("ecx") 0,
+            out("eax") xcr0,
+            out("edx") _,
+            options(nomem, nostack, preserves_flags)
+        );
+    }
+    let ymm = xcr0 & 0b110 == 0b110;
+    let zmm = xcr0 & 0b1110_0110 == 0b1110_0110;
+
+    let leaf7 = unsafe { __cpuid_count(7, 0) };
+    let avx512f = leaf7.ebx & (1 << 16) != 0;
+    if avx512f && zmm {
+        AVX512F
+    } else if fma && ymm {
+        AVX_FMA
+    } else {
+        SSE2
+    }
+}
+
+macro_rules! impl_dispatch {
+    ($t:ty, $f:ident ($($x:ident),*)) => {
+        pub(crate) fn $f($($x: $t),*) -> $t {
+            match level() {
+                // SAFETY: The CPU supports the instructions.
+                AVX512F => unsafe { avx512f::$f($($x),*) },
+                AVX_FMA => unsafe { avx_fma::$f($($x),*) },
+                _ => sse2::$f($($x),*),
+            }
+        }
+    };
+}
+
+impl_dispatch!(f64, sqrt1_rd(x));
+impl_dispatch!(f64, sqrt1_ru(x));
+impl_dispatch!(f64, sub1_ru(x, y));
+impl_dispatch!(F64X2, add_ru(x, y));
+impl_dispatch!(F64X2, sub_ru(x, y));
+impl_dispatch!(F64X2, mul_ru(x, y));
+impl_dispatch!(F64X2, div_ru(x, y));
+impl_dispatch!(F64X2, mul_add_ru(x, y, z));
+impl_dispatch!(f32, sqrt1_rd_f32(x));
+impl_dispatch!(f32, sqrt1_ru_f32(x));
+impl_dispatch!(F32X4, add_ru_f32x4(x, y));
+impl_dispatch!(F32X4, mul_ru_f32x4(x, y));
+impl_dispatch!(F32X4, div_ru_f32x4(x, y));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simd::{constant, extract};
+    use alloc::vec::Vec;
+    use core::mem::transmute;
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn detect_without_std() {
+        let expected = if is_x86_feature_detected!("avx512f") {
+            AVX512F
+        } else if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+            AVX_FMA
+        } else {
+            SSE2
+        };
+        assert_eq!(detect(), expected);
+    }
+
+    // All implementations must return the same results.
+    #[test]
+    fn consistency() {
+        let xs = [
+            0.0,
+            -0.0,
+            0.1,
+            1.0 / 3.0,
+            1.0 + f64::EPSILON,
+            f64::MIN_POSITIVE,
+            f64::from_bits(1),
+            f64::MAX,
+            f64::INFINITY,
+            -0.7,
+            -f64::MAX,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            f32::MIN_POSITIVE as f64,
+            f32::from_bits(1) as f64,
+            f32::MAX as f64,
+        ];
+        let f32x4 = |x: [f32; 4]| unsafe { transmute::<[f32; 4], F32X4>(x) };
+        let f32s = |x: F32X4| unsafe { transmute::<F32X4, [f32; 4]>(x) };
+
+        // The results of all dispatched operations with the implementation in `$m`,
+        // where the `f32` results are converted to `f64` exactly.
+        macro_rules! results {
+            ($m:ident, $a:expr, $b:expr, $c:expr) => {{
+                let (a, b, c): (f64, f64, f64) = ($a, $b, $c);
+                let (x, y, z) = (constant(a, b), constant(b, c), constant(c, a));
+                let (fa, fb, fc) = (a as f32, b as f32, c as f32);
+                let (fx, fy) = (f32x4([fa, fb, fc, fa]), f32x4([fb, fc, fa, fb]));
+                #[allow(unused_unsafe)]
+                let (vs, ss, fs, fvs) = unsafe {
+                    (
+                        [
+                            $m::add_ru(x, y),
+                            $m::sub_ru(x, y),
+                            $m::mul_ru(x, y),
+                            $m::div_ru(x, y),
+                            $m::mul_add_ru(x, y, z),
+                        ],
+                        [$m::sqrt1_rd(a), $m::sqrt1_ru(a), $m::sub1_ru(a, b)],
+                        [$m::sqrt1_rd_f32(fa), $m::sqrt1_ru_f32(fa)],
+                        [
+                            $m::add_ru_f32x4(fx, fy),
+                            $m::mul_ru_f32x4(fx, fy),
+                            $m::div_ru_f32x4(fx, fy),
+                        ],
+                    )
+                };
+                let mut r = Vec::new();
+                r.extend(vs.into_iter().flat_map(extract));
+                r.extend(ss);
+                r.extend(fs.into_iter().map(f64::from));
+                r.extend(fvs.into_iter().flat_map(f32s).map(f64::from));
+                r
+            }};
+        }
+
+        let same = |x: f64, y: f64| x.to_bits() == y.to_bits() || x.is_nan() && y.is_nan();
+        let check = |expected: &[f64], actual: &[f64], args: (f64, f64, f64)| {
+            for (i, (&e, &a)) in expected.iter().zip(actual).enumerate() {
+                assert!(same(e, a), "#{} {:?} {:?} {:?}", i, args, e, a);
+            }
+        };
+        let has_avx_fma = is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma");
+        let has_avx512f = is_x86_feature_detected!("avx512f");
+        for &a in &xs {
+            for &b in &xs {
+                for &c in &xs {
+                    let expected = results!(sse2, a, b, c);
+                    if has_avx_fma {
+                        check(&expected, &results!(avx_fma, a, b, c), (a, b, c));
+                    }
+                    if has_avx512f {
+                        check(&expected, &results!(avx512f, a, b, c), (a, b, c));
+                    }
+                }
+            }
+        }
+    }
+}
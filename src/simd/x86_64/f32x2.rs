@@ -0,0 +1,113 @@
+// The lanes x2 and x3 of a `F32X4` obtained from `load` are zero,
+// and they are discarded by `store`.
+
+use super::{add_ru_f32x4, div_ru_f32x4, mul_ru_f32x4, F32X4};
+use core::{arch::x86_64::*, mem::transmute};
+
+pub(crate) use super::{sqrt1_rd_f32 as sqrt1_rd, sqrt1_ru_f32 as sqrt1_ru};
+
+pub(crate) type F32X2 = [f32; 2];
+
+fn load(x: F32X2) -> F32X4 {
+    unsafe { _mm_castpd_ps(_mm_set_sd(transmute::<F32X2, f64>(x))) }
+}
+
+fn store(x: F32X4) -> F32X2 {
+    unsafe { transmute::<f64, F32X2>(_mm_cvtsd_f64(_mm_castps_pd(x))) }
+}
+
+pub(crate) fn abs(x: F32X2) -> F32X2 {
+    constant(x[0].abs(), x[1].abs())
+}
+
+/// `add_ru([x0, x1], [y0, y1]) = [x0 + y0, x1 + y1]` rounded toward +∞.
+pub(crate) fn add_ru(x: F32X2, y: F32X2) -> F32X2 {
+    store(add_ru_f32x4(load(x), load(y)))
+}
+
+pub(crate) fn all(x: F32X2) -> bool {
+    bitmask(x) == 3
+}
+
+pub(crate) fn any(x: F32X2) -> bool {
+    bitmask(x) != 0
+}
+
+pub(crate) fn bitmask(x: F32X2) -> u32 {
+    unsafe { _mm_movemask_ps(load(x)) as u32 & 3 }
+}
+
+pub(crate) fn constant(x: f32, y: f32) -> F32X2 {
+    [x, y]
+}
+
+pub(crate) fn div_ru(x: F32X2, y: F32X2) -> F32X2 {
+    store(div_ru_f32x4(load(x), load(y)))
+}
+
+pub(crate) fn eq(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_cmpeq_ps(load(x), load(y))) }
+}
+
+pub(crate) fn extract0(x: F32X2) -> f32 {
+    x[0]
+}
+
+pub(crate) fn extract1(x: F32X2) -> f32 {
+    x[1]
+}
+
+pub(crate) fn ge(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_cmpge_ps(load(x), load(y))) }
+}
+
+pub(crate) fn le(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_cmple_ps(load(x), load(y))) }
+}
+
+pub(crate) fn lt(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_cmplt_ps(load(x), load(y))) }
+}
+
+pub(crate) fn max(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_max_ps(load(x), load(y))) }
+}
+
+pub(crate) fn min(x: F32X2, y: F32X2) -> F32X2 {
+    unsafe { store(_mm_min_ps(load(x), load(y))) }
+}
+
+pub(crate) fn mul_ru(x: F32X2, y: F32X2) -> F32X2 {
+    store(mul_ru_f32x4(load(x), load(y)))
+}
+
+pub(crate) fn neg(x: F32X2) -> F32X2 {
+    constant(-x[0], -x[1])
+}
+
+pub(crate) fn neg0(x: F32X2) -> F32X2 {
+    constant(-x[0], x[1])
+}
+
+/// `shuffle02([x0, x1], [x2, x3]) = [x0, x2]`
+pub(crate) fn shuffle02(x: F32X2, y: F32X2) -> F32X2 {
+    constant(x[0], y[0])
+}
+
+/// `shuffle03([x0, x1], [x2, x3]) = [x0, x3]`
+pub(crate) fn shuffle03(x: F32X2, y: F32X2) -> F32X2 {
+    constant(x[0], y[1])
+}
+
+/// `shuffle13([x0, x1], [x2, x3]) = [x1, x3]`
+pub(crate) fn shuffle13(x: F32X2, y: F32X2) -> F32X2 {
+    constant(x[1], y[1])
+}
+
+pub(crate) fn splat(x: f32) -> F32X2 {
+    [x, x]
+}
+
+pub(crate) fn swap(x: F32X2) -> F32X2 {
+    constant(x[1], x[0])
+}
@@ -0,0 +1,56 @@
+// The implementation for the CPUs that support neither AVX nor FMA, which uses
+// the legacy SSE instructions with the rounding mode in MXCSR temporarily changed.
+// FMA is emulated with error-free transformations.
+
+use super::{constant, extract, F32X4, F64X2};
+use crate::simd::portable;
+use core::arch::asm;
+
+macro_rules! impl_op_round {
+    ($t:ty, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, rd) => {
+        impl_op_round!($t, $f ($x $(,$y)*), $inst, "16256"); // _MM_ROUND_DOWN | _MM_MASK_MASK
+    };
+
+    ($t:ty, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, ru) => {
+        impl_op_round!($t, $f ($x $(,$y)*), $inst, "24448"); // _MM_ROUND_UP | _MM_MASK_MASK
+    };
+
+    ($t:ty, $f:ident ($x:ident $(,$y:ident)*), $inst:literal, $mxcsr:literal) => {
+        pub(crate) fn $f(mut $x: $t, $($y: $t,)*) -> $t {
+            unsafe {
+                asm!(
+                    "push {rax}", // Same as "sub rsp, 8", but does not modify flags.
+                    "stmxcsr [rsp]",
+                    concat!("mov dword ptr [rsp + 4], ", $mxcsr),
+                    "ldmxcsr [rsp + 4]",
+                    $inst,
+                    "ldmxcsr [rsp]",
+                    "pop {rax}", // Same as "add rsp, 8", but does not modify flags.
+                    $x = inout(xmm_reg) $x,
+                    $($y = in(xmm_reg) $y,)*
+                    rax = out(reg) _, // Any 64-bit general-purpose register.
+                    options(pure, nomem, preserves_flags)
+                );
+            }
+            $x
+        }
+    };
+}
+
+impl_op_round!(f64, sqrt1_rd(x), "sqrtsd {x}, {x}", rd);
+impl_op_round!(f64, sqrt1_ru(x), "sqrtsd {x}, {x}", ru);
+impl_op_round!(f64, sub1_ru(x, y), "subsd {x}, {y}", ru);
+impl_op_round!(F64X2, add_ru(x, y), "addpd {x}, {y}", ru);
+impl_op_round!(F64X2, sub_ru(x, y), "subpd {x}, {y}", ru);
+impl_op_round!(F64X2, mul_ru(x, y), "mulpd {x}, {y}", ru);
+impl_op_round!(F64X2, div_ru(x, y), "divpd {x}, {y}", ru);
+impl_op_round!(f32, sqrt1_rd_f32(x), "sqrtss {x}, {x}", rd);
+impl_op_round!(f32, sqrt1_ru_f32(x), "sqrtss {x}, {x}", ru);
+impl_op_round!(F32X4, add_ru_f32x4(x, y), "addps {x}, {y}", ru);
+impl_op_round!(F32X4, mul_ru_f32x4(x, y), "mulps {x}, {y}", ru);
+impl_op_round!(F32X4, div_ru_f32x4(x, y), "divps {x}, {y}", ru);
+
+pub(crate) fn mul_add_ru(x: F64X2, y: F64X2, z: F64X2) -> F64X2 {
+    let [a, b] = portable::mul_add_ru(extract(x), extract(y), extract(z));
+    constant(a, b)
+}
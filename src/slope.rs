@@ -0,0 +1,663 @@
+use crate::{const_interval, interval::*};
+use rug::{float::Round, Float};
+
+// The initial and the maximum precision used for evaluating the point functions.
+// The initial precision must be at least 3 × 53, so that x^3 is computed exactly.
+const MIN_PREC: u32 = 192;
+const MAX_PREC: u32 = 1 << 14;
+const _: () = assert!(MIN_PREC >= 3 * f64::MANTISSA_DIGITS);
+
+fn reverse(rnd: Round) -> Round {
+    match rnd {
+        Round::Down => Round::Up,
+        _ => Round::Down,
+    }
+}
+
+// Returns c n(x) / x^k rounded in the direction `rnd`, where n(x, r) must return the value of
+// the numerator rounded in the direction `r`, at the same precision as `x`.
+//
+// The value is evaluated with increasing precision until the lower and upper bounds round to
+// the same `f64` number (Ziv's strategy). If that fails, a valid bound is returned anyway.
+fn slope(x: f64, k: u32, c: u32, n: fn(&Float, Round) -> Float, rnd: Round) -> f64 {
+    // For small |x|, the numerator suffers from cancellation of about k |log2 |x|| bits.
+    let loss = k * (-x.abs().log2()).max(0.0) as u32;
+    let mut prec = MIN_PREC + loss;
+    loop {
+        let eval = |r: Round| {
+            let x = Float::with_val(prec, x);
+            // The power is computed exactly, since `prec` ≥ 3 × 53.
+            let mut d = x.clone();
+            for _ in 1..k {
+                d *= &x;
+            }
+            let n = n(&x, if d.is_sign_negative() { reverse(r) } else { r });
+            let q = Float::with_val_round(prec, &n / &d, r).0;
+            Float::with_val_round(prec, &q * c, r).0.to_f64_round(rnd)
+        };
+        let lo = eval(Round::Down);
+        let hi = eval(Round::Up);
+        if lo == hi || prec >= MAX_PREC + loss {
+            return if rnd == Round::Down { lo } else { hi };
+        }
+        prec *= 2;
+    }
+}
+
+macro_rules! slope_fn {
+    ($f:ident, $k:expr, $c:expr, $at_neg_inf:expr, $at_pos_inf:expr, |$x:ident, $r:ident| $n:expr) => {
+        fn $f(x: f64, rnd: Round) -> f64 {
+            if x == 0.0 {
+                1.0
+            } else if x == f64::NEG_INFINITY {
+                $at_neg_inf
+            } else if x == f64::INFINITY {
+                $at_pos_inf
+            } else {
+                slope(x, $k, $c, |$x: &Float, $r: Round| $n, rnd)
+            }
+        }
+    };
+}
+
+// In the following, `x` is given with a precision high enough to represent x, x^2 and x^3 exactly.
+slope_fn!(exp_slope1_point, 1, 1, 0.0, f64::INFINITY, |x, r| {
+    // e^x - 1
+    Float::with_val_round(x.prec(), x.exp_m1_ref(), r).0
+});
+slope_fn!(exp_slope2_point, 2, 2, 0.0, f64::INFINITY, |x, r| {
+    // e^x - 1 - x
+    let y = Float::with_val_round(x.prec(), x.exp_m1_ref(), r).0;
+    Float::with_val_round(x.prec(), &y - x, r).0
+});
+slope_fn!(log_slope1_point, 2, 2, f64::NAN, 0.0, |x, r| {
+    // x - ln(1 + x)
+    let y = Float::with_val_round(x.prec(), x.ln_1p_ref(), reverse(r)).0;
+    Float::with_val_round(x.prec(), x - &y, r).0
+});
+slope_fn!(log_slope2_point, 3, 3, f64::NAN, 0.0, |x, r| {
+    // ln(1 + x) - x + x^2 / 2
+    let y = Float::with_val_round(x.prec(), x.ln_1p_ref(), r).0;
+    let y = Float::with_val_round(x.prec(), &y - x, r).0;
+    let x2 = Float::with_val(x.prec(), x.square_ref()) / 2u32;
+    Float::with_val_round(x.prec(), &y + &x2, r).0
+});
+slope_fn!(cos_slope2_point, 2, 2, 0.0, 0.0, |x, r| {
+    // 1 - cos(x)
+    let y = Float::with_val_round(x.prec(), x.cos_ref(), reverse(r)).0;
+    Float::with_val_round(x.prec(), 1u32 - &y, r).0
+});
+slope_fn!(sin_slope3_point, 3, 6, 0.0, 0.0, |x, r| {
+    // x - sin(x)
+    let y = Float::with_val_round(x.prec(), x.sin_ref(), reverse(r)).0;
+    Float::with_val_round(x.prec(), x - &y, r).0
+});
+slope_fn!(asin_slope3_point, 3, 6, f64::NAN, f64::NAN, |x, r| {
+    // asin(x) - x
+    let y = Float::with_val_round(x.prec(), x.asin_ref(), r).0;
+    Float::with_val_round(x.prec(), &y - x, r).0
+});
+slope_fn!(atan_slope3_point, 3, 3, 0.0, 0.0, |x, r| {
+    // x - atan(x)
+    let y = Float::with_val_round(x.prec(), x.atan_ref(), reverse(r)).0;
+    Float::with_val_round(x.prec(), x - &y, r).0
+});
+slope_fn!(
+    cosh_slope2_point,
+    2,
+    2,
+    f64::INFINITY,
+    f64::INFINITY,
+    |x, r| {
+        // cosh(x) - 1
+        let y = Float::with_val_round(x.prec(), x.cosh_ref(), r).0;
+        Float::with_val_round(x.prec(), &y - 1u32, r).0
+    }
+);
+slope_fn!(
+    sinh_slope3_point,
+    3,
+    6,
+    f64::INFINITY,
+    f64::INFINITY,
+    |x, r| {
+        // sinh(x) - x
+        let y = Float::with_val_round(x.prec(), x.sinh_ref(), r).0;
+        Float::with_val_round(x.prec(), &y - x, r).0
+    }
+);
+
+impl Interval {
+    /// Returns the slope of the exponential function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{expSlope1}(x) = \begin{cases}
+    ///   (\e^x - 1) / x & \for x ≠ 0, \\\\
+    ///   1              & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range    |
+    /// | ------ | -------- |
+    /// | $\R$   | $(0, ∞)$ |
+    #[must_use]
+    pub fn exp_slope1(self) -> Self {
+        self.mono_inc(exp_slope1_point)
+    }
+
+    /// Returns the second-order slope of the exponential function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{expSlope2}(x) = \begin{cases}
+    ///   2 (\e^x - 1 - x) / x^2 & \for x ≠ 0, \\\\
+    ///   1                      & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range    |
+    /// | ------ | -------- |
+    /// | $\R$   | $(0, ∞)$ |
+    #[must_use]
+    pub fn exp_slope2(self) -> Self {
+        self.mono_inc(exp_slope2_point)
+    }
+
+    /// Returns the second-order slope of $\ln(1 + x)$ at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{logSlope1}(x) = \begin{cases}
+    ///   2 (x - \ln(1 + x)) / x^2 & \for x ≠ 0, \\\\
+    ///   1                        & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain    | Range    |
+    /// | --------- | -------- |
+    /// | $(-1, ∞)$ | $(0, ∞)$ |
+    #[must_use]
+    pub fn log_slope1(self) -> Self {
+        self.log_slope(log_slope1_point)
+    }
+
+    /// Returns the third-order slope of $\ln(1 + x)$ at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{logSlope2}(x) = \begin{cases}
+    ///   3 (\ln(1 + x) - x + x^2 / 2) / x^3 & \for x ≠ 0, \\\\
+    ///   1                                  & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain    | Range    |
+    /// | --------- | -------- |
+    /// | $(-1, ∞)$ | $(0, ∞)$ |
+    #[must_use]
+    pub fn log_slope2(self) -> Self {
+        self.log_slope(log_slope2_point)
+    }
+
+    /// Returns the second-order slope of the cosine function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{cosSlope2}(x) = \begin{cases}
+    ///   2 (1 - \cos x) / x^2 & \for x ≠ 0, \\\\
+    ///   1                    & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range      |
+    /// | ------ | ---------- |
+    /// | $\R$   | $\[0, 1\]$ |
+    #[must_use]
+    pub fn cos_slope2(self) -> Self {
+        const TWO_PI: Interval = Interval::TAU;
+
+        if self.is_empty() {
+            return self;
+        }
+
+        let x = self.abs();
+        let a = x.inf_raw();
+        let b = x.sup_raw();
+        if b <= TWO_PI.inf_raw() {
+            // monotonically decreasing
+            return Self::with_infsup_raw(
+                cos_slope2_point(b, Round::Down),
+                cos_slope2_point(a, Round::Up),
+            );
+        }
+
+        if b <= TWO_PI.sup_raw() {
+            return Self::with_infsup_raw(0.0, cos_slope2_point(a, Round::Up));
+        }
+
+        // The function is (sin(y) / y)^2, where y = x/2. On [0, ∞), it has zeros at y = nπ
+        // for n ≥ 1 and is unimodal between each pair of adjacent zeros; the local maximum
+        // 1 / (1 + y^2) is attained at the unique solution y of tan(y) = y in (nπ, nπ + π/2).
+        // The part of x above 2π, except for a tiny neighborhood of 2π.
+        let x2 = Self::with_infsup_raw(a.max(TWO_PI.sup_raw()), b);
+        let one_hump = (x2 / TWO_PI).floor().is_singleton();
+        let inf = if one_hump && a >= TWO_PI.sup_raw() {
+            cos_slope2_point(a, Round::Down).min(cos_slope2_point(b, Round::Down))
+        } else {
+            0.0
+        };
+        let sup_a = cos_slope2_point(a, Round::Up);
+        let sup_b = cos_slope2_point(b, Round::Up);
+        let sup = if one_hump && cos_slope2_sign(b) > 0.0 {
+            // Decreasing on [a, 2π] if a < 2π, and increasing on [max(a, 2π), b].
+            sup_a.max(sup_b)
+        } else if one_hump && a >= TWO_PI.sup_raw() && cos_slope2_sign(a) < 0.0 {
+            // decreasing
+            sup_a
+        } else {
+            sup_a.max(sup_b).max(cos_slope2_peak(a))
+        };
+        Self::with_infsup_raw(inf, sup.min(1.0))
+    }
+
+    /// Returns the third-order slope of the sine function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{sinSlope3}(x) = \begin{cases}
+    ///   6 (x - \sin x) / x^3 & \for x ≠ 0, \\\\
+    ///   1                    & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $(0, 1\]$ |
+    #[must_use]
+    pub fn sin_slope3(self) -> Self {
+        self.even_dec(sin_slope3_point)
+    }
+
+    /// Returns the third-order slope of the inverse sine function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{asinSlope3}(x) = \begin{cases}
+    ///   6 (\operatorname{asin} x - x) / x^3 & \for x ≠ 0, \\\\
+    ///   1                                   & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain      | Range            |
+    /// | ----------- | ---------------- |
+    /// | $\[-1, 1\]$ | $\[1, 3π - 6\]$ |
+    #[must_use]
+    pub fn asin_slope3(self) -> Self {
+        const DOM: Interval = const_interval!(-1.0, 1.0);
+        self.intersection(DOM).even_inc(asin_slope3_point)
+    }
+
+    /// Returns the third-order slope of the inverse tangent function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{atanSlope3}(x) = \begin{cases}
+    ///   3 (x - \operatorname{atan} x) / x^3 & \for x ≠ 0, \\\\
+    ///   1                                   & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $(0, 1\]$ |
+    #[must_use]
+    pub fn atan_slope3(self) -> Self {
+        self.even_dec(atan_slope3_point)
+    }
+
+    /// Returns the second-order slope of the hyperbolic cosine function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{coshSlope2}(x) = \begin{cases}
+    ///   2 (\cosh x - 1) / x^2 & \for x ≠ 0, \\\\
+    ///   1                     & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $\[1, ∞)$ |
+    #[must_use]
+    pub fn cosh_slope2(self) -> Self {
+        self.even_inc(cosh_slope2_point)
+    }
+
+    /// Returns the third-order slope of the hyperbolic sine function at `self`.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{sinhSlope3}(x) = \begin{cases}
+    ///   6 (\sinh x - x) / x^3 & \for x ≠ 0, \\\\
+    ///   1                     & \for x = 0.
+    ///  \end{cases}
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $\[1, ∞)$ |
+    #[must_use]
+    pub fn sinh_slope3(self) -> Self {
+        self.even_inc(sinh_slope3_point)
+    }
+
+    fn mono_inc(self, f: fn(f64, Round) -> f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        Self::with_infsup_raw(f(self.inf_raw(), Round::Down), f(self.sup_raw(), Round::Up))
+    }
+
+    // For a function that is even and monotonically increasing on [0, ∞).
+    fn even_inc(self, f: fn(f64, Round) -> f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let x = self.abs();
+        Self::with_infsup_raw(f(x.inf_raw(), Round::Down), f(x.sup_raw(), Round::Up))
+    }
+
+    // For a function that is even and monotonically decreasing on [0, ∞).
+    fn even_dec(self, f: fn(f64, Round) -> f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let x = self.abs();
+        Self::with_infsup_raw(f(x.sup_raw(), Round::Down), f(x.inf_raw(), Round::Up))
+    }
+
+    // For a function that is monotonically decreasing on the domain (-1, ∞).
+    fn log_slope(self, f: fn(f64, Round) -> f64) -> Self {
+        const DOM: Interval = const_interval!(-1.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        let a = x.inf_raw();
+        let b = x.sup_raw();
+        if x.is_empty() || b <= -1.0 {
+            return Self::EMPTY;
+        }
+
+        let sup = if a == -1.0 {
+            f64::INFINITY
+        } else {
+            f(a, Round::Up)
+        };
+        Self::with_infsup_raw(f(b, Round::Down), sup)
+    }
+}
+
+// Returns an upper bound of the local maxima of cosSlope2 on [max(x, 2π), ∞).
+fn cos_slope2_peak(x: f64) -> f64 {
+    // With y = x/2, the local maximum between nπ and (n + 1)π is attained at y = nπ + atan(y),
+    // which we bound from below by iterating the contraction.
+    let n = (Interval::with_infsup_raw(x, x) / Interval::TAU)
+        .floor()
+        .inf_raw()
+        .max(1.0);
+    let n_pi = Interval::with_infsup_raw(n, n) * Interval::PI;
+    let mut y = n_pi;
+    for _ in 0..4 {
+        y = n_pi + y.atan();
+    }
+    let y = y.max(Interval::with_infsup_raw(x, x) / const_interval!(2.0, 2.0));
+    (const_interval!(1.0, 1.0) / (const_interval!(1.0, 1.0) + y.sqr())).sup_raw()
+}
+
+// Returns a number whose sign is that of the derivative of cosSlope2 at x ≥ 2π,
+// or zero if the sign cannot be determined.
+fn cos_slope2_sign(x: f64) -> f64 {
+    // The derivative has the same sign as sin(y) (y cos(y) - sin(y)), where y = x/2.
+    let y = Interval::with_infsup_raw(x / 2.0, x / 2.0);
+    let s = y.sin();
+    let d = s * (y * y.cos() - s);
+    if d.inf_raw() > 0.0 {
+        1.0
+    } else if d.sup_raw() < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+
+    #[test]
+    fn empty() {
+        assert!(I::EMPTY.exp_slope1().is_empty());
+        assert!(I::EMPTY.exp_slope2().is_empty());
+        assert!(I::EMPTY.log_slope1().is_empty());
+        assert!(I::EMPTY.log_slope2().is_empty());
+        assert!(I::EMPTY.cos_slope2().is_empty());
+        assert!(I::EMPTY.sin_slope3().is_empty());
+        assert!(I::EMPTY.asin_slope3().is_empty());
+        assert!(I::EMPTY.atan_slope3().is_empty());
+        assert!(I::EMPTY.cosh_slope2().is_empty());
+        assert!(I::EMPTY.sinh_slope3().is_empty());
+    }
+
+    #[test]
+    fn zero() {
+        let x = I::zero();
+        let one = const_interval!(1.0, 1.0);
+        assert_eq!(x.exp_slope1(), one);
+        assert_eq!(x.exp_slope2(), one);
+        assert_eq!(x.log_slope1(), one);
+        assert_eq!(x.log_slope2(), one);
+        assert_eq!(x.cos_slope2(), one);
+        assert_eq!(x.sin_slope3(), one);
+        assert_eq!(x.asin_slope3(), one);
+        assert_eq!(x.atan_slope3(), one);
+        assert_eq!(x.cosh_slope2(), one);
+        assert_eq!(x.sinh_slope3(), one);
+
+        // Near zero, the values are close to 1 despite the cancellation in the numerators.
+        for x in [
+            const_interval!(-1e-300, 1e-300),
+            const_interval!(-1e-8, 1e-8),
+        ] {
+            for y in [
+                x.exp_slope1(),
+                x.exp_slope2(),
+                x.log_slope1(),
+                x.log_slope2(),
+                x.cos_slope2(),
+                x.sin_slope3(),
+                x.asin_slope3(),
+                x.atan_slope3(),
+                x.cosh_slope2(),
+                x.sinh_slope3(),
+            ] {
+                assert!(y.contains(1.0));
+                assert!(y.wid() < 1e-7);
+            }
+        }
+    }
+
+    // Returns `true` if every element of `x` is within `tol` from some element of `y`.
+    fn close(x: I, y: I, tol: f64) -> bool {
+        (x - y).subset(interval!(-tol, tol).unwrap())
+    }
+
+    #[test]
+    fn values() {
+        let one = const_interval!(1.0, 1.0);
+        let two = const_interval!(2.0, 2.0);
+        let three = const_interval!(3.0, 3.0);
+        let six = const_interval!(6.0, 6.0);
+
+        assert!(close(one.exp_slope1(), I::E - one, 1e-15));
+        assert!(close(one.exp_slope2(), two * (I::E - two), 1e-15));
+        assert!(close((-one).exp_slope1(), one - I::E.recip(), 1e-15));
+        assert!(close(one.log_slope1(), two * (one - I::LN_2), 1e-15));
+        assert!(close(
+            one.log_slope2(),
+            three * (I::LN_2 - const_interval!(0.5, 0.5)),
+            1e-15
+        ));
+        assert!(close(one.cos_slope2(), two * (one - one.cos()), 1e-15));
+        assert!(close(one.sin_slope3(), six * (one - one.sin()), 1e-15));
+        assert!(close(one.asin_slope3(), six * (I::FRAC_PI_2 - one), 1e-14));
+        assert!(close(
+            one.atan_slope3(),
+            three * (one - I::FRAC_PI_4),
+            1e-15
+        ));
+        assert!(close(one.cosh_slope2(), two * (one.cosh() - one), 1e-15));
+        assert!(close(one.sinh_slope3(), six * (one.sinh() - one), 1e-15));
+
+        // The results for point intervals are tight.
+        for y in [
+            one.exp_slope1(),
+            one.exp_slope2(),
+            one.log_slope1(),
+            one.log_slope2(),
+            one.cos_slope2(),
+            one.sin_slope3(),
+            one.asin_slope3(),
+            one.atan_slope3(),
+            one.cosh_slope2(),
+            one.sinh_slope3(),
+        ] {
+            assert!(y.wid() <= 2.0 * f64::EPSILON * y.mag());
+        }
+
+        // Even functions.
+        let x = const_interval!(-1.0, 0.5);
+        assert_eq!(x.asin_slope3(), const_interval!(0.0, 1.0).asin_slope3());
+        assert_eq!(x.sin_slope3(), const_interval!(0.0, 1.0).sin_slope3());
+        assert_eq!(x.cos_slope2(), const_interval!(0.0, 1.0).cos_slope2());
+        assert_eq!(
+            const_interval!(-1.0, 2.0).asin_slope3(),
+            const_interval!(0.0, 1.0).asin_slope3()
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        let x = I::ENTIRE;
+        assert_eq!(x.exp_slope1(), const_interval!(0.0, f64::INFINITY));
+        assert_eq!(x.exp_slope2(), const_interval!(0.0, f64::INFINITY));
+        assert_eq!(x.log_slope1(), const_interval!(0.0, f64::INFINITY));
+        assert_eq!(x.log_slope2(), const_interval!(0.0, f64::INFINITY));
+        assert_eq!(x.cos_slope2(), const_interval!(0.0, 1.0));
+        assert_eq!(x.sin_slope3(), const_interval!(0.0, 1.0));
+        assert_eq!(x.atan_slope3(), const_interval!(0.0, 1.0));
+        assert_eq!(x.cosh_slope2(), const_interval!(1.0, f64::INFINITY));
+        assert_eq!(x.sinh_slope3(), const_interval!(1.0, f64::INFINITY));
+
+        assert!(const_interval!(-3.0, -1.0).log_slope1().is_empty());
+        assert!(const_interval!(-3.0, -1.0).log_slope2().is_empty());
+        assert!(const_interval!(2.0, 3.0).asin_slope3().is_empty());
+    }
+
+    #[test]
+    fn cos_slope2() {
+        fn f(x: f64) -> f64 {
+            2.0 * (1.0 - x.cos()) / (x * x)
+        }
+
+        // Check that the enclosures contain sampled values and are reasonably tight.
+        for (a, b) in [
+            (1.0, 5.0),
+            (5.0, 7.0),
+            (6.0, 7.0),
+            (6.0, 13.0),
+            (7.0, 8.0),
+            (8.0, 9.0),
+            (9.0, 12.0),
+            (13.0, 14.0),
+            (-30.0, -20.0),
+            (100.0, 100.5),
+        ] {
+            let y = interval!(a, b).unwrap().cos_slope2();
+            let samples = (0..=1000)
+                .map(|i| f(a + (b - a) * i as f64 / 1000.0))
+                .collect::<Vec<_>>();
+            let min = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            assert!(y.inf() <= min + 1e-15 && y.sup() >= max - 1e-15);
+            assert!(y.inf() >= min - 1e-5 && y.sup() <= max + 1e-5);
+        }
+
+        assert!(const_interval!(0.0, 20.0).cos_slope2().sup() == 1.0);
+        assert!(const_interval!(6.0, 7.0).cos_slope2().inf() == 0.0);
+    }
+
+    #[test]
+    fn cubic() {
+        use rug::{ops::CompleteRound, Float};
+
+        // The functions with k = 3, where x^3 is not exact at the precision of 128 bits
+        // for |x| ≥ 1/2. The references are computed with a much higher precision.
+        const PREC: u32 = 2048;
+        type Slope = fn(I) -> I;
+        type Ref = fn(&Float) -> Float;
+        let fs: [(Slope, u32, Ref); 5] = [
+            (I::log_slope2, 3, |x| {
+                x.ln_1p_ref().complete(PREC) - x + x.square_ref().complete(PREC) / 2u32
+            }),
+            (I::sin_slope3, 6, |x| x - x.sin_ref().complete(PREC)),
+            (I::asin_slope3, 6, |x| x.asin_ref().complete(PREC) - x),
+            (I::atan_slope3, 3, |x| x - x.atan_ref().complete(PREC)),
+            (I::sinh_slope3, 6, |x| x.sinh_ref().complete(PREC) - x),
+        ];
+        for i in 0..1000 {
+            let x = 0.5 + (i as f64 + 0.5) / 2000.0;
+            for x in [x, -x] {
+                let xf = Float::with_val(PREC, x);
+                for &(f, c, n) in &fs {
+                    let y = f(I::try_from((x, x)).unwrap());
+                    let r = n(&xf) * c / (xf.square_ref().complete(PREC) * &xf);
+                    assert!(y.inf() <= r && r <= y.sup(), "x = {x}, y = {y:?}, r = {r}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1301 @@
+use crate::{
+    const_interval,
+    interval::*,
+    simd::portable::{next_down, next_up},
+};
+use rug::{float::Round, Float};
+
+const PREC: u32 = f64::MANTISSA_DIGITS;
+
+fn reverse(rnd: Round) -> Round {
+    match rnd {
+        Round::Down => Round::Up,
+        _ => Round::Down,
+    }
+}
+
+macro_rules! mpfr_fn {
+    ($f:ident, $f_ref:ident) => {
+        fn $f(x: f64, rnd: Round) -> f64 {
+            let x = Float::with_val(PREC, x);
+            Float::with_val_round(PREC, x.$f_ref(), rnd)
+                .0
+                .to_f64_round(rnd)
+        }
+    };
+}
+
+mpfr_fn!(ai_point, ai_ref);
+mpfr_fn!(digamma_point, digamma_ref);
+mpfr_fn!(erf_point, erf_ref);
+mpfr_fn!(erfc_point, erfc_ref);
+mpfr_fn!(gamma_point, gamma_ref);
+mpfr_fn!(li2_point, li2_ref);
+mpfr_fn!(zeta_point, zeta_ref);
+
+fn jn_point(n: i32, x: f64, rnd: Round) -> f64 {
+    let x = Float::with_val(PREC, x);
+    Float::with_val_round(PREC, x.jn_ref(n), rnd)
+        .0
+        .to_f64_round(rnd)
+}
+
+fn yn_point(n: i32, x: f64, rnd: Round) -> f64 {
+    let x = Float::with_val(PREC, x);
+    Float::with_val_round(PREC, x.yn_ref(n), rnd)
+        .0
+        .to_f64_round(rnd)
+}
+
+// ln |Γ(x)|.
+fn lgamma_point(x: f64, rnd: Round) -> f64 {
+    let mut x = Float::with_val(PREC, x);
+    x.ln_abs_gamma_round(rnd);
+    x.to_f64_round(rnd)
+}
+
+// Numbers whose magnitude is at least 2^52 are integers.
+const MIN_INTEGER: f64 = 4503599627370496.0;
+
+// Returns `true` if `x` is a pole of Γ, i.e., a non-positive integer.
+fn is_pole(x: f64) -> bool {
+    x <= 0.0 && x == x.floor()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Poles {
+    // No pole is in the interval.
+    None,
+    // Only the bounds of the interval can be poles.
+    AtBounds,
+    // Some pole is in the interior of the interval.
+    Inside,
+}
+
+// Classifies the poles of Γ in [a, b], which must not be empty.
+fn poles(a: f64, b: f64) -> Poles {
+    // The largest pole in (-∞, b].
+    let k = b.min(0.0).floor();
+    if k < a {
+        Poles::None
+    } else if a <= -MIN_INTEGER {
+        // The poles are too dense to be dealt with; report the worst case.
+        Poles::Inside
+    } else if a < k && k < b || k == b && a < k - 1.0 {
+        Poles::Inside
+    } else {
+        Poles::AtBounds
+    }
+}
+
+// Returns `true` if Γ is negative between the poles around `x`,
+// where `x` is not a pole or the left one of them.
+fn is_gamma_neg(x: f64) -> bool {
+    let n = x.floor();
+    n < 0.0 && (n / 2.0).floor() * 2.0 != n
+}
+
+// Returns the bounds of h(x) = |Γ(x)|, or h(x) = ln |Γ(x)| if `log` is `true`, over [l, u],
+// which must be a subset of the closure of the interval between two adjacent poles or (0, ∞).
+// h(x) is regarded as +∞ at the poles and at +∞.
+//
+// Between two adjacent poles, ψ = Γ′/Γ increases from -∞ to +∞, thus h decreases
+// and then increases. The zero of ψ is enclosed by bisection, and the minimum of h
+// is bounded with the mean value theorem.
+fn gamma_segment(l: f64, u: f64, log: bool) -> (f64, f64) {
+    let neg = is_gamma_neg(l);
+    let h = |x: f64, rnd: Round| {
+        if is_pole(x) || x == f64::INFINITY {
+            f64::INFINITY
+        } else if log {
+            lgamma_point(x, rnd)
+        } else if neg {
+            -gamma_point(x, reverse(rnd))
+        } else {
+            gamma_point(x, rnd)
+        }
+    };
+
+    if !is_pole(u) && digamma_point(u, Round::Up) <= 0.0 {
+        // h is decreasing on [l, u].
+        return (h(u, Round::Down), h(l, Round::Up));
+    }
+    if !is_pole(l) && digamma_point(l, Round::Down) >= 0.0 {
+        // h is increasing on [l, u].
+        return (h(l, Round::Down), h(u, Round::Up));
+    }
+
+    // The zero of ψ is in [p, q]. Since ψ(2) = 1 - γ > 0, we can use 2 instead of a larger u.
+    let mut p = if is_pole(l) { next_up(l) } else { l };
+    let mut q = if is_pole(u) { next_down(u) } else { u.min(2.0) };
+    loop {
+        let m = 0.5 * (p + q);
+        if m <= p || m >= q {
+            break;
+        }
+        // Since the result is correctly rounded, the sign is exact.
+        if digamma_point(m, Round::Up) <= 0.0 {
+            p = m;
+        } else {
+            q = m;
+        }
+    }
+
+    // |ψ| ≤ s on [p, q].
+    let s = Interval::with_infsup_raw(
+        0.0,
+        (-digamma_point(p, Round::Down)).max(digamma_point(q, Round::Up)),
+    );
+    // |h′| ≤ t on [p, q], since h′ = ψ h or h′ = ψ, respectively.
+    let t = if log {
+        s
+    } else {
+        s * Interval::with_infsup_raw(0.0, h(p, Round::Up).max(h(q, Round::Up)))
+    };
+    let hpq = h(p, Round::Down).max(h(q, Round::Down));
+    let w = Interval::with_infsup_raw(q, q) - Interval::with_infsup_raw(p, p);
+    let min = (Interval::with_infsup_raw(hpq, hpq) - t * w).inf_raw();
+    (min, h(l, Round::Up).max(h(u, Round::Up)))
+}
+
+// The maximum number of evaluations of the point function performed by `osc_range`.
+const MAX_EVALS: u32 = 1000;
+
+// The pieces whose error bound is less than this value are not subdivided further.
+const OSC_TOL: f64 = f64::EPSILON / 16.0;
+
+// Returns the bounds of a function over [a, b], where `a` and `b` must be finite, and
+//
+// - `f(x, rnd)` is the value of the function rounded in the direction `rnd`,
+// - `d2(p, q)` is an upper bound of the absolute value of its second derivative over [p, q],
+// - `env(p)` is an upper bound of the absolute value of the function over [p, b].
+//
+// The range is enclosed by branch and bound, in which the function on each piece [p, q]
+// is bounded by the linear interpolation of f(p) and f(q), whose error is at most
+// d2(p, q) (q - p)^2 / 8.
+fn osc_range(
+    a: f64,
+    b: f64,
+    f: &dyn Fn(f64, Round) -> f64,
+    d2: &dyn Fn(f64, f64) -> f64,
+    env: &dyn Fn(f64) -> f64,
+) -> (f64, f64) {
+    let eval = |x: f64| (f(x, Round::Down), f(x, Round::Up));
+    let fa = eval(a);
+    let fb = eval(b);
+    let mut lo = fa.0.min(fb.0);
+    let mut hi = fa.1.max(fb.1);
+    let mut evals = 2;
+    let mut pieces = vec![(a, fa, b, fb)];
+    while let Some((p, fp, q, fq)) = pieces.pop() {
+        let w = Interval::with_infsup_raw(q, q) - Interval::with_infsup_raw(p, p);
+        let d2 = d2(p, q);
+        let e = (Interval::with_infsup_raw(d2, d2) * w.sqr() / const_interval!(8.0, 8.0)).sup_raw();
+        let mut piece_lo = (Interval::with_infsup_raw(fp.0.min(fq.0), fp.0.min(fq.0))
+            - Interval::with_infsup_raw(e, e))
+        .inf_raw();
+        let mut piece_hi = (Interval::with_infsup_raw(fp.1.max(fq.1), fp.1.max(fq.1))
+            + Interval::with_infsup_raw(e, e))
+        .sup_raw();
+        if piece_lo >= lo && piece_hi <= hi {
+            continue;
+        }
+
+        // The envelope can be expensive to evaluate, and is only useful for wide pieces.
+        let wide = w.sup_raw() > 1.0;
+        if wide {
+            let env = env(p);
+            piece_lo = piece_lo.max(-env);
+            piece_hi = piece_hi.min(env);
+            if piece_lo >= lo && piece_hi <= hi {
+                continue;
+            }
+        }
+
+        let m = 0.5 * (p + q);
+        if e <= OSC_TOL || evals >= MAX_EVALS || m <= p || m >= q {
+            if !wide {
+                let env = env(p);
+                piece_lo = piece_lo.max(-env);
+                piece_hi = piece_hi.min(env);
+            }
+            lo = lo.min(piece_lo);
+            hi = hi.max(piece_hi);
+            continue;
+        }
+
+        let fm = eval(m);
+        evals += 1;
+        lo = lo.min(fm.0);
+        hi = hi.max(fm.1);
+        pieces.push((m, fm, q, fq));
+        pieces.push((p, fp, m, fm));
+    }
+    (lo, hi)
+}
+
+// The length of the interval on which the branch and bound is performed
+// when the point function is evaluated over an unbounded interval.
+const OSC_LEN: f64 = 64.0;
+
+// Returns an upper bound of √(J_n(x)^2 + Y_n(x)^2) for x > 0, which is decreasing in x
+// by Nicholson's formula. Thus, it bounds |J_n| and |Y_n| over [x, ∞).
+fn bessel_env(n: i32, x: f64) -> f64 {
+    let j = jn_point(n, x, Round::Up)
+        .abs()
+        .max(jn_point(n, x, Round::Down).abs());
+    let y = yn_point(n, x, Round::Up)
+        .abs()
+        .max(yn_point(n, x, Round::Down).abs());
+    (Interval::with_infsup_raw(j, j).sqr() + Interval::with_infsup_raw(y, y).sqr())
+        .sqrt()
+        .sup_raw()
+}
+
+// Returns a number t such that C_n is monotone on (0, t] for C = J or C = Y.
+// J_0 decreases until j′_{0,1} ≈ 3.83, J_1 increases until j′_{1,1} ≈ 1.84,
+// and J_n, n ≥ 2, increases until j′_{n,1} > n.
+// Y_n increases until y′_{n,1} > max(n, 2).
+fn bessel_mono_bound(n: i32, y: bool) -> f64 {
+    let n = (n as f64).abs();
+    if y {
+        n.max(2.0)
+    } else if n == 0.0 {
+        3.0
+    } else if n == 1.0 {
+        1.8
+    } else {
+        n
+    }
+}
+
+// Returns the bounds of J_n (if `y` is `false`) or Y_n (if `y` is `true`) over [a, b],
+// where 0 ≤ a ≤ b ≤ +∞.
+fn bessel_range(n: i32, a: f64, b: f64, y: bool) -> (f64, f64) {
+    let f = |x: f64, rnd: Round| {
+        if y {
+            yn_point(n, x, rnd)
+        } else {
+            jn_point(n, x, rnd)
+        }
+    };
+    let t = bessel_mono_bound(n, y);
+    let mut lo = f64::INFINITY;
+    let mut hi = f64::NEG_INFINITY;
+
+    if a < t {
+        // C_{-n} = (-1)^n C_n.
+        let flip = n < 0 && n % 2 != 0;
+        let inc = if !y && n == 0 { flip } else { !flip };
+        let (l, u) = (a, b.min(t));
+        let (fl, fu) = if inc {
+            (f(l, Round::Down), f(u, Round::Up))
+        } else {
+            (f(u, Round::Down), f(l, Round::Up))
+        };
+        lo = fl;
+        hi = fu;
+    }
+
+    if b > t {
+        let l = a.max(t);
+        let u = if b == f64::INFINITY {
+            (l + OSC_LEN).max(l)
+        } else {
+            b
+        };
+        // |C_n′| ≤ M_{n∓1} + |n| M_n / x, where M_n = √(J_n^2 + Y_n^2),
+        // and |C_n″| ≤ |C_n′| / x + |1 - n^2 / x^2| |C_n| by the differential equation.
+        // Since these bounds are decreasing in x, the ones at l are valid over [l, u].
+        let d2 = if y {
+            let abs_n = (n as f64).abs();
+            let m = if n > 0 { n - 1 } else { n + 1 };
+            let x = Interval::with_infsup_raw(l, l);
+            let env_n = Interval::with_infsup_raw(0.0, bessel_env(n, l));
+            let env_m = Interval::with_infsup_raw(0.0, bessel_env(m, l));
+            let d1 = env_m + Interval::with_infsup_raw(abs_n, abs_n) / x * env_n;
+            // Since l ≥ |n|, |1 - n^2 / x^2| ≤ 1.
+            (d1 / x + env_n).sup_raw()
+        } else {
+            1.0
+        };
+        let env = |p: f64| {
+            let e = bessel_env(n, p);
+            if y {
+                e
+            } else {
+                e.min(1.0)
+            }
+        };
+        let (l, h) = osc_range(l, u, &f, &|_, _| d2, &env);
+        lo = lo.min(l);
+        hi = hi.max(h);
+        if u < b {
+            let e = env(u);
+            lo = lo.min(-e);
+            hi = hi.max(e);
+        }
+    }
+
+    (lo, hi)
+}
+
+// Bounds of the global maximum and minimum of Ai.
+const AI_MAX: f64 = 0.5357;
+const AI_MIN: f64 = -0.4191;
+
+// Ai is not evaluated below this number, where MPFR becomes too slow.
+const AI_EVAL_MIN: f64 = -64.0;
+
+impl Interval {
+    /// Returns the Airy function $\operatorname{Ai}$ of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range                 |
+    /// | ------ | --------------------- |
+    /// | $\R$   | $\[-0.419…, 0.535…\]$ |
+    ///
+    /// The result is not tight if `self` contains a number less than $-64$.
+    #[must_use]
+    pub fn ai(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+
+        // Ai is decreasing on [a′_1, ∞), where a′_1 ≈ -1.019 is the largest zero of Ai′.
+        if b > -1.0 {
+            lo = ai_point(b, Round::Down);
+            hi = ai_point(a.max(-1.0), Round::Up);
+        }
+
+        if a < -1.0 {
+            let (l, h) = if a < AI_EVAL_MIN {
+                (AI_MIN, AI_MAX)
+            } else {
+                // |Ai″(x)| = |x Ai(x)|.
+                let d2 = |p: f64, q: f64| {
+                    (Interval::with_infsup_raw(p.abs().max(q.abs()), p.abs().max(q.abs()))
+                        * const_interval!(AI_MAX, AI_MAX))
+                    .sup_raw()
+                };
+                osc_range(a, b.min(-1.0), &ai_point, &d2, &|_| AI_MAX)
+            };
+            lo = lo.min(l.max(AI_MIN));
+            hi = hi.max(h.min(AI_MAX));
+        }
+
+        Self::with_infsup_raw(lo, hi)
+    }
+
+    /// Returns the digamma function $ψ = Γ′/Γ$ of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range |
+    /// | ------------------------- | ----- |
+    /// | $\R ∖ \set{0, -1, -2, …}$ | $\R$  |
+    #[must_use]
+    pub fn digamma(self) -> Self {
+        self.digamma_impl().0
+    }
+
+    fn digamma_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b && is_pole(a) {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        // ψ is increasing between adjacent poles, from -∞ to +∞.
+        match poles(a, b) {
+            Poles::None => (
+                Self::with_infsup_raw(digamma_point(a, Round::Down), digamma_point(b, Round::Up)),
+                Decoration::Com,
+            ),
+            Poles::AtBounds => {
+                let y_a = if is_pole(a) {
+                    f64::NEG_INFINITY
+                } else {
+                    digamma_point(a, Round::Down)
+                };
+                let y_b = if is_pole(b) {
+                    f64::INFINITY
+                } else {
+                    digamma_point(b, Round::Up)
+                };
+                (Self::with_infsup_raw(y_a, y_b), Decoration::Trv)
+            }
+            Poles::Inside => (Self::ENTIRE, Decoration::Trv),
+        }
+    }
+
+    /// Returns the error function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range     |
+    /// | ------ | --------- |
+    /// | $\R$   | $(-1, 1)$ |
+    #[must_use]
+    pub fn erf(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        Self::with_infsup_raw(
+            erf_point(self.inf_raw(), Round::Down),
+            erf_point(self.sup_raw(), Round::Up),
+        )
+    }
+
+    /// Returns the complementary error function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range    |
+    /// | ------ | -------- |
+    /// | $\R$   | $(0, 2)$ |
+    #[must_use]
+    pub fn erfc(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        Self::with_infsup_raw(
+            erfc_point(self.sup_raw(), Round::Down),
+            erfc_point(self.inf_raw(), Round::Up),
+        )
+    }
+
+    /// Returns the gamma function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range         |
+    /// | ------------------------- | ------------- |
+    /// | $\R ∖ \set{0, -1, -2, …}$ | $\R ∖ \set 0$ |
+    #[must_use]
+    pub fn gamma(self) -> Self {
+        self.gamma_impl().0
+    }
+
+    fn gamma_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b && is_pole(a) {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let d = match poles(a, b) {
+            Poles::None => Decoration::Com,
+            Poles::AtBounds => Decoration::Trv,
+            // Γ tends to +∞ on one side of each pole and to -∞ on the other.
+            Poles::Inside => return (Self::ENTIRE, Decoration::Trv),
+        };
+        let (lo, hi) = gamma_segment(a, b, false);
+        let y = if is_gamma_neg(a) {
+            Self::with_infsup_raw(-hi, -lo)
+        } else {
+            Self::with_infsup_raw(lo, hi)
+        };
+        (y, d)
+    }
+
+    /// Returns the Bessel function of the first kind of order 0 of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range            |
+    /// | ------ | ---------------- |
+    /// | $\R$   | $\[-0.402…, 1\]$ |
+    #[must_use]
+    pub fn j0(self) -> Self {
+        self.jn(0)
+    }
+
+    /// Returns the Bessel function of the first kind of order 1 of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range                 |
+    /// | ------ | --------------------- |
+    /// | $\R$   | $\[-0.581…, 0.581…\]$ |
+    #[must_use]
+    pub fn j1(self) -> Self {
+        self.jn(1)
+    }
+
+    /// Returns the Bessel function of the first kind of order `n` of `self`.
+    ///
+    /// The domain of the point functions is $\R$, and their ranges are subsets of $\[-1, 1\]$.
+    ///
+    /// The result is not tight if `self` covers too many oscillations.
+    #[must_use]
+    pub fn jn(self, n: i32) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        // J_n(-x) = (-1)^n J_n(x).
+        let odd = n % 2 != 0;
+        let flip = |(lo, hi): (f64, f64)| if odd { (-hi, -lo) } else { (lo, hi) };
+        let (lo, hi) = if a >= 0.0 {
+            bessel_range(n, a, b, false)
+        } else if b <= 0.0 {
+            flip(bessel_range(n, -b, -a, false))
+        } else if odd {
+            let (lo1, hi1) = bessel_range(n, 0.0, b, false);
+            let (lo2, hi2) = flip(bessel_range(n, 0.0, -a, false));
+            (lo1.min(lo2), hi1.max(hi2))
+        } else {
+            bessel_range(n, 0.0, (-a).max(b), false)
+        };
+
+        Self::with_infsup_raw(lo.max(-1.0), hi.min(1.0))
+    }
+
+    /// Returns the Lambert $W$ function of `self`, i.e., the principal branch $W_0$
+    /// of the inverse of $x ↦ x \e^x$.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain        | Range      |
+    /// | ------------- | ---------- |
+    /// | $\[-1/\e, ∞)$ | $\[-1, ∞)$ |
+    #[must_use]
+    pub fn lambert_w(self) -> Self {
+        self.lambert_w_impl().0
+    }
+
+    fn lambert_w_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        // -1/e is irrational, thus the comparison is performed with a precision high enough
+        // to decide it for any `f64` number.
+        let e = |rnd: Round| {
+            Float::with_val_round(
+                LAMBERT_PREC,
+                Float::with_val(LAMBERT_PREC, 1).exp_ref(),
+                rnd,
+            )
+            .0
+        };
+        if b < 0.0 && Float::with_val_round(LAMBERT_PREC, b * e(Round::Down), Round::Up).0 < -1 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+        let a_in_dom =
+            a >= 0.0 || Float::with_val_round(LAMBERT_PREC, a * e(Round::Up), Round::Down).0 >= -1;
+
+        let y = Self::with_infsup_raw(
+            if a_in_dom {
+                lambert_w_point(a, Round::Down)
+            } else {
+                -1.0
+            },
+            lambert_w_point(b, Round::Up),
+        );
+        let d = if a_in_dom {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (y, d)
+    }
+
+    /// Returns the logarithm of the absolute value of the gamma function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain                    | Range |
+    /// | ------------------------- | ----- |
+    /// | $\R ∖ \set{0, -1, -2, …}$ | $\R$  |
+    #[must_use]
+    pub fn lgamma(self) -> Self {
+        self.lgamma_impl().0
+    }
+
+    fn lgamma_impl(self) -> (Self, Decoration) {
+        if self.is_empty() {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == b && is_pole(a) {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        match poles(a, b) {
+            Poles::None => {
+                let (lo, hi) = gamma_segment(a, b, true);
+                (Self::with_infsup_raw(lo, hi), Decoration::Com)
+            }
+            _ if a <= -MIN_INTEGER => (Self::ENTIRE, Decoration::Trv),
+            _ => {
+                // ln |Γ| tends to +∞ at every pole. Its local minima between adjacent poles
+                // decrease as the poles go to -∞. Thus, the minimum is attained on one of the
+                // partial intervals at the ends or the leftmost interval between poles.
+                let k_lo = a.ceil();
+                let k_hi = b.min(0.0).floor();
+                let mut lo = f64::INFINITY;
+                if a < k_lo {
+                    lo = lo.min(gamma_segment(a, k_lo, true).0);
+                }
+                if k_hi < b {
+                    lo = lo.min(gamma_segment(k_hi, b, true).0);
+                }
+                if k_lo < k_hi {
+                    lo = lo.min(gamma_segment(k_lo, k_lo + 1.0, true).0);
+                }
+                (Self::with_infsup_raw(lo, f64::INFINITY), Decoration::Trv)
+            }
+        }
+    }
+
+    /// Returns the dilogarithm of `self`. For $x > 1$, the point function is defined
+    /// as the real part of the analytic continuation.
+    ///
+    /// The point function is defined as follows:
+    ///
+    /// $$
+    /// \operatorname{Li}_2(x) = -\operatorname{Re} ∫_0^x \frac{\ln(1 - t)}{t} \\, dt.
+    /// $$
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain | Range          |
+    /// | ------ | -------------- |
+    /// | $\R$   | $(-∞, π^2/4\]$ |
+    #[must_use]
+    pub fn li2(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        // Li₂ is increasing on (-∞, 2] and decreasing on [2, ∞).
+        if b <= 2.0 {
+            Self::with_infsup_raw(li2_point(a, Round::Down), li2_point(b, Round::Up))
+        } else if a >= 2.0 {
+            Self::with_infsup_raw(li2_point(b, Round::Down), li2_point(a, Round::Up))
+        } else {
+            Self::with_infsup_raw(
+                li2_point(a, Round::Down).min(li2_point(b, Round::Down)),
+                li2_point(2.0, Round::Up),
+            )
+        }
+    }
+
+    /// Returns the Bessel function of the second kind of order 0 of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain   | Range           |
+    /// | -------- | --------------- |
+    /// | $(0, ∞)$ | $(-∞, 0.520…\]$ |
+    #[must_use]
+    pub fn y0(self) -> Self {
+        self.yn(0)
+    }
+
+    fn y0_impl(self) -> (Self, Decoration) {
+        self.yn_impl(0)
+    }
+
+    /// Returns the Bessel function of the second kind of order 1 of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain   | Range           |
+    /// | -------- | --------------- |
+    /// | $(0, ∞)$ | $(-∞, 0.416…\]$ |
+    #[must_use]
+    pub fn y1(self) -> Self {
+        self.yn(1)
+    }
+
+    fn y1_impl(self) -> (Self, Decoration) {
+        self.yn_impl(1)
+    }
+
+    /// Returns the Bessel function of the second kind of order `n` of `self`.
+    ///
+    /// The domain of the point functions is $(0, ∞)$.
+    ///
+    /// The result is not tight if `self` covers too many oscillations.
+    #[must_use]
+    pub fn yn(self, n: i32) -> Self {
+        self.yn_impl(n).0
+    }
+
+    fn yn_impl(self, n: i32) -> (Self, Decoration) {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = self.intersection(DOM);
+
+        let a = x.inf_raw();
+        let b = x.sup_raw();
+        if x.is_empty() || b <= 0.0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let (lo, hi) = bessel_range(n, a, b, true);
+        let d = if self.interior(DOM) {
+            Decoration::Com
+        } else {
+            Decoration::Trv
+        };
+        (Self::with_infsup_raw(lo, hi), d)
+    }
+
+    /// Returns the Riemann zeta function of `self`.
+    ///
+    /// The domain and the range of the point function are:
+    ///
+    /// | Domain        | Range |
+    /// | ------------- | ----- |
+    /// | $\R ∖ \set 1$ | $\R$  |
+    #[must_use]
+    pub fn zeta(self) -> Self {
+        self.zeta_impl().0
+    }
+
+    fn zeta_impl(self) -> (Self, Decoration) {
+        // ζ is decreasing on (x_0, 1) and (1, ∞), where x_0 ≈ -2.717 is a local maximum.
+        const X0: f64 = -2.5;
+
+        if self.is_empty() {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+
+        let a = self.inf_raw();
+        let b = self.sup_raw();
+        if a == 1.0 && b == 1.0 {
+            return (Self::EMPTY, Decoration::Trv);
+        }
+        if a < 1.0 && b > 1.0 {
+            return (Self::ENTIRE, Decoration::Trv);
+        }
+
+        let mut y = Self::EMPTY;
+        if b > 1.0 {
+            let y_a = if a == 1.0 {
+                f64::INFINITY
+            } else {
+                zeta_point(a, Round::Up)
+            };
+            y = Self::with_infsup_raw(zeta_point(b, Round::Down), y_a);
+        }
+        if a < 1.0 && b > X0 {
+            let y_b = if b == 1.0 {
+                f64::NEG_INFINITY
+            } else {
+                zeta_point(b, Round::Down)
+            };
+            y = y.convex_hull(Self::with_infsup_raw(y_b, zeta_point(a.max(X0), Round::Up)));
+        }
+        if a < X0 {
+            // The functional equation ζ(s) = 2^s π^(s - 1) sin(π s / 2) Γ(1 - s) ζ(1 - s).
+            let s = Self::with_infsup_raw(a, b.min(X0));
+            let t = Self::with_infsup_raw(1.0, 1.0) - s;
+            let zeta_t = Self::with_infsup_raw(
+                zeta_point(t.sup_raw(), Round::Down),
+                zeta_point(t.inf_raw(), Round::Up),
+            );
+            let z = s.exp2()
+                * Self::PI.pow(s - Self::with_infsup_raw(1.0, 1.0))
+                * (s * const_interval!(0.5, 0.5)).sin_pi()
+                * t.gamma()
+                * zeta_t;
+            y = y.convex_hull(z);
+        }
+
+        let d = if a <= 1.0 && b >= 1.0 {
+            Decoration::Trv
+        } else {
+            Decoration::Com
+        };
+        (y, d)
+    }
+}
+
+const LAMBERT_PREC: u32 = 128;
+
+// Returns y e^y rounded in the direction `rnd`, which is increasing for y ≥ -1.
+fn lambert_w_inv(y: f64, rnd: Round) -> Float {
+    let y = Float::with_val(LAMBERT_PREC, y);
+    // For y < 0, a smaller factor e^y gives a greater product.
+    let e_rnd = if y.is_sign_negative() {
+        reverse(rnd)
+    } else {
+        rnd
+    };
+    let e = Float::with_val_round(LAMBERT_PREC, y.exp_ref(), e_rnd).0;
+    Float::with_val_round(LAMBERT_PREC, &y * &e, rnd).0
+}
+
+// Returns an approximate value of W(x) for x ≥ -1/e.
+fn lambert_w_approx(x: f64) -> f64 {
+    if x > 1.0 {
+        // Newton's method for w + ln(w) = ln(x), starting from above the solution.
+        let l = x.ln();
+        let mut w = x.ln_1p();
+        for _ in 0..20 {
+            let dw = (w + w.ln() - l) / (1.0 + 1.0 / w);
+            w -= dw;
+            if dw.abs() <= f64::EPSILON * w {
+                break;
+            }
+        }
+        w
+    } else {
+        // Halley's method for w e^w = x.
+        let mut w = if x < -0.25 {
+            // The series expansion at the branch point.
+            let p = (2.0 * (std::f64::consts::E * x + 1.0)).max(0.0).sqrt();
+            -1.0 + p - p * p / 3.0 + 11.0 / 72.0 * p * p * p
+        } else {
+            x.ln_1p()
+        };
+        for _ in 0..20 {
+            let e = w.exp();
+            let f = w * e - x;
+            let w1 = w + 1.0;
+            if f == 0.0 || w1 <= 0.0 {
+                break;
+            }
+            let dw = f / (e * w1 - (w + 2.0) * f / (2.0 * w1));
+            w = (w - dw).max(-1.0);
+            if dw.abs() <= f64::EPSILON * w.abs() {
+                break;
+            }
+        }
+        w
+    }
+}
+
+// Returns W(x) rounded in the direction `rnd`, where x ≥ -1/e.
+//
+// The result is obtained by bisection from an approximate value, and each step is validated
+// by evaluating the inverse function with directed rounding.
+fn lambert_w_point(x: f64, rnd: Round) -> f64 {
+    if x == f64::INFINITY {
+        return x;
+    }
+
+    let x_f = Float::with_val(PREC, x);
+    // y ≤ W(x) and y ≥ W(x), respectively, provided y ≥ -1.
+    let is_lower = |y: f64| lambert_w_inv(y, Round::Up) <= x_f;
+    let is_upper = |y: f64| lambert_w_inv(y, Round::Down) >= x_f;
+
+    let w = lambert_w_approx(x);
+    let d = w.abs().max(1.0) * 2f64.powi(-40);
+    let mut lo = (w - d).max(-1.0);
+    if !is_lower(lo) {
+        lo = -1.0;
+    }
+    let mut hi = w + d;
+    if !is_upper(hi) {
+        // W(x) ≤ max(x, 0).
+        hi = x.max(0.0);
+    }
+    loop {
+        let m = 0.5 * lo + 0.5 * hi;
+        if m <= lo || m >= hi {
+            break;
+        }
+        let l = is_lower(m);
+        let u = is_upper(m);
+        if l {
+            lo = m;
+        }
+        if u {
+            hi = m;
+        }
+        if !l && !u {
+            break;
+        }
+    }
+
+    if rnd == Round::Down {
+        lo
+    } else {
+        hi
+    }
+}
+
+macro_rules! impl_dec {
+    ($f:ident) => {
+        #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
+        ///
+        /// A NaI is returned if `self` is NaI.
+        #[must_use]
+        pub fn $f(self) -> Self {
+            Self::set_dec(self.x.$f(), self.d)
+        }
+    };
+
+    ($f:ident, $f_impl:ident) => {
+        #[doc = concat!("The decorated version of [`Interval::", stringify!($f), "`].")]
+        ///
+        /// A NaI is returned if `self` is NaI.
+        #[must_use]
+        pub fn $f(self) -> Self {
+            let (y, d) = self.x.$f_impl();
+            Self::set_dec(y, self.d.min(d))
+        }
+    };
+}
+
+impl DecInterval {
+    impl_dec!(ai);
+    impl_dec!(digamma, digamma_impl);
+    impl_dec!(erf);
+    impl_dec!(erfc);
+    impl_dec!(gamma, gamma_impl);
+    impl_dec!(j0);
+    impl_dec!(j1);
+
+    /// The decorated version of [`Interval::jn`].
+    ///
+    /// A NaI is returned if `self` is NaI.
+    #[must_use]
+    pub fn jn(self, n: i32) -> Self {
+        Self::set_dec(self.x.jn(n), self.d)
+    }
+
+    impl_dec!(lambert_w, lambert_w_impl);
+    impl_dec!(lgamma, lgamma_impl);
+    impl_dec!(li2);
+    impl_dec!(y0, y0_impl);
+    impl_dec!(y1, y1_impl);
+
+    /// The decorated version of [`Interval::yn`].
+    ///
+    /// A NaI is returned if `self` is NaI.
+    #[must_use]
+    pub fn yn(self, n: i32) -> Self {
+        let (y, d) = self.x.yn_impl(n);
+        Self::set_dec(y, self.d.min(d))
+    }
+
+    impl_dec!(zeta, zeta_impl);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use DecInterval as DI;
+    use Interval as I;
+
+    #[test]
+    fn empty() {
+        assert!(I::EMPTY.ai().is_empty());
+        assert!(I::EMPTY.digamma().is_empty());
+        assert!(I::EMPTY.erf().is_empty());
+        assert!(I::EMPTY.erfc().is_empty());
+        assert!(I::EMPTY.gamma().is_empty());
+        assert!(I::EMPTY.j0().is_empty());
+        assert!(I::EMPTY.j1().is_empty());
+        assert!(I::EMPTY.jn(2).is_empty());
+        assert!(I::EMPTY.lambert_w().is_empty());
+        assert!(I::EMPTY.lgamma().is_empty());
+        assert!(I::EMPTY.li2().is_empty());
+        assert!(I::EMPTY.y0().is_empty());
+        assert!(I::EMPTY.y1().is_empty());
+        assert!(I::EMPTY.yn(2).is_empty());
+        assert!(I::EMPTY.zeta().is_empty());
+    }
+
+    #[test]
+    fn nai() {
+        assert!(DI::NAI.ai().is_nai());
+        assert!(DI::NAI.digamma().is_nai());
+        assert!(DI::NAI.erf().is_nai());
+        assert!(DI::NAI.erfc().is_nai());
+        assert!(DI::NAI.gamma().is_nai());
+        assert!(DI::NAI.j0().is_nai());
+        assert!(DI::NAI.j1().is_nai());
+        assert!(DI::NAI.jn(2).is_nai());
+        assert!(DI::NAI.lambert_w().is_nai());
+        assert!(DI::NAI.lgamma().is_nai());
+        assert!(DI::NAI.li2().is_nai());
+        assert!(DI::NAI.y0().is_nai());
+        assert!(DI::NAI.y1().is_nai());
+        assert!(DI::NAI.yn(2).is_nai());
+        assert!(DI::NAI.zeta().is_nai());
+    }
+
+    // Checks that the image of `x` encloses the images of the points sampled from `x`.
+    fn check_samples(f: impl Fn(I) -> I, x: I) {
+        let y = f(x);
+        let n = 50;
+        for i in 0..=n {
+            let t = x.inf() + (x.sup() - x.inf()) * i as f64 / n as f64;
+            let t = interval!(t.min(x.sup()), t.min(x.sup())).unwrap();
+            assert!(f(t).subset(y), "{:?} at {:?}", x, t);
+        }
+    }
+
+    #[test]
+    fn erf() {
+        assert_eq!(I::zero().erf(), I::zero());
+        assert_eq!(I::zero().erfc(), const_interval!(1.0, 1.0));
+        assert_eq!(I::ENTIRE.erf(), const_interval!(-1.0, 1.0));
+        assert_eq!(I::ENTIRE.erfc(), const_interval!(0.0, 2.0));
+        assert_eq!(DI::new(I::ENTIRE).erf().decoration(), Decoration::Dac);
+        check_samples(I::erf, const_interval!(-3.0, 2.0));
+        check_samples(I::erfc, const_interval!(-3.0, 2.0));
+    }
+
+    #[test]
+    fn gamma() {
+        let one = const_interval!(1.0, 1.0);
+        assert_eq!(one.gamma(), one);
+        assert_eq!(const_interval!(3.0, 4.0).gamma(), const_interval!(2.0, 6.0));
+
+        // The minimum of Γ over (0, ∞) is Γ(1.4616…) = 0.8856…
+        let y = const_interval!(1.0, 2.0).gamma();
+        assert_eq!(y.sup(), 1.0);
+        assert!(y.inf() > 0.885603194410888 && y.inf() <= 0.8856031944108887);
+        assert_eq!(const_interval!(0.0, f64::INFINITY).gamma().inf(), y.inf());
+
+        // Γ < 0 on (-1, 0) and (-3, -2) and Γ > 0 on (-2, -1).
+        let y = const_interval!(-1.0, 0.0).gamma();
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert!(y.sup() < -3.544 && y.sup() > -3.545);
+        let y = const_interval!(-2.0, -1.0).gamma();
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert!(y.inf() > 2.302 && y.inf() < 2.303);
+        let y = const_interval!(-3.0, -2.0).gamma();
+        assert!(y.sup() < -0.888 && y.sup() > -0.889);
+        check_samples(I::gamma, const_interval!(-2.9, -2.1));
+        check_samples(I::gamma, const_interval!(-20.9, -20.1));
+        check_samples(I::gamma, const_interval!(0.1, 5.0));
+
+        // Poles.
+        assert!(const_interval!(0.0, 0.0).gamma().is_empty());
+        assert!(const_interval!(-5.0, -5.0).gamma().is_empty());
+        assert_eq!(const_interval!(-0.5, 0.5).gamma(), I::ENTIRE);
+        assert_eq!(const_interval!(-2.0, 0.0).gamma(), I::ENTIRE);
+        assert_eq!(I::ENTIRE.gamma(), I::ENTIRE);
+
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).gamma().decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(-1.0, -0.5)).gamma().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(-0.5, 0.5)).gamma().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(200.0, 300.0)).gamma().decoration(),
+            Decoration::Dac
+        );
+    }
+
+    #[test]
+    fn lgamma() {
+        let y = const_interval!(1.0, 2.0).lgamma();
+        assert_eq!(y.sup(), 0.0);
+        assert!(y.inf() < -0.1214 && y.inf() > -0.1215);
+
+        // ln |Γ(-1/2)| = ln(2√π) > ln |Γ(1/2)| = ln √π.
+        let y = const_interval!(-0.5, 0.5).lgamma();
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert!(y.inf() <= 0.5723649429247001 && y.inf() > 0.5723649429247);
+
+        // The minimum over (-3, 0) is attained in (-3, -2).
+        let y = const_interval!(-3.0, 0.0).lgamma();
+        assert!(y.inf() < -0.1186 && y.inf() > -0.1187);
+        assert_eq!(I::ENTIRE.lgamma(), I::ENTIRE);
+        assert!(const_interval!(-1.0, -1.0).lgamma().is_empty());
+        check_samples(I::lgamma, const_interval!(-3.9, -3.1));
+        check_samples(I::lgamma, const_interval!(-4.0, 3.0));
+        check_samples(I::lgamma, const_interval!(0.1, 30.0));
+
+        assert_eq!(
+            DI::new(const_interval!(-0.5, -0.25)).lgamma().decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(-0.5, 0.0)).lgamma().decoration(),
+            Decoration::Trv
+        );
+    }
+
+    #[test]
+    fn digamma() {
+        // ψ(1) = -γ.
+        let y = const_interval!(1.0, 1.0).digamma();
+        assert!(y.contains(-0.5772156649015329));
+        assert_eq!(
+            const_interval!(0.0, 1.0).digamma(),
+            I::with_infsup_raw(f64::NEG_INFINITY, y.sup())
+        );
+        assert_eq!(const_interval!(-1.5, -0.5).digamma(), I::ENTIRE);
+        assert!(const_interval!(-2.0, -2.0).digamma().is_empty());
+        check_samples(I::digamma, const_interval!(-2.9, -2.1));
+
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).digamma().decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(0.0, 1.0)).digamma().decoration(),
+            Decoration::Trv
+        );
+    }
+
+    #[test]
+    fn bessel() {
+        let one = const_interval!(1.0, 1.0);
+        assert_eq!(I::zero().j0(), one);
+        assert_eq!(I::zero().j1(), I::zero());
+        assert_eq!(I::zero().jn(-3), I::zero());
+
+        // The minimum of J_0 is J_0(3.8317…) = -0.4027…
+        let y = I::ENTIRE.j0();
+        assert_eq!(y.sup(), 1.0);
+        assert!(y.inf() < -0.40275 && y.inf() > -0.40276);
+        let y = I::ENTIRE.j1();
+        assert!(y.sup() > 0.58186 && y.sup() < 0.58187);
+
+        // J_n(-x) = (-1)^n J_n(x) and J_{-n}(x) = (-1)^n J_n(x).
+        let x = const_interval!(2.0, 7.0);
+        assert_eq!((-x).jn(3), -x.jn(3));
+        assert_eq!(x.jn(-3), -x.jn(3));
+        assert_eq!((-x).jn(4), x.jn(4));
+
+        for n in [0, 1, 2, -3, 10] {
+            check_samples(|x| x.jn(n), const_interval!(-12.0, 9.0));
+            check_samples(|x| x.yn(n), const_interval!(0.5, 20.0));
+        }
+        check_samples(|x| x.jn(30), const_interval!(25.0, 45.0));
+        check_samples(|x| x.yn(30), const_interval!(25.0, 45.0));
+
+        // The envelope √(J_0^2 + Y_0^2) bounds the functions over unbounded intervals.
+        let y = const_interval!(100.0, f64::INFINITY).j0();
+        assert!(y.subset(const_interval!(-0.08, 0.08)));
+        let y = const_interval!(0.0, f64::INFINITY).y0();
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert!(y.sup() > 0.52078 && y.sup() < 0.52079);
+
+        assert!(const_interval!(-1.0, 0.0).y0().is_empty());
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 1.0)).y1().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).yn(5).decoration(),
+            Decoration::Com
+        );
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 1.0)).jn(5).decoration(),
+            Decoration::Com
+        );
+    }
+
+    #[test]
+    fn ai() {
+        // Ai(0) = 3^(-2/3) / Γ(2/3).
+        assert!(I::zero().ai().contains(0.3550280538878172));
+        let y = I::ENTIRE.ai();
+        assert!(y.inf() < -0.41901 && y.sup() > 0.53565);
+        assert!(y.subset(const_interval!(-0.42, 0.54)));
+        assert_eq!(const_interval!(1.0, f64::INFINITY).ai().inf(), 0.0);
+        check_samples(I::ai, const_interval!(-10.0, 2.0));
+        check_samples(I::ai, const_interval!(-70.0, -60.0));
+    }
+
+    #[test]
+    fn zeta() {
+        assert!(const_interval!(2.0, 2.0)
+            .zeta()
+            .contains(1.6449340668482264));
+        assert!(const_interval!(0.0, 0.0).zeta().contains(-0.5));
+        assert!(const_interval!(-2.0, -2.0).zeta().contains(0.0));
+        assert!(const_interval!(-4.0, -4.0).zeta().contains(0.0));
+        assert_eq!(const_interval!(2.0, f64::INFINITY).zeta().inf(), 1.0);
+        check_samples(I::zeta, const_interval!(-9.0, -1.0));
+        check_samples(I::zeta, const_interval!(1.5, 9.0));
+
+        // The pole at 1.
+        assert!(const_interval!(1.0, 1.0).zeta().is_empty());
+        assert_eq!(const_interval!(0.0, 2.0).zeta(), I::ENTIRE);
+        assert_eq!(const_interval!(1.0, 2.0).zeta().sup(), f64::INFINITY);
+        assert_eq!(const_interval!(0.0, 1.0).zeta().inf(), f64::NEG_INFINITY);
+        assert_eq!(
+            DI::new(const_interval!(1.0, 2.0)).zeta().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(-10.0, 0.0)).zeta().decoration(),
+            Decoration::Com
+        );
+    }
+
+    #[test]
+    fn li2() {
+        // Li₂(1) = π^2/6 and the maximum is Li₂(2) = π^2/4.
+        assert!(const_interval!(1.0, 1.0).li2().contains(1.6449340668482264));
+        let y = const_interval!(0.0, 10.0).li2();
+        assert!(y.contains(2.4674011002723395));
+        assert!(y.sup() < 2.4674011002724);
+        assert_eq!(I::zero().li2(), I::zero());
+        check_samples(I::li2, const_interval!(-10.0, 10.0));
+    }
+
+    #[test]
+    fn lambert_w() {
+        assert_eq!(I::zero().lambert_w(), I::zero());
+        // W(e) = 1.
+        let y = I::E.lambert_w();
+        assert!(y.contains(1.0) && y.wid() < 1e-15);
+        let y = const_interval!(1e300, 1e300).lambert_w();
+        assert!(y.wid() <= 2.0 * f64::EPSILON * y.inf());
+        check_samples(I::lambert_w, const_interval!(-0.25, 20.0));
+
+        // The branch point -1/e.
+        assert_eq!(
+            const_interval!(-1.0, 0.0).lambert_w(),
+            const_interval!(-1.0, 0.0)
+        );
+        assert!(const_interval!(-1.0, -0.5).lambert_w().is_empty());
+        let x = I::E.recip();
+        let y = (-x).lambert_w();
+        assert!(y.contains(-1.0) && y.sup() < -0.99999);
+        assert_eq!(
+            DI::new(const_interval!(-1.0, 0.0)).lambert_w().decoration(),
+            Decoration::Trv
+        );
+        assert_eq!(
+            DI::new(const_interval!(-0.3, 0.0)).lambert_w().decoration(),
+            Decoration::Com
+        );
+    }
+}
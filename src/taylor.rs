@@ -0,0 +1,1052 @@
+use crate::{forward_ref::*, interval::*, vector::point, IntervalVector};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::{
+    iter::once,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// The number of iterations of the domain reduction in the linear dominated bounder.
+const LDB_ITER: usize = 4;
+
+/// A Taylor model, i.e., a multivariate polynomial with [`f64`] coefficients plus an interval
+/// remainder that together enclose a function over a domain box.
+///
+/// A Taylor model $(p, R)$ over a domain $D ⊆ \R^n$ encloses a function $f$ if
+/// $f(x) ∈ p(x - x_0) + R$ for all $x ∈ D$, where $x_0$ is the midpoint of $D$.
+/// Since the polynomial part keeps track of the dependency between subexpressions,
+/// evaluating a long expression with Taylor models usually gives much tighter enclosures
+/// than evaluating it with intervals.
+///
+/// The arithmetic operations and the elementary functions return Taylor models of the same
+/// domain and order that enclose the results. The terms of degree higher than the order, as
+/// well as the rounding errors in the coefficients, are bounded and moved into the remainder.
+/// If a function is evaluated where it is not analytic, the resulting Taylor model has
+/// a zero polynomial and the remainder $\[-∞, +∞\]$.
+///
+/// Binary operations on Taylor models panic if the operands have different domains or orders.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, IntervalVector as V, TaylorModel as T};
+/// let d = V::new(vec![c!(0.0, 1.0)]);
+/// let x = T::variable(0, &d, 4);
+/// // x (1 - x)
+/// let y = &x * &(c!(1.0, 1.0) - &x);
+/// assert_eq!(y.bound(), c!(0.0, 0.25));
+/// // Compare with the interval evaluation.
+/// let x = c!(0.0, 1.0);
+/// assert_eq!(x * (c!(1.0, 1.0) - x), c!(0.0, 1.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaylorModel {
+    order: u32,
+    domain: IntervalVector,
+    center: Vec<f64>,
+    // The domain translated by -center, over which the polynomial is evaluated.
+    shifted: Vec<Interval>,
+    // The coefficients indexed by the exponents of the variables.
+    coeffs: BTreeMap<Vec<u32>, f64>,
+    rem: Interval,
+}
+
+impl TaylorModel {
+    /// Creates a Taylor model of the constant function $x ↦ 𝚌$ over `domain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain` has an empty or unbounded element.
+    pub fn constant(c: Interval, domain: &IntervalVector, order: u32) -> Self {
+        let tm = Self::zero(domain, order);
+        let zero = tm.zero_exps();
+        tm.with_terms(once((zero, c)).collect(), Interval::zero())
+    }
+
+    /// Creates a Taylor model of the function $x ↦ x_i$ over `domain`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= domain.len()` or `domain` has an empty or unbounded element.
+    pub fn variable(i: usize, domain: &IntervalVector, order: u32) -> Self {
+        assert!(i < domain.len(), "the index is out of bounds");
+        let tm = Self::zero(domain, order);
+        let mut e = tm.zero_exps();
+        let c = point(tm.center[i]);
+        let mut terms = once((e.clone(), c)).collect::<BTreeMap<_, _>>();
+        e[i] = 1;
+        terms.insert(e, point(1.0));
+        tm.with_terms(terms, Interval::zero())
+    }
+
+    /// Creates the Taylor models of the functions $x ↦ x_i$ over `domain` for all $i$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain` has an empty or unbounded element.
+    pub fn variables(domain: &IntervalVector, order: u32) -> Vec<Self> {
+        (0..domain.len())
+            .map(|i| Self::variable(i, domain, order))
+            .collect()
+    }
+
+    /// Returns an enclosure of the range of the function over the domain.
+    ///
+    /// The range of the polynomial part is bounded by the linear dominated bounder (LDB),
+    /// which is exact for polynomials of degree one and tight when the linear terms dominate.
+    pub fn bound(&self) -> Interval {
+        if self.rem.is_empty() {
+            return Interval::EMPTY;
+        }
+
+        let n = self.shifted.len();
+        let zero = self.zero_exps();
+        let c0 = self.coeffs.get(&zero).copied().unwrap_or(0.0);
+        let mut lin = vec![0.0; n];
+        let mut higher = vec![];
+        for (e, &c) in &self.coeffs {
+            match degree(e) {
+                0 => (),
+                1 => lin[e.iter().position(|&k| k == 1).unwrap()] = c,
+                _ => higher.push((e, c)),
+            }
+        }
+        let bound_higher = |d: &[Interval]| {
+            higher.iter().fold(Interval::zero(), |s, &(e, c)| {
+                point(c).mul_add(mono_range(e, d), s)
+            })
+        };
+        let bound_all = |d: &[Interval]| {
+            lin.iter()
+                .zip(d)
+                .fold(point(c0) + bound_higher(d), |s, (&a, &d)| {
+                    point(a).mul_add(d, s)
+                })
+        };
+
+        // Shrinks the domain to a box that contains a minimizer (or a maximizer if `upper`)
+        // of the polynomial. If the variation of the higher-order terms over the box is `w`,
+        // a minimizer can be found where `a_i (x_i - inf d_i) ≤ w` holds for `a_i > 0`.
+        let reduce = |upper: bool| {
+            let mut d = self.shifted.clone();
+            for _ in 0..LDB_ITER {
+                let w = bound_higher(&d).wid();
+                if !w.is_finite() {
+                    break;
+                }
+                for (d, &a) in d.iter_mut().zip(&lin) {
+                    if a == 0.0 {
+                        continue;
+                    }
+                    let s = (point(w) / point(a.abs())).sup();
+                    *d = if (a > 0.0) != upper {
+                        let b = (point(d.inf()) + point(s)).sup();
+                        Interval::with_infsup_raw(d.inf(), b).intersection(*d)
+                    } else {
+                        let a = (point(d.sup()) - point(s)).inf();
+                        Interval::with_infsup_raw(a, d.sup()).intersection(*d)
+                    };
+                }
+            }
+            bound_all(&d)
+        };
+
+        let lo = reduce(false).inf();
+        let hi = reduce(true).sup();
+        Interval::with_infsup_raw(lo, hi) + self.rem
+    }
+
+    /// Returns the center $x_0$ of the domain, around which the polynomial is expanded.
+    pub fn center(&self) -> &[f64] {
+        &self.center
+    }
+
+    /// Returns an iterator over the nonzero coefficients of the polynomial $p$, along with the
+    /// exponents of the variables $x_i - x_{0,i}$ of the corresponding terms.
+    pub fn coefficients(&self) -> impl Iterator<Item = (&[u32], f64)> + '_ {
+        self.coeffs.iter().map(|(e, &c)| (e.as_slice(), c))
+    }
+
+    /// Returns the domain.
+    pub fn domain(&self) -> &IntervalVector {
+        &self.domain
+    }
+
+    /// Returns an enclosure of the range of the function over the box `x`.
+    ///
+    /// $\[-∞, +∞\]$ is returned if `x` is not a subset of the domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `x` does not match the dimension of the domain.
+    pub fn eval(&self, x: &IntervalVector) -> Interval {
+        if !x.subset(&self.domain) {
+            return Interval::ENTIRE;
+        }
+        if x.is_empty_set() {
+            return Interval::EMPTY;
+        }
+
+        let t = x
+            .iter()
+            .zip(&self.center)
+            .map(|(&x, &c)| x - point(c))
+            .collect::<Vec<_>>();
+        self.coeffs.iter().fold(self.rem, |s, (e, &c)| {
+            point(c).mul_add(mono_range(e, &t), s)
+        })
+    }
+
+    /// Returns the order of the Taylor model, i.e., the maximum degree of the polynomial.
+    pub fn order(&self) -> u32 {
+        self.order
+    }
+
+    /// Returns the remainder.
+    pub fn remainder(&self) -> Interval {
+        self.rem
+    }
+
+    /// Returns the reciprocal of `self`.
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        self.compose(|x, len| {
+            let one = once(point(1.0))
+                .chain((1..len).map(|_| Interval::zero()))
+                .collect::<Vec<_>>();
+            ser_div(&one, &ident(x, len))
+        })
+    }
+
+    /// Returns the square of `self`.
+    #[must_use]
+    pub fn sqr(&self) -> Self {
+        self * self
+    }
+
+    /// Returns the principal square root of `self`.
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        self.compose(|x, len| ser_sqrt(&ident(x, len)))
+    }
+
+    fn check_compat(&self, rhs: &Self) {
+        assert!(
+            self.order == rhs.order && self.domain == rhs.domain,
+            "the Taylor models must have the same domain and order"
+        );
+    }
+
+    // Returns the Taylor model of `f(self)`, where `series(x, len)` returns the enclosures of
+    // the first `len` Taylor coefficients of `f` at every point in the interval `x`.
+    fn compose(&self, series: impl Fn(Interval, usize) -> Vec<Interval>) -> Self {
+        if self.rem.is_empty() {
+            return self.with_remainder(Interval::EMPTY);
+        }
+
+        // f(c + m) = Σ_{k=0}^n f⁽ᵏ⁾(c)/k! m^k + f⁽ⁿ⁺¹⁾(ξ)/(n+1)! m^(n+1),
+        // where m ∈ j, and ξ lies between c and c + m.
+        let n = self.order as usize;
+        let zero = self.zero_exps();
+        let c = self.coeffs.get(&zero).copied().unwrap_or(0.0);
+        let mut m = self.clone();
+        m.coeffs.remove(&zero);
+        let j = m.bound().convex_hull(Interval::zero());
+        let s = series(point(c), n + 1);
+        let r = series(point(c) + j, n + 2)[n + 1] * pown(j, n as u32 + 1);
+        if !(s.iter().all(|x| x.is_common_interval()) && r.is_common_interval()) {
+            return self.with_remainder(Interval::ENTIRE);
+        }
+
+        let mut acc = self.with_terms(once((zero, s[n])).collect(), Interval::zero());
+        for &s in s[..n].iter().rev() {
+            acc = &(&acc * &m) + s;
+        }
+        acc.rem += r;
+        acc
+    }
+
+    // Returns a Taylor model with the same domain and order as `self` with the given terms,
+    // which may be of a higher degree than the order.
+    fn with_terms(&self, terms: BTreeMap<Vec<u32>, Interval>, mut rem: Interval) -> Self {
+        let mut coeffs = BTreeMap::new();
+        for (e, c) in terms {
+            if c.is_empty() {
+                rem = Interval::EMPTY;
+                continue;
+            }
+            let r = mono_range(&e, &self.shifted);
+            if degree(&e) > self.order {
+                rem += c * r;
+                continue;
+            }
+            let m = c.mid();
+            rem += (c - point(m)) * r;
+            if m != 0.0 {
+                coeffs.insert(e, m);
+            }
+        }
+        Self {
+            order: self.order,
+            domain: self.domain.clone(),
+            center: self.center.clone(),
+            shifted: self.shifted.clone(),
+            coeffs,
+            rem,
+        }
+    }
+
+    // Returns an enclosure of the range of the polynomial part by naive interval evaluation.
+    fn poly_bound(&self) -> Interval {
+        self.coeffs.iter().fold(Interval::zero(), |s, (e, &c)| {
+            point(c).mul_add(mono_range(e, &self.shifted), s)
+        })
+    }
+
+    fn terms(&self) -> BTreeMap<Vec<u32>, Interval> {
+        self.coeffs
+            .iter()
+            .map(|(e, &c)| (e.clone(), point(c)))
+            .collect()
+    }
+
+    // Returns a Taylor model with a zero polynomial and the given remainder.
+    fn with_remainder(&self, rem: Interval) -> Self {
+        self.with_terms(BTreeMap::new(), rem)
+    }
+
+    fn zero(domain: &IntervalVector, order: u32) -> Self {
+        assert!(
+            domain.iter().all(|x| x.is_common_interval()),
+            "the domain must be a nonempty and bounded box"
+        );
+        let center = domain.mid();
+        let shifted = domain
+            .iter()
+            .zip(&center)
+            .map(|(&x, &c)| x - point(c))
+            .collect();
+        Self {
+            order,
+            domain: domain.clone(),
+            center,
+            shifted,
+            coeffs: BTreeMap::new(),
+            rem: Interval::zero(),
+        }
+    }
+
+    fn zero_exps(&self) -> Vec<u32> {
+        vec![0; self.shifted.len()]
+    }
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+impl TaylorModel {
+    /// Returns the inverse cosine of `self`.
+    #[must_use]
+    pub fn acos(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let one = point(1.0);
+            let w = ser_sqrt(&ser_add_const(one, &ser_neg(&ser_mul(&u, &u))));
+            ser_integrate(&ser_neg(&ser_div(&ser_deriv(&u), &w[..len - 1])), x.acos())
+        })
+    }
+
+    /// Returns the inverse hyperbolic cosine of `self`.
+    #[must_use]
+    pub fn acosh(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let w = ser_sqrt(&ser_add_const(point(-1.0), &ser_mul(&u, &u)));
+            ser_integrate(&ser_div(&ser_deriv(&u), &w[..len - 1]), x.acosh())
+        })
+    }
+
+    /// Returns the inverse sine of `self`.
+    #[must_use]
+    pub fn asin(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let w = ser_sqrt(&ser_add_const(point(1.0), &ser_neg(&ser_mul(&u, &u))));
+            ser_integrate(&ser_div(&ser_deriv(&u), &w[..len - 1]), x.asin())
+        })
+    }
+
+    /// Returns the inverse hyperbolic sine of `self`.
+    #[must_use]
+    pub fn asinh(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let w = ser_sqrt(&ser_add_const(point(1.0), &ser_mul(&u, &u)));
+            ser_integrate(&ser_div(&ser_deriv(&u), &w[..len - 1]), x.asinh())
+        })
+    }
+
+    /// Returns the inverse tangent of `self`.
+    #[must_use]
+    pub fn atan(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let w = ser_add_const(point(1.0), &ser_mul(&u, &u));
+            ser_integrate(&ser_div(&ser_deriv(&u), &w[..len - 1]), x.atan())
+        })
+    }
+
+    /// Returns the inverse hyperbolic tangent of `self`.
+    #[must_use]
+    pub fn atanh(&self) -> Self {
+        self.compose(|x, len| {
+            let u = ident(x, len);
+            let w = ser_add_const(point(1.0), &ser_neg(&ser_mul(&u, &u)));
+            ser_integrate(&ser_div(&ser_deriv(&u), &w[..len - 1]), x.atanh())
+        })
+    }
+
+    /// Returns the cosine of `self`.
+    #[must_use]
+    pub fn cos(&self) -> Self {
+        self.compose(|x, len| ser_sin_cos(&ident(x, len)).1)
+    }
+
+    /// Returns the hyperbolic cosine of `self`.
+    #[must_use]
+    pub fn cosh(&self) -> Self {
+        self.compose(|x, len| ser_sinh_cosh(&ident(x, len)).1)
+    }
+
+    /// Returns `self` raised to the power of $\e$.
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        self.compose(|x, len| ser_exp(&ident(x, len)))
+    }
+
+    /// Returns `self` raised to the power of 10.
+    #[must_use]
+    pub fn exp10(&self) -> Self {
+        (self * Interval::LN_10).exp()
+    }
+
+    /// Returns `self` raised to the power of 2.
+    #[must_use]
+    pub fn exp2(&self) -> Self {
+        (self * Interval::LN_2).exp()
+    }
+
+    /// Returns the natural logarithm of `self`.
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        self.compose(|x, len| ser_ln(&ident(x, len)))
+    }
+
+    /// Returns the base-10 logarithm of `self`.
+    #[must_use]
+    pub fn log10(&self) -> Self {
+        &self.ln() / Interval::LN_10
+    }
+
+    /// Returns the base-2 logarithm of `self`.
+    #[must_use]
+    pub fn log2(&self) -> Self {
+        &self.ln() / Interval::LN_2
+    }
+
+    /// Returns `self` raised to the power of `rhs`, computed as $\exp(\rhs \ln \self)$.
+    #[must_use]
+    pub fn pow(&self, rhs: &Self) -> Self {
+        (rhs * &self.ln()).exp()
+    }
+
+    /// Returns `self` raised to the power of `rhs`.
+    #[must_use]
+    pub fn powi(&self, rhs: i32) -> Self {
+        let mut y = Self::constant(point(1.0), &self.domain, self.order);
+        let mut x = self.clone();
+        let mut n = rhs.unsigned_abs();
+        while n > 0 {
+            if n % 2 == 1 {
+                y = &y * &x;
+            }
+            n /= 2;
+            if n > 0 {
+                x = x.sqr();
+            }
+        }
+        if rhs < 0 {
+            y.recip()
+        } else {
+            y
+        }
+    }
+
+    /// Returns the sine of `self`.
+    #[must_use]
+    pub fn sin(&self) -> Self {
+        self.compose(|x, len| ser_sin_cos(&ident(x, len)).0)
+    }
+
+    /// Returns the hyperbolic sine of `self`.
+    #[must_use]
+    pub fn sinh(&self) -> Self {
+        self.compose(|x, len| ser_sinh_cosh(&ident(x, len)).0)
+    }
+
+    /// Returns the tangent of `self`.
+    #[must_use]
+    pub fn tan(&self) -> Self {
+        self.compose(|x, len| {
+            let (s, c) = ser_sin_cos(&ident(x, len));
+            ser_div(&s, &c)
+        })
+    }
+
+    /// Returns the hyperbolic tangent of `self`.
+    #[must_use]
+    pub fn tanh(&self) -> Self {
+        self.compose(|x, len| {
+            let (s, c) = ser_sinh_cosh(&ident(x, len));
+            ser_div(&s, &c)
+        })
+    }
+}
+
+fn degree(e: &[u32]) -> u32 {
+    e.iter().sum()
+}
+
+// Returns an enclosure of the range of the monomial with the exponents `e` over the box `d`.
+fn mono_range(e: &[u32], d: &[Interval]) -> Interval {
+    e.iter()
+        .zip(d)
+        .filter(|(&k, _)| k > 0)
+        .fold(point(1.0), |s, (&k, &d)| s * pown(d, k))
+}
+
+fn pown(x: Interval, n: u32) -> Interval {
+    match n {
+        0 => point(1.0),
+        1 => x,
+        _ if n % 2 == 0 => pown(x, n / 2).sqr(),
+        _ => pown(x, n - 1) * x,
+    }
+}
+
+// Operations on truncated Taylor series whose coefficients are intervals.
+
+// Returns the series of t ↦ x + t.
+fn ident(x: Interval, len: usize) -> Vec<Interval> {
+    once(x)
+        .chain(once(point(1.0)))
+        .chain((2..).map(|_| Interval::zero()))
+        .take(len)
+        .collect()
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_mul(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    (0..a.len().min(b.len()))
+        .map(|k| (0..=k).fold(Interval::zero(), |s, j| a[j].mul_add(b[k - j], s)))
+        .collect()
+}
+
+fn ser_div(a: &[Interval], b: &[Interval]) -> Vec<Interval> {
+    let mut c = Vec::<Interval>::with_capacity(a.len());
+    for k in 0..a.len().min(b.len()) {
+        let s = (1..=k).fold(a[k], |s, j| (-b[j]).mul_add(c[k - j], s));
+        c.push(s / b[0]);
+    }
+    c
+}
+
+fn ser_sqrt(a: &[Interval]) -> Vec<Interval> {
+    let Some(&a0) = a.first() else {
+        return vec![];
+    };
+    // v = u^r, where r = 1/2, satisfies k u₀ v_k = Σ_{j=1}^k (r j - (k - j)) u_j v_{k-j}.
+    let mut v = vec![a0.sqrt()];
+    for k in 1..a.len() {
+        let s = (1..=k).fold(Interval::zero(), |s, j| {
+            let f = point(0.5 * j as f64 - (k - j) as f64);
+            (f * a[j]).mul_add(v[k - j], s)
+        });
+        v.push(s / (point(k as f64) * a0));
+    }
+    v
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_add_const(c: Interval, a: &[Interval]) -> Vec<Interval> {
+    let mut a = a.to_vec();
+    if let Some(a0) = a.first_mut() {
+        *a0 = c + *a0;
+    }
+    a
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_deriv(a: &[Interval]) -> Vec<Interval> {
+    (1..a.len()).map(|k| point(k as f64) * a[k]).collect()
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_exp(a: &[Interval]) -> Vec<Interval> {
+    let Some(&a0) = a.first() else {
+        return vec![];
+    };
+    let mut v = vec![a0.exp()];
+    for k in 1..a.len() {
+        let s = (1..=k).fold(Interval::zero(), |s, j| {
+            (point(j as f64) * a[j]).mul_add(v[k - j], s)
+        });
+        v.push(s / point(k as f64));
+    }
+    v
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_integrate(d: &[Interval], v0: Interval) -> Vec<Interval> {
+    once(v0)
+        .chain(
+            d.iter()
+                .enumerate()
+                .map(|(k, &d)| d / point((k + 1) as f64)),
+        )
+        .collect()
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_ln(a: &[Interval]) -> Vec<Interval> {
+    let Some(&a0) = a.first() else {
+        return vec![];
+    };
+    let mut v = vec![a0.ln()];
+    for k in 1..a.len() {
+        let s = (1..k).fold(Interval::zero(), |s, j| {
+            (point((k - j) as f64) * a[j]).mul_add(v[k - j], s)
+        });
+        v.push((a[k] - s / point(k as f64)) / a0);
+    }
+    v
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_neg(a: &[Interval]) -> Vec<Interval> {
+    a.iter().map(|&x| -x).collect()
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_sin_cos(a: &[Interval]) -> (Vec<Interval>, Vec<Interval>) {
+    let Some(&a0) = a.first() else {
+        return (vec![], vec![]);
+    };
+    let mut s = vec![a0.sin()];
+    let mut c = vec![a0.cos()];
+    for k in 1..a.len() {
+        let (ds, dc) = (1..=k).fold((Interval::zero(), Interval::zero()), |(ds, dc), j| {
+            let ja = point(j as f64) * a[j];
+            (ja.mul_add(c[k - j], ds), ja.mul_add(s[k - j], dc))
+        });
+        let k = point(k as f64);
+        s.push(ds / k);
+        c.push(-dc / k);
+    }
+    (s, c)
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn ser_sinh_cosh(a: &[Interval]) -> (Vec<Interval>, Vec<Interval>) {
+    let Some(&a0) = a.first() else {
+        return (vec![], vec![]);
+    };
+    let mut s = vec![a0.sinh()];
+    let mut c = vec![a0.cosh()];
+    for k in 1..a.len() {
+        let (ds, dc) = (1..=k).fold((Interval::zero(), Interval::zero()), |(ds, dc), j| {
+            let ja = point(j as f64) * a[j];
+            (ja.mul_add(c[k - j], ds), ja.mul_add(s[k - j], dc))
+        });
+        let k = point(k as f64);
+        s.push(ds / k);
+        c.push(dc / k);
+    }
+    (s, c)
+}
+
+impl Neg for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn neg(self) -> TaylorModel {
+        let mut tm = self.clone();
+        for c in tm.coeffs.values_mut() {
+            *c = -*c;
+        }
+        tm.rem = -tm.rem;
+        tm
+    }
+}
+
+impl Neg for TaylorModel {
+    type Output = TaylorModel;
+
+    fn neg(self) -> TaylorModel {
+        -&self
+    }
+}
+
+impl Add for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn add(self, rhs: Self) -> TaylorModel {
+        self.check_compat(rhs);
+        let mut terms = self.terms();
+        for (e, &c) in &rhs.coeffs {
+            let t = terms.entry(e.clone()).or_insert_with(Interval::zero);
+            *t += point(c);
+        }
+        self.with_terms(terms, self.rem + rhs.rem)
+    }
+}
+
+forward_owned_binop!(impl Add, add for TaylorModel, TaylorModel, TaylorModel);
+
+impl Sub for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn sub(self, rhs: Self) -> TaylorModel {
+        self + &-rhs
+    }
+}
+
+forward_owned_binop!(impl Sub, sub for TaylorModel, TaylorModel, TaylorModel);
+
+impl Mul for &TaylorModel {
+    type Output = TaylorModel;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> TaylorModel {
+        self.check_compat(rhs);
+        let mut terms = BTreeMap::<Vec<u32>, Interval>::new();
+        for (e1, &a) in &self.coeffs {
+            for (e2, &b) in &rhs.coeffs {
+                let e = e1.iter().zip(e2).map(|(i, j)| i + j).collect();
+                let t = terms.entry(e).or_insert_with(Interval::zero);
+                *t = point(a).mul_add(point(b), *t);
+            }
+        }
+        // (p₁ + r₁)(p₂ + r₂) = p₁ p₂ + p₁ r₂ + r₁ p₂ + r₁ r₂
+        let (p1, r1) = (self.poly_bound(), self.rem);
+        let (p2, r2) = (rhs.poly_bound(), rhs.rem);
+        let rem = p1.mul_add(r2, r1.mul_add(p2, r1 * r2));
+        self.with_terms(terms, rem)
+    }
+}
+
+forward_owned_binop!(impl Mul, mul for TaylorModel, TaylorModel, TaylorModel);
+
+impl Div for &TaylorModel {
+    type Output = TaylorModel;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> TaylorModel {
+        self * &rhs.recip()
+    }
+}
+
+forward_owned_binop!(impl Div, div for TaylorModel, TaylorModel, TaylorModel);
+
+impl Add<Interval> for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn add(self, rhs: Interval) -> TaylorModel {
+        let mut terms = self.terms();
+        let t = terms.entry(self.zero_exps()).or_insert_with(Interval::zero);
+        *t += rhs;
+        self.with_terms(terms, self.rem)
+    }
+}
+
+impl Add<Interval> for TaylorModel {
+    type Output = TaylorModel;
+
+    fn add(self, rhs: Interval) -> TaylorModel {
+        &self + rhs
+    }
+}
+
+impl Add<&TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn add(self, rhs: &TaylorModel) -> TaylorModel {
+        rhs + self
+    }
+}
+
+impl Add<TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn add(self, rhs: TaylorModel) -> TaylorModel {
+        &rhs + self
+    }
+}
+
+impl Sub<Interval> for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn sub(self, rhs: Interval) -> TaylorModel {
+        self + -rhs
+    }
+}
+
+impl Sub<Interval> for TaylorModel {
+    type Output = TaylorModel;
+
+    fn sub(self, rhs: Interval) -> TaylorModel {
+        &self + -rhs
+    }
+}
+
+impl Sub<&TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn sub(self, rhs: &TaylorModel) -> TaylorModel {
+        -rhs + self
+    }
+}
+
+impl Sub<TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn sub(self, rhs: TaylorModel) -> TaylorModel {
+        -&rhs + self
+    }
+}
+
+impl Mul<Interval> for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn mul(self, rhs: Interval) -> TaylorModel {
+        let terms = self
+            .coeffs
+            .iter()
+            .map(|(e, &c)| (e.clone(), point(c) * rhs))
+            .collect();
+        self.with_terms(terms, self.rem * rhs)
+    }
+}
+
+impl Mul<Interval> for TaylorModel {
+    type Output = TaylorModel;
+
+    fn mul(self, rhs: Interval) -> TaylorModel {
+        &self * rhs
+    }
+}
+
+impl Mul<&TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn mul(self, rhs: &TaylorModel) -> TaylorModel {
+        rhs * self
+    }
+}
+
+impl Mul<TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    fn mul(self, rhs: TaylorModel) -> TaylorModel {
+        &rhs * self
+    }
+}
+
+impl Div<Interval> for &TaylorModel {
+    type Output = TaylorModel;
+
+    fn div(self, rhs: Interval) -> TaylorModel {
+        let terms = self
+            .coeffs
+            .iter()
+            .map(|(e, &c)| (e.clone(), point(c) / rhs))
+            .collect();
+        self.with_terms(terms, self.rem / rhs)
+    }
+}
+
+impl Div<Interval> for TaylorModel {
+    type Output = TaylorModel;
+
+    fn div(self, rhs: Interval) -> TaylorModel {
+        &self / rhs
+    }
+}
+
+impl Div<&TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: &TaylorModel) -> TaylorModel {
+        &rhs.recip() * self
+    }
+}
+
+impl Div<TaylorModel> for Interval {
+    type Output = TaylorModel;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: TaylorModel) -> TaylorModel {
+        &rhs.recip() * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+    use IntervalVector as V;
+    use TaylorModel as T;
+
+    #[test]
+    fn arith() {
+        let d = V::new(vec![const_interval!(-1.0, 1.0), const_interval!(2.0, 3.0)]);
+        let [x, y]: [T; 2] = T::variables(&d, 3).try_into().unwrap();
+        assert_eq!(x.bound(), const_interval!(-1.0, 1.0));
+        assert_eq!(y.bound(), const_interval!(2.0, 3.0));
+        assert_eq!(x.center(), &[0.0, 2.5]);
+
+        let z = &x - &x;
+        assert_eq!(z.bound(), const_interval!(0.0, 0.0));
+        let z = &(&x * &y) - &(&y * &x);
+        assert_eq!(z.bound(), const_interval!(0.0, 0.0));
+
+        // (x + y)² - x² - 2xy - y² = 0
+        let s = &x + &y;
+        let z = &(&(&s.sqr() - &x.sqr()) - &(const_interval!(2.0, 2.0) * &(&x * &y))) - &y.sqr();
+        assert!(z.bound().subset(const_interval!(-1e-14, 1e-14)));
+
+        // Terms of degree 4 are moved into the remainder.
+        let z = x.sqr().sqr();
+        assert_eq!(z.coefficients().count(), 0);
+        assert_eq!(z.bound(), const_interval!(0.0, 1.0));
+
+        let z = &y / &y;
+        assert!(z.bound().contains(1.0));
+        assert!(z.bound().wid() < 0.1);
+
+        let z = x.clone() + const_interval!(1.0, 2.0);
+        assert_eq!(z.bound(), const_interval!(0.0, 3.0));
+        assert_eq!(
+            z.eval(&V::new(vec![
+                const_interval!(0.0, 0.0),
+                const_interval!(2.0, 2.0)
+            ])),
+            const_interval!(1.0, 2.0)
+        );
+        assert_eq!(
+            z.eval(&V::new(vec![
+                const_interval!(0.0, 2.0),
+                const_interval!(2.0, 2.0)
+            ])),
+            I::ENTIRE
+        );
+    }
+
+    #[test]
+    fn bound() {
+        let d = V::new(vec![const_interval!(0.0, 1.0)]);
+        let x = T::variable(0, &d, 2);
+        // x (1 - x) = 1/4 - (x - 1/2)²
+        let y = &x * &(const_interval!(1.0, 1.0) - &x);
+        assert_eq!(y.bound(), const_interval!(0.0, 0.25));
+
+        // 10 (x - 1/2) + (x - 1/2)²: the linear term dominates.
+        let t = &x - const_interval!(0.5, 0.5);
+        let y = &(const_interval!(10.0, 10.0) * &t) + &t.sqr();
+        let b = y.bound();
+        assert!(b.inf() <= -4.75 && b.inf() > -4.7501 && b.sup() == 5.25);
+
+        let y = T::constant(I::EMPTY, &d, 2);
+        assert!(y.bound().is_empty());
+        assert!((&y + &x).bound().is_empty());
+    }
+
+    #[test]
+    fn sqrt_recip() {
+        let d = V::new(vec![const_interval!(1.0, 2.0)]);
+        let x = T::variable(0, &d, 10);
+        let y = &x.sqrt().sqr() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-4, 1e-4)));
+        let y = &(&x * &x.recip()) - const_interval!(1.0, 1.0);
+        assert!(y.bound().subset(const_interval!(-1e-2, 1e-2)));
+
+        let d = V::new(vec![const_interval!(-1.0, 1.0)]);
+        let x = T::variable(0, &d, 3);
+        assert_eq!(x.recip().remainder(), I::ENTIRE);
+        assert_eq!(x.recip().bound(), I::ENTIRE);
+        assert_eq!(x.sqrt().bound(), I::ENTIRE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn domain_mismatch() {
+        let x = T::variable(0, &V::new(vec![const_interval!(0.0, 1.0)]), 2);
+        let y = T::variable(0, &V::new(vec![const_interval!(0.0, 2.0)]), 2);
+        let _ = x + y;
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn elementary() {
+        let d = V::new(vec![const_interval!(-0.25, 0.25)]);
+        let x = T::variable(0, &d, 10);
+
+        let y = &(&x.sin().sqr() + &x.cos().sqr()) - const_interval!(1.0, 1.0);
+        assert!(y.bound().subset(const_interval!(-1e-8, 1e-8)));
+
+        let y = &x.exp() * &(-&x).exp();
+        assert!(y.bound().subset(const_interval!(0.99999999, 1.00000001)));
+
+        let y = &x.exp().ln() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-5, 1e-5)));
+
+        let y = &x.atan().tan() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-6, 1e-6)));
+
+        let y = &x.asin().sin() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-4, 1e-4)));
+
+        let y = &x.acos().cos() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-4, 1e-4)));
+
+        let y = &x.asinh().sinh() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-6, 1e-6)));
+
+        let y = &x.atanh().tanh() - &x;
+        assert!(y.bound().subset(const_interval!(-1e-4, 1e-4)));
+
+        let y = &(&x.cosh().sqr() - &x.sinh().sqr()) - const_interval!(1.0, 1.0);
+        assert!(y.bound().subset(const_interval!(-1e-8, 1e-8)));
+
+        let y = &(&x.exp2() * &x.exp10()) - &(&x * (I::LN_2 + I::LN_10)).exp();
+        assert!(y.bound().subset(const_interval!(-1e-6, 1e-6)));
+
+        let y = &x.powi(3) - &(&x * &x.sqr());
+        assert!(y.bound().subset(const_interval!(-1e-14, 1e-14)));
+
+        let z = T::variable(0, &V::new(vec![const_interval!(2.0, 2.5)]), 10);
+        let y = &z.acosh().cosh() - &z;
+        assert!(y.bound().subset(const_interval!(-1e-4, 1e-4)));
+        let y = &(&z.log2() + &z.log10()) - &(&z.ln() * (I::LN_2.recip() + I::LN_10.recip()));
+        assert!(y.bound().subset(const_interval!(-1e-8, 1e-8)));
+        let y = &z.pow(&z) - &(&z * &z.ln()).exp();
+        assert!(y.bound().subset(const_interval!(-1e-6, 1e-6)));
+        let y = &z.powi(-2) - &z.sqr().recip();
+        assert!(y.bound().subset(const_interval!(-1e-6, 1e-6)));
+
+        // Enclosure of the range.
+        let y = x.exp();
+        assert!(const_interval!(0.7788007830714049, 1.2840254166877414).subset(y.bound()));
+        assert!(y.bound().wid() < 0.506);
+
+        assert_eq!(x.ln().bound(), I::ENTIRE);
+        assert_eq!((const_interval!(8.0, 8.0) * &x).asin().bound(), I::ENTIRE);
+    }
+}
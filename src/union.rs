@@ -0,0 +1,646 @@
+use crate::{const_interval, interval::*};
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A set of real numbers represented as a union of at most `N` disjoint intervals,
+/// also known as a multi-interval.
+///
+/// The intervals are sorted in ascending order and are separated by gaps. When an operation would
+/// produce more than `N` intervals, the adjacent intervals separated by the narrowest gaps are
+/// merged, so that the result is still an enclosure of the exact one.
+///
+/// Unlike the operations on [`Interval`], division by an interval that contains zero and
+/// functions evaluated over a pole, such as [`IntervalUnion::tan`], keep the gaps in the results.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, IntervalUnion as U};
+/// let x: U = U::from(c!(1.0, 2.0)) / U::from(c!(-1.0, 1.0));
+/// assert_eq!(
+///     x.intervals(),
+///     [c!(f64::NEG_INFINITY, -1.0), c!(1.0, f64::INFINITY)]
+/// );
+/// assert_eq!(x.complement().intervals(), [c!(-1.0, 1.0)]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IntervalUnion<const N: usize = 4> {
+    // The elements after the first `len` ones are always empty.
+    xs: [Interval; N],
+    len: usize,
+}
+
+macro_rules! impl_map {
+    ($($f:ident),*) => {
+        $(
+            #[doc = concat!("Applies [`Interval::", stringify!($f), "`] to each interval of `self`.")]
+            #[must_use]
+            pub fn $f(self) -> Self {
+                self.map(Interval::$f)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_zip_map {
+    ($($f:ident),*) => {
+        $(
+            #[doc = concat!("Applies [`Interval::", stringify!($f), "`] to each pair of the intervals of `self` and `rhs`.")]
+            #[must_use]
+            pub fn $f(self, rhs: Self) -> Self {
+                self.zip_map(rhs, Interval::$f)
+            }
+        )*
+    };
+}
+
+impl<const N: usize> IntervalUnion<N> {
+    /// $∅$, the empty set.
+    pub const EMPTY: Self = Self {
+        xs: [Interval::EMPTY; N],
+        len: 0,
+    };
+
+    /// Creates a union that consists of a single interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn new(x: Interval) -> Self {
+        Self::from_intervals([x])
+    }
+
+    /// Creates the union of the given intervals.
+    ///
+    /// The intervals can be in any order and can overlap. Empty intervals are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalUnion as U};
+    /// let x = U::<2>::from_intervals([c!(4.0, 5.0), c!(0.0, 1.0), c!(1.0, 2.0)]);
+    /// assert_eq!(x.intervals(), [c!(0.0, 2.0), c!(4.0, 5.0)]);
+    /// // The intervals separated by the narrowest gap are merged.
+    /// let x = U::<2>::from_intervals([c!(0.0, 1.0), c!(2.0, 3.0), c!(5.0, 6.0)]);
+    /// assert_eq!(x.intervals(), [c!(0.0, 3.0), c!(5.0, 6.0)]);
+    /// ```
+    pub fn from_intervals<I: IntoIterator<Item = Interval>>(xs: I) -> Self {
+        assert!(N > 0, "the capacity must be positive");
+        let mut xs = xs.into_iter().filter(|x| !x.is_empty()).collect::<Vec<_>>();
+        xs.sort_by(|x, y| x.inf_raw().total_cmp(&y.inf_raw()));
+
+        let mut ys: Vec<Interval> = Vec::with_capacity(xs.len());
+        for x in xs {
+            match ys.last_mut() {
+                Some(y) if x.inf_raw() <= y.sup_raw() => *y = y.convex_hull(x),
+                _ => ys.push(x),
+            }
+        }
+
+        while ys.len() > N {
+            // The gaps are nonempty, thus `gap` never returns NaN.
+            let gap = |i: usize| ys[i + 1].inf_raw() - ys[i].sup_raw();
+            let i = (0..ys.len() - 1)
+                .min_by(|&i, &j| gap(i).total_cmp(&gap(j)))
+                .unwrap();
+            ys[i] = ys[i].convex_hull(ys[i + 1]);
+            ys.remove(i + 1);
+        }
+
+        let mut u = Self::EMPTY;
+        u.xs[..ys.len()].copy_from_slice(&ys);
+        u.len = ys.len();
+        u
+    }
+
+    /// Returns `true` if `rhs` is a member of `self`.
+    pub fn contains(&self, rhs: f64) -> bool {
+        self.intervals().iter().any(|x| x.contains(rhs))
+    }
+
+    /// Returns the tightest interval that contains `self`.
+    pub fn hull(&self) -> Interval {
+        match self.intervals() {
+            [] => Interval::EMPTY,
+            [x, .., y] => x.convex_hull(*y),
+            [x] => *x,
+        }
+    }
+
+    /// Returns the intervals that constitute `self` in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.xs[..self.len]
+    }
+
+    /// Returns `true` if `self` is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of the intervals that constitute `self`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the closure of $\R ∖ \self$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, Interval as I, IntervalUnion as U};
+    /// let x = U::<2>::new(c!(1.0, 2.0));
+    /// assert_eq!(
+    ///     x.complement().intervals(),
+    ///     [c!(f64::NEG_INFINITY, 1.0), c!(2.0, f64::INFINITY)]
+    /// );
+    /// assert!(U::<2>::new(I::ENTIRE).complement().is_empty());
+    /// ```
+    #[must_use]
+    pub fn complement(self) -> Self {
+        Self::new(Interval::ENTIRE).difference(self)
+    }
+
+    /// Returns the closure of $\self ∖ \rhs$.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalUnion as U};
+    /// let x = U::<2>::new(c!(0.0, 3.0));
+    /// let y = U::<2>::new(c!(1.0, 2.0));
+    /// assert_eq!(x.difference(y).intervals(), [c!(0.0, 1.0), c!(2.0, 3.0)]);
+    /// assert!(y.difference(x).is_empty());
+    /// ```
+    #[must_use]
+    pub fn difference(self, rhs: Self) -> Self {
+        let mut xs = self.intervals().to_vec();
+        for &y in rhs.intervals() {
+            xs = xs.into_iter().flat_map(|x| x.difference(y)).collect();
+        }
+        Self::from_intervals(xs)
+    }
+
+    /// Returns $\self ∩ \rhs$.
+    #[must_use]
+    pub fn intersection(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Interval::intersection)
+    }
+
+    /// Returns $\self ∪ \rhs$.
+    #[must_use]
+    pub fn union(self, rhs: Self) -> Self {
+        Self::from_intervals(self.intervals().iter().chain(rhs.intervals()).copied())
+    }
+
+    impl_map!(abs, sqr, sqrt);
+    impl_zip_map!(max, min);
+
+    /// Returns the reciprocal of `self`.
+    ///
+    /// If `self` contains zero in its interior, the result has a gap around zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        self.map2(|x| x.mul_rev_to_pair(const_interval!(1.0, 1.0)))
+    }
+
+    // Applies `f` to each interval of `self`.
+    fn map(self, f: impl Fn(Interval) -> Interval) -> Self {
+        Self::from_intervals(self.intervals().iter().map(|&x| f(x)))
+    }
+
+    // Applies `f`, which returns a pair of intervals, to each interval of `self`.
+    fn map2(self, f: impl Fn(Interval) -> [Interval; 2]) -> Self {
+        Self::from_intervals(self.intervals().iter().flat_map(|&x| f(x)))
+    }
+
+    // Applies `f` to each pair of the intervals of `self` and `rhs`.
+    fn zip_map(self, rhs: Self, f: impl Fn(Interval, Interval) -> Interval) -> Self {
+        Self::from_intervals(
+            self.intervals()
+                .iter()
+                .flat_map(|&x| rhs.intervals().iter().map(move |&y| (x, y)))
+                .map(|(x, y)| f(x, y)),
+        )
+    }
+}
+
+// Returns the union of [f(a), +∞] and [-∞, f(b)] if `inc` is `true`, or [-∞, f(a)] and [f(b), +∞]
+// otherwise, where x = [a, b], which encloses the image of x under a function f that is
+// monotonic between consecutive poles, provided that x contains at most one pole.
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn split_at_pole(x: Interval, f: impl Fn(Interval) -> Interval, inc: bool) -> [Interval; 2] {
+    let ya = f(Interval::with_infsup_raw(x.inf_raw(), x.inf_raw()));
+    let yb = f(Interval::with_infsup_raw(x.sup_raw(), x.sup_raw()));
+    if ya.either_empty(yb) {
+        // A bound of x is a pole.
+        return [Interval::ENTIRE, Interval::EMPTY];
+    }
+
+    if inc {
+        [
+            Interval::with_infsup_raw(ya.inf_raw(), f64::INFINITY),
+            Interval::with_infsup_raw(f64::NEG_INFINITY, yb.sup_raw()),
+        ]
+    } else {
+        [
+            Interval::with_infsup_raw(f64::NEG_INFINITY, ya.sup_raw()),
+            Interval::with_infsup_raw(yb.inf_raw(), f64::INFINITY),
+        ]
+    }
+}
+
+// Returns `true` if `t` contains at most one integer.
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn at_most_one_integer(t: Interval) -> bool {
+    t.floor().sup_raw() - t.ceil().inf_raw() < 1.0
+}
+
+// Evaluates a function that has poles at integral values of `t(x)` and is monotonic between them,
+// keeping the gap in the result if `x` contains a single pole.
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn periodic_pole_pair(
+    x: Interval,
+    f: impl Fn(Interval) -> Interval,
+    t: impl Fn(Interval) -> Interval,
+    inc: bool,
+) -> [Interval; 2] {
+    let y = f(x);
+    if !y.is_entire() || !at_most_one_integer(t(x)) {
+        return [y, Interval::EMPTY];
+    }
+    split_at_pole(x, f, inc)
+}
+
+// Evaluates a function that has a pole at zero and is decreasing on both sides of it,
+// keeping the gap in the result if `x` contains zero in its interior.
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+fn zero_pole_pair(x: Interval, f: impl Fn(Interval) -> Interval) -> [Interval; 2] {
+    if x.inf_raw() < 0.0 && x.sup_raw() > 0.0 {
+        split_at_pole(x, f, false)
+    } else {
+        [f(x), Interval::EMPTY]
+    }
+}
+
+#[cfg(any(feature = "gmp", feature = "pure-rust"))]
+impl<const N: usize> IntervalUnion<N> {
+    impl_map!(
+        acos, acos_pi, acosh, acot, acsc, asec, asin, asin_pi, asinh, atan, atan_pi, atanh, cos,
+        cos_pi, cosh, exp, exp10, exp10m1, exp2, exp2m1, expm1, ln, log10, log10p1, log2, log2p1,
+        logp1, rsqrt, sech, sin, sin_pi, sinh, tanh
+    );
+    impl_zip_map!(atan2, atan2_pi, hypot, pow);
+
+    /// Applies [`Interval::acoth`] to each interval of `self`.
+    ///
+    /// If `self` contains both $\[-∞, -1)$ and $(1, +∞\]$, the result has a gap around zero.
+    #[must_use]
+    pub fn acoth(self) -> Self {
+        const NEG: Interval = const_interval!(f64::NEG_INFINITY, -1.0);
+        const POS: Interval = const_interval!(1.0, f64::INFINITY);
+        self.map2(|x| [x.intersection(NEG).acoth(), x.intersection(POS).acoth()])
+    }
+
+    /// Applies [`Interval::compound`] to each interval of `self`.
+    #[must_use]
+    pub fn compound(self, rhs: i32) -> Self {
+        self.map(|x| x.compound(rhs))
+    }
+
+    /// Applies [`Interval::cot`] to each interval of `self`.
+    ///
+    /// If an interval contains a single pole of the point function, the result has a gap there.
+    #[must_use]
+    pub fn cot(self) -> Self {
+        self.map2(|x| periodic_pole_pair(x, Interval::cot, |x| x / Interval::PI, false))
+    }
+
+    /// Applies [`Interval::coth`] to each interval of `self`.
+    ///
+    /// If an interval contains zero in its interior, the result has a gap there.
+    #[must_use]
+    pub fn coth(self) -> Self {
+        self.map2(|x| zero_pole_pair(x, Interval::coth))
+    }
+
+    /// Applies [`Interval::csc`] to each interval of `self`.
+    ///
+    /// If an interval contains a pole of the point function, the result has a gap there.
+    #[must_use]
+    pub fn csc(self) -> Self {
+        self.map2(|x| {
+            let y = x.csc();
+            if y.is_entire() {
+                // csc(x) = 1/sin(x), and 1/[c, d] with c < 0 < d does not exceed the range.
+                x.sin().mul_rev_to_pair(const_interval!(1.0, 1.0))
+            } else {
+                [y, Interval::EMPTY]
+            }
+        })
+    }
+
+    /// Applies [`Interval::csch`] to each interval of `self`.
+    ///
+    /// If an interval contains zero in its interior, the result has a gap there.
+    #[must_use]
+    pub fn csch(self) -> Self {
+        self.map2(|x| zero_pole_pair(x, Interval::csch))
+    }
+
+    /// Applies [`Interval::powi`] to each interval of `self`.
+    ///
+    /// If `rhs` is negative and odd and an interval contains zero in its interior,
+    /// the result has a gap there.
+    #[must_use]
+    pub fn powi(self, rhs: i32) -> Self {
+        if rhs < 0 && rhs % 2 != 0 {
+            self.map2(|x| zero_pole_pair(x, |x| x.powi(rhs)))
+        } else {
+            self.map(|x| x.powi(rhs))
+        }
+    }
+
+    /// Applies [`Interval::rootn`] to each interval of `self`.
+    ///
+    /// If `rhs` is negative and odd and an interval contains zero in its interior,
+    /// the result has a gap there.
+    #[must_use]
+    pub fn rootn(self, rhs: i32) -> Self {
+        if rhs < 0 && rhs % 2 != 0 {
+            self.map2(|x| zero_pole_pair(x, |x| x.rootn(rhs)))
+        } else {
+            self.map(|x| x.rootn(rhs))
+        }
+    }
+
+    /// Applies [`Interval::sec`] to each interval of `self`.
+    ///
+    /// If an interval contains a pole of the point function, the result has a gap there.
+    #[must_use]
+    pub fn sec(self) -> Self {
+        self.map2(|x| {
+            let y = x.sec();
+            if y.is_entire() {
+                // sec(x) = 1/cos(x), and 1/[c, d] with c < 0 < d does not exceed the range.
+                x.cos().mul_rev_to_pair(const_interval!(1.0, 1.0))
+            } else {
+                [y, Interval::EMPTY]
+            }
+        })
+    }
+
+    /// Applies [`Interval::tan`] to each interval of `self`.
+    ///
+    /// If an interval contains a single pole of the point function, the result has a gap there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalUnion as U};
+    /// let y = U::<2>::new(c!(1.0, 2.0)).tan();
+    /// assert_eq!(y.len(), 2);
+    /// assert!(!y.contains(0.0));
+    /// ```
+    #[must_use]
+    pub fn tan(self) -> Self {
+        self.map2(|x| {
+            periodic_pole_pair(
+                x,
+                Interval::tan,
+                |x| x / Interval::PI - const_interval!(0.5, 0.5),
+                true,
+            )
+        })
+    }
+
+    /// Applies [`Interval::tan_pi`] to each interval of `self`.
+    ///
+    /// If an interval contains a single pole of the point function, the result has a gap there.
+    #[must_use]
+    pub fn tan_pi(self) -> Self {
+        self.map2(|x| {
+            periodic_pole_pair(x, Interval::tan_pi, |x| x - const_interval!(0.5, 0.5), true)
+        })
+    }
+}
+
+impl<const N: usize> From<Interval> for IntervalUnion<N> {
+    fn from(x: Interval) -> Self {
+        Self::new(x)
+    }
+}
+
+impl<const N: usize> Neg for IntervalUnion<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|x| -x)
+    }
+}
+
+impl<const N: usize> Add for IntervalUnion<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |x, y| x + y)
+    }
+}
+
+impl<const N: usize> Sub for IntervalUnion<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |x, y| x - y)
+    }
+}
+
+impl<const N: usize> Mul for IntervalUnion<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |x, y| x * y)
+    }
+}
+
+impl<const N: usize> Div for IntervalUnion<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::from_intervals(self.intervals().iter().flat_map(|&x| {
+            rhs.intervals()
+                .iter()
+                .flat_map(move |y| y.mul_rev_to_pair(x))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+    use IntervalUnion as U;
+
+    #[test]
+    fn normalize() {
+        let x = U::<3>::from_intervals([
+            I::EMPTY,
+            const_interval!(3.0, 4.0),
+            const_interval!(0.0, 1.0),
+            const_interval!(0.5, 2.0),
+        ]);
+        assert_eq!(
+            x.intervals(),
+            [const_interval!(0.0, 2.0), const_interval!(3.0, 4.0)]
+        );
+        assert_eq!(x.hull(), const_interval!(0.0, 4.0));
+        assert!(x.contains(3.5));
+        assert!(!x.contains(2.5));
+
+        let x = U::<2>::from_intervals([
+            const_interval!(0.0, 1.0),
+            const_interval!(3.0, 4.0),
+            const_interval!(4.5, 5.0),
+        ]);
+        assert_eq!(
+            x.intervals(),
+            [const_interval!(0.0, 1.0), const_interval!(3.0, 5.0)]
+        );
+
+        assert!(U::<2>::new(I::EMPTY).is_empty());
+        assert_eq!(U::<2>::EMPTY.hull(), I::EMPTY);
+        assert_eq!(U::<2>::EMPTY, U::new(I::EMPTY));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity() {
+        let _ = U::<0>::new(I::PI);
+    }
+
+    #[test]
+    fn set_ops() {
+        let x = U::<4>::from_intervals([const_interval!(0.0, 2.0), const_interval!(4.0, 6.0)]);
+        let y = U::<4>::new(const_interval!(1.0, 5.0));
+        assert_eq!(
+            x.intersection(y).intervals(),
+            [const_interval!(1.0, 2.0), const_interval!(4.0, 5.0)]
+        );
+        assert_eq!(x.union(y).intervals(), [const_interval!(0.0, 6.0)]);
+        assert_eq!(
+            x.difference(y).intervals(),
+            [const_interval!(0.0, 1.0), const_interval!(5.0, 6.0)]
+        );
+        assert_eq!(y.difference(x).intervals(), [const_interval!(2.0, 4.0)]);
+        assert_eq!(
+            x.complement().intervals(),
+            [
+                const_interval!(f64::NEG_INFINITY, 0.0),
+                const_interval!(2.0, 4.0),
+                const_interval!(6.0, f64::INFINITY)
+            ]
+        );
+        assert_eq!(x.complement().complement(), x);
+        assert!(x.difference(x).is_empty());
+        assert_eq!(
+            U::<4>::new(const_interval!(1.0, 1.0)).complement(),
+            U::new(I::ENTIRE)
+        );
+        assert_eq!(U::<4>::EMPTY.complement(), U::new(I::ENTIRE));
+    }
+
+    #[test]
+    fn arith() {
+        let x = U::<4>::from_intervals([const_interval!(-2.0, -1.0), const_interval!(1.0, 2.0)]);
+        assert_eq!(
+            (x + x).intervals(),
+            [
+                const_interval!(-4.0, -2.0),
+                const_interval!(-1.0, 1.0),
+                const_interval!(2.0, 4.0)
+            ]
+        );
+        assert_eq!((x - x).hull(), const_interval!(-4.0, 4.0));
+        assert_eq!(
+            (x * x).intervals(),
+            [const_interval!(-4.0, -1.0), const_interval!(1.0, 4.0)]
+        );
+        assert_eq!(-x, x);
+        assert_eq!(x.sqr().intervals(), [const_interval!(1.0, 4.0)]);
+        assert_eq!(x.abs().intervals(), [const_interval!(1.0, 2.0)]);
+
+        let y = U::<4>::new(const_interval!(-1.0, 2.0));
+        assert_eq!(
+            (U::new(const_interval!(1.0, 1.0)) / y).intervals(),
+            [
+                const_interval!(f64::NEG_INFINITY, -1.0),
+                const_interval!(0.5, f64::INFINITY)
+            ]
+        );
+        assert_eq!(y.recip(), U::new(const_interval!(1.0, 1.0)) / y);
+        assert_eq!((U::new(I::zero()) / y).intervals(), [I::ENTIRE]);
+        assert_eq!(
+            (x / U::new(const_interval!(0.0, 1.0))).intervals(),
+            [
+                const_interval!(f64::NEG_INFINITY, -1.0),
+                const_interval!(1.0, f64::INFINITY)
+            ]
+        );
+    }
+
+    #[cfg(feature = "gmp")]
+    #[test]
+    fn poles() {
+        let y = U::<4>::new(const_interval!(1.0, 2.0)).tan();
+        assert_eq!(y.len(), 2);
+        assert_eq!(y.intervals()[0].inf(), f64::NEG_INFINITY);
+        assert_eq!(y.intervals()[1].sup(), f64::INFINITY);
+        assert!(y.intervals()[0].sup() < -2.18);
+        assert!(y.intervals()[1].inf() > 1.55);
+        assert_eq!(
+            U::<4>::new(const_interval!(1.0, 5.0)).tan(),
+            U::new(I::ENTIRE)
+        );
+
+        let y = U::<4>::new(const_interval!(0.25, 0.75)).tan_pi();
+        assert_eq!(
+            y.intervals(),
+            [
+                const_interval!(f64::NEG_INFINITY, -1.0),
+                const_interval!(1.0, f64::INFINITY)
+            ]
+        );
+
+        let y = U::<4>::new(const_interval!(-1.0, 1.0)).cot();
+        assert_eq!(y.len(), 2);
+        assert!(!y.contains(0.0));
+
+        let y = U::<4>::new(const_interval!(-1.0, 1.0)).csc();
+        assert_eq!(y.len(), 2);
+        assert!(y.intervals()[0].sup() < -1.18);
+        assert!(y.intervals()[1].inf() > 1.18);
+        let y = U::<4>::new(const_interval!(1.0, 2.0)).sec();
+        assert_eq!(y.len(), 2);
+        assert!(!y.contains(0.0));
+        let y = U::<4>::new(const_interval!(0.0, 7.0)).sec();
+        assert_eq!(
+            y.intervals(),
+            [
+                const_interval!(f64::NEG_INFINITY, -1.0),
+                const_interval!(1.0, f64::INFINITY)
+            ]
+        );
+
+        let x = U::<4>::new(const_interval!(-1.0, 1.0));
+        assert_eq!(x.coth().len(), 2);
+        assert_eq!(x.csch().len(), 2);
+        assert_eq!(x.powi(-1), x.recip());
+        assert_eq!(x.powi(-2).len(), 1);
+        assert_eq!(x.rootn(-3).len(), 2);
+        assert_eq!(U::<4>::new(const_interval!(-2.0, 2.0)).acoth().len(), 2);
+        assert_eq!(x.exp().intervals(), [x.hull().exp()]);
+    }
+}
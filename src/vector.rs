@@ -0,0 +1,493 @@
+use crate::{forward_ref::*, interval::*};
+use alloc::{vec, vec::Vec};
+use core::{
+    ops::{Add, Index, IndexMut, Neg, Sub},
+    slice,
+};
+
+/// A vector of intervals, also known as an interval box.
+///
+/// The operations on [`IntervalVector`] return enclosures of the exact results, in the same
+/// manner as the operations on [`Interval`]. The binary operations panic if the operands have
+/// different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use inari::{const_interval as c, IntervalVector as V};
+/// let x = V::new(vec![c!(1.0, 2.0), c!(-1.0, 1.0)]);
+/// let y = V::from_points(&[3.0, 4.0]);
+/// assert_eq!(x.dot(&y), c!(-1.0, 10.0));
+/// assert_eq!(&x + &y, V::new(vec![c!(4.0, 5.0), c!(3.0, 5.0)]));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalVector {
+    xs: Vec<Interval>,
+}
+
+/// An $n$-dimensional box $𝒙_1 × ⋯ × 𝒙_n$, represented by an [`IntervalVector`].
+///
+/// Such boxes are the subject of [`IntervalVector::bisect`], [`IntervalVector::widest_axis`],
+/// [`IntervalVector::smear_axis`] and the global optimizer [`crate::optimize::minimize`].
+pub type IntervalBox = IntervalVector;
+
+impl IntervalVector {
+    /// Creates a vector from the given elements.
+    pub fn new(xs: Vec<Interval>) -> Self {
+        Self { xs }
+    }
+
+    /// Creates a vector whose elements are the singleton intervals $\set{𝚡_i}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the numbers is not finite.
+    pub fn from_points(x: &[f64]) -> Self {
+        x.iter().map(|&x| point(x)).collect()
+    }
+
+    /// Creates a vector of length `n` whose elements are all $\[0, 0\]$.
+    pub fn zeros(n: usize) -> Self {
+        Self {
+            xs: vec![Interval::zero(); n],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Returns `true` if the vector has no elements.
+    ///
+    /// Note that it is not the same as the vector representing the empty set.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[Interval] {
+        &self.xs
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [Interval] {
+        &mut self.xs
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> slice::Iter<'_, Interval> {
+        self.xs.iter()
+    }
+
+    /// Returns an iterator that allows modifying the elements.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Interval> {
+        self.xs.iter_mut()
+    }
+
+    /// Converts the vector into a [`Vec`] of the elements.
+    pub fn into_vec(self) -> Vec<Interval> {
+        self.xs
+    }
+
+    /// Computes $\self ← 𝚊 𝚡 + \self$ (known as axpy in BLAS).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalVector as V};
+    /// let mut y = V::from_points(&[1.0, 2.0]);
+    /// y.axpy(c!(2.0, 3.0), &V::from_points(&[1.0, -1.0]));
+    /// assert_eq!(y, V::new(vec![c!(3.0, 4.0), c!(-1.0, 0.0)]));
+    /// ```
+    pub fn axpy(&mut self, a: Interval, x: &Self) {
+        check_len(self.len(), x.len());
+        for (y, &x) in self.xs.iter_mut().zip(x.iter()) {
+            *y = a.mul_add(x, *y);
+        }
+    }
+
+    /// Splits `self` into two boxes at the midpoint of the `i`th element.
+    ///
+    /// If the `i`th element is empty or a singleton, both of the boxes are equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalBox as B};
+    /// let x = B::new(vec![c!(0.0, 1.0), c!(0.0, 4.0)]);
+    /// let [y, z] = x.bisect(1);
+    /// assert_eq!(y, B::new(vec![c!(0.0, 1.0), c!(0.0, 2.0)]));
+    /// assert_eq!(z, B::new(vec![c!(0.0, 1.0), c!(2.0, 4.0)]));
+    /// ```
+    pub fn bisect(&self, i: usize) -> [Self; 2] {
+        let x = self[i];
+        let mut y = self.clone();
+        let mut z = self.clone();
+        if !x.is_empty() {
+            let m = x.mid();
+            y[i] = Interval::with_infsup_raw(x.inf(), m);
+            z[i] = Interval::with_infsup_raw(m, x.sup());
+        }
+        [y, z]
+    }
+
+    /// Returns the elementwise convex hull of `self` and `rhs`.
+    pub fn convex_hull(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, Interval::convex_hull)
+    }
+
+    /// Returns the dot product $\sum_i \self_i \rhs_i$.
+    pub fn dot(&self, rhs: &Self) -> Interval {
+        check_len(self.len(), rhs.len());
+        self.iter()
+            .zip(rhs.iter())
+            .fold(Interval::zero(), |s, (&x, &y)| x.mul_add(y, s))
+    }
+
+    /// Returns `true` if every element of `self` is in the interior of the corresponding
+    /// element of `rhs`.
+    ///
+    /// See [`Interval::interior`].
+    pub fn interior(&self, rhs: &Self) -> bool {
+        check_len(self.len(), rhs.len());
+        self.iter().zip(rhs.iter()).all(|(x, &y)| x.interior(y))
+    }
+
+    /// Returns the elementwise intersection of `self` and `rhs`.
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        self.zip_map(rhs, Interval::intersection)
+    }
+
+    /// Returns `true` if any of the elements is empty, i.e., the vector represents the empty set.
+    pub fn is_empty_set(&self) -> bool {
+        self.iter().any(|x| x.is_empty())
+    }
+
+    /// Returns the elementwise midpoints.
+    ///
+    /// See [`Interval::mid`].
+    pub fn mid(&self) -> Vec<f64> {
+        self.iter().map(|x| x.mid()).collect()
+    }
+
+    /// Returns an enclosure of the 1-norm $\sum_i |\self_i|$.
+    pub fn norm1(&self) -> Interval {
+        self.iter().fold(Interval::zero(), |s, x| s + x.abs())
+    }
+
+    /// Returns an enclosure of the Euclidean norm $\sqrt{\sum_i \self_i^2}$.
+    pub fn norm2(&self) -> Interval {
+        self.iter()
+            .fold(Interval::zero(), |s, x| s + x.sqr())
+            .sqrt()
+    }
+
+    /// Returns an enclosure of the maximum norm $\max_i |\self_i|$.
+    pub fn norm_inf(&self) -> Interval {
+        self.iter().fold(Interval::zero(), |s, x| s.max(x.abs()))
+    }
+
+    /// Returns the elementwise radii.
+    ///
+    /// See [`Interval::rad`].
+    pub fn rad(&self) -> Vec<f64> {
+        self.iter().map(|x| x.rad()).collect()
+    }
+
+    /// Returns the index $i$ that maximizes the smear value $\wid(\self_i) \mag(𝚐_i)$, where $𝚐$ is
+    /// an enclosure of the gradient of a function over `self`.
+    ///
+    /// Bisection along the axis usually reduces the overestimation of the range of the function
+    /// more than bisection along the widest axis. If the smear values are all zero, the result of
+    /// [`IntervalVector::widest_axis`] is returned. [`None`] is returned if `self` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `g` have different lengths.
+    pub fn smear_axis(&self, g: &Self) -> Option<usize> {
+        check_len(self.len(), g.len());
+        let smear = self
+            .iter()
+            .zip(g.iter())
+            .map(|(x, g)| {
+                let s = x.wid() * g.mag();
+                if s.is_nan() {
+                    0.0
+                } else {
+                    s
+                }
+            })
+            .collect::<Vec<_>>();
+        match argmax(&smear) {
+            Some(i) if smear[i] > 0.0 => Some(i),
+            _ => self.widest_axis(),
+        }
+    }
+
+    /// Returns $𝚊 \self$.
+    pub fn scale(&self, a: Interval) -> Self {
+        self.iter().map(|&x| a * x).collect()
+    }
+
+    /// Returns `true` if every element of `self` is a subset of the corresponding element of
+    /// `rhs`.
+    ///
+    /// See [`Interval::subset`].
+    pub fn subset(&self, rhs: &Self) -> bool {
+        check_len(self.len(), rhs.len());
+        self.iter().zip(rhs.iter()).all(|(x, &y)| x.subset(y))
+    }
+
+    /// Returns the elementwise widths.
+    ///
+    /// See [`Interval::wid`].
+    pub fn wid(&self) -> Vec<f64> {
+        self.iter().map(|x| x.wid()).collect()
+    }
+
+    /// Returns the index of the element with the largest width, or [`None`] if `self` is empty.
+    ///
+    /// If there are more than one such elements, the smallest index is returned. Empty elements
+    /// are regarded as having zero width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inari::{const_interval as c, IntervalBox as B};
+    /// let x = B::new(vec![c!(0.0, 1.0), c!(0.0, 4.0), c!(1.0, 5.0)]);
+    /// assert_eq!(x.widest_axis(), Some(1));
+    /// assert_eq!(B::new(vec![]).widest_axis(), None);
+    /// ```
+    pub fn widest_axis(&self) -> Option<usize> {
+        let wid = self
+            .iter()
+            .map(|x| if x.is_empty() { 0.0 } else { x.wid() })
+            .collect::<Vec<_>>();
+        argmax(&wid)
+    }
+
+    fn zip_map(&self, rhs: &Self, f: impl Fn(Interval, Interval) -> Interval) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter()
+            .zip(rhs.iter())
+            .map(|(&x, &y)| f(x, y))
+            .collect()
+    }
+}
+
+// Returns the smallest index of the maximum element.
+fn argmax(xs: &[f64]) -> Option<usize> {
+    xs.iter()
+        .enumerate()
+        .fold(None, |m: Option<(usize, f64)>, (i, &x)| match m {
+            Some((_, y)) if y >= x => m,
+            _ => Some((i, x)),
+        })
+        .map(|(i, _)| i)
+}
+
+pub(crate) fn check_len(n: usize, m: usize) {
+    assert_eq!(n, m, "the dimensions of the operands must match");
+}
+
+pub(crate) fn point(x: f64) -> Interval {
+    assert!(x.is_finite(), "the number must be finite");
+    Interval::with_infsup_raw(x, x)
+}
+
+impl From<Vec<Interval>> for IntervalVector {
+    fn from(xs: Vec<Interval>) -> Self {
+        Self::new(xs)
+    }
+}
+
+impl FromIterator<Interval> for IntervalVector {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for IntervalVector {
+    type Item = Interval;
+    type IntoIter = vec::IntoIter<Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.xs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalVector {
+    type Item = &'a Interval;
+    type IntoIter = slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.xs.iter()
+    }
+}
+
+impl Index<usize> for IntervalVector {
+    type Output = Interval;
+
+    fn index(&self, i: usize) -> &Interval {
+        &self.xs[i]
+    }
+}
+
+impl IndexMut<usize> for IntervalVector {
+    fn index_mut(&mut self, i: usize) -> &mut Interval {
+        &mut self.xs[i]
+    }
+}
+
+impl Neg for &IntervalVector {
+    type Output = IntervalVector;
+
+    fn neg(self) -> IntervalVector {
+        self.iter().map(|&x| -x).collect()
+    }
+}
+
+impl Neg for IntervalVector {
+    type Output = IntervalVector;
+
+    fn neg(self) -> IntervalVector {
+        -&self
+    }
+}
+
+impl Add for &IntervalVector {
+    type Output = IntervalVector;
+
+    fn add(self, rhs: Self) -> IntervalVector {
+        self.zip_map(rhs, |x, y| x + y)
+    }
+}
+
+forward_owned_binop!(impl Add, add for IntervalVector, IntervalVector, IntervalVector);
+
+impl Sub for &IntervalVector {
+    type Output = IntervalVector;
+
+    fn sub(self, rhs: Self) -> IntervalVector {
+        self.zip_map(rhs, |x, y| x - y)
+    }
+}
+
+forward_owned_binop!(impl Sub, sub for IntervalVector, IntervalVector, IntervalVector);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use Interval as I;
+    use IntervalVector as V;
+
+    #[test]
+    fn arith() {
+        let x = V::new(vec![const_interval!(1.0, 2.0), const_interval!(-3.0, 1.0)]);
+        let y = V::from_points(&[0.5, 2.0]);
+        assert_eq!(
+            &x + &y,
+            V::new(vec![const_interval!(1.5, 2.5), const_interval!(-1.0, 3.0)])
+        );
+        assert_eq!(
+            x.clone() - y.clone(),
+            V::new(vec![const_interval!(0.5, 1.5), const_interval!(-5.0, -1.0)])
+        );
+        assert_eq!(
+            -&x,
+            V::new(vec![
+                const_interval!(-2.0, -1.0),
+                const_interval!(-1.0, 3.0)
+            ])
+        );
+        assert_eq!(
+            x.scale(const_interval!(-1.0, 2.0)),
+            V::new(vec![const_interval!(-2.0, 4.0), const_interval!(-6.0, 3.0)])
+        );
+        assert_eq!(x.dot(&y), const_interval!(-5.5, 3.0));
+        assert_eq!(V::zeros(0).dot(&V::zeros(0)), const_interval!(0.0, 0.0));
+    }
+
+    #[test]
+    fn dot_enclosure() {
+        let x = V::from_points(&[0.1, 0.2, 0.3]);
+        let y = V::from_points(&[3.0, 2.0, 1.0]);
+        let d = x.dot(&y);
+        assert!(d.contains(1.0));
+        assert!(d.wid() < 1e-15);
+
+        let x = V::new(vec![I::EMPTY, I::ENTIRE]);
+        assert!(x.dot(&V::from_points(&[1.0, 1.0])).is_empty());
+        assert!(x.is_empty_set());
+    }
+
+    #[test]
+    fn norms() {
+        let x = V::new(vec![const_interval!(-3.0, 1.0), const_interval!(4.0, 4.0)]);
+        assert_eq!(x.norm1(), const_interval!(4.0, 7.0));
+        assert_eq!(x.norm_inf(), const_interval!(4.0, 4.0));
+        assert_eq!(x.norm2(), const_interval!(4.0, 5.0));
+    }
+
+    #[test]
+    fn set_op() {
+        let x = V::new(vec![const_interval!(1.0, 2.0), const_interval!(-1.0, 1.0)]);
+        let y = V::new(vec![const_interval!(3.0, 4.0), const_interval!(0.0, 2.0)]);
+        assert_eq!(
+            x.convex_hull(&y),
+            V::new(vec![const_interval!(1.0, 4.0), const_interval!(-1.0, 2.0)])
+        );
+        let z = x.intersection(&y);
+        assert!(z[0].is_empty() && z[1] == const_interval!(0.0, 1.0));
+        assert!(z.is_empty_set());
+        assert!(z.subset(&x));
+        assert!(!x.subset(&y));
+        assert!(V::from_points(&[1.5, 0.0]).interior(&x));
+    }
+
+    #[test]
+    fn bisection() {
+        let x = V::new(vec![
+            const_interval!(0.0, 4.0),
+            const_interval!(1.0, 1.0),
+            I::ENTIRE,
+            I::EMPTY,
+        ]);
+        let [y, z] = x.bisect(0);
+        assert_eq!(y[0], const_interval!(0.0, 2.0));
+        assert_eq!(z[0], const_interval!(2.0, 4.0));
+        assert_eq!(x.bisect(1), [x.clone(), x.clone()]);
+        assert_eq!(x.bisect(3), [x.clone(), x.clone()]);
+        let [y, z] = x.bisect(2);
+        assert_eq!(y[2], const_interval!(f64::NEG_INFINITY, 0.0));
+        assert_eq!(z[2], const_interval!(0.0, f64::INFINITY));
+
+        assert_eq!(x.widest_axis(), Some(2));
+        let g = V::new(vec![
+            const_interval!(-1.0, 1.0),
+            const_interval!(5.0, 5.0),
+            const_interval!(0.0, 0.0),
+            I::ENTIRE,
+        ]);
+        assert_eq!(x.smear_axis(&g), Some(0));
+        assert_eq!(x.smear_axis(&V::zeros(4)), Some(2));
+        assert_eq!(V::zeros(0).smear_axis(&V::zeros(0)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn length_mismatch() {
+        let _ = V::zeros(2) + V::zeros(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_finite_point() {
+        let _ = V::from_points(&[f64::INFINITY]);
+    }
+}
@@ -9,7 +9,7 @@ use inari::*;
     clippy::float_cmp
 )]
 mod itf1788_tests {
-    //mod abs_rev;
+    mod abs_rev;
     mod atan2;
     mod c_xsc;
     mod fi_lib;
@@ -23,11 +23,11 @@ mod itf1788_tests {
     mod libieeep1788_num;
     mod libieeep1788_overlap;
     mod libieeep1788_rec_bool;
-    //mod libieeep1788_reduction;
-    //mod libieeep1788_rev;
+    mod libieeep1788_reduction;
+    mod libieeep1788_rev;
     mod libieeep1788_set;
     mod mpfi;
-    //mod pow_rev;
+    mod pow_rev;
 }
 
 pub fn n2i(a: f64, b: f64) -> Interval {
@@ -0,0 +1,146 @@
+/*
+ *
+ * Unit tests for the reverse absolute value function
+ * converted into portable ITL format by Oliver Heimlich.
+ *
+ * Copyright 2015-2017 Oliver Heimlich (oheim@posteo.de)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//Language imports
+
+//Test library imports
+
+//Arithmetic library imports
+
+//Preamble
+use crate::*;
+use inari::{DecInterval as DI, Decoration as D, Interval as I};
+
+#[test]
+fn minimal_abs_rev_test() {
+    assert_eq2!(I::EMPTY.abs_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-1.1, -0.4).abs_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(0.0, f64::INFINITY).abs_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.1, 2.1).abs_rev(I::ENTIRE), n2i(-2.1, 2.1));
+    assert_eq2!(n2i(-1.9, 0.2).abs_rev(I::ENTIRE), n2i(-0.2, 0.2));
+    assert_eq2!(n2i(0.0, 0.2).abs_rev(I::ENTIRE), n2i(-0.2, 0.2));
+    assert_eq2!(n2i(-1.5, f64::INFINITY).abs_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(I::ENTIRE.abs_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, 0.0).abs_rev(I::ENTIRE), n2i(0.0, 0.0));
+    assert_eq2!(n2i(-0.0, -0.0).abs_rev(I::ENTIRE), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.1, 0.1).abs_rev(I::ENTIRE), n2i(-0.1, 0.1));
+}
+
+#[test]
+fn minimal_abs_rev_bin_test() {
+    assert_eq2!(I::EMPTY.abs_rev(n2i(0.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).abs_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.0, 1.0).abs_rev(n2i(-2.0, -1.5)), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).abs_rev(n2i(-1.5, 1.5)), n2i(-1.5, 1.5));
+    assert_eq2!(
+        n2i(1.0, 2.0).abs_rev(n2i(0.0, f64::INFINITY)),
+        n2i(1.0, 2.0)
+    );
+    assert_eq2!(n2i(0.0, 0.2).abs_rev(n2i(-0.1, 0.1)), n2i(-0.1, 0.1));
+    assert_eq2!(n2i(-1.0, 3.0).abs_rev(n2i(-2.0, 1.0)), n2i(-2.0, 1.0));
+    assert_eq2!(
+        n2i(0.5, f64::INFINITY).abs_rev(n2i(-1.0, 0.7)),
+        n2i(-1.0, 0.7)
+    );
+    assert_eq2!(n2i(1.1, 2.1).abs_rev(n2i(1.0, 1.5)), n2i(1.1, 1.5));
+    assert_eq2!(n2i(-0.0, 0.0).abs_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+}
+
+#[test]
+fn minimal_abs_rev_dec_test() {
+    assert_eq2!(DI::NAI.abs_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.abs_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.abs_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-1.1, -0.4, D::Dac).abs_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).abs_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 2.1, D::Trv).abs_rev(DI::ENTIRE),
+        nd2di(-2.1, 2.1, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.9, 0.2, D::Com).abs_rev(DI::ENTIRE),
+        nd2di(-0.2, 0.2, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.2, D::Dac).abs_rev(DI::ENTIRE),
+        nd2di(-0.2, 0.2, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.5, f64::INFINITY, D::Def).abs_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).abs_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).abs_rev(DI::ENTIRE),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).abs_rev(DI::ENTIRE),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.1, D::Def).abs_rev(DI::ENTIRE),
+        nd2di(-0.1, 0.1, D::Trv)
+    );
+}
+
+#[test]
+fn minimal_abs_rev_dec_bin_test() {
+    assert_eq2!(DI::EMPTY.abs_rev(nd2di(0.0, 1.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(nd2di(1.0, 2.0, D::Dac).abs_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, 1.0, D::Def).abs_rev(nd2di(-2.0, -1.5, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Trv).abs_rev(nd2di(-1.5, 1.5, D::Dac)),
+        nd2di(-1.5, 1.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Com).abs_rev(nd2di(0.0, f64::INFINITY, D::Dac)),
+        nd2di(1.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.2, D::Dac).abs_rev(nd2di(-0.1, 0.1, D::Dac)),
+        nd2di(-0.1, 0.1, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 3.0, D::Def).abs_rev(nd2di(-2.0, 1.0, D::Dac)),
+        nd2di(-2.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, f64::INFINITY, D::Trv).abs_rev(nd2di(-1.0, 0.7, D::Dac)),
+        nd2di(-1.0, 0.7, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 2.1, D::Com).abs_rev(nd2di(1.0, 1.5, D::Dac)),
+        nd2di(1.1, 1.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, 0.0, D::Dac).abs_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+}
@@ -0,0 +1,129 @@
+/*
+ *
+ * Unit tests from libieeep1788 for reduction operations
+ * (Original author: Marco Nehmeier)
+ * converted into portable ITL format by Oliver Heimlich.
+ *
+ * Copyright 2013-2015 Marco Nehmeier (nehmeier@informatik.uni-wuerzburg.de)
+ * Copyright 2015-2017 Oliver Heimlich (oheim@posteo.de)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//Language imports
+
+//Test library imports
+
+//Arithmetic library imports
+
+//Preamble
+use crate::*;
+use inari::reduction::{RoundingDirection as R, *};
+
+#[test]
+fn minimal_sum_test() {
+    assert_eq2!(sum(&[1.0, 2.0, 3.0], R::TiesToEven), 6.0);
+    assert_eq2!(sum(&[1.0, 2.0, f64::NAN, 3.0], R::TiesToEven), f64::NAN);
+    assert_eq2!(
+        sum(
+            &[1.0, f64::NEG_INFINITY, 2.0, f64::INFINITY, 3.0],
+            R::TiesToEven
+        ),
+        f64::NAN
+    );
+}
+
+#[test]
+fn minimal_sum_abs_test() {
+    assert_eq2!(sum_abs(&[1.0, -2.0, 3.0], R::TiesToEven), 6.0);
+    assert_eq2!(
+        sum_abs(&[1.0, -2.0, f64::NAN, 3.0], R::TiesToEven),
+        f64::NAN
+    );
+    assert_eq2!(
+        sum_abs(
+            &[1.0, f64::NEG_INFINITY, 2.0, f64::INFINITY, 3.0],
+            R::TiesToEven
+        ),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn minimal_sum_sqr_test() {
+    assert_eq2!(sum_square(&[1.0, 2.0, 3.0], R::TiesToEven), 14.0);
+    assert_eq2!(
+        sum_square(&[1.0, 2.0, f64::NAN, 3.0], R::TiesToEven),
+        f64::NAN
+    );
+    assert_eq2!(
+        sum_square(
+            &[1.0, f64::NEG_INFINITY, 2.0, f64::INFINITY, 3.0],
+            R::TiesToEven
+        ),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn minimal_dot_test() {
+    assert_eq2!(dot(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0], R::TiesToEven), 14.0);
+    assert_eq2!(
+        dot(
+            &[4503599627370497.0, 2.028240960365167e+31],
+            &[4503599627370495.0, -1.0],
+            R::TiesToEven
+        ),
+        -1.0
+    );
+    assert_eq2!(
+        dot(
+            &[1.0, 2.0, f64::NAN, 3.0],
+            &[1.0, 2.0, 3.0, 4.0],
+            R::TiesToEven
+        ),
+        f64::NAN
+    );
+    assert_eq2!(
+        dot(
+            &[1.0, 2.0, 3.0, 4.0],
+            &[1.0, 2.0, f64::NAN, 3.0],
+            R::TiesToEven
+        ),
+        f64::NAN
+    );
+    assert_eq2!(
+        dot(&[1.0, f64::INFINITY, 3.0], &[1.0, 0.0, 3.0], R::TiesToEven),
+        f64::NAN
+    );
+    assert_eq2!(
+        dot(&[1.0, f64::INFINITY, 3.0], &[1.0, -1.0, 3.0], R::TiesToEven),
+        f64::NEG_INFINITY
+    );
+    assert_eq2!(
+        dot(
+            &[1.0, f64::INFINITY, 3.0],
+            &[1.0, f64::NEG_INFINITY, 3.0],
+            R::TiesToEven
+        ),
+        f64::NEG_INFINITY
+    );
+    assert_eq2!(
+        dot(
+            &[1.0, f64::INFINITY, 3.0],
+            &[1.0, f64::INFINITY, f64::NEG_INFINITY],
+            R::TiesToEven
+        ),
+        f64::NAN
+    );
+}
@@ -0,0 +1,6034 @@
+/*
+ *
+ * Unit tests from libieeep1788 for reverse elementary functions
+ * (Original author: Marco Nehmeier)
+ * converted into portable ITL format by Oliver Heimlich.
+ *
+ * Copyright 2013-2015 Marco Nehmeier (nehmeier@informatik.uni-wuerzburg.de)
+ * Copyright 2015-2017 Oliver Heimlich (oheim@posteo.de)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//Language imports
+
+//Test library imports
+
+//Arithmetic library imports
+
+//Preamble
+use crate::*;
+use inari::{DecInterval as DI, Decoration as D, Interval as I};
+
+#[test]
+fn minimal_sqr_rev_test() {
+    assert_eq2!(I::EMPTY.sqr_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-10.0, -1.0).sqr_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(0.0, f64::INFINITY).sqr_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, 1.0).sqr_rev(I::ENTIRE), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-0.5, 1.0).sqr_rev(I::ENTIRE), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-1000.0, 1.0).sqr_rev(I::ENTIRE), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(0.0, 25.0).sqr_rev(I::ENTIRE), n2i(-5.0, 5.0));
+    assert_eq2!(n2i(-1.0, 25.0).sqr_rev(I::ENTIRE), n2i(-5.0, 5.0));
+    assert_eq2!(
+        n2i(0.01, 0.010000000000000002).sqr_rev(I::ENTIRE),
+        n2i(-0.10000000000000002, 0.10000000000000002)
+    );
+    assert_eq2!(
+        n2i(0.0, 105170.49000000002).sqr_rev(I::ENTIRE),
+        n2i(-324.30000000000007, 324.30000000000007)
+    );
+}
+
+#[test]
+fn minimal_sqr_rev_bin_test() {
+    assert_eq2!(I::EMPTY.sqr_rev(n2i(-5.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(-10.0, -1.0).sqr_rev(n2i(-5.0, 1.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).sqr_rev(n2i(-5.0, 1.0)),
+        n2i(-5.0, 1.0)
+    );
+    assert_eq2!(n2i(0.0, 1.0).sqr_rev(n2i(-0.1, 1.0)), n2i(-0.1, 1.0));
+    assert_eq2!(n2i(-0.5, 1.0).sqr_rev(n2i(-0.1, 1.0)), n2i(-0.1, 1.0));
+    assert_eq2!(n2i(-1000.0, 1.0).sqr_rev(n2i(-0.1, 1.0)), n2i(-0.1, 1.0));
+    assert_eq2!(n2i(0.0, 25.0).sqr_rev(n2i(-4.1, 6.0)), n2i(-4.1, 5.0));
+    assert_eq2!(n2i(-1.0, 25.0).sqr_rev(n2i(-4.1, 6.0)), n2i(-4.1, 5.0));
+    assert_eq2!(n2i(-1.0, 25.0).sqr_rev(n2i(5.1, 6.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(0.01, 0.010000000000000002).sqr_rev(n2i(-0.1, 0.1)),
+        n2i(-0.1, 0.1)
+    );
+    assert_eq2!(
+        n2i(0.0, 105170.49000000002).sqr_rev(n2i(-0.1, 324.30000000000007)),
+        n2i(-0.1, 324.30000000000007)
+    );
+}
+
+#[test]
+fn minimal_sqr_rev_dec_test() {
+    assert_eq2!(DI::NAI.sqr_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.sqr_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.sqr_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-10.0, -1.0, D::Dac).sqr_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).sqr_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 1.0, D::Trv).sqr_rev(DI::ENTIRE),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 1.0, D::Com).sqr_rev(DI::ENTIRE),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1000.0, 1.0, D::Dac).sqr_rev(DI::ENTIRE),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 25.0, D::Def).sqr_rev(DI::ENTIRE),
+        nd2di(-5.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 25.0, D::Trv).sqr_rev(DI::ENTIRE),
+        nd2di(-5.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.01, 0.010000000000000002, D::Com).sqr_rev(DI::ENTIRE),
+        nd2di(-0.10000000000000002, 0.10000000000000002, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 105170.49000000002, D::Dac).sqr_rev(DI::ENTIRE),
+        nd2di(-324.30000000000007, 324.30000000000007, D::Trv)
+    );
+}
+
+#[test]
+fn minimal_sqr_rev_dec_bin_test() {
+    assert_eq2!(DI::EMPTY.sqr_rev(nd2di(-5.0, 1.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-10.0, -1.0, D::Dac).sqr_rev(nd2di(-5.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).sqr_rev(nd2di(-5.0, 1.0, D::Dac)),
+        nd2di(-5.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 1.0, D::Trv).sqr_rev(nd2di(-0.1, 1.0, D::Dac)),
+        nd2di(-0.1, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 1.0, D::Com).sqr_rev(nd2di(-0.1, 1.0, D::Dac)),
+        nd2di(-0.1, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1000.0, 1.0, D::Dac).sqr_rev(nd2di(-0.1, 1.0, D::Dac)),
+        nd2di(-0.1, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 25.0, D::Def).sqr_rev(nd2di(-4.1, 6.0, D::Dac)),
+        nd2di(-4.1, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 25.0, D::Trv).sqr_rev(nd2di(-4.1, 6.0, D::Dac)),
+        nd2di(-4.1, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 25.0, D::Com).sqr_rev(nd2di(5.1, 6.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.01, 0.010000000000000002, D::Dac).sqr_rev(nd2di(-0.1, 0.1, D::Dac)),
+        nd2di(-0.1, 0.1, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 105170.49000000002, D::Def).sqr_rev(nd2di(-0.1, 324.30000000000007, D::Dac)),
+        nd2di(-0.1, 324.30000000000007, D::Trv)
+    );
+}
+
+#[test]
+fn minimal_abs_rev_test() {
+    assert_eq2!(I::EMPTY.abs_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-1.1, -0.4).abs_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(0.0, f64::INFINITY).abs_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.1, 2.1).abs_rev(I::ENTIRE), n2i(-2.1, 2.1));
+    assert_eq2!(n2i(-1.1, 2.0).abs_rev(I::ENTIRE), n2i(-2.0, 2.0));
+    assert_eq2!(n2i(-1.1, 0.0).abs_rev(I::ENTIRE), n2i(0.0, 0.0));
+    assert_eq2!(n2i(-1.9, 0.2).abs_rev(I::ENTIRE), n2i(-0.2, 0.2));
+    assert_eq2!(n2i(0.0, 0.2).abs_rev(I::ENTIRE), n2i(-0.2, 0.2));
+    assert_eq2!(n2i(-1.5, f64::INFINITY).abs_rev(I::ENTIRE), I::ENTIRE);
+}
+
+#[test]
+fn minimal_abs_rev_bin_test() {
+    assert_eq2!(I::EMPTY.abs_rev(n2i(-1.1, 5.0)), I::EMPTY);
+    assert_eq2!(n2i(-1.1, -0.4).abs_rev(n2i(-1.1, 5.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).abs_rev(n2i(-1.1, 5.0)),
+        n2i(-1.1, 5.0)
+    );
+    assert_eq2!(n2i(1.1, 2.1).abs_rev(n2i(-1.0, 5.0)), n2i(1.1, 2.1));
+    assert_eq2!(n2i(-1.1, 2.0).abs_rev(n2i(-1.1, 5.0)), n2i(-1.1, 2.0));
+    assert_eq2!(n2i(-1.1, 0.0).abs_rev(n2i(-1.1, 5.0)), n2i(0.0, 0.0));
+    assert_eq2!(n2i(-1.9, 0.2).abs_rev(n2i(-1.1, 5.0)), n2i(-0.2, 0.2));
+}
+
+#[test]
+fn minimal_abs_rev_dec_test() {
+    assert_eq2!(DI::NAI.abs_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.abs_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.abs_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-1.1, -0.4, D::Dac).abs_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).abs_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 2.1, D::Trv).abs_rev(DI::ENTIRE),
+        nd2di(-2.1, 2.1, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.1, 2.0, D::Com).abs_rev(DI::ENTIRE),
+        nd2di(-2.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.1, 0.0, D::Dac).abs_rev(DI::ENTIRE),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.9, 0.2, D::Def).abs_rev(DI::ENTIRE),
+        nd2di(-0.2, 0.2, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.2, D::Trv).abs_rev(DI::ENTIRE),
+        nd2di(-0.2, 0.2, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.5, f64::INFINITY, D::Dac).abs_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[test]
+fn minimal_abs_rev_dec_bin_test() {
+    assert_eq2!(DI::EMPTY.abs_rev(nd2di(-1.1, 5.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.1, -0.4, D::Dac).abs_rev(nd2di(-1.1, 5.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).abs_rev(nd2di(-1.1, 5.0, D::Dac)),
+        nd2di(-1.1, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 2.1, D::Trv).abs_rev(nd2di(-1.0, 5.0, D::Dac)),
+        nd2di(1.1, 2.1, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.1, 2.0, D::Com).abs_rev(nd2di(-1.1, 5.0, D::Dac)),
+        nd2di(-1.1, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.1, 0.0, D::Dac).abs_rev(nd2di(-1.1, 5.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.9, 0.2, D::Def).abs_rev(nd2di(-1.1, 5.0, D::Dac)),
+        nd2di(-0.2, 0.2, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_powi_rev_test() {
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 0), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 0), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(n2i(1.1, 10.0).powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 0),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 0), I::EMPTY);
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 0), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 0), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 0),
+        I::EMPTY
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 0), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 1), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 1), n2i(1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 1), n2i(-1.0, 5.0));
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 1), n2i(-1.0, 0.0));
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 1), n2i(-1.0, 0.0));
+    assert_eq2!(n2i(1.1, 10.0).powi_rev(I::ENTIRE, 1), n2i(1.1, 10.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 1), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 1),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 1),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 1),
+        n2i(-1.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 1),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 1),
+        n2i(1.7976931348623157e+308, f64::INFINITY)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 1), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 2), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 2), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 2),
+        n2i(-2.23606797749979, 2.23606797749979)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 2), n2i(0.0, 0.0));
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 2), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, 2),
+        n2i(-3.1622776601683795, 3.1622776601683795)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 2), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 2),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 2),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 2), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 2), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 2),
+        I::ENTIRE
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 2), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 8), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 8), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 8),
+        n2i(-1.2228445449938519, 1.2228445449938519)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 8), n2i(0.0, 0.0));
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 8), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, 8),
+        n2i(-1.3335214321633242, 1.3335214321633242)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 8), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 8),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 8),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 8), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 8), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 8),
+        I::ENTIRE
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 8), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 3), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 3), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 3),
+        n2i(-1.0, 1.709975946676697)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 3), n2i(-1.0, 0.0));
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 3), n2i(-1.0, 0.0));
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, 3),
+        n2i(1.032280115456367, 2.154434690031884)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 3), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 3),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 3),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 3),
+        n2i(-1.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 3),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 3),
+        n2i(5.643803094122361e+102, f64::INFINITY)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 3), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, 7), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, 7), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(I::ENTIRE, 7),
+        n2i(-1.0, 1.258498950641827)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, 7), n2i(-1.0, 0.0));
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, 7), n2i(-1.0, 0.0));
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, 7),
+        n2i(1.013708856295468, 1.3894954943731377)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, 7), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, 7),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, 7),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, 7),
+        n2i(-1.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, 7),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, 7),
+        n2i(1.087396515837749e+44, f64::INFINITY)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, 7), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, -1), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, -1), n2i(1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, -1), I::ENTIRE);
+    assert_eq2!(
+        n2i(-1.0, 0.0).powi_rev(I::ENTIRE, -1),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, -0.0).powi_rev(I::ENTIRE, -1),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, -1),
+        n2i(0.09999999999999999, 0.9090909090909091)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, -1), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, -1),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, -1),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, -1), I::ENTIRE);
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, -1),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, -1),
+        n2i(0.0, 5.56268464626801e-309)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, -1), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, -3), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, -3), n2i(1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, -3), I::ENTIRE);
+    assert_eq2!(
+        n2i(-1.0, 0.0).powi_rev(I::ENTIRE, -3),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, -0.0).powi_rev(I::ENTIRE, -3),
+        n2i(f64::NEG_INFINITY, -1.0)
+    );
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, -3),
+        n2i(0.46415888336127786, 0.9687293061514644)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, -3), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, -3),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, -3),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, -3), I::ENTIRE);
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, -3),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, -3),
+        n2i(0.0, 1.7718548704178432e-103)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, -3), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, -2), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, -2), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, -2), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, -2), I::EMPTY);
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, -2), I::EMPTY);
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, -2),
+        n2i(-0.9534625892455924, 0.9534625892455924)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, -2), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, -2),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, -2),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, -2), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, -2), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, -2),
+        n2i(-7.458340731200208e-155, 7.458340731200208e-155)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, -2), I::ENTIRE);
+    assert_eq2!(I::EMPTY.powi_rev(I::ENTIRE, -8), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(I::ENTIRE, -8), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(I::ENTIRE, -8), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 0.0).powi_rev(I::ENTIRE, -8), I::EMPTY);
+    assert_eq2!(n2i(-1.0, -0.0).powi_rev(I::ENTIRE, -8), I::EMPTY);
+    assert_eq2!(
+        n2i(1.1, 10.0).powi_rev(I::ENTIRE, -8),
+        n2i(-0.988156915513073, 0.988156915513073)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(I::ENTIRE, -8), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(I::ENTIRE, -8),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).powi_rev(I::ENTIRE, -8),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(-1.0, f64::INFINITY).powi_rev(I::ENTIRE, -8), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).powi_rev(I::ENTIRE, -8), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.7976931348623157e+308, f64::INFINITY).powi_rev(I::ENTIRE, -8),
+        n2i(-2.9387358770557194e-39, 2.9387358770557194e-39)
+    );
+    assert_eq2!(I::ENTIRE.powi_rev(I::ENTIRE, -8), I::ENTIRE);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_powi_rev_bin_test() {
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 0), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 0), n2i(-5.0, 5.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 0), n2i(1.0, 5.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 0),
+        n2i(-51.0, 12.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 0), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 0), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 0), I::EMPTY);
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 0), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 0),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 0),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 0),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 0),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 0),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 0), I::EMPTY);
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 0),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 1), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 1), n2i(1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 1), n2i(1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 1), n2i(-1.0, 5.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 1), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 1), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 1), n2i(0.5, 0.5));
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 1), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 1),
+        n2i(-1.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 1),
+        n2i(4.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 1),
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 1),
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 1),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 1), I::EMPTY);
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 1),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 2), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 2), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 2), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 2),
+        n2i(-2.23606797749979, 2.23606797749979)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 2), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 2), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 2),
+        n2i(-0.7071067811865476, -0.7071067811865475)
+    );
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 2), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 2),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 2),
+        n2i(2.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 2),
+        n2i(1.8732342473651362e+28, 1.8732342473651364e+28)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 2),
+        n2i(-1.8732342473651364e+28, 1.8732342473651364e+28)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 2),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 2), I::EMPTY);
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 2),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 8), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 8), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 8), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 8),
+        n2i(-1.2228445449938519, 1.2228445449938519)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 8), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 8), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 8),
+        n2i(-0.9170040432046713, -0.9170040432046712)
+    );
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 8), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 8),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 8),
+        n2i(1.189207115002721, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 8),
+        n2i(11698980.640181351, 11698980.640181353)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 8),
+        n2i(-11698980.640181353, 11698980.640181353)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 8),
+        n2i(-0.8177654339579425, -0.7498942093324558)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 8),
+        n2i(0.7498942093324558, 0.8177654339579425)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 8),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 3), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 3), n2i(1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 3), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 3),
+        n2i(-1.0, 1.709975946676697)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 3), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 3), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 3), I::EMPTY);
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 3), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 3),
+        n2i(-1.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 3),
+        n2i(1.5874010519681994, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 3),
+        n2i(7.05333848924707e+18, 7.053338489247071e+18)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 3),
+        n2i(7.05333848924707e+18, 7.053338489247071e+18)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 3),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 3),
+        n2i(0.5, 0.5848035476425733)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 3),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), 7), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), 7), n2i(1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), 7), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), 7),
+        n2i(-1.0, 1.258498950641827)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), 7), n2i(0.0, 0.0));
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), 7), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), 7), I::EMPTY);
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), 7), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), 7),
+        n2i(-1.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), 7),
+        n2i(1.2190136542044754, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), 7),
+        n2i(119641942.07258298, 119641942.07258299)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, 7),
+        n2i(119641942.07258298, 119641942.07258299)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), 7),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), 7),
+        n2i(0.7196856730011519, 0.7945974047018524)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), 7),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), -1), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), -1), n2i(1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), -1), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), -1),
+        n2i(-51.0, 12.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), -1), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), -1), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), -1), I::EMPTY);
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), -1), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), -1),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), -1),
+        n2i(0.0, 0.25)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), -1),
+        n2i(2.8498094461577734e-57, 2.849809446157774e-57)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, -1),
+        n2i(2.8498094461577734e-57, 2.849809446157774e-57)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), -1),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), -1),
+        n2i(4.999999999999999, 10.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), -1),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), -3), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), -3), n2i(1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), -3), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), -3),
+        n2i(-51.0, 12.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), -3), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), -3), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), -3), I::EMPTY);
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), -3), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), -3),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), -3),
+        n2i(0.0, 0.6299605249474366)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), -3),
+        n2i(1.417768339807477e-19, 1.4177683398074773e-19)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, -3),
+        n2i(1.417768339807477e-19, 1.4177683398074773e-19)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), -3),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), -3),
+        n2i(1.7099759466766968, 2.154434690031884)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), -3),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), -2), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), -2), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), -2), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), -2),
+        n2i(-51.0, 12.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), -2), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), -2), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), -2),
+        n2i(-1.4142135623730951, -1.414213562373095)
+    );
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), -2), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), -2),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), -2),
+        n2i(-0.5, 0.5)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), -2),
+        n2i(5.338360653007413e-29, 5.338360653007414e-29)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, -2),
+        n2i(-5.338360653007414e-29, 5.338360653007414e-29)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), -2),
+        n2i(-3.1622776601683795, -2.2360679774997894)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), -2),
+        n2i(2.2360679774997894, 3.1622776601683795)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), -2),
+        n2i(8e-323, 8.095e-320)
+    );
+    assert_eq2!(I::EMPTY.powi_rev(n2i(1.0, 1.0), -8), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(-5.0, 5.0), -8), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(1.0, 1.0).powi_rev(n2i(1.0, 5.0), -8), n2i(1.0, 1.0));
+    assert_eq2!(
+        n2i(-1.0, 5.0).powi_rev(n2i(-51.0, 12.0), -8),
+        n2i(-51.0, 12.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(-1.0, 1.0), -8), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).powi_rev(n2i(2.0, 3.0), -8), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).powi_rev(n2i(-2.0, 0.5), -8),
+        n2i(-1.0905077326652577, -1.0905077326652575)
+    );
+    assert_eq2!(n2i(-27.0, 8.0).powi_rev(n2i(-1.0, 1.0), -8), n2i(-1.0, 1.0));
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).powi_rev(n2i(-1.0, 1.0), -8),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(4.0, f64::INFINITY).powi_rev(n2i(-1.0, f64::INFINITY), -8),
+        n2i(-0.8408964152537146, 0.8408964152537146)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(n2i(0.0, f64::INFINITY), -8),
+        n2i(8.54775326805309e-08, 8.547753268053092e-08)
+    );
+    assert_eq2!(
+        n2i(3.5090065455016287e+56, 3.5090065455016287e+56).powi_rev(I::ENTIRE, -8),
+        n2i(-8.547753268053092e-08, 8.547753268053092e-08)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(f64::NEG_INFINITY, -0.5), -8),
+        n2i(-1.3335214321633242, -1.2228445449938516)
+    );
+    assert_eq2!(
+        n2i(0.1, 0.2).powi_rev(n2i(0.5, 1e+300), -8),
+        n2i(1.2228445449938516, 1.3335214321633242)
+    );
+    assert_eq2!(
+        I::ENTIRE.powi_rev(n2i(8e-323, 8.095e-320), -8),
+        n2i(8e-323, 8.095e-320)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_powi_rev_dec_test() {
+    assert_eq2!(DI::NAI.powi_rev(DI::ENTIRE, 2), DI::NAI);
+    assert_eq2!(DI::ENTIRE.powi_rev(DI::NAI, 2), DI::NAI);
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 0), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 0),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 0),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 0), DI::EMPTY);
+    assert_eq2!(nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 0), DI::EMPTY);
+    assert_eq2!(nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 0), DI::EMPTY);
+    assert_eq2!(nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 0), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 0),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 0),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 0),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 1), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 1),
+        nd2di(-1.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 1),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 1),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 1),
+        nd2di(1.1, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 1),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 1),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 1),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 1),
+        nd2di(-1.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 1),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 1),
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 1),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 2),
+        nd2di(-2.23606797749979, 2.23606797749979, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 2),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 2),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 2),
+        nd2di(-3.1622776601683795, 3.1622776601683795, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 2),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 2),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 8),
+        nd2di(-1.2228445449938519, 1.2228445449938519, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 8),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 8),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 8),
+        nd2di(-1.3335214321633242, 1.3335214321633242, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 8),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 8),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 3), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 3),
+        nd2di(-1.0, 1.709975946676697, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 3),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 3),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 3),
+        nd2di(1.032280115456367, 2.154434690031884, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 3),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 3),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 3),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 3),
+        nd2di(-1.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 3),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 3),
+        nd2di(5.643803094122361e+102, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 3),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, 7), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, 7),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, 7),
+        nd2di(-1.0, 1.258498950641827, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, 7),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, 7),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, 7),
+        nd2di(1.013708856295468, 1.3894954943731377, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, 7),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, 7),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, 7),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 7),
+        nd2di(-1.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, 7),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, 7),
+        nd2di(1.087396515837749e+44, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, 7),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, -1), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, -1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, -1),
+        nd2di(0.09999999999999999, 0.9090909090909091, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, -1), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, -1),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, -1),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, -1),
+        nd2di(0.0, 5.56268464626801e-309, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -1),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, -3), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, -3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, -3),
+        nd2di(0.46415888336127786, 0.9687293061514644, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, -3), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, -3),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, -3),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, -3),
+        nd2di(0.0, 1.7718548704178432e-103, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -3),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, -2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, -2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, -2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, -2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, -2),
+        nd2di(-0.9534625892455924, 0.9534625892455924, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, -2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, -2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, -2),
+        nd2di(-7.458340731200208e-155, 7.458340731200208e-155, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -2),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(DI::ENTIRE, -8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(DI::ENTIRE, -8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Def).powi_rev(DI::ENTIRE, -8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 0.0, D::Trv).powi_rev(DI::ENTIRE, -8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, -0.0, D::Com).powi_rev(DI::ENTIRE, -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.1, 10.0, D::Dac).powi_rev(DI::ENTIRE, -8),
+        nd2di(-0.988156915513073, 0.988156915513073, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Def).powi_rev(DI::ENTIRE, -8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Trv).powi_rev(DI::ENTIRE, -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).powi_rev(DI::ENTIRE, -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Def).powi_rev(DI::ENTIRE, -8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.7976931348623157e+308, f64::INFINITY, D::Trv).powi_rev(DI::ENTIRE, -8),
+        nd2di(-2.9387358770557194e-39, 2.9387358770557194e-39, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).powi_rev(DI::ENTIRE, -8),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_powi_rev_dec_bin_test() {
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 0), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 0),
+        nd2di(-5.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 0),
+        nd2di(1.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 0),
+        nd2di(-51.0, 12.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 0),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 0),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 0),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 1), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 1),
+        nd2di(-1.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 1),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 1),
+        nd2di(0.5, 0.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 1),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 1),
+        nd2di(-1.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 1),
+        nd2di(4.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 1),
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 1),
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 1),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 2),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 2),
+        nd2di(-2.23606797749979, 2.23606797749979, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 2),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 2),
+        nd2di(-0.7071067811865476, -0.7071067811865475, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 2),
+        nd2di(2.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 2),
+        nd2di(1.8732342473651362e+28, 1.8732342473651364e+28, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 2),
+        nd2di(-1.8732342473651364e+28, 1.8732342473651364e+28, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 2),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 8),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 8),
+        nd2di(-1.2228445449938519, 1.2228445449938519, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 8),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 8),
+        nd2di(-0.9170040432046713, -0.9170040432046712, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 8),
+        nd2di(1.189207115002721, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 8),
+        nd2di(11698980.640181351, 11698980.640181353, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 8),
+        nd2di(-11698980.640181353, 11698980.640181353, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 8),
+        nd2di(-0.8177654339579425, -0.7498942093324558, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 8),
+        nd2di(0.7498942093324558, 0.8177654339579425, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 8),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 3), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 3),
+        nd2di(-1.0, 1.709975946676697, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 3),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 3),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 3),
+        nd2di(-1.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 3),
+        nd2di(1.5874010519681994, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 3),
+        nd2di(7.05333848924707e+18, 7.053338489247071e+18, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 3),
+        nd2di(7.05333848924707e+18, 7.053338489247071e+18, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 3),
+        nd2di(0.5, 0.5848035476425733, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 3),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), 7), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), 7),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), 7),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), 7),
+        nd2di(-1.0, 1.258498950641827, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), 7),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), 7),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), 7),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), 7),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), 7),
+        nd2di(-1.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), 7),
+        nd2di(1.2190136542044754, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), 7),
+        nd2di(119641942.07258298, 119641942.07258299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, 7),
+        nd2di(119641942.07258298, 119641942.07258299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), 7),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), 7),
+        nd2di(0.7196856730011519, 0.7945974047018524, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), 7),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), -1), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), -1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), -1),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), -1),
+        nd2di(-51.0, 12.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), -1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), -1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), -1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), -1),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), -1),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), -1),
+        nd2di(0.0, 0.25, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), -1),
+        nd2di(2.8498094461577734e-57, 2.849809446157774e-57, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, -1),
+        nd2di(2.8498094461577734e-57, 2.849809446157774e-57, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), -1),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), -1),
+        nd2di(4.999999999999999, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), -1),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), -3), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), -3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), -3),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), -3),
+        nd2di(-51.0, 12.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), -3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), -3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), -3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), -3),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), -3),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), -3),
+        nd2di(0.0, 0.6299605249474366, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), -3),
+        nd2di(1.417768339807477e-19, 1.4177683398074773e-19, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, -3),
+        nd2di(1.417768339807477e-19, 1.4177683398074773e-19, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), -3),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), -3),
+        nd2di(1.7099759466766968, 2.154434690031884, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), -3),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), -2), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), -2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), -2),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), -2),
+        nd2di(-51.0, 12.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), -2),
+        nd2di(-1.4142135623730951, -1.414213562373095, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), -2),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), -2),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), -2),
+        nd2di(-0.5, 0.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), -2),
+        nd2di(5.338360653007413e-29, 5.338360653007414e-29, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, -2),
+        nd2di(-5.338360653007414e-29, 5.338360653007414e-29, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), -2),
+        nd2di(-3.1622776601683795, -2.2360679774997894, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), -2),
+        nd2di(2.2360679774997894, 3.1622776601683795, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), -2),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+    assert_eq2!(DI::EMPTY.powi_rev(nd2di(1.0, 1.0, D::Dac), -8), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).powi_rev(nd2di(-5.0, 5.0, D::Dac), -8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).powi_rev(nd2di(1.0, 5.0, D::Dac), -8),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 5.0, D::Trv).powi_rev(nd2di(-51.0, 12.0, D::Dac), -8),
+        nd2di(-51.0, 12.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).powi_rev(nd2di(-1.0, 1.0, D::Dac), -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).powi_rev(nd2di(2.0, 3.0, D::Dac), -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).powi_rev(nd2di(-2.0, 0.5, D::Dac), -8),
+        nd2di(-1.0905077326652577, -1.0905077326652575, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-27.0, 8.0, D::Trv).powi_rev(nd2di(-1.0, 1.0, D::Dac), -8),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).powi_rev(nd2di(-1.0, 1.0, D::Dac), -8),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(4.0, f64::INFINITY, D::Dac).powi_rev(nd2di(-1.0, f64::INFINITY, D::Dac), -8),
+        nd2di(-0.8408964152537146, 0.8408964152537146, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Def)
+            .powi_rev(nd2di(0.0, f64::INFINITY, D::Dac), -8),
+        nd2di(8.54775326805309e-08, 8.547753268053092e-08, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.5090065455016287e+56, 3.5090065455016287e+56, D::Trv).powi_rev(DI::ENTIRE, -8),
+        nd2di(-8.547753268053092e-08, 8.547753268053092e-08, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Com).powi_rev(nd2di(f64::NEG_INFINITY, -0.5, D::Dac), -8),
+        nd2di(-1.3335214321633242, -1.2228445449938516, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.1, 0.2, D::Dac).powi_rev(nd2di(0.5, 1e+300, D::Dac), -8),
+        nd2di(1.2228445449938516, 1.3335214321633242, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+            .powi_rev(nd2di(8e-323, 8.095e-320, D::Dac), -8),
+        nd2di(8e-323, 8.095e-320, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_sin_rev_test() {
+    assert_eq2!(I::EMPTY.sin_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-2.0, -1.1).sin_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.1, 2.0).sin_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, 0.0).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-0.0, -0.0).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 0.0).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.5, 0.5).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+    assert_eq2!(n2i(-0.5, 0.5).sin_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(I::ENTIRE.sin_rev(I::ENTIRE), I::ENTIRE);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_sin_rev_bin_test() {
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(n2i(-1.0, 1.0)), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(n2i(-10.0, -1.0)), n2i(-10.0, -1.0));
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).sin_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(0.0, 0.0).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-0.0, -0.0).sin_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(-0.0, -0.0).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-1.0, 0.0).sin_rev(n2i(-1.0, 1.0)), n2i(-1.0, 0.0));
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 4.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 0.0)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).sin_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -1.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(-1.0, 1.0)),
+        n2i(0.5235987755982988, 0.5235987755982989)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(2.0, 4.0)),
+        n2i(2.617993877991494, 2.6179938779914944)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(0.5235987755982988, 2.6179938779914944)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.5235987755982988, 0.5235987755982989)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(0.0, 10.0)),
+        n2i(0.5235987755982988, 8.901179185171081)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.94837673636768, -3.665191429188092)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000001.03283, 10000000995.870504)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4499999999999996.5)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.5593551923)
+    );
+    assert_eq2!(n2i(0.5, 0.5).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(n2i(-1.0, 1.0)),
+        n2i(1.224646799147353e-16, 1.2246467991473535e-16)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(n2i(2.0, 4.0)),
+        n2i(3.1415926535897927, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(1.224646799147353e-16, 1.2246467991473535e-16)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(n2i(0.0, 10.0)),
+        n2i(1.224646799147353e-16, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).sin_rev(I::EMPTY),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(-1.0, 1.0)),
+        n2i(-0.5235987755982989, 0.5235987755982989)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(2.0, 4.0)),
+        n2i(2.617993877991494, 3.6651914291880923)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-0.5235987755982989, 0.5235987755982989)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.94837673636768)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(-10.0, -1.0)),
+        n2i(-9.94837673636768, -2.617993877991494)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-0.5, 0.5).sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(I::ENTIRE.sin_rev(n2i(-1.0, 1.0)), n2i(-1.0, 1.0));
+    assert_eq2!(I::ENTIRE.sin_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(I::ENTIRE.sin_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(I::ENTIRE.sin_rev(n2i(-10.0, -1.0)), n2i(-10.0, -1.0));
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        I::ENTIRE.sin_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(I::ENTIRE.sin_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(I::EMPTY.sin_rev(n2i(0.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(-2.0, -1.1).sin_rev(I::ENTIRE), I::EMPTY);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_sin_rev_dec_test() {
+    assert_eq2!(DI::NAI.sin_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.sin_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.sin_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-2.0, -1.1, D::Dac).sin_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(1.1, 2.0, D::Def).sin_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).sin_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_sin_rev_dec_bin_test() {
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 1.0, D::Def).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Dac).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(nd2di(-0.0, -0.0, D::Com).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 0.0, D::Trv).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.5235987755982988, 0.5235987755982989, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.617993877991494, 2.6179938779914944, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(0.5235987755982988, 2.6179938779914944, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.5235987755982988, 0.5235987755982989, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.5235987755982988, 8.901179185171081, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.94837673636768, -3.665191429188092, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000001.03283, 10000000995.870504, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4499999999999996.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.5593551923, D::Trv)
+    );
+    assert_eq2!(nd2di(0.5, 0.5, D::Def).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).sin_rev(nd2di(
+            -1.0,
+            1.0,
+            D::Dac
+        )),
+        nd2di(1.224646799147353e-16, 1.2246467991473535e-16, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).sin_rev(nd2di(
+            2.0,
+            4.0,
+            D::Dac
+        )),
+        nd2di(3.1415926535897927, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).sin_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).sin_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).sin_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).sin_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).sin_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(1.224646799147353e-16, 1.2246467991473535e-16, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).sin_rev(nd2di(
+            0.0,
+            10.0,
+            D::Dac
+        )),
+        nd2di(1.224646799147353e-16, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).sin_rev(nd2di(
+            -10.0,
+            -1.0,
+            D::Dac
+        )),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).sin_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).sin_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).sin_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).sin_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).sin_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).sin_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-0.5235987755982989, 0.5235987755982989, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.617993877991494, 3.6651914291880923, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).sin_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).sin_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).sin_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).sin_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-0.5235987755982989, 0.5235987755982989, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.94837673636768, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.94837673636768, -2.617993877991494, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).sin_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).sin_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).sin_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).sin_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-0.5, 0.5, D::Com).sin_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        DI::ENTIRE.sin_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).sin_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).sin_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).sin_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.sin_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).sin_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).sin_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).sin_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.sin_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).sin_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).sin_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).sin_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.sin_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).sin_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).sin_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(DI::EMPTY.sin_rev(nd2di(0.0, 1.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(nd2di(-2.0, -1.1, D::Dac).sin_rev(DI::ENTIRE), DI::EMPTY);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cos_rev_test() {
+    assert_eq2!(I::EMPTY.cos_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-2.0, -1.1).cos_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.1, 2.0).cos_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, 0.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-0.0, -0.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 0.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.5, 0.5).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+    assert_eq2!(n2i(-0.5, 0.5).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(I::ENTIRE.cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.0, 1.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, -1.0).cos_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cos_rev_bin_test() {
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(n2i(-1.0, 1.0)), n2i(-1.0, 1.0));
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(n2i(-10.0, -1.0)), n2i(-10.0, -1.0));
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).cos_rev(n2i(2.0, 4.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948966, 7.853981633974484)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-7.853981633974484, -1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.5, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.6065527435)
+    );
+    assert_eq2!(n2i(0.0, 0.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-0.0, -0.0).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(-0.0, -0.0).cos_rev(n2i(2.0, 4.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948966, 7.853981633974484)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-7.853981633974484, -1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.5, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.6065527435)
+    );
+    assert_eq2!(n2i(-0.0, -0.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-1.0, 0.0).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(-1.0, 0.0).cos_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948966, 10.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-10.0, -1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, 0.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-1.0, 0.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(0.5, 0.5).cos_rev(n2i(2.0, 4.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.0471975511965979, 1.0471975511965979)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.0471975511965979, 1.0471975511965979)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.0471975511965976, 7.3303828583761845)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-7.3303828583761845, -1.0471975511965976)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000001.556427, 10000000998.488499)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000003.0, 4499999999999999.0)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100002.1301515191)
+    );
+    assert_eq2!(n2i(0.5, 0.5).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(n2i(-1.0, 1.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(n2i(2.0, 4.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.5707963267948966, 1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948966, 1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948963, 7.853981633974484)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-7.853981633974484, -1.5707963267948963)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.5, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16)
+            .cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.6065527435)
+    );
+    assert_eq2!(
+        n2i(1.224646799147353e-16, 1.2246467991473532e-16).cos_rev(I::EMPTY),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(-0.5, 0.5).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(2.0, 4.0)),
+        n2i(2.0, 2.0943951023931957)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-2.0943951023931957, 2.0943951023931957)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.0471975511965976, 8.377580409572783)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-8.377580409572783, -1.0471975511965976)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000001.556427, 10000000998.488499)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.0, 4499999999999999.0)
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.5, 0.5).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.0829539679)
+    );
+    assert_eq2!(n2i(-0.5, 0.5).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(I::ENTIRE.cos_rev(n2i(-1.0, 1.0)), n2i(-1.0, 1.0));
+    assert_eq2!(I::ENTIRE.cos_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(I::ENTIRE.cos_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(I::ENTIRE.cos_rev(n2i(-10.0, -1.0)), n2i(-10.0, -1.0));
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        I::ENTIRE.cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(I::ENTIRE.cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).cos_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+    assert_eq2!(n2i(1.0, 1.0).cos_rev(n2i(2.0, 4.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-6.283185307179587, -6.283185307179586)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000004.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100003.17734907029)
+    );
+    assert_eq2!(n2i(1.0, 1.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-1.0, -1.0).cos_rev(n2i(-1.0, 1.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(0.0, 10.0)),
+        n2i(3.141592653589793, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000003.650824, 10000000996.394104)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4499999999999997.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, -1.0).cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(-1.0, -1.0).cos_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(n2i(-1.0, 1.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(n2i(2.0, 4.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.570796326794897, 1.570796326794897)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948968, 7.853981633974484)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(n2i(-10.0, -1.0)),
+        n2i(-7.853981633974484, -1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.5, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16)
+            .cos_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.6065527435)
+    );
+    assert_eq2!(
+        n2i(-3.216245299353273e-16, -3.2162452993532727e-16).cos_rev(I::EMPTY),
+        I::EMPTY
+    );
+    assert_eq2!(I::EMPTY.cos_rev(n2i(0.0, 1.0)), I::EMPTY);
+    assert_eq2!(n2i(-2.0, -1.1).cos_rev(I::ENTIRE), I::EMPTY);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cos_rev_dec_test() {
+    assert_eq2!(DI::NAI.cos_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.cos_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.cos_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-2.0, -1.1, D::Dac).cos_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(1.1, 2.0, D::Def).cos_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Com).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Dac).cos_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cos_rev_dec_bin_test() {
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 1.0, D::Def).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(1.5707963267948966, 7.853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-7.853981633974484, -1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000002.5, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100001.6065527435, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Dac).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(1.5707963267948966, 7.853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-7.853981633974484, -1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000002.5, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100001.6065527435, D::Trv)
+    );
+    assert_eq2!(nd2di(-0.0, -0.0, D::Com).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(1.5707963267948966, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Trv).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Com).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Dac).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, 0.0, D::Def).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 0.0, D::Trv).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-1.0471975511965979, 1.0471975511965979, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.0471975511965979, 1.0471975511965979, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(1.0471975511965976, 7.3303828583761845, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-7.3303828583761845, -1.0471975511965976, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000001.556427, 10000000998.488499, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Def).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Trv).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000003.0, 4499999999999999.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Dac).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100002.1301515191, D::Trv)
+    );
+    assert_eq2!(nd2di(0.5, 0.5, D::Def).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).cos_rev(nd2di(
+            -1.0,
+            1.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).cos_rev(nd2di(
+            2.0,
+            4.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).cos_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).cos_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).cos_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).cos_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948966, 1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).cos_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948966, 1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).cos_rev(nd2di(
+            0.0,
+            10.0,
+            D::Dac
+        )),
+        nd2di(1.5707963267948963, 7.853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).cos_rev(nd2di(
+            -10.0,
+            -1.0,
+            D::Dac
+        )),
+        nd2di(-7.853981633974484, -1.5707963267948963, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).cos_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).cos_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Def).cos_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000002.5, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Trv).cos_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Com).cos_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100001.6065527435, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.224646799147353e-16, 1.2246467991473532e-16, D::Dac).cos_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 2.0943951023931957, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-2.0943951023931957, 2.0943951023931957, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(1.0471975511965976, 8.377580409572783, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-8.377580409572783, -1.0471975511965976, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000001.556427, 10000000998.488499, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Com).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Dac).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000002.0, 4499999999999999.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Def).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.5, 0.5, D::Trv).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100001.0829539679, D::Trv)
+    );
+    assert_eq2!(nd2di(-0.5, 0.5, D::Com).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        DI::ENTIRE.cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).cos_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).cos_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).cos_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).cos_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).cos_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).cos_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).cos_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).cos_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).cos_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-6.283185307179587, -6.283185307179586, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000004.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100003.17734907029, D::Trv)
+    );
+    assert_eq2!(nd2di(1.0, 1.0, D::Def).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Trv).cos_rev(nd2di(-1.0, 1.0, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Com).cos_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Dac).cos_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Def).cos_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Trv).cos_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Com).cos_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Dac).cos_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Def).cos_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(3.141592653589793, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Trv).cos_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Com).cos_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000003.650824, 10000000996.394104, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Dac).cos_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Def).cos_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4499999999999997.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Trv).cos_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, -1.0, D::Com).cos_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, -1.0, D::Dac).cos_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Def).cos_rev(nd2di(
+            -1.0,
+            1.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Trv).cos_rev(nd2di(
+            2.0,
+            4.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Com).cos_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Dac).cos_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Def).cos_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Trv).cos_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-1.570796326794897, 1.570796326794897, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Com).cos_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Dac).cos_rev(nd2di(
+            0.0,
+            10.0,
+            D::Dac
+        )),
+        nd2di(1.5707963267948968, 7.853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Def).cos_rev(nd2di(
+            -10.0,
+            -1.0,
+            D::Dac
+        )),
+        nd2di(-7.853981633974484, -1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Trv).cos_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Com).cos_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Dac).cos_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000002.5, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Def).cos_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Trv).cos_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100001.6065527435, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.216245299353273e-16, -3.2162452993532727e-16, D::Com).cos_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(DI::EMPTY.cos_rev(nd2di(0.0, 1.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(nd2di(-2.0, -1.1, D::Def).cos_rev(DI::ENTIRE), DI::EMPTY);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_tan_rev_test() {
+    assert_eq2!(I::EMPTY.tan_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1e+300).tan_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+    assert_eq2!(n2i(1e+300, f64::INFINITY).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-1.0, 1.0).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, 0.0).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(-0.0, -0.0).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.0, 1.0).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(f64::NEG_INFINITY, 0.0).tan_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+    assert_eq2!(I::ENTIRE.tan_rev(I::ENTIRE), I::ENTIRE);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_tan_rev_bin_test() {
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(-1.0, 1.0)),
+        n2i(-0.7853981633974484, 0.7853981633974484)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(2.0, 4.0)),
+        n2i(2.356194490192345, 3.926990816987242)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-0.7853981633974484, 0.7853981633974484)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).tan_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-10.0, -2.356194490192345)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).tan_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(0.0, 0.0).tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-0.0, -0.0).tan_rev(n2i(-1.0, 1.0)), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(2.0, 4.0)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-0.0, -0.0).tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(-0.0, -0.0).tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(-1.0, 1.0)),
+        n2i(0.7853981633974483, 0.7853981633974484)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(2.0, 4.0)),
+        n2i(3.9269908169872414, 3.926990816987242)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-2.3561944901923453, 0.7853981633974484)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(0.7853981633974483, 0.7853981633974484)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(0.0, 10.0)),
+        n2i(0.7853981633974483, 7.068583470577035)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-8.639379797371932, -2.356194490192345)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000001.294628, 10000000997.1795)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000001.5, 4499999999999998.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 1.0).tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100002.3919509069)
+    );
+    assert_eq2!(n2i(1.0, 1.0).tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(-1.0, 1.0)),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(2.0, 4.0)),
+        n2i(2.0, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(0.0, 10.0)),
+        n2i(0.0, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-10.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, 0.0).tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(n2i(f64::NEG_INFINITY, 0.0).tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(n2i(-1.0, 1.0)),
+        n2i(-1.2246467991473532e-16, -1.2246467991473527e-16)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(n2i(2.0, 4.0)),
+        n2i(3.1415926535897927, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.2246467991473532e-16, -1.2246467991473527e-16)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(n2i(0.0, 10.0)),
+        n2i(3.1415926535897927, 9.424777960769381)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-9.424777960769381, -3.141592653589793)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.50923, 10000000999.535696)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.5, 4500000000000000.0)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16)
+            .tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0357564167)
+    );
+    assert_eq2!(
+        n2i(-1.2246467991473532e-16, -1.224646799147353e-16).tan_rev(I::EMPTY),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(n2i(-1.0, 1.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(n2i(2.0, 4.0)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(n2i(0.0, 10.0)),
+        n2i(1.5707963267948963, 7.853981633974484)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(n2i(-10.0, -1.0)),
+        n2i(-7.853981633974484, -1.5707963267948966)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000002.080027, 10000000997.9649)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000002.5, 4499999999999998.5)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16)
+            .tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100001.6065527435)
+    );
+    assert_eq2!(
+        n2i(1.6331239353195368e+16, 1.633123935319537e+16).tan_rev(I::EMPTY),
+        I::EMPTY
+    );
+    assert_eq2!(I::ENTIRE.tan_rev(n2i(-1.0, 1.0)), n2i(-1.0, 1.0));
+    assert_eq2!(I::ENTIRE.tan_rev(n2i(2.0, 4.0)), n2i(2.0, 4.0));
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(3.141592653589793, 3.141592653589793)),
+        n2i(3.141592653589793, 3.141592653589793)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(3.1415926535897936, 3.1415926535897936)),
+        n2i(3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(3.141592653589793, 3.1415926535897936)),
+        n2i(3.141592653589793, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(-3.1415926535897936, 3.1415926535897936)),
+        n2i(-3.1415926535897936, 3.1415926535897936)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(-1.5707963267948968, 1.5707963267948968)),
+        n2i(-1.5707963267948968, 1.5707963267948968)
+    );
+    assert_eq2!(I::ENTIRE.tan_rev(n2i(0.0, 10.0)), n2i(0.0, 10.0));
+    assert_eq2!(I::ENTIRE.tan_rev(n2i(-10.0, -1.0)), n2i(-10.0, -1.0));
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(10000000000.0, 10000001000.0)),
+        n2i(10000000000.0, 10000001000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(-1e+300, -9.99999999999999e+299)),
+        n2i(-1e+300, -9.99999999999999e+299)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(4000000000000000.0, 4500000000000000.0)),
+        n2i(4000000000000000.0, 4500000000000000.0)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(6.283185307179586, 6.283185307179587)),
+        n2i(6.283185307179586, 6.283185307179587)
+    );
+    assert_eq2!(
+        I::ENTIRE.tan_rev(n2i(f64::NEG_INFINITY, -100000.0)),
+        n2i(f64::NEG_INFINITY, -100000.0)
+    );
+    assert_eq2!(I::ENTIRE.tan_rev(I::EMPTY), I::EMPTY);
+    assert_eq2!(I::EMPTY.tan_rev(n2i(0.0, 1.0)), I::EMPTY);
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1e+300).tan_rev(I::ENTIRE),
+        I::ENTIRE
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_tan_rev_dec_test() {
+    assert_eq2!(DI::NAI.tan_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.tan_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.tan_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1e+300, D::Dac).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1e+300, f64::INFINITY, D::Def).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Com).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Dac).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_tan_rev_dec_bin_test() {
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-0.7853981633974484, 0.7853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.356194490192345, 3.926990816987242, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).tan_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).tan_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).tan_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).tan_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).tan_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(-0.7853981633974484, 0.7853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -2.356194490192345, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).tan_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).tan_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).tan_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).tan_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).tan_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(nd2di(-1.0, 1.0, D::Def).tan_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).tan_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).tan_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).tan_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).tan_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).tan_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).tan_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).tan_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).tan_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).tan_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).tan_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(nd2di(0.0, 0.0, D::Dac).tan_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).tan_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).tan_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).tan_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).tan_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).tan_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).tan_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Com).tan_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Dac).tan_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Def).tan_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-0.0, -0.0, D::Trv).tan_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(nd2di(-0.0, -0.0, D::Com).tan_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(0.7853981633974483, 0.7853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(3.9269908169872414, 3.926990816987242, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).tan_rev(nd2di(3.141592653589793, 3.141592653589793, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).tan_rev(nd2di(3.1415926535897936, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).tan_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).tan_rev(nd2di(-3.1415926535897936, 3.1415926535897936, D::Dac)),
+        nd2di(-2.3561944901923453, 0.7853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).tan_rev(nd2di(-1.5707963267948968, 1.5707963267948968, D::Dac)),
+        nd2di(0.7853981633974483, 0.7853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.7853981633974483, 7.068583470577035, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-8.639379797371932, -2.356194490192345, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).tan_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000001.294628, 10000000997.1795, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Trv).tan_rev(nd2di(-1e+300, -9.99999999999999e+299, D::Dac)),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).tan_rev(nd2di(4000000000000000.0, 4500000000000000.0, D::Dac)),
+        nd2di(4000000000000001.5, 4499999999999998.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).tan_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).tan_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100002.3919509069, D::Trv)
+    );
+    assert_eq2!(nd2di(1.0, 1.0, D::Trv).tan_rev(DI::EMPTY), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Def).tan_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv).tan_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Def).tan_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(10000000000.0, 10000001000.0, D::Dac)),
+        nd2di(10000000000.0, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Def).tan_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv).tan_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Dac).tan_rev(nd2di(f64::NEG_INFINITY, -100000.0, D::Dac)),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.0, D::Def).tan_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Trv).tan_rev(nd2di(
+            -1.0,
+            1.0,
+            D::Dac
+        )),
+        nd2di(-1.2246467991473532e-16, -1.2246467991473527e-16, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Com).tan_rev(nd2di(
+            2.0,
+            4.0,
+            D::Dac
+        )),
+        nd2di(3.1415926535897927, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Dac).tan_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Def).tan_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Trv).tan_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Com).tan_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Dac).tan_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.2246467991473532e-16, -1.2246467991473527e-16, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Def).tan_rev(nd2di(
+            0.0,
+            10.0,
+            D::Dac
+        )),
+        nd2di(3.1415926535897927, 9.424777960769381, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Trv).tan_rev(nd2di(
+            -10.0,
+            -1.0,
+            D::Dac
+        )),
+        nd2di(-9.424777960769381, -3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Com).tan_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000000.50923, 10000000999.535696, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Dac).tan_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Def).tan_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.5, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Trv).tan_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Com).tan_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100000.0357564167, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.2246467991473532e-16, -1.224646799147353e-16, D::Dac).tan_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Def).tan_rev(nd2di(
+            -1.0,
+            1.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Trv).tan_rev(nd2di(
+            2.0,
+            4.0,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Com).tan_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Dac).tan_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Def).tan_rev(nd2di(
+            3.141592653589793,
+            3.1415926535897936,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Trv).tan_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Com).tan_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Dac).tan_rev(nd2di(
+            0.0,
+            10.0,
+            D::Dac
+        )),
+        nd2di(1.5707963267948963, 7.853981633974484, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Def).tan_rev(nd2di(
+            -10.0,
+            -1.0,
+            D::Dac
+        )),
+        nd2di(-7.853981633974484, -1.5707963267948966, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Trv).tan_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000002.080027, 10000000997.9649, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Com).tan_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Dac).tan_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000002.5, 4499999999999998.5, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Def).tan_rev(nd2di(
+            6.283185307179586,
+            6.283185307179587,
+            D::Dac
+        )),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Trv).tan_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100001.6065527435, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.6331239353195368e+16, 1.633123935319537e+16, D::Com).tan_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        DI::ENTIRE.tan_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).tan_rev(nd2di(2.0, 4.0, D::Dac)),
+        nd2di(2.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).tan_rev(nd2di(
+            3.141592653589793,
+            3.141592653589793,
+            D::Dac
+        )),
+        nd2di(3.141592653589793, 3.141592653589793, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).tan_rev(nd2di(
+            3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.tan_rev(nd2di(3.141592653589793, 3.1415926535897936, D::Dac)),
+        nd2di(3.141592653589793, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).tan_rev(nd2di(
+            -3.1415926535897936,
+            3.1415926535897936,
+            D::Dac
+        )),
+        nd2di(-3.1415926535897936, 3.1415926535897936, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).tan_rev(nd2di(
+            -1.5707963267948968,
+            1.5707963267948968,
+            D::Dac
+        )),
+        nd2di(-1.5707963267948968, 1.5707963267948968, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).tan_rev(nd2di(0.0, 10.0, D::Dac)),
+        nd2di(0.0, 10.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.tan_rev(nd2di(-10.0, -1.0, D::Dac)),
+        nd2di(-10.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).tan_rev(nd2di(
+            10000000000.0,
+            10000001000.0,
+            D::Dac
+        )),
+        nd2di(10000000000.0, 10000001000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).tan_rev(nd2di(
+            -1e+300,
+            -9.99999999999999e+299,
+            D::Dac
+        )),
+        nd2di(-1e+300, -9.99999999999999e+299, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Dac).tan_rev(nd2di(
+            4000000000000000.0,
+            4500000000000000.0,
+            D::Dac
+        )),
+        nd2di(4000000000000000.0, 4500000000000000.0, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.tan_rev(nd2di(6.283185307179586, 6.283185307179587, D::Dac)),
+        nd2di(6.283185307179586, 6.283185307179587, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).tan_rev(nd2di(
+            f64::NEG_INFINITY,
+            -100000.0,
+            D::Dac
+        )),
+        nd2di(f64::NEG_INFINITY, -100000.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).tan_rev(DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(DI::EMPTY.tan_rev(nd2di(0.0, 1.0, D::Dac)), DI::EMPTY);
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1e+300, D::Dac).tan_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cosh_rev_test() {
+    assert_eq2!(I::EMPTY.cosh_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(-1.1, -0.4).cosh_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, f64::INFINITY).cosh_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(0.0, f64::INFINITY).cosh_rev(I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.0, 1.0).cosh_rev(I::ENTIRE), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(1.5430806348152437, 1.705784684221514e+130).cosh_rev(I::ENTIRE),
+        n2i(-300.5632345000001, 300.5632345000001)
+    );
+    assert_eq2!(n2i(f64::NEG_INFINITY, 0.5).cosh_rev(I::ENTIRE), I::EMPTY);
+    assert_eq2!(I::ENTIRE.cosh_rev(I::ENTIRE), I::ENTIRE);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cosh_rev_bin_test() {
+    assert_eq2!(I::EMPTY.cosh_rev(n2i(0.0, f64::INFINITY)), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, f64::INFINITY).cosh_rev(n2i(0.0, f64::INFINITY)),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(1.0, f64::INFINITY).cosh_rev(n2i(1.0, 2.0)),
+        n2i(1.0, 2.0)
+    );
+    assert_eq2!(
+        n2i(0.0, f64::INFINITY).cosh_rev(n2i(-1.0, 1.0)),
+        n2i(-1.0, 1.0)
+    );
+    assert_eq2!(n2i(1.0, 1.0).cosh_rev(n2i(1.0, f64::INFINITY)), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).cosh_rev(n2i(-2.0, 2.0)), n2i(0.0, 0.0));
+    assert_eq2!(
+        n2i(1.5430806348152437, 1.705784684221514e+130).cosh_rev(n2i(f64::NEG_INFINITY, 0.0)),
+        n2i(-300.5632345000001, -0.9999999999999999)
+    );
+    assert_eq2!(
+        n2i(1.5430806348152437, 1.705784684221514e+130).cosh_rev(n2i(0.0, f64::INFINITY)),
+        n2i(0.9999999999999999, 300.5632345000001)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cosh_rev_dec_test() {
+    assert_eq2!(DI::NAI.cosh_rev(DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.cosh_rev(DI::NAI), DI::NAI);
+    assert_eq2!(DI::EMPTY.cosh_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(nd2di(-1.1, -0.4, D::Dac).cosh_rev(DI::ENTIRE), DI::EMPTY);
+    assert_eq2!(
+        nd2di(1.0, f64::INFINITY, D::Def).cosh_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Trv).cosh_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cosh_rev(DI::ENTIRE),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.5430806348152437, 1.705784684221514e+130, D::Dac).cosh_rev(DI::ENTIRE),
+        nd2di(-300.5632345000001, 300.5632345000001, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, 0.5, D::Def).cosh_rev(DI::ENTIRE),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv).cosh_rev(DI::ENTIRE),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_cosh_rev_dec_bin_test() {
+    assert_eq2!(
+        DI::EMPTY.cosh_rev(nd2di(0.0, f64::INFINITY, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, f64::INFINITY, D::Dac).cosh_rev(nd2di(0.0, f64::INFINITY, D::Dac)),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, f64::INFINITY, D::Def).cosh_rev(nd2di(1.0, 2.0, D::Dac)),
+        nd2di(1.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, f64::INFINITY, D::Trv).cosh_rev(nd2di(-1.0, 1.0, D::Dac)),
+        nd2di(-1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).cosh_rev(nd2di(1.0, f64::INFINITY, D::Dac)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).cosh_rev(nd2di(-2.0, 2.0, D::Dac)),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.5430806348152437, 1.705784684221514e+130, D::Def).cosh_rev(nd2di(
+            f64::NEG_INFINITY,
+            0.0,
+            D::Dac
+        )),
+        nd2di(-300.5632345000001, -0.9999999999999999, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.5430806348152437, 1.705784684221514e+130, D::Trv).cosh_rev(nd2di(
+            0.0,
+            f64::INFINITY,
+            D::Dac
+        )),
+        nd2di(0.9999999999999999, 300.5632345000001, D::Trv)
+    );
+}
+
+#[test]
+fn minimal_mul_rev_test() {
+    assert_eq2!(I::EMPTY.mul_rev(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).mul_rev(I::EMPTY, I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).mul_rev(n2i(2.0, 4.0), I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, 2.0).mul_rev(n2i(2.0, 4.0), I::ENTIRE),
+        n2i(1.0, 4.0)
+    );
+    assert_eq2!(
+        n2i(-2.0, -1.0).mul_rev(n2i(2.0, 4.0), I::ENTIRE),
+        n2i(-4.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 2.0).mul_rev(n2i(-4.0, 2.0), I::ENTIRE),
+        n2i(-4.0, 2.0)
+    );
+    assert_eq2!(n2i(-1.0, 1.0).mul_rev(n2i(1.0, 2.0), I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(-1.0, 1.0).mul_rev(n2i(1.0, 2.0), n2i(0.0, 5.0)),
+        n2i(1.0, 5.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).mul_rev(n2i(1.0, 2.0), n2i(-0.5, 0.5)),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).mul_rev(n2i(1.0, 2.0), n2i(-3.0, 0.5)),
+        n2i(-3.0, -1.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 2.0).mul_rev(n2i(1.0, 2.0), I::ENTIRE),
+        n2i(0.5, f64::INFINITY)
+    );
+    assert_eq2!(n2i(0.0, 0.0).mul_rev(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).mul_rev(n2i(-1.0, 2.0), I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(0.0, 0.0).mul_rev(n2i(-1.0, 2.0), n2i(3.0, 4.0)),
+        n2i(3.0, 4.0)
+    );
+    assert_eq2!(
+        n2i(1.0, 3.0).mul_rev(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.3333333333333333, 1.0)
+    );
+    assert_eq2!(
+        n2i(3.0, 3.0).mul_rev(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.3333333333333333, 0.33333333333333337)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).mul_rev(n2i(1.0, 2.0), I::ENTIRE),
+        n2i(-2.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(f64::NEG_INFINITY, -1.0).mul_rev(n2i(1.0, 2.0), n2i(-1.0, 1.0)),
+        n2i(-1.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(2.0, 4.0).mul_rev(n2i(4.0, 8.0), n2i(1.5, 3.0)),
+        n2i(1.5, 3.0)
+    );
+    assert_eq2!(
+        n2i(2.0, 4.0).mul_rev(n2i(4.0, 8.0), n2i(5.0, 6.0)),
+        I::EMPTY
+    );
+}
+
+#[test]
+fn minimal_mul_rev_dec_test() {
+    assert_eq2!(DI::NAI.mul_rev(DI::ENTIRE, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.mul_rev(DI::NAI, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.mul_rev(DI::ENTIRE, DI::NAI), DI::NAI);
+    assert_eq2!(
+        DI::EMPTY.mul_rev(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Dac).mul_rev(DI::EMPTY, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Def).mul_rev(nd2di(2.0, 4.0, D::Dac), DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Trv).mul_rev(
+            nd2di(2.0, 4.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(1.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-2.0, -1.0, D::Com).mul_rev(
+            nd2di(2.0, 4.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(-4.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Dac).mul_rev(
+            nd2di(-4.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(-4.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).mul_rev(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Trv).mul_rev(nd2di(1.0, 2.0, D::Dac), nd2di(0.0, 5.0, D::Def)),
+        nd2di(1.0, 5.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).mul_rev(nd2di(1.0, 2.0, D::Dac), nd2di(-0.5, 0.5, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Dac).mul_rev(nd2di(1.0, 2.0, D::Dac), nd2di(-3.0, 0.5, D::Def)),
+        nd2di(-3.0, -1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 2.0, D::Def).mul_rev(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.5, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).mul_rev(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).mul_rev(
+            nd2di(-1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).mul_rev(nd2di(-1.0, 2.0, D::Dac), nd2di(3.0, 4.0, D::Def)),
+        nd2di(3.0, 4.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 3.0, D::Def).mul_rev(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.3333333333333333, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(3.0, 3.0, D::Trv).mul_rev(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.3333333333333333, 0.33333333333333337, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac).mul_rev(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(-2.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, -1.0, D::Dac)
+            .mul_rev(nd2di(1.0, 2.0, D::Dac), nd2di(-1.0, 1.0, D::Def)),
+        nd2di(-1.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(2.0, 4.0, D::Def).mul_rev(nd2di(4.0, 8.0, D::Dac), nd2di(1.5, 3.0, D::Def)),
+        nd2di(1.5, 3.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(2.0, 4.0, D::Trv).mul_rev(nd2di(4.0, 8.0, D::Dac), nd2di(5.0, 6.0, D::Def)),
+        DI::EMPTY
+    );
+}
@@ -0,0 +1,493 @@
+/*
+ *
+ * Unit tests for the reverse power functions
+ * converted into portable ITL format by Oliver Heimlich.
+ *
+ * Copyright 2015-2017 Oliver Heimlich (oheim@posteo.de)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//Language imports
+
+//Test library imports
+
+//Arithmetic library imports
+
+//Preamble
+use crate::*;
+use inari::{DecInterval as DI, Decoration as D, Interval as I};
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_pow_rev1_test() {
+    assert_eq2!(I::EMPTY.pow_rev1(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).pow_rev1(I::EMPTY, I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).pow_rev1(n2i(1.0, 2.0), I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, 2.0).pow_rev1(n2i(1.0, 2.0), n2i(-2.0, -1.0)),
+        I::EMPTY
+    );
+    assert_eq2!(n2i(1.0, 2.0).pow_rev1(n2i(-2.0, -1.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(1.0, 2.0).pow_rev1(n2i(0.0, 0.0), I::ENTIRE),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(n2i(-2.0, -1.0).pow_rev1(n2i(0.0, 0.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(2.0, 3.0).pow_rev1(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(1.0, 1.0)
+    );
+    assert_eq2!(
+        n2i(-3.0, -2.0).pow_rev1(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(1.0, 1.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).pow_rev1(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).pow_rev1(n2i(1.0, 1.0), n2i(-1.0, 2.0)),
+        n2i(0.0, 2.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).pow_rev1(n2i(2.0, 3.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(-1.0, 1.0).pow_rev1(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        I::ENTIRE.pow_rev1(I::ENTIRE, I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(I::ENTIRE.pow_rev1(n2i(0.0, 0.0), I::ENTIRE), n2i(0.0, 0.0));
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_pow_rev1_dec_test() {
+    assert_eq2!(DI::NAI.pow_rev1(DI::ENTIRE, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.pow_rev1(DI::NAI, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.pow_rev1(DI::ENTIRE, DI::NAI), DI::NAI);
+    assert_eq2!(
+        DI::EMPTY.pow_rev1(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Dac)
+            .pow_rev1(DI::EMPTY, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Def).pow_rev1(nd2di(1.0, 2.0, D::Dac), DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Trv).pow_rev1(nd2di(1.0, 2.0, D::Dac), nd2di(-2.0, -1.0, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Com).pow_rev1(
+            nd2di(-2.0, -1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Dac).pow_rev1(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-2.0, -1.0, D::Def).pow_rev1(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(2.0, 3.0, D::Trv).pow_rev1(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-3.0, -2.0, D::Com).pow_rev1(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(1.0, 1.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).pow_rev1(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).pow_rev1(nd2di(1.0, 1.0, D::Dac), nd2di(-1.0, 2.0, D::Def)),
+        nd2di(0.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).pow_rev1(
+            nd2di(2.0, 3.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Com).pow_rev1(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        DI::ENTIRE.pow_rev1(DI::ENTIRE, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def).pow_rev1(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_pow_rev2_test() {
+    assert_eq2!(I::EMPTY.pow_rev2(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 2.0).pow_rev2(I::EMPTY, I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(2.0, 3.0).pow_rev2(n2i(1.0, 2.0), I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(-2.0, -1.0).pow_rev2(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(2.0, 3.0).pow_rev2(n2i(-2.0, -1.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, 0.0).pow_rev2(n2i(0.0, 0.0), I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).pow_rev2(n2i(0.0, 0.0), n2i(-1.0, 2.0)),
+        n2i(0.0, 2.0)
+    );
+    assert_eq2!(n2i(0.0, 0.0).pow_rev2(n2i(1.0, 2.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).pow_rev2(n2i(1.0, 1.0), I::ENTIRE), I::ENTIRE);
+    assert_eq2!(n2i(1.0, 1.0).pow_rev2(n2i(2.0, 3.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(2.0, 2.0).pow_rev2(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(2.0, 4.0).pow_rev2(n2i(1.0, 1.0), I::ENTIRE),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(
+        n2i(0.5, 0.5).pow_rev2(n2i(1.0, 1.0), n2i(1.0, 2.0)),
+        I::EMPTY
+    );
+    assert_eq2!(I::ENTIRE.pow_rev2(I::ENTIRE, I::ENTIRE), I::ENTIRE);
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_pow_rev2_dec_test() {
+    assert_eq2!(DI::NAI.pow_rev2(DI::ENTIRE, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.pow_rev2(DI::NAI, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.pow_rev2(DI::ENTIRE, DI::NAI), DI::NAI);
+    assert_eq2!(
+        DI::EMPTY.pow_rev2(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Dac)
+            .pow_rev2(DI::EMPTY, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(2.0, 3.0, D::Def).pow_rev2(nd2di(1.0, 2.0, D::Dac), DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-2.0, -1.0, D::Trv).pow_rev2(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(2.0, 3.0, D::Com).pow_rev2(
+            nd2di(-2.0, -1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).pow_rev2(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Def).pow_rev2(nd2di(0.0, 0.0, D::Dac), nd2di(-1.0, 2.0, D::Def)),
+        nd2di(0.0, 2.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).pow_rev2(
+            nd2di(1.0, 2.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Com).pow_rev2(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac).pow_rev2(
+            nd2di(2.0, 3.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(2.0, 2.0, D::Def).pow_rev2(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(2.0, 4.0, D::Trv).pow_rev2(
+            nd2di(1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.5, 0.5, D::Com).pow_rev2(nd2di(1.0, 1.0, D::Dac), nd2di(1.0, 2.0, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        DI::ENTIRE.pow_rev2(DI::ENTIRE, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_atan2_rev1_test() {
+    assert_eq2!(I::EMPTY.atan2_rev1(n2i(0.0, 1.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).atan2_rev1(I::EMPTY, I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).atan2_rev1(n2i(0.0, 1.0), I::EMPTY), I::EMPTY);
+    assert_eq2!(n2i(0.0, 0.0).atan2_rev1(n2i(0.0, 0.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev1(n2i(1.5707963267948966, 1.5707963267948968), I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev1(n2i(-1.5707963267948968, -1.5707963267948966), I::ENTIRE),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(
+        n2i(-1.0, 1.0).atan2_rev1(n2i(0.0, 0.0), I::ENTIRE),
+        n2i(0.0, 0.0)
+    );
+    assert_eq2!(n2i(1.0, 2.0).atan2_rev1(n2i(2.0, 3.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(
+        n2i(-2.0, -1.0).atan2_rev1(n2i(-1.0, 1.0), I::ENTIRE),
+        I::EMPTY
+    );
+    assert_eq2!(I::ENTIRE.atan2_rev1(I::ENTIRE, I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(1.0, 2.0).atan2_rev1(n2i(0.0, 0.0), n2i(1.0, 2.0)),
+        I::EMPTY
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_atan2_rev1_dec_test() {
+    assert_eq2!(DI::NAI.atan2_rev1(DI::ENTIRE, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.atan2_rev1(DI::NAI, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.atan2_rev1(DI::ENTIRE, DI::NAI), DI::NAI);
+    assert_eq2!(
+        DI::EMPTY.atan2_rev1(
+            nd2di(0.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac)
+            .atan2_rev1(DI::EMPTY, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).atan2_rev1(nd2di(0.0, 1.0, D::Dac), DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).atan2_rev1(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).atan2_rev1(
+            nd2di(1.5707963267948966, 1.5707963267948968, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).atan2_rev1(
+            nd2di(-1.5707963267948968, -1.5707963267948966, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(-1.0, 1.0, D::Def).atan2_rev1(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Trv).atan2_rev1(
+            nd2di(2.0, 3.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(-2.0, -1.0, D::Com).atan2_rev1(
+            nd2di(-1.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        DI::ENTIRE.atan2_rev1(DI::ENTIRE, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Def).atan2_rev1(nd2di(0.0, 0.0, D::Dac), nd2di(1.0, 2.0, D::Def)),
+        DI::EMPTY
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_atan2_rev2_test() {
+    assert_eq2!(I::EMPTY.atan2_rev2(n2i(0.0, 1.0), I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).atan2_rev2(I::EMPTY, I::ENTIRE), I::EMPTY);
+    assert_eq2!(n2i(1.0, 1.0).atan2_rev2(n2i(0.0, 1.0), I::EMPTY), I::EMPTY);
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev2(n2i(0.0, 0.0), I::ENTIRE),
+        n2i(0.0, f64::INFINITY)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev2(n2i(3.141592653589793, 3.1415926535897936), I::ENTIRE),
+        n2i(f64::NEG_INFINITY, 0.0)
+    );
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev2(n2i(1.5707963267948966, 1.5707963267948968), I::ENTIRE),
+        I::EMPTY
+    );
+    assert_eq2!(
+        n2i(1.0, 2.0).atan2_rev2(n2i(-1.0, -0.5), I::ENTIRE),
+        I::EMPTY
+    );
+    assert_eq2!(I::ENTIRE.atan2_rev2(I::ENTIRE, I::ENTIRE), I::ENTIRE);
+    assert_eq2!(
+        n2i(0.0, 0.0).atan2_rev2(n2i(0.0, 0.0), n2i(-2.0, -1.0)),
+        I::EMPTY
+    );
+}
+
+#[cfg(feature = "gmp")]
+#[test]
+fn minimal_atan2_rev2_dec_test() {
+    assert_eq2!(DI::NAI.atan2_rev2(DI::ENTIRE, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.atan2_rev2(DI::NAI, DI::ENTIRE), DI::NAI);
+    assert_eq2!(DI::ENTIRE.atan2_rev2(DI::ENTIRE, DI::NAI), DI::NAI);
+    assert_eq2!(
+        DI::EMPTY.atan2_rev2(
+            nd2di(0.0, 1.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Dac)
+            .atan2_rev2(DI::EMPTY, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 1.0, D::Def).atan2_rev2(nd2di(0.0, 1.0, D::Dac), DI::EMPTY),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Trv).atan2_rev2(
+            nd2di(0.0, 0.0, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(0.0, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).atan2_rev2(
+            nd2di(3.141592653589793, 3.1415926535897936, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        nd2di(f64::NEG_INFINITY, 0.0, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Dac).atan2_rev2(
+            nd2di(1.5707963267948966, 1.5707963267948968, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(1.0, 2.0, D::Def).atan2_rev2(
+            nd2di(-1.0, -0.5, D::Dac),
+            nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)
+        ),
+        DI::EMPTY
+    );
+    assert_eq2!(
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+            .atan2_rev2(DI::ENTIRE, nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Def)),
+        nd2di(f64::NEG_INFINITY, f64::INFINITY, D::Trv)
+    );
+    assert_eq2!(
+        nd2di(0.0, 0.0, D::Com).atan2_rev2(nd2di(0.0, 0.0, D::Dac), nd2di(-2.0, -1.0, D::Def)),
+        DI::EMPTY
+    );
+}